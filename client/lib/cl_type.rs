@@ -89,6 +89,13 @@ pub(crate) fn supported_cl_types() -> Vec<(&'static str, CLType)> {
     ]
 }
 
+/// Returns whether a value of the given `CLType` can be parsed from a string.
+pub(crate) fn is_supported(cl_type: &CLType) -> bool {
+    supported_cl_types()
+        .iter()
+        .any(|(_, supported_type)| supported_type == cl_type)
+}
+
 /// Functions for use in help commands.
 pub mod help {
     use std::convert::TryFrom;
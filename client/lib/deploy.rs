@@ -11,7 +11,7 @@ use casper_node::{
     rpcs::{account::PutDeploy, chain::GetBlockResult, info::GetDeploy, RpcWithParams},
     types::{Deploy, DeployHash, TimeDiff, Timestamp},
 };
-use casper_types::SecretKey;
+use casper_types::{PublicKey, SecretKey};
 
 use crate::{
     error::{Error, Result},
@@ -104,6 +104,9 @@ pub struct DeployParams {
 
     /// The name of the chain this `Deploy` will be considered for inclusion in.
     pub chain_name: String,
+
+    /// The account paying for this `Deploy`, if other than the account of `secret_key`.
+    pub fee_payer: Option<PublicKey>,
 }
 
 /// An extension trait that adds some client-specific functionality to `Deploy`.
@@ -144,6 +147,7 @@ impl DeployExt for Deploy {
             gas_price,
             dependencies,
             chain_name,
+            fee_payer,
             secret_key,
         } = params;
         let mut rng = casper_node::new_rng();
@@ -153,6 +157,7 @@ impl DeployExt for Deploy {
             gas_price,
             dependencies,
             chain_name,
+            fee_payer,
             payment,
             session,
             &secret_key,
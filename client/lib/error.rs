@@ -4,7 +4,7 @@ use humantime::{DurationError, TimestampError};
 use jsonrpc_lite::JsonRpc;
 use thiserror::Error;
 
-use casper_node::crypto::Error as CryptoError;
+use casper_node::{crypto::Error as CryptoError, EvidenceVerificationError};
 use casper_types::{
     bytesrepr::Error as ToBytesError, CLValueError, UIntParseError, URefFromStrError,
 };
@@ -119,6 +119,10 @@ pub enum Error {
     #[error("Invalid response: {0}")]
     InvalidResponse(#[from] ValidateResponseError),
 
+    /// Evidence against a validator failed verification.
+    #[error("Invalid evidence: {0}")]
+    InvalidEvidence(#[from] EvidenceVerificationError),
+
     /// Must call FFI's setup function prior to making ffi calls.
     #[cfg(feature = "ffi")]
     #[error("Failed to call casper_setup_client()")]
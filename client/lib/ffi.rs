@@ -52,6 +52,7 @@ pub enum casper_error_t {
     CASPER_FFI_SETUP_NOT_CALLED = -21,
     CASPER_FFI_PTR_NULL_BUT_REQUIRED = -22,
     CASPER_CONFLICTING_ARGUMENTS = -23,
+    CASPER_INVALID_EVIDENCE = -24,
 }
 
 trait AsFFIError {
@@ -84,6 +85,7 @@ impl AsFFIError for Error {
             Error::FFISetupNotCalled => casper_error_t::CASPER_FFI_SETUP_NOT_CALLED,
             Error::FFIPtrNullButRequired(_) => casper_error_t::CASPER_FFI_PTR_NULL_BUT_REQUIRED,
             Error::ConflictingArguments { .. } => casper_error_t::CASPER_CONFLICTING_ARGUMENTS,
+            Error::InvalidEvidence(_) => casper_error_t::CASPER_INVALID_EVIDENCE,
         }
     }
 }
@@ -632,6 +634,7 @@ pub struct casper_deploy_params_t {
     dependencies: *const *const c_char,
     dependencies_len: usize,
     chain_name: *const c_char,
+    fee_payer: *const c_char,
 }
 
 impl TryInto<super::DeployStrParams<'_>> for casper_deploy_params_t {
@@ -643,6 +646,7 @@ impl TryInto<super::DeployStrParams<'_>> for casper_deploy_params_t {
         let ttl = unsafe_str_arg(self.ttl, "casper_deploy_params_t.ttl")?;
         let gas_price = unsafe_str_arg(self.gas_price, "casper_deploy_params_t.gas_price")?;
         let chain_name = unsafe_str_arg(self.chain_name, "casper_deploy_params_t.chain_name")?;
+        let fee_payer = unsafe_str_arg(self.fee_payer, "casper_deploy_params_t.fee_payer")?;
         let dependencies = unsafe_vec_of_str_arg(
             self.dependencies,
             self.dependencies_len,
@@ -655,6 +659,7 @@ impl TryInto<super::DeployStrParams<'_>> for casper_deploy_params_t {
             gas_price,
             chain_name,
             dependencies,
+            fee_payer,
         })
     }
 }
@@ -797,6 +802,7 @@ impl TryInto<super::SessionStrParams<'static>> for casper_session_params_t {
             session_args_complex,
             session_version,
             session_entry_point,
+            session_batch: Vec::new(),
         })
     }
 }
@@ -22,14 +22,18 @@ mod parsing;
 mod rpc;
 mod validation;
 
-use std::{convert::TryInto, fs::File};
+use std::{
+    convert::{TryFrom, TryInto},
+    fs::File,
+    io::BufReader,
+};
 
 use jsonrpc_lite::JsonRpc;
 use serde::Serialize;
 
-use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_node::types::Deploy;
-use casper_types::{UIntParseError, U512};
+use casper_execution_engine::core::engine_state::{BatchedCall, ExecutableDeployItem};
+use casper_node::{types::Deploy, JsonEvidence};
+use casper_types::{EntryPoint, PublicKey, UIntParseError, U512};
 
 pub use cl_type::help;
 pub use deploy::ListDeploysResult;
@@ -374,6 +378,57 @@ pub fn get_balance(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(state_root_hash, purse)
 }
 
+/// Retrieves the ABI of a contract, i.e. its entry points, from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `key` must be the formatted [`Key::Hash`](https://docs.rs/casper-types/latest/casper-types/enum.Key.html)
+///   of a contract or of a contract package, e.g.
+/// ```text
+/// hash-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20
+/// ```
+/// * `version` must be a `u32` representing the version of the contract within the contract
+///   package, or empty. If empty and `key` is a contract package, the highest enabled version is
+///   used. It must be empty if `key` is a contract.
+pub fn get_contract_abi(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    key: &str,
+    version: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_contract_abi(
+        state_root_hash,
+        key,
+        version,
+    )
+}
+
+/// Checks and encodes args for an entry point of a contract by the types its parameters are
+/// declared with, e.g. as retrieved via [`get_contract_abi()`](fn.get_contract_abi.html).
+///
+/// Returns the args in the JSON format of the file read via `session_args_complex` or
+/// `payment_args_complex`, see [`SessionStrParams`](struct.SessionStrParams.html).
+///
+/// * `entry_point` is the entry point to be called.
+/// * `args` must contain exactly one arg per parameter of the entry point, each formatted as
+///   `"NAME='VALUE'"`, or `"NAME=null"` for an optional value which is `None`. The supported types
+///   are the same as for simple args, see
+///   [`help::supported_cl_type_list()`](help/fn.supported_cl_type_list.html).
+pub fn encode_args_by_abi(entry_point: &EntryPoint, args: &[&str]) -> Result<String> {
+    parsing::abi_args_to_json(entry_point, args)
+}
+
 /// Retrieves era information from the network.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -419,6 +474,93 @@ pub fn get_auction_info(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_auction_info()
 }
 
+/// Retrieves the evidence against a faulty validator in a given era from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `era_id` must be a `u64` representing the era in which the validator is faulty.
+/// * `public_key` must be the hex-encoded public key of the faulty validator.
+pub fn get_evidence(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    era_id: &str,
+    public_key: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_evidence(era_id, public_key)
+}
+
+/// Retrieves a page of the deploys waiting to be included in a block from the node.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `maybe_account` must be the hex-encoded public key of an account, or empty. If given, only
+///   deploys from that account are returned.
+/// * `offset` must be a `u32` representing the number of deploys to skip.
+/// * `limit` must be a `u32` representing the maximum number of deploys to return. The node returns
+///   at most 100 deploys per request.
+pub fn get_pending_deploys(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_account: &str,
+    offset: &str,
+    limit: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_pending_deploys(
+        maybe_account,
+        offset,
+        limit,
+    )
+}
+
+/// Verifies previously-saved evidence against a faulty validator, without contacting a node.
+///
+/// Returns the public key of the faulty validator if the evidence is valid.
+///
+/// * `evidence_path` specifies the path to the evidence file. This can be the saved response of the
+///   `info_get_evidence` RPC, its `result`, or just the `evidence` itself.
+/// * `validators_path` specifies the path to a file containing a JSON array of the hex-encoded
+///   public keys of all validators in the era.
+pub fn verify_evidence(evidence_path: &str, validators_path: &str) -> Result<PublicKey> {
+    let read_json = |path: &str, context: &str| -> Result<serde_json::Value> {
+        let input = File::open(path).map_err(|error| Error::IoError {
+            context: format!("unable to read {} file at '{}'", context, path),
+            error,
+        })?;
+        Ok(serde_json::from_reader(BufReader::new(input))?)
+    };
+
+    let mut evidence = read_json(evidence_path, "evidence")?;
+    for field in &["result", "evidence"] {
+        if let Some(inner) = evidence.get_mut(field) {
+            evidence = inner.take();
+        }
+    }
+    let evidence: JsonEvidence = serde_json::from_value(evidence)?;
+    let validators: Vec<PublicKey> =
+        serde_json::from_value(read_json(validators_path, "validators")?)?;
+
+    evidence.verify(validators)?;
+    Ok(*evidence.perpetrator())
+}
+
 /// Retrieves information and examples for all currently supported RPCs.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -465,6 +607,13 @@ pub struct DeployStrParams<'a> {
     /// Name of the chain, to avoid the `Deploy` from being accidentally or maliciously included in
     /// a different chain.
     pub chain_name: &'a str,
+    /// Hex-encoded public key of the account paying for the `Deploy`, if other than the account of
+    /// `secret_key`.
+    ///
+    /// If `fee_payer` is empty, the `Deploy`'s own account pays.  Otherwise the `Deploy` must also
+    /// be signed by the fee payer, e.g. via [`sign_deploy_file`](fn.sign_deploy_file.html), before
+    /// it is sent to the network.
+    pub fee_payer: &'a str,
 }
 
 impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
@@ -478,6 +627,7 @@ impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
             gas_price,
             dependencies,
             chain_name,
+            fee_payer,
         } = self;
         parsing::parse_deploy_params(
             secret_key,
@@ -486,6 +636,7 @@ impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
             gas_price,
             &dependencies,
             chain_name,
+            fee_payer,
         )
     }
 }
@@ -741,8 +892,25 @@ impl<'a> TryInto<ExecutableDeployItem> for SessionStrParams<'a> {
             session_args_complex,
             session_version,
             session_entry_point,
+            session_batch,
         } = self;
 
+        if !session_batch.is_empty() {
+            let calls = session_batch
+                .into_iter()
+                .map(|call| {
+                    let item: ExecutableDeployItem = call.try_into()?;
+                    BatchedCall::try_from(item).map_err(|item| {
+                        Error::InvalidArgument(
+                            "session_batch",
+                            format!("{} is not a stored contract call", item),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(ExecutableDeployItem::Batch { calls });
+        }
+
         parsing::parse_session_info(
             session_hash,
             session_name,
@@ -788,6 +956,7 @@ pub struct SessionStrParams<'a> {
     session_args_complex: &'a str,
     session_version: &'a str,
     session_entry_point: &'a str,
+    session_batch: Vec<SessionStrParams<'a>>,
 }
 
 impl<'a> SessionStrParams<'a> {
@@ -906,6 +1075,18 @@ impl<'a> SessionStrParams<'a> {
             ..Default::default()
         }
     }
+
+    /// Constructs a `SessionStrParams` which calls several stored contracts in order, atomically
+    /// and within a single session gas budget.
+    ///
+    /// * `session_batch` is the list of calls, each constructed using one of `with_hash`,
+    ///   `with_name`, `with_package_hash` or `with_package_name`.
+    pub fn with_batch(session_batch: Vec<SessionStrParams<'a>>) -> Self {
+        Self {
+            session_batch,
+            ..Default::default()
+        }
+    }
 }
 
 /// When `verbosity_level` is `1`, the value will be printed to `stdout` with long string fields
@@ -1044,6 +1225,26 @@ mod param_tests {
                 other => panic!("incorrect type parsed {:?}", other),
             }
         }
+
+        #[test]
+        pub fn with_batch() {
+            let params: Result<ExecutableDeployItem> = SessionStrParams::with_batch(vec![
+                SessionStrParams::with_name(NAME, ENTRYPOINT, args_simple(), ""),
+                SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, vec![], ""),
+            ])
+            .try_into();
+            match params {
+                Ok(ExecutableDeployItem::Batch { calls }) => {
+                    assert_eq!(calls.len(), 2);
+                    assert!(matches!(calls[0], BatchedCall::StoredContractByName { .. }));
+                    assert!(matches!(
+                        calls[1],
+                        BatchedCall::StoredVersionedContractByHash { .. }
+                    ));
+                }
+                other => panic!("incorrect type parsed {:?}", other),
+            }
+        }
     }
 
     /// Sample data creation methods for PaymentStrParams
@@ -1248,5 +1449,26 @@ mod param_tests {
                 .into())
             );
         }
+
+        #[test]
+        fn should_convert_with_fee_payer() {
+            let mut params = test_value();
+            params.fee_payer = "0119bf44096984cdfe8541bac167dc3b96c85086aa30b6b6cb0c5c38ad703166e1";
+            let deploy_params: DeployParams = params.try_into().expect("should convert");
+            assert!(deploy_params.fee_payer.is_some());
+        }
+
+        #[test]
+        fn should_fail_to_convert_with_bad_fee_payer() {
+            let mut params = test_value();
+            params.fee_payer = "not a public key";
+            let result: StdResult<DeployParams, Error> = params.try_into();
+            let result = result.map(|_| ());
+            if let Err(Error::CryptoError { context, .. }) = result {
+                assert_eq!(context, "fee_payer");
+            } else {
+                panic!("should be an error")
+            }
+        }
     }
 }
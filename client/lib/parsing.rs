@@ -1,9 +1,9 @@
 //! This module contains structs and helpers which are used by multiple subcommands related to
 //! creating deploys.
 
-use std::{convert::TryInto, fs, io, path::PathBuf, str::FromStr};
+use std::{collections::BTreeMap, convert::TryInto, fs, io, path::PathBuf, str::FromStr};
 
-use serde::{self, Deserialize};
+use serde::{self, Deserialize, Serialize};
 
 use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
 use casper_node::{
@@ -11,7 +11,8 @@ use casper_node::{
     types::{DeployHash, TimeDiff, Timestamp},
 };
 use casper_types::{
-    bytesrepr, AsymmetricType, CLType, CLValue, HashAddr, Key, NamedArg, PublicKey, RuntimeArgs,
+    bytesrepr::{self, ToBytes},
+    AsymmetricType, CLType, CLValue, EntryPoint, HashAddr, Key, NamedArg, PublicKey, RuntimeArgs,
     SecretKey, UIntParseError, U512,
 };
 
@@ -133,17 +134,20 @@ mod arg_simple {
 mod args_complex {
     use super::*;
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize)]
     #[serde(rename_all = "snake_case")]
     enum DeployArgValue {
         /// Contains `CLValue` serialized into bytes in base16 form.
-        #[serde(deserialize_with = "hex::deserialize")]
+        #[serde(
+            serialize_with = "hex::serialize",
+            deserialize_with = "hex::deserialize"
+        )]
         RawBytes(Vec<u8>),
     }
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize)]
     #[serde(rename_all = "snake_case")]
-    struct DeployArg {
+    pub(super) struct DeployArg {
         /// Deploy argument's name.
         name: String,
         value: DeployArgValue,
@@ -196,6 +200,20 @@ mod args_complex {
         }
     }
 
+    /// Returns the given args in the JSON format read from a file by `parse()`.
+    pub(crate) fn to_json(runtime_args: RuntimeArgs) -> Result<String> {
+        let cl_values: BTreeMap<String, CLValue> = runtime_args.into();
+        let mut args = Vec::with_capacity(cl_values.len());
+        for (name, cl_value) in cl_values {
+            let bytes = cl_value.to_bytes()?;
+            args.push(DeployArg {
+                name,
+                value: DeployArgValue::RawBytes(bytes),
+            });
+        }
+        Ok(serde_json::to_string_pretty(&args)?)
+    }
+
     fn get(path: &str) -> io::Result<RuntimeArgs> {
         let bytes = fs::read(path)?;
         // Received structured args in JSON format.
@@ -209,6 +227,86 @@ mod args_complex {
     }
 }
 
+/// Handles retrieval of args for an entry point, each encoded by the type its parameter is declared
+/// with in the contract's ABI.
+mod args_abi {
+    use super::*;
+
+    const ARG_VALUE_NAME: &str = r#""NAME='VALUE'" OR "NAME=null""#;
+
+    pub(crate) fn parse(entry_point: &EntryPoint, values: &[&str]) -> Result<RuntimeArgs> {
+        let mut values_by_name = BTreeMap::new();
+        for arg in values {
+            let (name, value) = split_arg(arg)?;
+            if values_by_name.insert(name, value).is_some() {
+                return Err(Error::InvalidArgument(
+                    "args",
+                    format!("arg {} is given more than once", name),
+                ));
+            }
+        }
+
+        let mut runtime_args = RuntimeArgs::new();
+        for parameter in entry_point.args() {
+            let value = values_by_name.remove(parameter.name()).ok_or_else(|| {
+                Error::InvalidArgument(
+                    "args",
+                    format!(
+                        "missing arg {} of type {:?} for entry point {}",
+                        parameter.name(),
+                        parameter.cl_type(),
+                        entry_point.name()
+                    ),
+                )
+            })?;
+            if !cl_type::is_supported(parameter.cl_type()) {
+                return Err(Error::InvalidArgument(
+                    "args",
+                    format!(
+                        "arg {} is of type {:?}, which can't be given as a string; use complex args \
+                        instead",
+                        parameter.name(),
+                        parameter.cl_type()
+                    ),
+                ));
+            }
+            let cl_value = cl_type::parts_to_cl_value(parameter.cl_type().clone(), value)?;
+            runtime_args.insert_cl_value(parameter.name(), cl_value);
+        }
+
+        if let Some(name) = values_by_name.keys().next() {
+            return Err(Error::InvalidArgument(
+                "args",
+                format!(
+                    "entry point {} has no parameter named {}",
+                    entry_point.name(),
+                    name
+                ),
+            ));
+        }
+        Ok(runtime_args)
+    }
+
+    /// Splits a single arg of the form `NAME='VALUE'` into its constituent parts.
+    fn split_arg(arg: &str) -> Result<(&str, &str)> {
+        let parts: Vec<_> = arg.splitn(2, '=').collect();
+        if parts.len() != 2 || parts[0].is_empty() {
+            return Err(Error::InvalidCLValue(format!(
+                "arg {} should be formatted as {}",
+                arg, ARG_VALUE_NAME
+            )));
+        }
+        Ok((parts[0], parts[1]))
+    }
+}
+
+/// Returns the given args for an entry point, encoded by the types declared in the contract's ABI,
+/// in the JSON format of complex args.
+pub(super) fn abi_args_to_json(entry_point: &EntryPoint, args: &[&str]) -> Result<String> {
+    let runtime_args = args_abi::parse(entry_point, args)?;
+    args_complex::to_json(runtime_args)
+}
+
 const STANDARD_PAYMENT_ARG_NAME: &str = "amount";
 fn standard_payment(value: &str) -> Result<RuntimeArgs> {
     if value.is_empty() {
@@ -339,6 +437,7 @@ pub(super) fn parse_deploy_params(
     gas_price: &str,
     dependencies: &[&str],
     chain_name: &str,
+    fee_payer: &str,
 ) -> Result<DeployParams> {
     let secret_key = self::secret_key(secret_key)?;
     let timestamp = self::timestamp(timestamp)?;
@@ -346,6 +445,7 @@ pub(super) fn parse_deploy_params(
     let gas_price = self::gas_price(gas_price)?;
     let dependencies = self::dependencies(dependencies)?;
     let chain_name = chain_name.to_string();
+    let fee_payer = self::fee_payer(fee_payer)?;
 
     Ok(DeployParams {
         timestamp,
@@ -353,6 +453,7 @@ pub(super) fn parse_deploy_params(
         gas_price,
         dependencies,
         chain_name,
+        fee_payer,
         secret_key,
     })
 }
@@ -580,6 +681,18 @@ fn version(value: &str) -> Result<u32> {
         .map_err(|error| Error::FailedToParseInt("version", error))
 }
 
+fn fee_payer(value: &str) -> Result<Option<PublicKey>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    PublicKey::from_hex(value)
+        .map(Some)
+        .map_err(|error| Error::CryptoError {
+            context: "fee_payer",
+            error: error.into(),
+        })
+}
+
 fn account(value: &str) -> Result<PublicKey> {
     PublicKey::from_hex(value).map_err(|error| Error::CryptoError {
         context: "account",
@@ -602,8 +715,8 @@ mod tests {
     use std::{convert::TryFrom, result::Result as StdResult};
 
     use casper_types::{
-        account::AccountHash, bytesrepr::ToBytes, AccessRights, CLTyped, CLValue, NamedArg,
-        PublicKey, RuntimeArgs, URef, U128, U256, U512,
+        account::AccountHash, bytesrepr::ToBytes, AccessRights, CLTyped, CLValue, EntryPointAccess,
+        EntryPointType, NamedArg, Parameter, PublicKey, RuntimeArgs, URef, U128, U256, U512,
     };
 
     use crate::{PaymentStrParams, SessionStrParams};
@@ -870,6 +983,81 @@ mod tests {
         invalid_simple_args_test(bad::LARGE_2K_INPUT);
     }
 
+    fn transfer_entry_point() -> EntryPoint {
+        EntryPoint::new(
+            "transfer",
+            vec![
+                Parameter::new("amount", CLType::U512),
+                Parameter::new("id", CLType::Option(Box::new(CLType::U64))),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        )
+    }
+
+    #[test]
+    fn should_parse_args_by_abi() {
+        let entry_point = transfer_entry_point();
+        let mut expected = RuntimeArgs::new();
+        expected.insert("amount", U512::from(10)).unwrap();
+        expected.insert("id", Some(7u64)).unwrap();
+        assert_eq!(
+            args_abi::parse(&entry_point, &["id='7'", "amount='10'"]).expect("should parse"),
+            expected
+        );
+
+        let mut expected = RuntimeArgs::new();
+        expected.insert("amount", U512::from(10)).unwrap();
+        expected.insert::<_, Option<u64>>("id", None).unwrap();
+        assert_eq!(
+            args_abi::parse(&entry_point, &["amount='10'", "id=null"]).expect("should parse"),
+            expected
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_args_not_matching_abi() {
+        let entry_point = transfer_entry_point();
+        for args in &[
+            &["amount='10'"][..],
+            &["amount='ten'", "id=null"][..],
+            &["amount='10'", "id=null", "memo='x'"][..],
+            &["amount='10'", "amount='11'", "id=null"][..],
+            &["amount:u512='10'", "id=null"][..],
+        ] {
+            assert!(
+                args_abi::parse(&entry_point, args).is_err(),
+                "{:?} should be an error",
+                args
+            );
+        }
+
+        let entry_point = EntryPoint::new(
+            "set_items",
+            vec![Parameter::new("items", CLType::List(Box::new(CLType::U8)))],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        assert!(args_abi::parse(&entry_point, &["items='1'"]).is_err());
+    }
+
+    #[test]
+    fn should_encode_args_by_abi_as_complex_args() {
+        let json = abi_args_to_json(&transfer_entry_point(), &["amount='10'", "id=null"])
+            .expect("should encode");
+        let args: Vec<NamedArg> = serde_json::from_str::<Vec<args_complex::DeployArg>>(&json)
+            .expect("should deserialize")
+            .into_iter()
+            .map(NamedArg::from)
+            .collect();
+        let mut expected = RuntimeArgs::new();
+        expected.insert("amount", U512::from(10)).unwrap();
+        expected.insert::<_, Option<u64>>("id", None).unwrap();
+        assert_eq!(RuntimeArgs::from(args), expected);
+    }
+
     #[test]
     fn should_fail_to_parse_conflicting_arg_types() {
         assert_eq!(
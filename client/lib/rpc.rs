@@ -17,8 +17,14 @@ use casper_node::{
             GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
         },
         docs::ListRpcs,
-        info::{GetDeploy, GetDeployParams},
-        state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
+        info::{
+            GetDeploy, GetDeployParams, GetEvidence, GetEvidenceParams, GetPendingDeploys,
+            GetPendingDeploysParams,
+        },
+        state::{
+            GetAuctionInfo, GetBalance, GetBalanceParams, GetContractAbi, GetContractAbiParams,
+            GetItem, GetItemParams,
+        },
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
     },
     types::{BlockHash, Deploy, DeployHash},
@@ -87,6 +93,48 @@ impl RpcCall {
         GetDeploy::request_with_map_params(self, params)
     }
 
+    pub(crate) fn get_evidence(self, era_id: &str, public_key: &str) -> Result<JsonRpc> {
+        let era_id = era_id
+            .parse::<u64>()
+            .map_err(|error| Error::FailedToParseInt("era_id", error))?;
+        let public_key = PublicKey::from_hex(public_key).map_err(|error| Error::CryptoError {
+            context: "public_key",
+            error: error.into(),
+        })?;
+        let params = GetEvidenceParams { era_id, public_key };
+        GetEvidence::request_with_map_params(self, params)
+    }
+
+    pub(crate) fn get_pending_deploys(
+        self,
+        maybe_account: &str,
+        offset: &str,
+        limit: &str,
+    ) -> Result<JsonRpc> {
+        let account = if maybe_account.is_empty() {
+            None
+        } else {
+            let public_key =
+                PublicKey::from_hex(maybe_account).map_err(|error| Error::CryptoError {
+                    context: "account",
+                    error: error.into(),
+                })?;
+            Some(public_key)
+        };
+        let offset = offset
+            .parse::<u32>()
+            .map_err(|error| Error::FailedToParseInt("offset", error))?;
+        let limit = limit
+            .parse::<u32>()
+            .map_err(|error| Error::FailedToParseInt("limit", error))?;
+        let params = GetPendingDeploysParams {
+            account,
+            offset,
+            limit,
+        };
+        GetPendingDeploys::request_with_map_params(self, params)
+    }
+
     pub(crate) fn get_item(self, state_root_hash: &str, key: &str, path: &str) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
@@ -149,6 +197,35 @@ impl RpcCall {
         Ok(response)
     }
 
+    pub(crate) fn get_contract_abi(
+        self,
+        state_root_hash: &str,
+        key: &str,
+        version: &str,
+    ) -> Result<JsonRpc> {
+        let state_root_hash =
+            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                context: "state_root_hash",
+                error,
+            })?;
+        let key = Key::from_formatted_str(key).map_err(|_| Error::FailedToParseKey)?;
+        let version = if version.is_empty() {
+            None
+        } else {
+            let version = version
+                .parse::<u32>()
+                .map_err(|error| Error::FailedToParseInt("version", error))?;
+            Some(version)
+        };
+
+        let params = GetContractAbiParams {
+            state_root_hash,
+            key: key.to_formatted_string(),
+            version,
+        };
+        GetContractAbi::request_with_map_params(self, params)
+    }
+
     pub(crate) fn get_era_info_by_switch_block(
         self,
         maybe_block_identifier: &str,
@@ -354,6 +431,10 @@ impl RpcClient for GetStateRootHash {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetContractAbi {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetItem {
     const RPC_METHOD: &'static str = <Self as RpcWithParams>::METHOD;
 }
@@ -366,6 +447,14 @@ impl RpcClient for GetAuctionInfo {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetEvidence {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
+impl RpcClient for GetPendingDeploys {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for ListRpcs {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -387,7 +476,10 @@ impl IntoJsonMap for GetBlockParams {}
 impl IntoJsonMap for GetBlockTransfersParams {}
 impl IntoJsonMap for GetStateRootHashParams {}
 impl IntoJsonMap for GetDeployParams {}
+impl IntoJsonMap for GetEvidenceParams {}
+impl IntoJsonMap for GetPendingDeploysParams {}
 impl IntoJsonMap for GetBalanceParams {}
 impl IntoJsonMap for GetItemParams {}
+impl IntoJsonMap for GetContractAbiParams {}
 impl IntoJsonMap for GetEraInfoParams {}
 impl IntoJsonMap for ListRpcs {}
@@ -26,6 +26,7 @@ pub(super) enum DisplayOrder {
     GasPrice,
     Dependencies,
     ChainName,
+    FeePayer,
     SessionCode,
     SessionArgSimple,
     SessionArgsComplex,
@@ -314,6 +315,31 @@ pub(super) mod chain_name {
     }
 }
 
+/// Handles providing the arg for and retrieval of the fee payer.
+pub(super) mod fee_payer {
+    use super::*;
+
+    const ARG_NAME: &str = "fee-payer";
+    const ARG_VALUE_NAME: &str = common::ARG_HEX_STRING;
+    const ARG_HELP: &str =
+        "Hex-encoded public key of the account paying for the deploy, if other than the account of \
+        the secret key. The fee payer must also sign the deploy, e.g. using the 'sign-deploy' \
+        subcommand";
+
+    pub(in crate::deploy) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::FeePayer as usize)
+    }
+
+    pub(in crate::deploy) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
 /// Handles providing the arg for and retrieval of the session code bytes.
 pub(super) mod session_path {
     use super::*;
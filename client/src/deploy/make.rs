@@ -21,6 +21,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for MakeDeploy {
             .display_order(display_order);
         let subcommand = creation_common::apply_common_session_options(subcommand);
         let subcommand = creation_common::apply_common_payment_options(subcommand);
+        let subcommand = subcommand.arg(creation_common::fee_payer::arg());
         creation_common::apply_common_creation_options(subcommand, false)
     }
 
@@ -33,6 +34,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for MakeDeploy {
         let gas_price = creation_common::gas_price::get(matches);
         let dependencies = creation_common::dependencies::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
+        let fee_payer = creation_common::fee_payer::get(matches);
 
         let session_str_params = creation_common::session_str_params(matches);
         let payment_str_params = creation_common::payment_str_params(matches);
@@ -48,6 +50,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for MakeDeploy {
                 dependencies,
                 gas_price,
                 chain_name,
+                fee_payer,
             },
             session_str_params,
             payment_str_params,
@@ -141,6 +141,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for Transfer {
                 dependencies,
                 gas_price,
                 chain_name,
+                fee_payer: "",
             },
             payment_str_params,
         )
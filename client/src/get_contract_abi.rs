@@ -0,0 +1,330 @@
+use std::{
+    io::{self, BufRead, Write},
+    str,
+};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+use jsonrpc_lite::JsonRpc;
+
+use casper_client::Error;
+use casper_node::rpcs::state::{GetContractAbi, GetContractAbiResult};
+use casper_types::{CLType, EntryPoint, EntryPointAccess};
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    StateRootHash,
+    Key,
+    ContractVersion,
+    EntryPoint,
+    Arg,
+    Interactive,
+}
+
+/// Handles providing the arg for and retrieval of the key of the contract or contract package.
+mod key {
+    use super::*;
+
+    const ARG_NAME: &str = "key";
+    const ARG_SHORT: &str = "k";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The key of the contract or of the contract package, formatted as \"hash-<HEX STRING>\"";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Key as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the version of the contract within the package.
+mod contract_version {
+    use super::*;
+
+    const ARG_NAME: &str = "contract-version";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "The version of the contract within the contract package. If not given for a contract \
+        package, the highest enabled version is used. Must not be given for a contract";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::ContractVersion as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the entry point whose args should be encoded.
+mod entry_point {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "entry-point";
+    const ARG_SHORT: &str = "e";
+    const ARG_VALUE_NAME: &str = "NAME";
+    const ARG_HELP: &str =
+        "Name of the entry point to encode the args for. The args are checked against the types \
+        declared in the contract's ABI and output in the format read via --session-args-complex \
+        and --payment-args-complex";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::EntryPoint as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Option<&'a str> {
+        matches.value_of(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the args for the entry point.
+mod args {
+    use super::*;
+
+    const ARG_NAME: &str = "arg";
+    const ARG_SHORT: &str = "a";
+    const ARG_VALUE_NAME: &str = r#""NAME='VALUE'" OR "NAME=null""#;
+    const ARG_HELP: &str =
+        "An arg for the entry point, typed by its parameter in the contract's ABI. Must be given \
+        once for each parameter, unless --interactive is given, in which case the values of \
+        missing args are prompted for. Requires --entry-point";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .multiple(true)
+            .number_of_values(1)
+            .requires(entry_point::ARG_NAME)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Arg as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Vec<&'a str> {
+        matches
+            .values_of(ARG_NAME)
+            .map(|values| values.collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the interactive flag.
+mod interactive {
+    use super::*;
+
+    const ARG_NAME: &str = "interactive";
+    const ARG_SHORT: &str = "i";
+    const ARG_HELP: &str =
+        "Lists the contract's callable entry points, prompts for the one to call unless \
+        --entry-point is given, then prompts for the value of each of its args not given via --arg";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Interactive as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+/// Returns the entry point's signature, e.g. `transfer(amount: U512) -> Unit`.
+fn signature(entry_point: &EntryPoint) -> String {
+    let params = entry_point
+        .args()
+        .iter()
+        .map(|parameter| format!("{}: {}", parameter.name(), type_name(parameter.cl_type())))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let access = match entry_point.access() {
+        EntryPointAccess::Public => String::new(),
+        EntryPointAccess::Groups(groups) => {
+            let groups = groups
+                .iter()
+                .map(|group| group.value())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" [groups: {}]", groups)
+        }
+    };
+    format!(
+        "{}({}) -> {}{}",
+        entry_point.name(),
+        params,
+        type_name(entry_point.ret()),
+        access
+    )
+}
+
+fn type_name(cl_type: &CLType) -> String {
+    match cl_type {
+        CLType::Struct { name, .. } | CLType::Enum { name, .. } => name.clone(),
+        _ => format!("{:?}", cl_type),
+    }
+}
+
+fn prompt(message: &str) -> Result<String, Error> {
+    let to_io_error = |error| Error::IoError {
+        context: "failed to read from stdin".to_string(),
+        error,
+    };
+    // Prompt on stderr, leaving stdout for the encoded args.
+    eprint!("{}: ", message);
+    io::stderr().flush().map_err(to_io_error)?;
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(to_io_error)?;
+    Ok(line.trim_end_matches(&['\r', '\n'][..]).to_string())
+}
+
+/// Returns the named entry point or, if no name is given, lists the callable entry points and
+/// prompts for the one to call.
+fn choose_entry_point<'a>(
+    entry_points: &'a [EntryPoint],
+    maybe_name: Option<&str>,
+) -> Result<&'a EntryPoint, Error> {
+    let find = |name: &str| {
+        entry_points
+            .iter()
+            .find(|entry_point| entry_point.name() == name)
+            .ok_or_else(|| {
+                Error::InvalidArgument("entry_point", format!("no entry point named {}", name))
+            })
+    };
+    if let Some(name) = maybe_name {
+        return find(name);
+    }
+
+    eprintln!("Callable entry points:");
+    for (index, entry_point) in entry_points.iter().enumerate() {
+        eprintln!("  {}: {}", index + 1, signature(entry_point));
+    }
+    let choice = prompt("Entry point to call (number or name)")?;
+    match choice.parse::<usize>() {
+        Ok(number) if (1..=entry_points.len()).contains(&number) => Ok(&entry_points[number - 1]),
+        _ => find(&choice),
+    }
+}
+
+/// Prompts for the value of each of the entry point's args which isn't given.
+fn prompt_for_missing_args(entry_point: &EntryPoint, args: &mut Vec<String>) -> Result<(), Error> {
+    for parameter in entry_point.args() {
+        let prefix = format!("{}=", parameter.name());
+        if args.iter().any(|arg| arg.starts_with(&prefix)) {
+            continue;
+        }
+        let value = prompt(&format!(
+            "{} ({})",
+            parameter.name(),
+            type_name(parameter.cl_type())
+        ))?;
+        if value == "null" {
+            args.push(format!("{}null", prefix));
+        } else {
+            args.push(format!("{}'{}'", prefix, value));
+        }
+    }
+    Ok(())
+}
+
+fn abi_from_response(response: &JsonRpc) -> Result<GetContractAbiResult, Error> {
+    let result = response
+        .get_result()
+        .cloned()
+        .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+    Ok(serde_json::from_value(result)?)
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetContractAbi {
+    const NAME: &'static str = "get-contract-abi";
+    const ABOUT: &'static str =
+        "Retrieves the entry points of a contract from the network, optionally encoding the args \
+        for one of them";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::state_root_hash::arg(
+                DisplayOrder::StateRootHash as usize,
+            ))
+            .arg(key::arg())
+            .arg(contract_version::arg())
+            .arg(entry_point::arg())
+            .arg(args::arg())
+            .arg(interactive::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let state_root_hash = common::state_root_hash::get(matches);
+        let key = key::get(matches);
+        let version = contract_version::get(matches);
+        let maybe_entry_point = entry_point::get(matches);
+        let args = args::get(matches);
+        let interactive = interactive::get(matches);
+
+        let response = casper_client::get_contract_abi(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            key,
+            version,
+        )?;
+        if maybe_entry_point.is_none() && !interactive {
+            return Ok(Success::from(response));
+        }
+
+        let abi = abi_from_response(&response)?;
+        let entry_point = choose_entry_point(&abi.entry_points, maybe_entry_point)?;
+
+        let mut args = args.iter().map(ToString::to_string).collect::<Vec<_>>();
+        if interactive {
+            prompt_for_missing_args(entry_point, &mut args)?;
+        }
+        let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+        casper_client::encode_args_by_abi(entry_point, &args).map(Success::Output)
+    }
+}
@@ -0,0 +1,137 @@
+use std::str;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::Error;
+use casper_node::rpcs::info::GetPendingDeploys;
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    Account,
+    Offset,
+    Limit,
+}
+
+/// Handles providing the arg for and retrieval of the account whose deploys should be listed.
+mod account {
+    use super::*;
+
+    const ARG_NAME: &str = "account";
+    const ARG_SHORT: &str = "a";
+    const ARG_VALUE_NAME: &str = common::ARG_HEX_STRING;
+    const ARG_HELP: &str =
+        "Hex-encoded public key of an account. If given, only deploys from this account are listed";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Account as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the number of deploys to skip.
+mod offset {
+    use super::*;
+
+    const ARG_NAME: &str = "offset";
+    const ARG_SHORT: &str = "o";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_DEFAULT: &str = "0";
+    const ARG_HELP: &str =
+        "The number of deploys to skip. Deploys are listed in the order in which they would be \
+        proposed";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Offset as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum number of deploys to list.
+mod limit {
+    use super::*;
+
+    const ARG_NAME: &str = "limit";
+    const ARG_SHORT: &str = "l";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_DEFAULT: &str = "100";
+    const ARG_HELP: &str =
+        "The maximum number of deploys to list. The node returns at most 100 per request";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Limit as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetPendingDeploys {
+    const NAME: &'static str = "get-pending-deploys";
+    const ABOUT: &'static str =
+        "Retrieves the deploys a node holds which have not been included in a block yet";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(account::arg())
+            .arg(offset::arg())
+            .arg(limit::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let maybe_account = account::get(matches);
+        let offset = offset::get(matches);
+        let limit = limit::get(matches);
+
+        casper_client::get_pending_deploys(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            maybe_account,
+            offset,
+            limit,
+        )
+        .map(Success::from)
+    }
+}
@@ -7,10 +7,14 @@ mod docs;
 mod generate_completion;
 mod get_auction_info;
 mod get_balance;
+mod get_contract_abi;
 mod get_era_info_by_switch_block;
+mod get_evidence;
+mod get_pending_deploys;
 mod get_state_hash;
 mod keygen;
 mod query_state;
+mod verify_evidence;
 
 use std::process;
 
@@ -21,8 +25,8 @@ use casper_node::rpcs::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
-    info::GetDeploy,
-    state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
+    info::{GetDeploy, GetEvidence, GetPendingDeploys},
+    state::{GetAuctionInfo, GetBalance, GetContractAbi, GetItem as QueryState},
 };
 
 use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
@@ -32,6 +36,7 @@ use command::{ClientCommand, Success};
 use deploy::Transfer;
 use generate_completion::GenerateCompletion;
 use keygen::Keygen;
+use verify_evidence::VerifyEvidence;
 
 const APP_NAME: &str = "Casper client";
 
@@ -49,8 +54,12 @@ enum DisplayOrder {
     GetStateRootHash,
     QueryState,
     GetBalance,
+    GetContractAbi,
     GetEraInfo,
     GetAuctionInfo,
+    GetEvidence,
+    VerifyEvidence,
+    GetPendingDeploys,
     Keygen,
     GenerateCompletion,
     GetRpcs,
@@ -73,6 +82,7 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         ))
         .subcommand(ListDeploys::build(DisplayOrder::ListDeploys as usize))
         .subcommand(GetBalance::build(DisplayOrder::GetBalance as usize))
+        .subcommand(GetContractAbi::build(DisplayOrder::GetContractAbi as usize))
         .subcommand(GetStateRootHash::build(
             DisplayOrder::GetStateRootHash as usize,
         ))
@@ -81,6 +91,11 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetEraInfo as usize,
         ))
         .subcommand(GetAuctionInfo::build(DisplayOrder::GetAuctionInfo as usize))
+        .subcommand(GetEvidence::build(DisplayOrder::GetEvidence as usize))
+        .subcommand(VerifyEvidence::build(DisplayOrder::VerifyEvidence as usize))
+        .subcommand(GetPendingDeploys::build(
+            DisplayOrder::GetPendingDeploys as usize,
+        ))
         .subcommand(Keygen::build(DisplayOrder::Keygen as usize))
         .subcommand(GenerateCompletion::build(
             DisplayOrder::GenerateCompletion as usize,
@@ -103,12 +118,16 @@ async fn main() {
         (GetBlockTransfers::NAME, Some(matches)) => (GetBlockTransfers::run(matches), matches),
         (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches), matches),
         (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches), matches),
+        (GetContractAbi::NAME, Some(matches)) => (GetContractAbi::run(matches), matches),
         (GetStateRootHash::NAME, Some(matches)) => (GetStateRootHash::run(matches), matches),
         (QueryState::NAME, Some(matches)) => (QueryState::run(matches), matches),
         (GetEraInfoBySwitchBlock::NAME, Some(matches)) => {
             (GetEraInfoBySwitchBlock::run(matches), matches)
         }
         (GetAuctionInfo::NAME, Some(matches)) => (GetAuctionInfo::run(matches), matches),
+        (GetEvidence::NAME, Some(matches)) => (GetEvidence::run(matches), matches),
+        (VerifyEvidence::NAME, Some(matches)) => (VerifyEvidence::run(matches), matches),
+        (GetPendingDeploys::NAME, Some(matches)) => (GetPendingDeploys::run(matches), matches),
         (Keygen::NAME, Some(matches)) => (Keygen::run(matches), matches),
         (GenerateCompletion::NAME, Some(matches)) => (GenerateCompletion::run(matches), matches),
         (ListRpcs::NAME, Some(matches)) => (ListRpcs::run(matches), matches),
@@ -0,0 +1,94 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::Error;
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Evidence,
+    Validators,
+}
+
+/// Handles providing the arg for and retrieval of the evidence file.
+mod evidence {
+    use super::*;
+
+    const ARG_NAME: &str = "evidence";
+    const ARG_SHORT: &str = "e";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to a file containing the evidence, e.g. the saved output of 'get-evidence'";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Evidence as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the validators file.
+mod validators {
+    use super::*;
+
+    const ARG_NAME: &str = "validators";
+    const ARG_SHORT: &str = "l";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to a file containing a JSON array of the hex-encoded public keys of all validators \
+        in the era";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Validators as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+pub struct VerifyEvidence {}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for VerifyEvidence {
+    const NAME: &'static str = "verify-evidence";
+    const ABOUT: &'static str =
+        "Verifies evidence against a faulty validator offline, given the era's validator set";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(evidence::arg())
+            .arg(validators::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let evidence_path = evidence::get(matches);
+        let validators_path = validators::get(matches);
+
+        casper_client::verify_evidence(evidence_path, validators_path).map(|perpetrator| {
+            Success::Output(format!(
+                "Evidence is valid: validator {} is faulty",
+                perpetrator
+            ))
+        })
+    }
+}
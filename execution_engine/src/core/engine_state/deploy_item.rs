@@ -15,6 +15,8 @@ pub struct DeployItem {
     pub gas_price: GasPrice,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    /// The account paying for the deploy's execution, if other than the deploy's account.
+    pub fee_payer: Option<AccountHash>,
 }
 
 impl DeployItem {
@@ -26,6 +28,7 @@ impl DeployItem {
         gas_price: GasPrice,
         authorization_keys: BTreeSet<AccountHash>,
         deploy_hash: DeployHash,
+        fee_payer: Option<AccountHash>,
     ) -> Self {
         DeployItem {
             address,
@@ -34,6 +37,7 @@ impl DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            fee_payer,
         }
     }
 }
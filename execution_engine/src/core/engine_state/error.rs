@@ -52,6 +52,8 @@ pub enum Error {
     ProtocolUpgrade(ProtocolUpgradeError),
     #[error("Unsupported deploy item variant: {0}")]
     InvalidDeployItemVariant(String),
+    #[error("Wasm-less transfers cannot be paid for by a separate fee payer")]
+    TransferWithFeePayer,
 }
 
 impl From<execution::Error> for Error {
@@ -3,6 +3,7 @@
 
 use std::{
     cell::RefCell,
+    convert::TryFrom,
     fmt::{self, Debug, Display, Formatter},
     rc::Rc,
 };
@@ -10,6 +11,7 @@ use std::{
 use datasize::DataSize;
 use hex_buffer_serde::{Hex, HexForm};
 use hex_fmt::HexFmt;
+use once_cell::sync::Lazy;
 use parity_wasm::elements::Module;
 use rand::{
     distributions::{Alphanumeric, Distribution, Standard},
@@ -21,9 +23,9 @@ use serde::{Deserialize, Serialize};
 use casper_types::{
     bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::{ContractVersion, DEFAULT_ENTRY_POINT_NAME},
-    system::mint::ARG_AMOUNT,
-    CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersionKey,
-    EntryPoint, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, U512,
+    system::{mint::ARG_AMOUNT, CallStackElement},
+    BlockTime, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash,
+    ContractVersionKey, EntryPoint, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, U512,
 };
 
 use super::error;
@@ -47,6 +49,9 @@ const STORED_CONTRACT_BY_NAME_TAG: u8 = 2;
 const STORED_VERSIONED_CONTRACT_BY_HASH_TAG: u8 = 3;
 const STORED_VERSIONED_CONTRACT_BY_NAME_TAG: u8 = 4;
 const TRANSFER_TAG: u8 = 5;
+const BATCH_TAG: u8 = 6;
+
+static EMPTY_ARGS: Lazy<RuntimeArgs> = Lazy::new(RuntimeArgs::new);
 
 #[derive(
     Clone, DataSize, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
@@ -89,6 +94,346 @@ pub enum ExecutableDeployItem {
     Transfer {
         args: RuntimeArgs,
     },
+    /// An ordered list of stored contract calls executed one after another within a single
+    /// session phase, sharing one gas budget.  If any call fails, the whole session fails.
+    Batch {
+        calls: Vec<BatchedCall>,
+    },
+}
+
+/// A stored contract call within an [`ExecutableDeployItem::Batch`].
+///
+/// Being a separate type rather than an `ExecutableDeployItem`, a batched call can't itself be a
+/// batch, so batches can't be nested and decoding one never recurses.  Each variant is encoded
+/// like the `ExecutableDeployItem` variant of the same name.
+#[derive(
+    Clone, DataSize, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub enum BatchedCall {
+    StoredContractByHash {
+        #[serde(with = "HexForm")]
+        #[schemars(with = "String", description = "Hex-encoded hash.")]
+        hash: ContractHash,
+        entry_point: String,
+        args: RuntimeArgs,
+    },
+    StoredContractByName {
+        name: String,
+        entry_point: String,
+        args: RuntimeArgs,
+    },
+    StoredVersionedContractByHash {
+        #[serde(with = "HexForm")]
+        #[schemars(with = "String", description = "Hex-encoded hash.")]
+        hash: ContractPackageHash,
+        version: Option<ContractVersion>, // defaults to highest enabled version
+        entry_point: String,
+        args: RuntimeArgs,
+    },
+    StoredVersionedContractByName {
+        name: String,
+        version: Option<ContractVersion>, // defaults to highest enabled version
+        entry_point: String,
+        args: RuntimeArgs,
+    },
+}
+
+impl From<BatchedCall> for ExecutableDeployItem {
+    fn from(call: BatchedCall) -> Self {
+        match call {
+            BatchedCall::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            },
+            BatchedCall::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            },
+            BatchedCall::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            },
+            BatchedCall::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            },
+        }
+    }
+}
+
+/// Returns the item back if it isn't a stored contract call.
+impl TryFrom<ExecutableDeployItem> for BatchedCall {
+    type Error = ExecutableDeployItem;
+
+    fn try_from(item: ExecutableDeployItem) -> Result<Self, Self::Error> {
+        match item {
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => Ok(BatchedCall::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            }),
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => Ok(BatchedCall::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            }),
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => Ok(BatchedCall::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            }),
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => Ok(BatchedCall::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            }),
+            item @ ExecutableDeployItem::ModuleBytes { .. }
+            | item @ ExecutableDeployItem::Transfer { .. }
+            | item @ ExecutableDeployItem::Batch { .. } => Err(item),
+        }
+    }
+}
+
+impl BatchedCall {
+    pub fn args(&self) -> &RuntimeArgs {
+        match self {
+            BatchedCall::StoredContractByHash { args, .. }
+            | BatchedCall::StoredContractByName { args, .. }
+            | BatchedCall::StoredVersionedContractByHash { args, .. }
+            | BatchedCall::StoredVersionedContractByName { args, .. } => args,
+        }
+    }
+}
+
+impl ToBytes for BatchedCall {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            BatchedCall::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            BatchedCall::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            BatchedCall::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_VERSIONED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            BatchedCall::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_VERSIONED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        TAG_LENGTH
+            + match self {
+                BatchedCall::StoredContractByHash {
+                    hash,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                BatchedCall::StoredContractByName {
+                    name,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                BatchedCall::StoredVersionedContractByHash {
+                    hash,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                BatchedCall::StoredVersionedContractByName {
+                    name,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+            }
+    }
+}
+
+impl FromBytes for BatchedCall {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            STORED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    BatchedCall::StoredContractByHash {
+                        hash,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    BatchedCall::StoredContractByName {
+                        name,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    BatchedCall::StoredVersionedContractByHash {
+                        hash,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    BatchedCall::StoredVersionedContractByName {
+                        name,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl Display for BatchedCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&ExecutableDeployItem::from(self.clone()), f)
+    }
+}
+
+impl Debug for BatchedCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&ExecutableDeployItem::from(self.clone()), f)
+    }
+}
+
+impl Distribution<BatchedCall> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BatchedCall {
+        loop {
+            if let Ok(call) = BatchedCall::try_from(rng.gen::<ExecutableDeployItem>()) {
+                return call;
+            }
+        }
+    }
 }
 
 impl ExecutableDeployItem {
@@ -107,17 +452,17 @@ impl ExecutableDeployItem {
                 })?;
                 Ok(Some(key))
             }
-            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
-                Ok(None)
-            }
+            ExecutableDeployItem::ModuleBytes { .. }
+            | ExecutableDeployItem::Transfer { .. }
+            | ExecutableDeployItem::Batch { .. } => Ok(None),
         }
     }
 
     pub fn entry_point_name(&self) -> &str {
         match self {
-            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
-                DEFAULT_ENTRY_POINT_NAME
-            }
+            ExecutableDeployItem::ModuleBytes { .. }
+            | ExecutableDeployItem::Transfer { .. }
+            | ExecutableDeployItem::Batch { .. } => DEFAULT_ENTRY_POINT_NAME,
             ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. }
             | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
             | ExecutableDeployItem::StoredContractByHash { entry_point, .. }
@@ -133,6 +478,8 @@ impl ExecutableDeployItem {
             | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
             | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
             | ExecutableDeployItem::Transfer { args } => args,
+            // Each batched call carries its own args.
+            ExecutableDeployItem::Batch { .. } => &EMPTY_ARGS,
         }
     }
 
@@ -140,6 +487,14 @@ impl ExecutableDeployItem {
         matches!(self, ExecutableDeployItem::Transfer { .. })
     }
 
+    /// Returns the calls of a `Batch` item, or `None` for any other variant.
+    pub fn batched_calls(&self) -> Option<&[BatchedCall]> {
+        match self {
+            ExecutableDeployItem::Batch { calls } => Some(calls),
+            _ => None,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn get_deploy_metadata<R>(
         &self,
@@ -150,11 +505,14 @@ impl ExecutableDeployItem {
         protocol_version: &ProtocolVersion,
         protocol_data: &ProtocolData,
         phase: Phase,
+        blocktime: BlockTime,
     ) -> Result<DeployMetadata, Error>
     where
         R: StateReader<Key, StoredValue>,
         R::Error: Into<ExecError>,
     {
+        let session_call_stack_element = CallStackElement::session(account.account_hash());
+
         let (contract_package, contract, contract_hash, base_key) = match self {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
                 let is_payment = matches!(phase, Phase::Payment);
@@ -164,6 +522,7 @@ impl ExecutableDeployItem {
                         contract: Contract::default(),
                         contract_package: ContractPackage::default(),
                         entry_point: EntryPoint::default(),
+                        call_stack: vec![session_call_stack_element],
                     });
                 }
 
@@ -172,6 +531,7 @@ impl ExecutableDeployItem {
                     module,
                     contract_package: ContractPackage::default(),
                     entry_point: EntryPoint::default(),
+                    call_stack: vec![session_call_stack_element],
                 });
             }
             ExecutableDeployItem::StoredContractByHash { .. }
@@ -196,10 +556,23 @@ impl ExecutableDeployItem {
                     return Err(error::Error::Exec(exec_error));
                 }
 
-                let contract_package = tracking_copy
+                let mut contract_package = tracking_copy
                     .borrow_mut()
                     .get_contract_package(correlation_id, contract.contract_package_hash())?;
 
+                if !contract_package.pending_versions().is_empty() {
+                    let era_id = tracking_copy
+                        .borrow_mut()
+                        .get_era_id(correlation_id, protocol_data.auction())?;
+                    contract_package.activate_versions(era_id, blocktime);
+                }
+
+                if contract_package.is_contract_pending(contract_hash.into()) {
+                    return Err(error::Error::Exec(
+                        execution::Error::PendingContractVersion(contract_hash.into()),
+                    ));
+                }
+
                 (
                     contract_package,
                     contract,
@@ -216,10 +589,17 @@ impl ExecutableDeployItem {
                     .into_hash()
                     .ok_or(Error::InvalidKeyVariant)?;
 
-                let contract_package = tracking_copy
+                let mut contract_package = tracking_copy
                     .borrow_mut()
                     .get_contract_package(correlation_id, contract_package_hash.into())?;
 
+                if !contract_package.pending_versions().is_empty() {
+                    let era_id = tracking_copy
+                        .borrow_mut()
+                        .get_era_id(correlation_id, protocol_data.auction())?;
+                    contract_package.activate_versions(era_id, blocktime);
+                }
+
                 let maybe_version_key =
                     version.map(|ver| ContractVersionKey::new(protocol_version.value().major, ver));
 
@@ -259,6 +639,11 @@ impl ExecutableDeployItem {
                     "Transfer",
                 )))
             }
+            ExecutableDeployItem::Batch { .. } => {
+                return Err(error::Error::InvalidDeployItemVariant(String::from(
+                    "Batch",
+                )))
+            }
         };
 
         let entry_point_name = self.entry_point_name();
@@ -270,6 +655,25 @@ impl ExecutableDeployItem {
                 error::Error::Exec(execution::Error::NoSuchMethod(entry_point_name.to_owned()))
             })?;
 
+        let call_stack = {
+            let contract_package_hash = contract.contract_package_hash();
+            let contract_hash = ContractHash::new(contract_hash);
+            let stored_call_stack_element = match entry_point.entry_point_type() {
+                EntryPointType::Session => CallStackElement::stored_session(
+                    account.account_hash(),
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point.name(),
+                ),
+                EntryPointType::Contract => CallStackElement::stored_contract(
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point.name(),
+                ),
+            };
+            vec![session_call_stack_element, stored_call_stack_element]
+        };
+
         if protocol_data
             .system_contracts()
             .contains(&contract_hash.into())
@@ -279,6 +683,7 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                call_stack,
             });
         }
 
@@ -293,6 +698,7 @@ impl ExecutableDeployItem {
                 module,
                 contract_package,
                 entry_point,
+                call_stack,
             }),
             EntryPointType::Contract => Ok(DeployMetadata::Contract {
                 module,
@@ -300,6 +706,7 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                call_stack,
             }),
         }
     }
@@ -362,6 +769,10 @@ impl ToBytes for ExecutableDeployItem {
                 buffer.insert(0, TRANSFER_TAG);
                 buffer.extend(args.to_bytes()?)
             }
+            ExecutableDeployItem::Batch { calls } => {
+                buffer.insert(0, BATCH_TAG);
+                buffer.extend(calls.to_bytes()?)
+            }
         }
         Ok(buffer)
     }
@@ -413,6 +824,7 @@ impl ToBytes for ExecutableDeployItem {
                         + args.serialized_length()
                 }
                 ExecutableDeployItem::Transfer { args } => args.serialized_length(),
+                ExecutableDeployItem::Batch { calls } => calls.serialized_length(),
             }
     }
 }
@@ -489,6 +901,10 @@ impl FromBytes for ExecutableDeployItem {
                 let (args, remainder) = FromBytes::from_bytes(remainder)?;
                 Ok((ExecutableDeployItem::Transfer { args }, remainder))
             }
+            BATCH_TAG => {
+                let (calls, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((ExecutableDeployItem::Batch { calls }, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -553,6 +969,7 @@ impl Display for ExecutableDeployItem {
                 name, entry_point,
             ),
             ExecutableDeployItem::Transfer { .. } => write!(f, "transfer"),
+            ExecutableDeployItem::Batch { calls } => write!(f, "batch [{} calls]", calls.len()),
         }
     }
 }
@@ -612,6 +1029,9 @@ impl Debug for ExecutableDeployItem {
             ExecutableDeployItem::Transfer { args } => {
                 f.debug_struct("Transfer").field("args", args).finish()
             }
+            ExecutableDeployItem::Batch { calls } => {
+                f.debug_struct("Batch").field("calls", calls).finish()
+            }
         }
     }
 }
@@ -680,6 +1100,7 @@ pub enum DeployMetadata {
         module: Module,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        call_stack: Vec<CallStackElement>,
     },
     Contract {
         // Contract hash
@@ -688,12 +1109,14 @@ pub enum DeployMetadata {
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        call_stack: Vec<CallStackElement>,
     },
     System {
         base_key: Key,
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        call_stack: Vec<CallStackElement>,
     },
 }
 
@@ -719,4 +1142,24 @@ mod tests {
             bytesrepr::test_serialization_roundtrip(&executable_deploy_item);
         }
     }
+
+    #[test]
+    fn batch_serialization_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let calls = (0..3).map(|_| rng.gen()).collect();
+        let batch = ExecutableDeployItem::Batch { calls };
+        bytesrepr::test_serialization_roundtrip(&batch);
+    }
+
+    #[test]
+    fn should_not_deserialize_nested_batch() {
+        let inner = ExecutableDeployItem::Batch { calls: vec![] };
+        let mut bytes = vec![BATCH_TAG];
+        bytes.extend(1u32.to_bytes().unwrap());
+        bytes.extend(inner.to_bytes().unwrap());
+        assert_eq!(
+            ExecutableDeployItem::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
 }
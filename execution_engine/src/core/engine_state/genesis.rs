@@ -19,16 +19,22 @@ use casper_types::{
     runtime_args,
     system::{
         auction::{
-            Bid, Bids, DelegationRate, Delegator, SeigniorageRecipient, SeigniorageRecipients,
-            SeigniorageRecipientsSnapshot, UnbondingPurses, ValidatorWeights, ARG_DELEGATION_RATE,
-            ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS,
-            ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY,
-            DELEGATION_RATE_DENOMINATOR, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
-            INITIAL_ERA_END_TIMESTAMP_MILLIS, INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY,
-            METHOD_ACTIVATE_BID, METHOD_ADD_BID, METHOD_DELEGATE, METHOD_DISTRIBUTE,
+            Bid, BidPublicKeyChanges, Bids, DelegationRate, Delegator, Redelegations,
+            SeigniorageRecipient, SeigniorageRecipients, SeigniorageRecipientsSnapshot,
+            SlashingRecords, UnbondingPurses, ValidatorWeights, ARG_DELEGATION_RATE, ARG_DELEGATOR,
+            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_MAXIMUM_DELEGATION_AMOUNT, ARG_MAXIMUM_DELEGATORS,
+            ARG_MINIMUM_DELEGATION_AMOUNT, ARG_NEW_PUBLIC_KEY, ARG_NEW_VALIDATOR, ARG_PUBLIC_KEY,
+            ARG_REWARD_DESTINATION, ARG_REWARD_FACTORS, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY,
+            AUCTION_DELAY_KEY, BID_PUBLIC_KEY_CHANGES_KEY, DELEGATION_RATE_DENOMINATOR,
+            DELEGATION_SLASHING_RATE_KEY, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
+            INITIAL_ERA_END_TIMESTAMP_MILLIS, INITIAL_ERA_ID, JAIL_PERIOD_KEY,
+            LOCKED_FUNDS_PERIOD_KEY, METHOD_ACTIVATE_BID, METHOD_ADD_BID,
+            METHOD_CHANGE_BID_PUBLIC_KEY, METHOD_DELEGATE, METHOD_DISTRIBUTE,
             METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID, METHOD_READ_SEIGNIORAGE_RECIPIENTS,
-            METHOD_RUN_AUCTION, METHOD_SLASH, METHOD_UNDELEGATE, METHOD_WITHDRAW_BID,
-            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, UNBONDING_PURSES_KEY,
+            METHOD_REDELEGATE, METHOD_RUN_AUCTION, METHOD_SET_DELEGATION_LIMITS,
+            METHOD_SET_REWARD_DESTINATION, METHOD_SLASH, METHOD_UNDELEGATE, METHOD_UNJAIL,
+            METHOD_WITHDRAW_BID, REDELEGATIONS_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+            SLASHING_RATE_KEY, SLASHING_RECORDS_KEY, UNBONDING_DELAY_KEY, UNBONDING_PURSES_KEY,
             VALIDATOR_SLOTS_KEY,
         },
         handle_payment::{
@@ -547,6 +553,9 @@ pub struct ExecConfig {
     locked_funds_period_millis: u64,
     round_seigniorage_rate: Ratio<u64>,
     unbonding_delay: u64,
+    slashing_rate: Ratio<u64>,
+    delegation_slashing_rate: Ratio<u64>,
+    jail_period: u64,
     genesis_timestamp_millis: u64,
 }
 
@@ -561,6 +570,9 @@ impl ExecConfig {
         locked_funds_period_millis: u64,
         round_seigniorage_rate: Ratio<u64>,
         unbonding_delay: u64,
+        slashing_rate: Ratio<u64>,
+        delegation_slashing_rate: Ratio<u64>,
+        jail_period: u64,
         genesis_timestamp_millis: u64,
     ) -> ExecConfig {
         ExecConfig {
@@ -572,6 +584,9 @@ impl ExecConfig {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            slashing_rate,
+            delegation_slashing_rate,
+            jail_period,
             genesis_timestamp_millis,
         }
     }
@@ -626,6 +641,18 @@ impl ExecConfig {
         self.unbonding_delay
     }
 
+    pub fn slashing_rate(&self) -> Ratio<u64> {
+        self.slashing_rate
+    }
+
+    pub fn delegation_slashing_rate(&self) -> Ratio<u64> {
+        self.delegation_slashing_rate
+    }
+
+    pub fn jail_period(&self) -> u64 {
+        self.jail_period
+    }
+
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
@@ -654,6 +681,12 @@ impl Distribution<ExecConfig> for Standard {
 
         let unbonding_delay = rng.gen();
 
+        let slashing_rate = Ratio::new(rng.gen_range(0, 100), 100);
+
+        let delegation_slashing_rate = Ratio::new(rng.gen_range(0, 100), 100);
+
+        let jail_period = rng.gen();
+
         let genesis_timestamp_millis = rng.gen();
 
         ExecConfig {
@@ -665,6 +698,9 @@ impl Distribution<ExecConfig> for Standard {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            slashing_rate,
+            delegation_slashing_rate,
+            jail_period,
             genesis_timestamp_millis,
         }
     }
@@ -1040,6 +1076,19 @@ where
         );
         named_keys.insert(UNBONDING_PURSES_KEY.into(), unbonding_purses_uref.into());
 
+        let redelegations_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            redelegations_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(Redelegations::new())
+                    .map_err(|_| GenesisError::CLValue(REDELEGATIONS_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(REDELEGATIONS_KEY.into(), redelegations_uref.into());
+
         let validator_slots = self.exec_config.validator_slots();
         let validator_slots_uref = self
             .uref_address_generator
@@ -1097,7 +1146,87 @@ where
         );
         named_keys.insert(UNBONDING_DELAY_KEY.into(), unbonding_delay_uref.into());
 
-        let entry_points = self.auction_entry_points();
+        let slashing_rate: Ratio<U512> = {
+            let (numer, denom) = self.exec_config.slashing_rate().into();
+            Ratio::new(numer.into(), denom.into())
+        };
+        let slashing_rate_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            slashing_rate_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(slashing_rate)
+                    .map_err(|_| GenesisError::CLValue(SLASHING_RATE_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(SLASHING_RATE_KEY.into(), slashing_rate_uref.into());
+
+        let delegation_slashing_rate: Ratio<U512> = {
+            let (numer, denom) = self.exec_config.delegation_slashing_rate().into();
+            Ratio::new(numer.into(), denom.into())
+        };
+        let delegation_slashing_rate_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            delegation_slashing_rate_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(delegation_slashing_rate)
+                    .map_err(|_| GenesisError::CLValue(DELEGATION_SLASHING_RATE_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(
+            DELEGATION_SLASHING_RATE_KEY.into(),
+            delegation_slashing_rate_uref.into(),
+        );
+
+        let jail_period = self.exec_config.jail_period();
+        let jail_period_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            jail_period_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(jail_period)
+                    .map_err(|_| GenesisError::CLValue(JAIL_PERIOD_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(JAIL_PERIOD_KEY.into(), jail_period_uref.into());
+
+        let slashing_records_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            slashing_records_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(SlashingRecords::new())
+                    .map_err(|_| GenesisError::CLValue(SLASHING_RECORDS_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(SLASHING_RECORDS_KEY.into(), slashing_records_uref.into());
+
+        let bid_public_key_changes_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            bid_public_key_changes_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(BidPublicKeyChanges::new())
+                    .map_err(|_| GenesisError::CLValue(BID_PUBLIC_KEY_CHANGES_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(
+            BID_PUBLIC_KEY_CHANGES_KEY.into(),
+            bid_public_key_changes_uref.into(),
+        );
+
+        let entry_points = auction_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1402,132 +1531,6 @@ where
         entry_points
     }
 
-    fn auction_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
-
-        let entry_point = EntryPoint::new(
-            METHOD_GET_ERA_VALIDATORS,
-            vec![],
-            Option::<ValidatorWeights>::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_SEIGNIORAGE_RECIPIENTS,
-            vec![],
-            SeigniorageRecipients::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ADD_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_WITHDRAW_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_UNDELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_RUN_AUCTION,
-            vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_SLASH,
-            vec![],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DISTRIBUTE,
-            vec![Parameter::new(
-                ARG_REWARD_FACTORS,
-                CLType::Map {
-                    key: Box::new(CLType::PublicKey),
-                    value: Box::new(CLType::U64),
-                },
-            )],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_ERA_ID,
-            vec![],
-            CLType::U64,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ACTIVATE_BID,
-            vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        entry_points
-    }
-
     fn standard_payment_entry_points(&self) -> EntryPoints {
         let mut entry_points = EntryPoints::new();
 
@@ -1547,6 +1550,197 @@ where
     }
 }
 
+/// Returns the entry points of the auction contract.
+///
+/// These are installed at genesis, and replace the entry points of an auction contract installed
+/// by an earlier protocol version when upgrading.
+pub(crate) fn auction_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let entry_point = EntryPoint::new(
+        METHOD_GET_ERA_VALIDATORS,
+        vec![],
+        Option::<ValidatorWeights>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_SEIGNIORAGE_RECIPIENTS,
+        vec![],
+        SeigniorageRecipients::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ADD_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_WITHDRAW_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_UNDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_REDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+            Parameter::new(ARG_NEW_VALIDATOR, PublicKey::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_DELEGATION_LIMITS,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_MINIMUM_DELEGATION_AMOUNT, Option::<U512>::cl_type()),
+            Parameter::new(ARG_MAXIMUM_DELEGATION_AMOUNT, Option::<U512>::cl_type()),
+            Parameter::new(ARG_MAXIMUM_DELEGATORS, Option::<u32>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_RUN_AUCTION,
+        vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SLASH,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DISTRIBUTE,
+        vec![Parameter::new(
+            ARG_REWARD_FACTORS,
+            CLType::Map {
+                key: Box::new(CLType::PublicKey),
+                value: Box::new(CLType::U64),
+            },
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_ERA_ID,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ACTIVATE_BID,
+        vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+    let entry_point = EntryPoint::new(
+        METHOD_UNJAIL,
+        vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CHANGE_BID_PUBLIC_KEY,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, CLType::PublicKey),
+            Parameter::new(ARG_NEW_PUBLIC_KEY, CLType::PublicKey),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_REWARD_DESTINATION,
+        vec![
+            Parameter::new(ARG_VALIDATOR, CLType::PublicKey),
+            Parameter::new(ARG_DELEGATOR, CLType::Option(Box::new(CLType::PublicKey))),
+            Parameter::new(ARG_REWARD_DESTINATION, CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    entry_points
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,9 +35,12 @@ use casper_types::{
     contracts::NamedKeys,
     system::{
         auction::{
-            EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
-            ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
-            LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            BidPublicKeyChanges, EraValidators, Redelegations, SlashingRecords,
+            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS, ARG_REWARD_FACTORS,
+            ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY, BID_PUBLIC_KEY_CHANGES_KEY,
+            DELEGATION_SLASHING_RATE_KEY, JAIL_PERIOD_KEY, LOCKED_FUNDS_PERIOD_KEY,
+            REDELEGATIONS_KEY, SLASHING_RATE_KEY, SLASHING_RECORDS_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         handle_payment,
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
@@ -52,7 +55,7 @@ pub use self::{
     engine_config::EngineConfig,
     era_validators::{GetEraValidatorsError, GetEraValidatorsRequest},
     error::{Error, RootNotFound},
-    executable_deploy_item::ExecutableDeployItem,
+    executable_deploy_item::{BatchedCall, ExecutableDeployItem},
     execute_request::ExecuteRequest,
     execution::Error as ExecError,
     execution_result::{ExecutionResult, ExecutionResults, ForcedTransferResult},
@@ -70,7 +73,7 @@ use crate::{
             genesis::GenesisInstaller, put_trie::InsertedTrieKeyAndMissingDescendants,
             upgrade::SystemUpgrader,
         },
-        execution::{self, DirectSystemContractCall, Executor},
+        execution::{self, AddressGenerator, DirectSystemContractCall, Executor},
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
     shared::{
@@ -99,6 +102,15 @@ pub const SYSTEM_ACCOUNT_ADDR: AccountHash = AccountHash::new([0u8; 32]);
 /// pay.
 pub const WASMLESS_TRANSFER_FIXED_GAS_PRICE: u64 = 1;
 
+/// The accounts of a deploy paid for by a separate fee payer, with the subsets of the deploy's
+/// authorization keys associated with each of them.
+struct AuthorizedSponsoredAccounts {
+    account: Account,
+    account_keys: BTreeSet<AccountHash>,
+    fee_payer: Account,
+    fee_payer_keys: BTreeSet<AccountHash>,
+}
+
 #[derive(Debug)]
 pub struct EngineState<S> {
     config: EngineConfig,
@@ -373,6 +385,110 @@ where
                 .write(locked_funds_period_key, value);
         }
 
+        // Auction contracts installed before delegations could be redelegated, before slashing was
+        // made configurable, or before bids could be moved to new public keys, lack the respective
+        // named keys, so any missing ones are created here.
+        {
+            let mut auction_values = Vec::new();
+            if let Some(new_slashing_rate) = upgrade_config.new_slashing_rate() {
+                let new_slashing_rate: Ratio<U512> = {
+                    let (numer, denom) = new_slashing_rate.into();
+                    Ratio::new(numer.into(), denom.into())
+                };
+                let value = CLValue::from_t(new_slashing_rate)
+                    .map_err(|_| Error::Bytesrepr("new_slashing_rate".to_string()))?;
+                auction_values.push((SLASHING_RATE_KEY, value));
+            }
+            if let Some(new_delegation_slashing_rate) =
+                upgrade_config.new_delegation_slashing_rate()
+            {
+                let new_delegation_slashing_rate: Ratio<U512> = {
+                    let (numer, denom) = new_delegation_slashing_rate.into();
+                    Ratio::new(numer.into(), denom.into())
+                };
+                let value = CLValue::from_t(new_delegation_slashing_rate)
+                    .map_err(|_| Error::Bytesrepr("new_delegation_slashing_rate".to_string()))?;
+                auction_values.push((DELEGATION_SLASHING_RATE_KEY, value));
+            }
+            if let Some(new_jail_period) = upgrade_config.new_jail_period() {
+                let value = CLValue::from_t(new_jail_period)
+                    .map_err(|_| Error::Bytesrepr("new_jail_period".to_string()))?;
+                auction_values.push((JAIL_PERIOD_KEY, value));
+            }
+
+            let mut auction_contract = tracking_copy
+                .borrow_mut()
+                .get_contract(correlation_id, new_protocol_data.auction())?;
+
+            if !auction_contract
+                .named_keys()
+                .contains_key(REDELEGATIONS_KEY)
+            {
+                let value = CLValue::from_t(Redelegations::new())
+                    .map_err(|_| Error::Bytesrepr("redelegations".to_string()))?;
+                auction_values.push((REDELEGATIONS_KEY, value));
+            }
+
+            if !auction_contract
+                .named_keys()
+                .contains_key(SLASHING_RECORDS_KEY)
+            {
+                let value = CLValue::from_t(SlashingRecords::new())
+                    .map_err(|_| Error::Bytesrepr("slashing_records".to_string()))?;
+                auction_values.push((SLASHING_RECORDS_KEY, value));
+            }
+
+            if !auction_contract
+                .named_keys()
+                .contains_key(BID_PUBLIC_KEY_CHANGES_KEY)
+            {
+                let value = CLValue::from_t(BidPublicKeyChanges::new())
+                    .map_err(|_| Error::Bytesrepr("bid_public_key_changes".to_string()))?;
+                auction_values.push((BID_PUBLIC_KEY_CHANGES_KEY, value));
+            }
+
+            let mut address_generator =
+                AddressGenerator::new(pre_state_hash.as_ref(), Phase::System);
+            let mut new_named_keys = NamedKeys::new();
+            for (name, value) in auction_values {
+                let key = match auction_contract.named_keys().get(name) {
+                    Some(key) => *key,
+                    None => {
+                        let key: Key = address_generator
+                            .new_uref(AccessRights::READ_ADD_WRITE)
+                            .into();
+                        new_named_keys.insert(name.to_string(), key);
+                        key
+                    }
+                };
+                tracking_copy
+                    .borrow_mut()
+                    .write(key, StoredValue::CLValue(value));
+            }
+
+            // Entry points added to the auction since the contract was installed are only callable
+            // once the contract's entry points are replaced with the current ones.
+            let entry_points = genesis::auction_entry_points();
+            let entry_points_outdated = *auction_contract.entry_points() != entry_points;
+            if entry_points_outdated {
+                auction_contract = Contract::new(
+                    auction_contract.contract_package_hash(),
+                    auction_contract.contract_wasm_hash(),
+                    auction_contract.named_keys().clone(),
+                    entry_points,
+                    auction_contract.protocol_version(),
+                );
+            }
+
+            if !new_named_keys.is_empty() || entry_points_outdated {
+                auction_contract.named_keys_append(&mut new_named_keys);
+                tracking_copy.borrow_mut().write(
+                    new_protocol_data.auction().into(),
+                    StoredValue::Contract(auction_contract),
+                );
+            }
+        }
+
         // apply the arbitrary modifications
         for (key, value) in upgrade_config.global_state_update() {
             tracking_copy.borrow_mut().write(*key, value.clone());
@@ -497,6 +613,52 @@ where
         Ok(account)
     }
 
+    /// Returns the deploy's account and its separate fee payer's account, along with the subsets
+    /// of the authorization keys associated with each of them.
+    ///
+    /// Every authorization key must be associated with at least one of the two accounts, and the
+    /// keys associated with each account must meet that account's deployment threshold.
+    fn get_authorized_sponsored_accounts(
+        &self,
+        correlation_id: CorrelationId,
+        account_hash: AccountHash,
+        fee_payer_hash: AccountHash,
+        authorization_keys: &BTreeSet<AccountHash>,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+    ) -> Result<AuthorizedSponsoredAccounts, Error> {
+        let get_account = |account_hash: AccountHash| {
+            tracking_copy
+                .borrow_mut()
+                .get_account(correlation_id, account_hash)
+                .map_err(|_| error::Error::Authorization)
+        };
+        let account: Account = get_account(account_hash)?;
+        let fee_payer: Account = get_account(fee_payer_hash)?;
+
+        let account_keys = account.associated_authorization_keys(authorization_keys);
+        let fee_payer_keys = fee_payer.associated_authorization_keys(authorization_keys);
+
+        // Authorize using provided authorization keys
+        if account_keys.union(&fee_payer_keys).count() != authorization_keys.len()
+            || !account.can_authorize(&account_keys)
+            || !fee_payer.can_authorize(&fee_payer_keys)
+        {
+            return Err(error::Error::Authorization);
+        }
+
+        // Check each account's key weight against its own deploy threshold
+        if !account.can_deploy_with(&account_keys) || !fee_payer.can_deploy_with(&fee_payer_keys) {
+            return Err(execution::Error::DeploymentAuthorizationFailure.into());
+        }
+
+        Ok(AuthorizedSponsoredAccounts {
+            account,
+            account_keys,
+            fee_payer,
+            fee_payer_keys,
+        })
+    }
+
     pub fn get_purse_balance(
         &self,
         correlation_id: CorrelationId,
@@ -571,6 +733,12 @@ where
             }
         };
 
+        if deploy_item.fee_payer.is_some() {
+            return Ok(ExecutionResult::precondition_failure(
+                Error::TransferWithFeePayer,
+            ));
+        }
+
         let authorization_keys = deploy_item.authorization_keys;
 
         let account = match self.get_authorized_account(
@@ -1116,44 +1284,89 @@ where
 
         let authorization_keys = deploy_item.authorization_keys;
 
-        // Get account from tracking copy
+        // Get account from tracking copy, along with the separate fee payer's account if the
+        // deploy is sponsored.  Each of them only sees its own keys during execution.
         // validation_spec_3: account validity
-        let account = match self.get_authorized_account(
-            correlation_id,
-            account_hash,
-            &authorization_keys,
-            Rc::clone(&tracking_copy),
-        ) {
-            Ok(account) => account,
-            Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
-        };
+        let (account, session_authorization_keys, maybe_fee_payer, payment_authorization_keys) =
+            match deploy_item.fee_payer {
+                None => match self.get_authorized_account(
+                    correlation_id,
+                    account_hash,
+                    &authorization_keys,
+                    Rc::clone(&tracking_copy),
+                ) {
+                    Ok(account) => (
+                        account,
+                        authorization_keys.clone(),
+                        None,
+                        authorization_keys.clone(),
+                    ),
+                    Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+                },
+                Some(fee_payer_hash) => match self.get_authorized_sponsored_accounts(
+                    correlation_id,
+                    account_hash,
+                    fee_payer_hash,
+                    &authorization_keys,
+                    Rc::clone(&tracking_copy),
+                ) {
+                    Ok(accounts) => (
+                        accounts.account,
+                        accounts.account_keys,
+                        Some(accounts.fee_payer),
+                        accounts.fee_payer_keys,
+                    ),
+                    Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+                },
+            };
+
+        // The account whose main purse funds the payment purse and receives any refund
+        let payer = maybe_fee_payer.as_ref().unwrap_or(&account);
+        let payer_base_key = Key::Account(payer.account_hash());
 
         let session = deploy_item.session;
         let payment = deploy_item.payment;
         let deploy_hash = deploy_item.deploy_hash;
 
+        // A batch session runs each of its calls in order; any other session is a single call.
+        let session_calls: Vec<ExecutableDeployItem> = match session {
+            ExecutableDeployItem::Batch { calls } => {
+                if calls.is_empty() {
+                    return Ok(ExecutionResult::precondition_failure(
+                        Error::InvalidDeployItemVariant(String::from("empty Batch")),
+                    ));
+                }
+                calls.into_iter().map(ExecutableDeployItem::from).collect()
+            }
+            session => vec![session],
+        };
+
         // Create session code `A` from provided session bytes
         // validation_spec_1: valid wasm bytes
         // we do this upfront as there is no reason to continue if session logic is invalid
-        let session_metadata = match session.get_deploy_metadata(
-            Rc::clone(&tracking_copy),
-            &account,
-            correlation_id,
-            &preprocessor,
-            &protocol_version,
-            &protocol_data,
-            Phase::Session,
-        ) {
-            Ok(metadata) => metadata,
-            Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+        let mut session_metadata = Vec::with_capacity(session_calls.len());
+        for session_call in &session_calls {
+            match session_call.get_deploy_metadata(
+                Rc::clone(&tracking_copy),
+                &account,
+                correlation_id,
+                &preprocessor,
+                &protocol_version,
+                &protocol_data,
+                Phase::Session,
+                blocktime,
+            ) {
+                Ok(metadata) => session_metadata.push(metadata),
+                Err(error) => {
+                    return Ok(ExecutionResult::precondition_failure(error));
+                }
             }
-        };
+        }
 
-        // Get account main purse balance key
+        // Get payer's main purse balance key
         // validation_spec_5: account main purse minimum balance
         let account_main_purse_balance_key: Key = {
-            let account_key = Key::URef(account.main_purse());
+            let account_key = Key::URef(payer.main_purse());
             match tracking_copy
                 .borrow_mut()
                 .get_purse_balance_key(correlation_id, account_key)
@@ -1216,12 +1429,13 @@ where
             let phase = Phase::Payment;
             let payment_metadata = match payment.get_deploy_metadata(
                 Rc::clone(&tracking_copy),
-                &account,
+                payer,
                 correlation_id,
                 &preprocessor,
                 &protocol_version,
                 &protocol_data,
                 phase,
+                blocktime,
             ) {
                 Ok(metadata) => metadata,
                 Err(error) => {
@@ -1236,30 +1450,35 @@ where
                 mut payment_named_keys,
                 payment_package,
                 payment_entry_point,
+                payment_call_stack,
                 is_standard_payment,
             ) = match payment_metadata {
                 DeployMetadata::System {
                     contract_package,
                     entry_point,
+                    call_stack,
                     ..
                 } => (
                     system_module.clone(),
-                    base_key,                     // this is account key
-                    account.named_keys().clone(), // standard payment uses account keys
+                    payer_base_key,             // this is account key
+                    payer.named_keys().clone(), // standard payment uses account keys
                     contract_package,
                     entry_point,
+                    call_stack,
                     true,
                 ),
                 DeployMetadata::Session {
                     module,
                     contract_package,
                     entry_point,
+                    call_stack,
                 } => (
                     module,
-                    base_key, // this is account key
-                    account.named_keys().clone(),
+                    payer_base_key, // this is account key
+                    payer.named_keys().clone(),
                     contract_package,
                     entry_point,
+                    call_stack,
                     false,
                 ),
                 DeployMetadata::Contract {
@@ -1268,12 +1487,14 @@ where
                     contract,
                     contract_package,
                     entry_point,
+                    call_stack,
                 } => (
                     module,
                     base_key, // this is contract key
                     contract.named_keys().clone(),
                     contract_package,
                     entry_point,
+                    call_stack,
                     false,
                 ),
             };
@@ -1286,9 +1507,9 @@ where
                     system_module.clone(),
                     payment_args,
                     payment_base_key,
-                    &account,
+                    payer,
                     &mut payment_named_keys,
-                    authorization_keys.clone(),
+                    payment_authorization_keys,
                     blocktime,
                     deploy_hash,
                     payment_gas_limit,
@@ -1300,17 +1521,32 @@ where
                     system_contract_cache,
                 )
             } else {
+                let hash_address_generator = {
+                    let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+                    Rc::new(RefCell::new(generator))
+                };
+                let uref_address_generator = {
+                    let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+                    Rc::new(RefCell::new(generator))
+                };
+                let transfer_address_generator = {
+                    let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+                    Rc::new(RefCell::new(generator))
+                };
                 executor.exec(
                     payment_module,
                     payment_entry_point,
                     payment_args,
                     payment_base_key,
-                    &account,
+                    payer,
                     &mut payment_named_keys,
-                    authorization_keys.clone(),
+                    payment_authorization_keys,
                     blocktime,
                     deploy_hash,
                     payment_gas_limit,
+                    hash_address_generator,
+                    uref_address_generator,
+                    transfer_address_generator,
                     protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -1318,6 +1554,7 @@ where
                     protocol_data,
                     system_contract_cache,
                     &payment_package,
+                    payment_call_stack,
                 )
             }
         };
@@ -1444,85 +1681,168 @@ where
         let post_payment_tracking_copy = tracking_copy.borrow();
         let session_tracking_copy = Rc::new(RefCell::new(post_payment_tracking_copy.fork()));
 
-        // session_code_spec_2: execute session code
-        let (
-            session_module,
-            session_base_key,
-            mut session_named_keys,
-            session_package,
-            session_entry_point,
-        ) = match session_metadata {
-            DeployMetadata::System {
-                base_key,
-                contract,
-                contract_package,
-                entry_point,
-            } => {
-                (
-                    system_module.clone(),
-                    base_key, // this is contract key
-                    contract.named_keys().clone(),
-                    contract_package,
-                    entry_point,
-                )
-            }
-            DeployMetadata::Session {
-                module,
-                contract_package,
-                entry_point,
-            } => (
-                module,
-                base_key,
-                account.named_keys().clone(),
-                contract_package,
-                entry_point,
-            ),
-            DeployMetadata::Contract {
-                module,
-                base_key,
-                contract,
-                contract_package,
-                entry_point,
-            } => (
-                module,
-                base_key,
-                contract.named_keys().clone(),
-                contract_package,
-                entry_point,
-            ),
+        // payment_code_spec_3_b_i: if (balance of handle payment pay purse) >= (gas spent
+        // during payment code execution) * gas_price, yes session
+        // session_code_spec_1: gas limit = ((balance of handle payment payment purse) /
+        // gas_price)
+        // - (gas spent during payment execution)
+        let session_gas_limit: Gas =
+            match Gas::from_motes(payment_purse_balance, deploy_item.gas_price)
+                .and_then(|gas| gas.checked_sub(payment_result_cost))
+            {
+                Some(gas) => gas,
+                None => {
+                    return Ok(ExecutionResult::precondition_failure(
+                        Error::GasConversionOverflow,
+                    ))
+                }
+            };
+
+        // Batched calls share the session address generators so that keys created by one call
+        // can never collide with keys created by another.
+        let hash_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), Phase::Session);
+            Rc::new(RefCell::new(generator))
+        };
+        let uref_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), Phase::Session);
+            Rc::new(RefCell::new(generator))
+        };
+        let transfer_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), Phase::Session);
+            Rc::new(RefCell::new(generator))
         };
 
-        let session_args = session.args().clone();
-        let mut session_result = {
-            // payment_code_spec_3_b_i: if (balance of handle payment pay purse) >= (gas spent
-            // during payment code execution) * gas_price, yes session
-            // session_code_spec_1: gas limit = ((balance of handle payment payment purse) /
-            // gas_price)
-            // - (gas spent during payment execution)
-            let session_gas_limit: Gas =
-                match Gas::from_motes(payment_purse_balance, deploy_item.gas_price)
-                    .and_then(|gas| gas.checked_sub(payment_result_cost))
+        let mut session_cost = Gas::default();
+        let mut session_transfers = Vec::new();
+        let mut session_result = ExecutionResult::default();
+
+        // session_code_spec_2: execute session code
+        for (index, (session_call, upfront_metadata)) in
+            session_calls.into_iter().zip(session_metadata).enumerate()
+        {
+            // Every call after the first has to observe the effects of the calls before it, so
+            // its account and metadata are reloaded from the session tracking copy.
+            let (account, metadata) = if index == 0 {
+                (account.clone(), Ok(upfront_metadata))
+            } else {
+                let account = match session_tracking_copy
+                    .borrow_mut()
+                    .get_account(correlation_id, account.account_hash())
                 {
-                    Some(gas) => gas,
-                    None => {
-                        return Ok(ExecutionResult::precondition_failure(
-                            Error::GasConversionOverflow,
-                        ))
+                    Ok(account) => account,
+                    Err(error) => {
+                        session_result = ExecutionResult::Failure {
+                            error: error.into(),
+                            effect: Default::default(),
+                            transfers: Vec::new(),
+                            cost: Gas::default(),
+                        };
+                        break;
                     }
                 };
+                let metadata = session_call.get_deploy_metadata(
+                    Rc::clone(&session_tracking_copy),
+                    &account,
+                    correlation_id,
+                    &preprocessor,
+                    &protocol_version,
+                    &protocol_data,
+                    Phase::Session,
+                    blocktime,
+                );
+                (account, metadata)
+            };
+
+            let metadata = match metadata {
+                Ok(metadata) => metadata,
+                Err(error) => {
+                    session_result = ExecutionResult::Failure {
+                        error,
+                        effect: Default::default(),
+                        transfers: Vec::new(),
+                        cost: Gas::default(),
+                    };
+                    break;
+                }
+            };
+
+            let (
+                session_module,
+                session_base_key,
+                mut session_named_keys,
+                session_package,
+                session_entry_point,
+                session_call_stack,
+            ) = match metadata {
+                DeployMetadata::System {
+                    base_key,
+                    contract,
+                    contract_package,
+                    entry_point,
+                    call_stack,
+                } => {
+                    (
+                        system_module.clone(),
+                        base_key, // this is contract key
+                        contract.named_keys().clone(),
+                        contract_package,
+                        entry_point,
+                        call_stack,
+                    )
+                }
+                DeployMetadata::Session {
+                    module,
+                    contract_package,
+                    entry_point,
+                    call_stack,
+                } => (
+                    module,
+                    base_key,
+                    account.named_keys().clone(),
+                    contract_package,
+                    entry_point,
+                    call_stack,
+                ),
+                DeployMetadata::Contract {
+                    module,
+                    base_key,
+                    contract,
+                    contract_package,
+                    entry_point,
+                    call_stack,
+                } => (
+                    module,
+                    base_key,
+                    contract.named_keys().clone(),
+                    contract_package,
+                    entry_point,
+                    call_stack,
+                ),
+            };
+
+            let session_args = session_call.args().clone();
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-            executor.exec(
+            // All calls draw from the single session gas budget.
+            let call_gas_limit = session_gas_limit
+                .checked_sub(session_cost)
+                .unwrap_or_default();
+
+            session_result = executor.exec(
                 session_module,
                 session_entry_point,
                 session_args,
                 session_base_key,
                 &account,
                 &mut session_named_keys,
-                authorization_keys.clone(),
+                session_authorization_keys.clone(),
                 blocktime,
                 deploy_hash,
-                session_gas_limit,
+                call_gas_limit,
+                Rc::clone(&hash_address_generator),
+                Rc::clone(&uref_address_generator),
+                Rc::clone(&transfer_address_generator),
                 protocol_version,
                 correlation_id,
                 Rc::clone(&session_tracking_copy),
@@ -1530,8 +1850,20 @@ where
                 protocol_data,
                 system_contract_cache,
                 &session_package,
-            )
-        };
+                session_call_stack,
+            );
+
+            session_cost += session_result.cost();
+            session_transfers.extend(session_result.transfers().iter().cloned());
+
+            if session_result.is_failure() {
+                break;
+            }
+        }
+
+        let mut session_result = session_result
+            .with_cost(session_cost)
+            .with_transfers(session_transfers);
         debug!("Session result: {:?}", session_result);
 
         // Create + persist deploy info.
@@ -1542,7 +1874,7 @@ where
                 deploy_hash,
                 &transfers,
                 account.account_hash(),
-                account.main_purse(),
+                payer.main_purse(),
                 cost,
             );
             session_tracking_copy.borrow_mut().write(
@@ -1578,7 +1910,7 @@ where
 
                 let maybe_runtime_args = RuntimeArgs::try_new(|args| {
                     args.insert(handle_payment::ARG_AMOUNT, finalize_cost_motes.value())?;
-                    args.insert(handle_payment::ARG_ACCOUNT, account_hash)?;
+                    args.insert(handle_payment::ARG_ACCOUNT, payer.account_hash())?;
                     args.insert(handle_payment::ARG_TARGET, proposer_purse)?;
                     Ok(())
                 });
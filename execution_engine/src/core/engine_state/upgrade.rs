@@ -87,6 +87,9 @@ pub struct UpgradeConfig {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_slashing_rate: Option<Ratio<u64>>,
+    new_delegation_slashing_rate: Option<Ratio<u64>>,
+    new_jail_period: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -104,6 +107,9 @@ impl UpgradeConfig {
         new_locked_funds_period_millis: Option<u64>,
         new_round_seigniorage_rate: Option<Ratio<u64>>,
         new_unbonding_delay: Option<u64>,
+        new_slashing_rate: Option<Ratio<u64>>,
+        new_delegation_slashing_rate: Option<Ratio<u64>>,
+        new_jail_period: Option<u64>,
         global_state_update: BTreeMap<Key, StoredValue>,
     ) -> Self {
         UpgradeConfig {
@@ -118,6 +124,9 @@ impl UpgradeConfig {
             new_locked_funds_period_millis,
             new_round_seigniorage_rate,
             new_unbonding_delay,
+            new_slashing_rate,
+            new_delegation_slashing_rate,
+            new_jail_period,
             global_state_update,
         }
     }
@@ -166,6 +175,18 @@ impl UpgradeConfig {
         self.new_unbonding_delay
     }
 
+    pub fn new_slashing_rate(&self) -> Option<Ratio<u64>> {
+        self.new_slashing_rate
+    }
+
+    pub fn new_delegation_slashing_rate(&self) -> Option<Ratio<u64>> {
+        self.new_delegation_slashing_rate
+    }
+
+    pub fn new_jail_period(&self) -> Option<u64> {
+        self.new_jail_period
+    }
+
     pub fn global_state_update(&self) -> &BTreeMap<Key, StoredValue> {
         &self.global_state_update
     }
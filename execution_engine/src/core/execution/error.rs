@@ -3,8 +3,8 @@ use thiserror::Error;
 
 use casper_types::{
     account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
-    bytesrepr, system, AccessRights, ApiError, CLType, CLValueError, ContractPackageHash,
-    ContractVersionKey, Key, URef,
+    bytesrepr, system, AccessRights, ApiError, CLType, CLValueError, ContractHash,
+    ContractPackageHash, ContractVersionKey, Key, URef,
 };
 
 use crate::{
@@ -82,6 +82,8 @@ pub enum Error {
     NoActiveContractVersions(ContractPackageHash),
     #[error("Invalid contract version: {}", _0)]
     InvalidContractVersion(ContractVersionKey),
+    #[error("Contract {} is a pending version and can't be called yet", _0)]
+    PendingContractVersion(ContractHash),
     #[error("No such method: {}", _0)]
     NoSuchMethod(String),
     #[error("Wasm preprocessing error: {}", _0)]
@@ -94,6 +96,8 @@ pub enum Error {
     UnexpectedStoredValueVariant,
     #[error("A locked contract cannot be upgraded")]
     LockedContract(ContractPackageHash),
+    #[error("URefs can't be extracted from a value holding type {:?}", _0)]
+    UnsupportedCLType(CLType),
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
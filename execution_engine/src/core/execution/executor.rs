@@ -8,7 +8,7 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::FromBytes,
     contracts::NamedKeys,
-    system::{auction, handle_payment, mint},
+    system::{auction, handle_payment, mint, CallStackElement},
     BlockTime, CLTyped, CLValue, ContractPackage, DeployHash, EntryPoint, EntryPointType, Key,
     Phase, ProtocolVersion, RuntimeArgs,
 };
@@ -97,6 +97,9 @@ impl Executor {
         blocktime: BlockTime,
         deploy_hash: DeployHash,
         gas_limit: Gas,
+        hash_address_generator: Rc<RefCell<AddressGenerator>>,
+        uref_address_generator: Rc<RefCell<AddressGenerator>>,
+        transfer_address_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -104,6 +107,7 @@ impl Executor {
         protocol_data: ProtocolData,
         system_contract_cache: SystemContractCache,
         contract_package: &ContractPackage,
+        call_stack: Vec<CallStackElement>,
     ) -> ExecutionResult
     where
         R: StateReader<Key, StoredValue>,
@@ -124,18 +128,6 @@ impl Executor {
             extract_access_rights_from_keys(keys)
         };
 
-        let hash_address_generator = {
-            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
-            Rc::new(RefCell::new(generator))
-        };
-        let uref_address_generator = {
-            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
-            Rc::new(RefCell::new(generator))
-        };
-        let target_address_generator = {
-            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
-            Rc::new(RefCell::new(generator))
-        };
         let gas_counter: Gas = Gas::default();
         let transfers = Vec::default();
 
@@ -158,7 +150,7 @@ impl Executor {
             gas_counter,
             hash_address_generator,
             uref_address_generator,
-            target_address_generator,
+            transfer_address_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -166,7 +158,14 @@ impl Executor {
             transfers,
         );
 
-        let mut runtime = Runtime::new(self.config, system_contract_cache, memory, module, context);
+        let mut runtime = Runtime::new(
+            self.config,
+            system_contract_cache,
+            memory,
+            module,
+            context,
+            call_stack,
+        );
 
         let accounts_access_rights = {
             let keys: Vec<Key> = account.named_keys().values().cloned().collect();
@@ -633,12 +632,15 @@ impl Executor {
             protocol_data.wasm_config(),
         )?;
 
+        let call_stack = vec![CallStackElement::session(account.account_hash())];
+
         let runtime = Runtime::new(
             self.config,
             system_contract_cache,
             memory,
             module,
             runtime_context,
+            call_stack,
         );
 
         Ok((instance, runtime))
@@ -50,6 +50,9 @@ pub enum FunctionIndex {
     Blake2b,
     RecordTransfer,
     RecordEraInfo,
+    AddContractVersionWithMigration,
+    LoadCallStack,
+    SetUpgradePolicy,
 }
 
 impl Into<usize> for FunctionIndex {
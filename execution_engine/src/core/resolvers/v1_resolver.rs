@@ -208,6 +208,18 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
                 FunctionIndex::RecordEraInfo.into(),
             ),
+            "casper_add_contract_version_with_migration" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 12][..], Some(ValueType::I32)),
+                FunctionIndex::AddContractVersionWithMigration.into(),
+            ),
+            "casper_load_call_stack" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::LoadCallStack.into(),
+            ),
+            "casper_set_upgrade_policy" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::SetUpgradePolicy.into(),
+            ),
             #[cfg(feature = "test-support")]
             "casper_print" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 2][..], None),
@@ -645,6 +645,7 @@ where
                     output_size as usize,
                     bytes_written_ptr,
                     version_ptr,
+                    None,
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -968,6 +969,110 @@ where
                 self.record_era_info(era_id, era_info)?;
                 Ok(Some(RuntimeValue::I32(0)))
             }
+
+            FunctionIndex::AddContractVersionWithMigration => {
+                // args(0) = pointer to package key in wasm memory
+                // args(1) = size of package key in wasm memory
+                // args(2) = pointer to version
+                // args(3) = pointer to entrypoints in wasm memory
+                // args(4) = size of entrypoints in wasm memory
+                // args(5) = pointer to named keys in wasm memory
+                // args(6) = size of named keys in wasm memory
+                // args(7) = pointer to migration entry point name in wasm memory
+                // args(8) = size of migration entry point name in wasm memory
+                // args(9) = pointer to output buffer for serialized key
+                // args(10) = size of output buffer
+                // args(11) = pointer to bytes written
+                let (
+                    contract_package_hash_ptr,
+                    contract_package_hash_size,
+                    version_ptr,
+                    entry_points_ptr,
+                    entry_points_size,
+                    named_keys_ptr,
+                    named_keys_size,
+                    migration_entry_point_ptr,
+                    migration_entry_point_size,
+                    output_ptr,
+                    output_size,
+                    bytes_written_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_contract_version_with_migration,
+                    [
+                        contract_package_hash_ptr,
+                        contract_package_hash_size,
+                        version_ptr,
+                        entry_points_ptr,
+                        entry_points_size,
+                        named_keys_ptr,
+                        named_keys_size,
+                        migration_entry_point_ptr,
+                        migration_entry_point_size,
+                        output_ptr,
+                        output_size,
+                        bytes_written_ptr,
+                    ],
+                )?;
+                scoped_instrumenter
+                    .add_property("entry_points_size", entry_points_size.to_string());
+                scoped_instrumenter.add_property("named_keys_size", named_keys_size.to_string());
+
+                let contract_package_hash: ContractPackageHash =
+                    self.t_from_mem(contract_package_hash_ptr, contract_package_hash_size)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let named_keys: NamedKeys = self.t_from_mem(named_keys_ptr, named_keys_size)?;
+                let migration_entry_point: String =
+                    self.t_from_mem(migration_entry_point_ptr, migration_entry_point_size)?;
+                let ret = self.add_contract_version(
+                    contract_package_hash,
+                    entry_points,
+                    named_keys,
+                    output_ptr,
+                    output_size as usize,
+                    bytes_written_ptr,
+                    version_ptr,
+                    Some(migration_entry_point),
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::LoadCallStack => {
+                // args(0) = pointer to length of call stack (output)
+                // args(1) = pointer to amount of serialized bytes (output)
+                let (call_stack_len_ptr, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.load_call_stack,
+                    [call_stack_len_ptr, result_size_ptr],
+                )?;
+                let ret = self.load_call_stack(call_stack_len_ptr, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SetUpgradePolicy => {
+                // args(0) = pointer to package hash in wasm memory
+                // args(1) = size of package hash in wasm memory
+                // args(2) = pointer to serialized upgrade policy in wasm memory
+                // args(3) = size of serialized upgrade policy in wasm memory
+                let (package_key_ptr, package_key_size, upgrade_policy_ptr, upgrade_policy_size) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.set_upgrade_policy,
+                    [
+                        package_key_ptr,
+                        package_key_size,
+                        upgrade_policy_ptr,
+                        upgrade_policy_size,
+                    ],
+                )?;
+                let contract_package_hash = self.t_from_mem(package_key_ptr, package_key_size)?;
+                let upgrade_policy = self.t_from_mem(upgrade_policy_ptr, upgrade_policy_size)?;
+
+                let result = self.set_upgrade_policy(contract_package_hash, upgrade_policy)?;
+
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
         }
     }
 }
@@ -23,14 +23,15 @@ use casper_types::{
     contracts::{
         self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
         DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
+        UpgradePolicy,
     },
     system::{
         self,
-        auction::{self, Auction, EraId, EraInfo},
+        auction::{self, Auction, EraId, EraInfo, RewardDestination},
         handle_payment::{self, HandlePayment},
         mint::{self, Mint},
         standard_payment::{self, StandardPayment},
-        SystemContractType,
+        CallStackElement, SystemContractType,
     },
     AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
     ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase, ProtocolVersion,
@@ -44,6 +45,7 @@ use crate::{
         resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
         runtime::scoped_instrumenter::ScopedInstrumenter,
         runtime_context::{self, RuntimeContext},
+        tracking_copy::TrackingCopyExt,
         Address,
     },
     shared::{
@@ -63,6 +65,7 @@ pub struct Runtime<'a, R> {
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    call_stack: Vec<CallStackElement>,
 }
 
 pub fn instance_and_memory(
@@ -956,6 +959,165 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
             let uref: URef = cl_value.to_owned().into_t()?; // TODO: optimize?
             Ok(vec![uref])
         }
+        CLType::Struct { .. } | CLType::Enum { .. } => {
+            let mut urefs = vec![];
+            extract_urefs_from_bytes(cl_value.cl_type(), cl_value.inner_bytes(), &mut urefs)?;
+            Ok(urefs)
+        }
+    }
+}
+
+/// Checks the args passed for parameters of struct or enum types against the entry point.
+///
+/// Values of such types can only be read as described by their type, so an arg serialized as a
+/// different struct or enum must not reach the entry point.  Other args are left to be checked by
+/// the called code as they're read.
+fn validate_named_type_args(entry_point: &EntryPoint, args: &RuntimeArgs) -> Result<(), Error> {
+    for parameter in entry_point.args() {
+        let expected = parameter.cl_type();
+        if !expected.is_named() {
+            continue;
+        }
+        if let Some(found) = args.get(parameter.name()).map(CLValue::cl_type) {
+            if expected != found {
+                return Err(Error::type_mismatch(expected.clone(), found.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if values of the given `CLType` serialize to no bytes at all.
+fn is_zero_sized(cl_type: &CLType) -> bool {
+    match cl_type {
+        CLType::Unit => true,
+        CLType::ByteArray(len) => *len == 0,
+        CLType::Tuple1(cl_types) => cl_types.iter().all(|cl_type| is_zero_sized(cl_type)),
+        CLType::Tuple2(cl_types) => cl_types.iter().all(|cl_type| is_zero_sized(cl_type)),
+        CLType::Tuple3(cl_types) => cl_types.iter().all(|cl_type| is_zero_sized(cl_type)),
+        CLType::Struct { fields, .. } => fields.iter().all(|(_, cl_type)| is_zero_sized(cl_type)),
+        _ => false,
+    }
+}
+
+/// Collects the `URef`s held by a value of the given `CLType` serialized in `bytes`, and returns
+/// the remainder of `bytes`.
+///
+/// The element count of a list or map comes from the untrusted `bytes`.  Elements which aren't
+/// zero-sized take at least one byte each, so a count larger than the bytes left is rejected before
+/// iterating, while zero-sized elements hold no `URef`s and are skipped without iterating.
+///
+/// The length of a value of type [`CLType::Any`] can't be known, so neither can the `URef`s
+/// following it.  Rather than letting those through unchecked, such values are rejected.
+fn extract_urefs_from_bytes<'a>(
+    cl_type: &CLType,
+    bytes: &'a [u8],
+    urefs: &mut Vec<URef>,
+) -> Result<&'a [u8], Error> {
+    fn skip<T: FromBytes>(bytes: &[u8]) -> Result<&[u8], Error> {
+        let (_, remainder) = T::from_bytes(bytes)?;
+        Ok(remainder)
+    }
+
+    fn extract_urefs_from_sequence<'a, 'b, I: IntoIterator<Item = &'b CLType>>(
+        cl_types: I,
+        mut bytes: &'a [u8],
+        urefs: &mut Vec<URef>,
+    ) -> Result<&'a [u8], Error> {
+        for cl_type in cl_types {
+            bytes = extract_urefs_from_bytes(cl_type, bytes, urefs)?;
+        }
+        Ok(bytes)
+    }
+
+    fn extract_urefs_from_repeated<'a>(
+        cl_types: &[&CLType],
+        count: u32,
+        mut bytes: &'a [u8],
+        urefs: &mut Vec<URef>,
+    ) -> Result<&'a [u8], Error> {
+        if cl_types.iter().all(|cl_type| is_zero_sized(cl_type)) {
+            return Ok(bytes);
+        }
+        if count as usize > bytes.len() {
+            return Err(bytesrepr::Error::EarlyEndOfStream.into());
+        }
+        for _ in 0..count {
+            bytes = extract_urefs_from_sequence(cl_types.iter().copied(), bytes, urefs)?;
+        }
+        Ok(bytes)
+    }
+
+    match cl_type {
+        CLType::Bool => skip::<bool>(bytes),
+        CLType::I32 => skip::<i32>(bytes),
+        CLType::I64 => skip::<i64>(bytes),
+        CLType::U8 => skip::<u8>(bytes),
+        CLType::U32 => skip::<u32>(bytes),
+        CLType::U64 => skip::<u64>(bytes),
+        CLType::U128 => skip::<U128>(bytes),
+        CLType::U256 => skip::<U256>(bytes),
+        CLType::U512 => skip::<U512>(bytes),
+        CLType::Unit => skip::<()>(bytes),
+        CLType::String => skip::<String>(bytes),
+        CLType::PublicKey => skip::<PublicKey>(bytes),
+        CLType::Key => {
+            let (key, remainder) = Key::from_bytes(bytes)?;
+            urefs.extend(key.into_uref());
+            Ok(remainder)
+        }
+        CLType::URef => {
+            let (uref, remainder) = URef::from_bytes(bytes)?;
+            urefs.push(uref);
+            Ok(remainder)
+        }
+        CLType::Option(inner) => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                bytesrepr::OPTION_NONE_TAG => Ok(remainder),
+                bytesrepr::OPTION_SOME_TAG => extract_urefs_from_bytes(inner, remainder, urefs),
+                _ => Err(bytesrepr::Error::Formatting.into()),
+            }
+        }
+        CLType::List(inner) => {
+            let (count, remainder) = u32::from_bytes(bytes)?;
+            extract_urefs_from_repeated(&[&**inner], count, remainder, urefs)
+        }
+        CLType::ByteArray(len) => bytes
+            .get(*len as usize..)
+            .ok_or_else(|| bytesrepr::Error::EarlyEndOfStream.into()),
+        CLType::Result { ok, err } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                bytesrepr::RESULT_ERR_TAG => extract_urefs_from_bytes(err, remainder, urefs),
+                bytesrepr::RESULT_OK_TAG => extract_urefs_from_bytes(ok, remainder, urefs),
+                _ => Err(bytesrepr::Error::Formatting.into()),
+            }
+        }
+        CLType::Map { key, value } => {
+            let (count, remainder) = u32::from_bytes(bytes)?;
+            extract_urefs_from_repeated(&[&**key, &**value], count, remainder, urefs)
+        }
+        CLType::Tuple1(cl_types) => {
+            extract_urefs_from_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes, urefs)
+        }
+        CLType::Tuple2(cl_types) => {
+            extract_urefs_from_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes, urefs)
+        }
+        CLType::Tuple3(cl_types) => {
+            extract_urefs_from_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes, urefs)
+        }
+        CLType::Any => Err(Error::UnsupportedCLType(CLType::Any)),
+        CLType::Struct { fields, .. } => {
+            extract_urefs_from_sequence(fields.iter().map(|(_, cl_type)| cl_type), bytes, urefs)
+        }
+        CLType::Enum { variants, .. } => {
+            let (index, remainder) = u8::from_bytes(bytes)?;
+            let (_, fields) = variants
+                .get(index as usize)
+                .ok_or(bytesrepr::Error::Formatting)?;
+            extract_urefs_from_sequence(fields.iter().map(|(_, cl_type)| cl_type), remainder, urefs)
+        }
     }
 }
 
@@ -970,6 +1132,7 @@ where
         memory: MemoryRef,
         module: Module,
         context: RuntimeContext<'a, R>,
+        call_stack: Vec<CallStackElement>,
     ) -> Self {
         Runtime {
             config,
@@ -978,6 +1141,7 @@ where
             module,
             host_buffer: None,
             context,
+            call_stack,
         }
     }
 
@@ -989,6 +1153,11 @@ where
         &self.module
     }
 
+    /// Returns the chain of callers, ending with the code currently executing.
+    pub fn call_stack(&self) -> &[CallStackElement] {
+        &self.call_stack
+    }
+
     pub fn context(&self) -> &RuntimeContext<'a, R> {
         &self.context
     }
@@ -1353,6 +1522,7 @@ where
             self.memory.clone(),
             self.module.clone(),
             mint_context,
+            self.call_stack.clone(),
         );
 
         let system_config = protocol_data.system_config();
@@ -1498,6 +1668,7 @@ where
             self.memory.clone(),
             self.module.clone(),
             runtime_context,
+            self.call_stack.clone(),
         );
 
         let system_config = protocol_data.system_config();
@@ -1625,6 +1796,7 @@ where
             self.memory.clone(),
             self.module.clone(),
             runtime_context,
+            self.call_stack.clone(),
         );
 
         let system_config = protocol_data.system_config();
@@ -1706,6 +1878,49 @@ where
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_REDELEGATE => (|| {
+                runtime.charge_system_contract_call(auction_costs.redelegate)?;
+
+                let delegator = Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+                let new_validator =
+                    Self::get_named_argument(&runtime_args, auction::ARG_NEW_VALIDATOR)?;
+
+                let result = runtime
+                    .redelegate(delegator, validator, amount, new_validator)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SET_DELEGATION_LIMITS => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_delegation_limits)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let minimum_delegation_amount = Self::get_named_argument(
+                    &runtime_args,
+                    auction::ARG_MINIMUM_DELEGATION_AMOUNT,
+                )?;
+                let maximum_delegation_amount = Self::get_named_argument(
+                    &runtime_args,
+                    auction::ARG_MAXIMUM_DELEGATION_AMOUNT,
+                )?;
+                let maximum_delegators =
+                    Self::get_named_argument(&runtime_args, auction::ARG_MAXIMUM_DELEGATORS)?;
+
+                runtime
+                    .set_delegation_limits(
+                        public_key,
+                        minimum_delegation_amount,
+                        maximum_delegation_amount,
+                        maximum_delegators,
+                    )
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
             auction::METHOD_RUN_AUCTION => (|| {
                 runtime.charge_system_contract_call(auction_costs.run_auction)?;
 
@@ -1764,6 +1979,55 @@ where
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_UNJAIL => (|| {
+                runtime.charge_system_contract_call(auction_costs.unjail)?;
+
+                let validator_public_key: PublicKey =
+                    Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR_PUBLIC_KEY)?;
+
+                runtime
+                    .unjail(validator_public_key)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_CHANGE_BID_PUBLIC_KEY => (|| {
+                runtime.charge_system_contract_call(auction_costs.change_bid_public_key)?;
+
+                let public_key: PublicKey =
+                    Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let new_public_key: PublicKey =
+                    Self::get_named_argument(&runtime_args, auction::ARG_NEW_PUBLIC_KEY)?;
+
+                let result = runtime
+                    .change_bid_public_key(public_key, new_public_key)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SET_REWARD_DESTINATION => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_reward_destination)?;
+
+                let validator_public_key: PublicKey =
+                    Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let delegator_public_key: Option<PublicKey> =
+                    Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+                let reward_destination: RewardDestination =
+                    Self::get_named_argument(&runtime_args, auction::ARG_REWARD_DESTINATION)?;
+
+                runtime
+                    .set_reward_destination(
+                        validator_public_key,
+                        delegator_public_key,
+                        reward_destination,
+                    )
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
@@ -1803,15 +2067,33 @@ where
             None => return Err(Error::KeyNotFound(key)),
         };
 
+        let package_key = contract.contract_package_hash().into();
+        let mut contract_package = match self.context.read_gs(&package_key)? {
+            Some(StoredValue::ContractPackage(contract_package)) => contract_package,
+            Some(_) => {
+                return Err(Error::FunctionNotFound(format!(
+                    "Value at {:?} is not a versioned contract",
+                    package_key
+                )));
+            }
+            None => return Err(Error::KeyNotFound(package_key)),
+        };
+        self.activate_contract_versions(&mut contract_package)?;
+        if contract_package.is_contract_pending(contract_hash) {
+            return Err(Error::PendingContractVersion(contract_hash));
+        }
+
         let entry_point = contract
             .entry_point(entry_point_name)
             .cloned()
             .ok_or_else(|| Error::NoSuchMethod(entry_point_name.to_owned()))?;
 
+        validate_named_type_args(&entry_point, &args)?;
+
         let context_key = self.get_context_key_for_contract_call(contract_hash, &entry_point)?;
 
         self.execute_contract(
-            key,
+            contract_hash,
             context_key,
             contract,
             args,
@@ -1832,7 +2114,7 @@ where
     ) -> Result<CLValue, Error> {
         let key = contract_package_hash.into();
 
-        let contract_package = match self.context.read_gs(&key)? {
+        let mut contract_package = match self.context.read_gs(&key)? {
             Some(StoredValue::ContractPackage(contract_package)) => contract_package,
             Some(_) => {
                 return Err(Error::FunctionNotFound(format!(
@@ -1843,6 +2125,8 @@ where
             None => return Err(Error::KeyNotFound(key)),
         };
 
+        self.activate_contract_versions(&mut contract_package)?;
+
         let contract_version_key = match contract_version {
             Some(version) => {
                 ContractVersionKey::new(self.context.protocol_version().value().major, version)
@@ -1889,11 +2173,12 @@ where
                 return Err(Error::type_mismatch(expected, found));
             }
         }
+        validate_named_type_args(&entry_point, &args)?;
 
         let context_key = self.get_context_key_for_contract_call(contract_hash, &entry_point)?;
 
         self.execute_contract(
-            context_key,
+            contract_hash,
             context_key,
             contract,
             args,
@@ -1925,13 +2210,15 @@ where
 
     fn execute_contract(
         &mut self,
-        key: Key,
+        contract_hash: ContractHash,
         base_key: Key,
         contract: Contract,
         args: RuntimeArgs,
         entry_point: EntryPoint,
         protocol_version: ProtocolVersion,
     ) -> Result<CLValue, Error> {
+        let key = Key::from(contract_hash);
+
         // Check for major version compatibility before calling
         if !contract.is_compatible_protocol_version(protocol_version) {
             return Err(Error::IncompatibleProtocolMajorVersion {
@@ -1995,6 +2282,35 @@ where
             extra_keys
         };
 
+        let call_stack = {
+            let contract_package_hash = contract.contract_package_hash();
+            if entry_point.is_non_reentrant()
+                && self
+                    .call_stack
+                    .iter()
+                    .any(|element| element.contract_package_hash() == Some(&contract_package_hash))
+            {
+                return Err(Error::Revert(ApiError::ReentrantCall));
+            }
+
+            let element = match entry_point.entry_point_type() {
+                EntryPointType::Session => CallStackElement::stored_session(
+                    self.context.account().account_hash(),
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point.name(),
+                ),
+                EntryPointType::Contract => CallStackElement::stored_contract(
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point.name(),
+                ),
+            };
+            let mut call_stack = self.call_stack.clone();
+            call_stack.push(element);
+            call_stack
+        };
+
         let module = {
             let maybe_module = key
                 .into_hash()
@@ -2069,6 +2385,7 @@ where
             module,
             host_buffer,
             context,
+            call_stack,
         };
 
         let result = instance.invoke_export(entry_point_name, &[], &mut runtime);
@@ -2251,6 +2568,43 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the length of the call stack to `call_stack_len_ptr` and, unless it's empty, its
+    /// serialized size to `result_size_ptr`, with the serialized call stack put in the host buffer.
+    fn load_call_stack(
+        &mut self,
+        call_stack_len_ptr: u32,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let call_stack_len = self.call_stack.len() as u32;
+        let call_stack_len_bytes = call_stack_len.to_le_bytes();
+        if let Err(error) = self.memory.set(call_stack_len_ptr, &call_stack_len_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        if call_stack_len == 0 {
+            return Ok(Ok(()));
+        }
+
+        let call_stack = CLValue::from_t(self.call_stack.clone()).map_err(Error::CLValue)?;
+
+        let length = call_stack.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(call_stack) {
+            return Ok(Err(error));
+        }
+
+        let length_bytes = length.to_le_bytes();
+        if let Err(error) = self.memory.set(result_size_ptr, &length_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     fn create_contract_package(
         &mut self,
         is_locked: ContractPackageStatus,
@@ -2358,11 +2712,12 @@ where
         output_size: usize,
         bytes_written_ptr: u32,
         version_ptr: u32,
+        migration_entry_point: Option<String>,
     ) -> Result<Result<(), ApiError>, Error> {
         self.context
             .validate_key(&Key::from(contract_package_hash))?;
 
-        let mut contract_package: ContractPackage = self
+        let contract_package: ContractPackage = self
             .context
             .get_validated_contract_package(contract_package_hash)?;
 
@@ -2373,6 +2728,10 @@ where
             return Err(Error::LockedContract(contract_package_hash));
         }
 
+        if !self.is_upgrade_approved(&contract_package) {
+            return Ok(Err(contracts::Error::UpgradeNotApproved.into()));
+        }
+
         let contract_wasm_hash = self.context.new_hash_address()?;
         let contract_wasm = {
             let module_bytes = self.get_module_from_entry_points(&entry_points)?;
@@ -2401,13 +2760,42 @@ where
             protocol_version,
         );
 
-        let insert_contract_result =
-            contract_package.insert_contract_version(major, contract_hash.into());
-
         self.context
             .metered_write_gs_unsafe(contract_wasm_hash, contract_wasm)?;
         self.context
-            .metered_write_gs_unsafe(contract_hash, contract)?;
+            .metered_write_gs_unsafe(contract_hash, contract.clone())?;
+
+        // The migration runs before the new version is added to the package, so it can't be called
+        // by version until the migration has succeeded. Any error it returns fails the whole
+        // execution, discarding the new version along with any effects of the migration.
+        if let Some(migration_entry_point) = migration_entry_point {
+            self.run_migration(
+                contract_package.access_key(),
+                contract_hash.into(),
+                contract,
+                &migration_entry_point,
+            )?;
+        }
+
+        // The migration may have modified the package, e.g. its groups, so read it again.
+        let mut contract_package: ContractPackage = self
+            .context
+            .get_validated_contract_package(contract_package_hash)?;
+
+        let insert_contract_result = match contract_package.upgrade_policy().upgrade_delay() {
+            Some(upgrade_delay) => {
+                let era_id = self.current_era_id()?;
+                let activation =
+                    upgrade_delay.activation_after(era_id, self.context.get_blocktime());
+                contract_package.insert_pending_contract_version(
+                    major,
+                    contract_hash.into(),
+                    activation,
+                )
+            }
+            None => contract_package.insert_contract_version(major, contract_hash.into()),
+        };
+
         self.context
             .metered_write_gs_unsafe(contract_package_hash, contract_package)?;
 
@@ -2445,6 +2833,39 @@ where
         Ok(Ok(()))
     }
 
+    /// Calls the migration entry point of a newly added contract version, passing it the access
+    /// `URef` of the contract package.
+    fn run_migration(
+        &mut self,
+        access_key: URef,
+        contract_hash: ContractHash,
+        contract: Contract,
+        entry_point_name: &str,
+    ) -> Result<(), Error> {
+        let entry_point = contract
+            .entry_point(entry_point_name)
+            .cloned()
+            .ok_or_else(|| Error::NoSuchMethod(entry_point_name.to_owned()))?;
+
+        // Only a contract entry point runs in the context of the new version, with its named keys.
+        if entry_point.entry_point_type() != EntryPointType::Contract {
+            return Err(Error::InvalidContext);
+        }
+
+        let mut args = RuntimeArgs::new();
+        args.insert(contracts::MIGRATION_ACCESS_KEY_ARG_NAME, access_key)?;
+
+        self.execute_contract(
+            contract_hash,
+            Key::from(contract_hash),
+            contract,
+            args,
+            entry_point,
+            self.context.protocol_version(),
+        )?;
+        Ok(())
+    }
+
     fn disable_contract_version(
         &mut self,
         contract_package_hash: ContractPackageHash,
@@ -2462,6 +2883,10 @@ where
             return Err(Error::LockedContract(contract_package_hash));
         }
 
+        if !self.is_upgrade_approved(&contract_package) {
+            return Ok(Err(contracts::Error::UpgradeNotApproved.into()));
+        }
+
         if let Err(err) = contract_package.disable_contract_version(contract_hash) {
             return Ok(Err(err.into()));
         }
@@ -2472,6 +2897,93 @@ where
         Ok(Ok(()))
     }
 
+    /// Replaces the upgrade policy of a contract package. The current policy's approval groups
+    /// must approve the change, and the new policy must be at least as strict.
+    fn set_upgrade_policy(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+        upgrade_policy: UpgradePolicy,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let contract_package_key = contract_package_hash.into();
+        self.context.validate_key(&contract_package_key)?;
+
+        let mut contract_package: ContractPackage = self
+            .context
+            .get_validated_contract_package(contract_package_hash)?;
+
+        if contract_package.is_locked() {
+            return Err(Error::LockedContract(contract_package_hash));
+        }
+
+        if !self.is_upgrade_approved(&contract_package) {
+            return Ok(Err(contracts::Error::UpgradeNotApproved.into()));
+        }
+
+        if let Err(err) = contract_package.set_upgrade_policy(upgrade_policy) {
+            return Ok(Err(err.into()));
+        }
+
+        self.context
+            .metered_write_gs_unsafe(contract_package_key, contract_package)?;
+
+        Ok(Ok(()))
+    }
+
+    /// Checks that the current context holds a URef from each of the approval groups required by
+    /// the upgrade policy of `contract_package`.
+    fn is_upgrade_approved(&self, contract_package: &ContractPackage) -> bool {
+        contract_package
+            .upgrade_policy()
+            .approval_groups()
+            .iter()
+            .all(|group| {
+                contract_package
+                    .groups()
+                    .get(group)
+                    .map(|urefs| {
+                        urefs
+                            .iter()
+                            .any(|uref| self.context.validate_uref(uref).is_ok())
+                    })
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Checks that the URefs of `group` may be changed by the current context. Those of an approval
+    /// group of the upgrade policy of `contract_package` grant approval of upgrades, so changing
+    /// them needs the same approval as an upgrade.
+    fn is_group_change_approved(&self, contract_package: &ContractPackage, group: &Group) -> bool {
+        !contract_package
+            .upgrade_policy()
+            .approval_groups()
+            .contains(group)
+            || self.is_upgrade_approved(contract_package)
+    }
+
+    /// Makes the pending versions of `contract_package` whose activation point has been reached
+    /// available for use. The change isn't written to global state.
+    fn activate_contract_versions(
+        &mut self,
+        contract_package: &mut ContractPackage,
+    ) -> Result<(), Error> {
+        if contract_package.pending_versions().is_empty() {
+            return Ok(());
+        }
+        let era_id = self.current_era_id()?;
+        contract_package.activate_versions(era_id, self.context.get_blocktime());
+        Ok(())
+    }
+
+    /// Reads the current era id from the auction contract.
+    fn current_era_id(&mut self) -> Result<EraId, Error> {
+        let auction_contract_hash = self.context.protocol_data().auction();
+        let correlation_id = self.context.correlation_id();
+        self.context
+            .state()
+            .borrow_mut()
+            .get_era_id(correlation_id, auction_contract_hash)
+    }
+
     /// Writes function address (`hash_bytes`) into the Wasm memory (at
     /// `dest_ptr` pointer).
     fn function_address(&mut self, hash_bytes: [u8; 32], dest_ptr: u32) -> Result<(), Trap> {
@@ -3202,6 +3714,15 @@ where
             return Ok(Err(contracts::Error::GroupDoesNotExist.into()));
         }
 
+        // The upgrade policy can't be loosened, so its approval groups can't be removed.
+        if package
+            .upgrade_policy()
+            .approval_groups()
+            .contains(&group_to_remove)
+        {
+            return Ok(Err(contracts::Error::GroupInUse.into()));
+        }
+
         // Remove group if it is not referenced by at least one entry_point in active versions.
         let versions = package.versions();
         for contract_hash in versions.values() {
@@ -3246,10 +3767,15 @@ where
         let mut contract_package = self
             .context
             .get_validated_contract_package(contract_package_hash)?;
-        let groups = contract_package.groups_mut();
 
         let group_label = Group::new(label);
 
+        if !self.is_group_change_approved(&contract_package, &group_label) {
+            return Ok(Err(contracts::Error::UpgradeNotApproved.into()));
+        }
+
+        let groups = contract_package.groups_mut();
+
         // Ensure there are not too many urefs
         let total_urefs: usize = groups.values().map(|urefs| urefs.len()).sum();
 
@@ -3316,9 +3842,14 @@ where
             .context
             .get_validated_contract_package(contract_package_hash)?;
 
-        let groups = contract_package.groups_mut();
         let group_label = Group::new(label);
 
+        if !self.is_group_change_approved(&contract_package, &group_label) {
+            return Ok(Err(contracts::Error::UpgradeNotApproved.into()));
+        }
+
+        let groups = contract_package.groups_mut();
+
         let group = match groups.get_mut(&group_label) {
             Some(group) => group,
             None => return Ok(Err(contracts::Error::GroupDoesNotExist.into())),
@@ -3367,9 +3898,15 @@ mod tests {
         result,
     };
 
-    use casper_types::{gens::*, AccessRights, CLType, CLValue, Key, PublicKey, SecretKey, URef};
+    use casper_types::{
+        bytesrepr::{self, ToBytes},
+        gens::*,
+        AccessRights, CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key,
+        Parameter, PublicKey, RuntimeArgs, SecretKey, URef,
+    };
 
-    use super::extract_urefs;
+    use super::{extract_urefs, validate_named_type_args};
+    use crate::core::execution::Error;
 
     fn cl_value_with_urefs_arb() -> impl Strategy<Value = (CLValue, Vec<URef>)> {
         // If compiler brings you here it most probably means you've added a variant to `CLType`
@@ -3399,7 +3936,9 @@ mod tests {
                 | CLType::Tuple2(_)
                 | CLType::Tuple3(_)
                 | CLType::PublicKey
-                | CLType::Any => (),
+                | CLType::Any
+                | CLType::Struct { .. }
+                | CLType::Enum { .. } => (),
             }
         };
 
@@ -3494,6 +4033,29 @@ mod tests {
                 urefs.extend(x.1.as_uref().into_iter().cloned());
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
             }),
+            (".*", vec(uref_arb(), 0..10), option::of(key_arb())).prop_map(|x| {
+                let cl_type = CLType::new_struct(
+                    "Purses",
+                    &[
+                        ("name", CLType::String),
+                        ("purses", <Vec<URef>>::cl_type()),
+                        ("key", <Option<Key>>::cl_type()),
+                    ],
+                );
+                let mut urefs = x.1.clone();
+                urefs.extend(x.2.iter().flat_map(Key::as_uref).cloned());
+                let bytes = x.to_bytes().expect("should serialize");
+                (CLValue::from_components(cl_type, bytes), urefs)
+            }),
+            option::of(uref_arb()).prop_map(|x| {
+                let cl_type = CLType::new_enum(
+                    "MaybePurse",
+                    &[("None", &[]), ("Some", &[("purse", CLType::URef)])],
+                );
+                let urefs = x.iter().cloned().collect();
+                let bytes = x.to_bytes().expect("should serialize");
+                (CLValue::from_components(cl_type, bytes), urefs)
+            }),
         ]
     }
 
@@ -3529,4 +4091,96 @@ mod tests {
         let cl_value = CLValue::from_t(map).unwrap();
         assert_eq!(extract_urefs(&cl_value).unwrap(), vec![uref]);
     }
+
+    #[test]
+    fn should_skip_zero_sized_list_elements_without_iterating() {
+        let cl_type = CLType::new_struct(
+            "Units",
+            &[
+                ("units", CLType::List(Box::new(CLType::Unit))),
+                (
+                    "empty",
+                    CLType::List(Box::new(CLType::new_struct("Empty", &[]))),
+                ),
+                ("purse", CLType::URef),
+            ],
+        );
+        let uref = URef::new([43; 32], AccessRights::READ_ADD_WRITE);
+        let mut bytes = u32::MAX.to_bytes().unwrap();
+        bytes.extend(u32::MAX.to_bytes().unwrap());
+        bytes.extend(uref.to_bytes().unwrap());
+        let cl_value = CLValue::from_components(cl_type, bytes);
+        assert_eq!(extract_urefs(&cl_value).unwrap(), vec![uref]);
+    }
+
+    #[test]
+    fn should_not_extract_urefs_from_list_longer_than_its_bytes() {
+        let cl_type = CLType::new_struct("Bytes", &[("bytes", CLType::List(Box::new(CLType::U8)))]);
+        let mut bytes = u32::MAX.to_bytes().unwrap();
+        bytes.extend(&[1, 2, 3]);
+        let cl_value = CLValue::from_components(cl_type, bytes);
+        assert!(matches!(
+            extract_urefs(&cl_value),
+            Err(Error::BytesRepr(bytesrepr::Error::EarlyEndOfStream))
+        ));
+
+        let cl_type = CLType::new_struct(
+            "Keys",
+            &[(
+                "keys",
+                CLType::Map {
+                    key: Box::new(CLType::Unit),
+                    value: Box::new(CLType::Key),
+                },
+            )],
+        );
+        let cl_value = CLValue::from_components(cl_type, u32::MAX.to_bytes().unwrap());
+        assert!(matches!(
+            extract_urefs(&cl_value),
+            Err(Error::BytesRepr(bytesrepr::Error::EarlyEndOfStream))
+        ));
+    }
+
+    #[test]
+    fn should_not_extract_urefs_following_any() {
+        let cl_type =
+            CLType::new_struct("Opaque", &[("value", CLType::Any), ("purse", CLType::URef)]);
+        let uref = URef::new([43; 32], AccessRights::READ_ADD_WRITE);
+        let bytes = uref.to_bytes().unwrap();
+        let cl_value = CLValue::from_components(cl_type, bytes);
+        assert!(matches!(
+            extract_urefs(&cl_value),
+            Err(Error::UnsupportedCLType(CLType::Any))
+        ));
+    }
+
+    #[test]
+    fn should_validate_named_type_args() {
+        let point_type = CLType::new_struct("Point", &[("x", CLType::I32), ("y", CLType::I32)]);
+        let size_type =
+            CLType::new_struct("Size", &[("width", CLType::I32), ("height", CLType::I32)]);
+        let entry_point = EntryPoint::new(
+            "move_to",
+            vec![
+                Parameter::new("point", point_type.clone()),
+                Parameter::new("speed", CLType::U32),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        let bytes = (1i32, 2i32).to_bytes().unwrap();
+
+        let mut args = RuntimeArgs::new();
+        args.insert_cl_value("point", CLValue::from_components(point_type, bytes.clone()));
+        args.insert("speed", 1u64).unwrap();
+        assert!(validate_named_type_args(&entry_point, &args).is_ok());
+
+        let mut args = RuntimeArgs::new();
+        args.insert_cl_value("point", CLValue::from_components(size_type, bytes));
+        assert!(matches!(
+            validate_named_type_args(&entry_point, &args),
+            Err(Error::TypeMismatch(_))
+        ));
+    }
 }
@@ -143,6 +143,11 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::Blake2b => "host_blake2b",
             FunctionIndex::RecordTransfer => "host_record_transfer",
             FunctionIndex::RecordEraInfo => "host_record_era_info",
+            FunctionIndex::AddContractVersionWithMigration => {
+                "host_function_add_contract_version_with_migration"
+            }
+            FunctionIndex::LoadCallStack => "host_function_load_call_stack",
+            FunctionIndex::SetUpgradePolicy => "host_function_set_upgrade_policy",
         };
 
         let mut properties = mem::take(&mut self.properties);
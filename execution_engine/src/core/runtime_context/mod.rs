@@ -545,7 +545,9 @@ where
                 | CLType::Tuple1(_)
                 | CLType::Tuple3(_)
                 | CLType::Any
-                | CLType::PublicKey => Ok(()),
+                | CLType::PublicKey
+                | CLType::Struct { .. }
+                | CLType::Enum { .. } => Ok(()),
                 CLType::Key => {
                     let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
                     self.validate_key(&key)
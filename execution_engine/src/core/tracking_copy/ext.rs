@@ -3,8 +3,10 @@ use std::convert::TryInto;
 use parity_wasm::elements::Module;
 
 use casper_types::{
-    account::AccountHash, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash,
-    ContractWasm, ContractWasmHash, Key, URef,
+    account::AccountHash,
+    system::auction::{EraId, ERA_ID_KEY},
+    CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
+    ContractWasmHash, Key, URef,
 };
 
 use crate::{
@@ -83,6 +85,13 @@ pub trait TrackingCopyExt<R> {
         contract_package_hash: ContractPackageHash,
     ) -> Result<ContractPackage, Self::Error>;
 
+    /// Gets the current era id as recorded by the auction contract
+    fn get_era_id(
+        &mut self,
+        correlation_id: CorrelationId,
+        auction_contract_hash: ContractHash,
+    ) -> Result<EraId, Self::Error>;
+
     fn get_system_module(&mut self, preprocessor: &Preprocessor) -> Result<Module, Self::Error>;
 }
 
@@ -244,6 +253,27 @@ where
         }
     }
 
+    fn get_era_id(
+        &mut self,
+        correlation_id: CorrelationId,
+        auction_contract_hash: ContractHash,
+    ) -> Result<EraId, Self::Error> {
+        let auction_contract = self.get_contract(correlation_id, auction_contract_hash)?;
+        let era_id_key = auction_contract
+            .named_keys()
+            .get(ERA_ID_KEY)
+            .cloned()
+            .ok_or_else(|| execution::Error::NamedKeyNotFound(ERA_ID_KEY.to_string()))?;
+        match self.get(correlation_id, &era_id_key).map_err(Into::into)? {
+            Some(StoredValue::CLValue(cl_value)) => Ok(cl_value.into_t()?),
+            Some(other) => Err(execution::Error::TypeMismatch(TypeMismatch::new(
+                "CLValue".to_string(),
+                other.type_name(),
+            ))),
+            None => Err(execution::Error::KeyNotFound(era_id_key)),
+        }
+    }
+
     fn get_system_module(&mut self, preprocessor: &Preprocessor) -> Result<Module, Self::Error> {
         Ok(wasm::do_nothing_module(preprocessor)?)
     }
@@ -186,6 +186,18 @@ impl Account {
                 .all(|e| self.associated_keys.contains_key(e))
     }
 
+    /// Returns the subset of the given authorization keys which are associated with this account.
+    pub fn associated_authorization_keys(
+        &self,
+        authorization_keys: &BTreeSet<AccountHash>,
+    ) -> BTreeSet<AccountHash> {
+        authorization_keys
+            .iter()
+            .filter(|key| self.associated_keys.contains_key(key))
+            .copied()
+            .collect()
+    }
+
     /// Checks whether the sum of the weights of all authorization keys is
     /// greater or equal to deploy threshold.
     pub fn can_deploy_with(&self, authorization_keys: &BTreeSet<AccountHash>) -> bool {
@@ -356,6 +368,34 @@ mod tests {
         assert!(!account.can_authorize(&BTreeSet::new()));
     }
 
+    #[test]
+    fn account_filters_associated_authorization_keys() {
+        let key_1 = AccountHash::new([1u8; 32]);
+        let key_2 = AccountHash::new([2u8; 32]);
+        let unassociated_key = AccountHash::new([42u8; 32]);
+        let mut keys = AssociatedKeys::new(key_1, Weight::new(1));
+        keys.add_key(key_2, Weight::new(2))
+            .expect("should add key_2");
+
+        let account = Account::new(
+            AccountHash::new([0u8; 32]),
+            NamedKeys::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            keys,
+            ActionThresholds::new(Weight::new(1), Weight::new(1))
+                .expect("should create thresholds"),
+        );
+
+        let authorization_keys = BTreeSet::from_iter(vec![key_2, unassociated_key]);
+        assert_eq!(
+            account.associated_authorization_keys(&authorization_keys),
+            BTreeSet::from_iter(vec![key_2])
+        );
+        assert!(account
+            .associated_authorization_keys(&BTreeSet::from_iter(vec![unassociated_key]))
+            .is_empty());
+    }
+
     #[test]
     fn account_can_deploy_with() {
         let associated_keys = {
@@ -227,6 +227,9 @@ pub struct HostFunctionCosts {
     pub remove_contract_user_group_urefs: HostFunction<[Cost; 6]>,
     pub print: HostFunction<[Cost; 2]>,
     pub blake2b: HostFunction<[Cost; 4]>,
+    pub add_contract_version_with_migration: HostFunction<[Cost; 12]>,
+    pub load_call_stack: HostFunction<[Cost; 2]>,
+    pub set_upgrade_policy: HostFunction<[Cost; 4]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -344,6 +347,9 @@ impl Default for HostFunctionCosts {
                 [NOT_USED, DEFAULT_PRINT_TEXT_SIZE_WEIGHT],
             ),
             blake2b: HostFunction::default(),
+            add_contract_version_with_migration: HostFunction::default(),
+            load_call_stack: HostFunction::default(),
+            set_upgrade_policy: HostFunction::default(),
         }
     }
 }
@@ -393,6 +399,9 @@ impl ToBytes for HostFunctionCosts {
         ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
         ret.append(&mut self.print.to_bytes()?);
         ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.add_contract_version_with_migration.to_bytes()?);
+        ret.append(&mut self.load_call_stack.to_bytes()?);
+        ret.append(&mut self.set_upgrade_policy.to_bytes()?);
         Ok(ret)
     }
 
@@ -439,6 +448,9 @@ impl ToBytes for HostFunctionCosts {
             + self.remove_contract_user_group_urefs.serialized_length()
             + self.print.serialized_length()
             + self.blake2b.serialized_length()
+            + self.add_contract_version_with_migration.serialized_length()
+            + self.load_call_stack.serialized_length()
+            + self.set_upgrade_policy.serialized_length()
     }
 }
 
@@ -486,6 +498,9 @@ impl FromBytes for HostFunctionCosts {
         let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
         let (print, rem) = FromBytes::from_bytes(rem)?;
         let (blake2b, rem) = FromBytes::from_bytes(rem)?;
+        let (add_contract_version_with_migration, rem) = FromBytes::from_bytes(rem)?;
+        let (load_call_stack, rem) = FromBytes::from_bytes(rem)?;
+        let (set_upgrade_policy, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             HostFunctionCosts {
                 read_value,
@@ -530,6 +545,9 @@ impl FromBytes for HostFunctionCosts {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                add_contract_version_with_migration,
+                load_call_stack,
+                set_upgrade_policy,
             },
             rem,
         ))
@@ -581,6 +599,9 @@ impl Distribution<HostFunctionCosts> for Standard {
             remove_contract_user_group_urefs: rng.gen(),
             print: rng.gen(),
             blake2b: rng.gen(),
+            add_contract_version_with_migration: rng.gen(),
+            load_call_stack: rng.gen(),
+            set_upgrade_policy: rng.gen(),
         }
     }
 }
@@ -639,6 +660,9 @@ pub mod gens {
             remove_contract_user_group_urefs in host_function_cost_arb(),
             print in host_function_cost_arb(),
             blake2b in host_function_cost_arb(),
+            add_contract_version_with_migration in host_function_cost_arb(),
+            load_call_stack in host_function_cost_arb(),
+            set_upgrade_policy in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -683,6 +707,9 @@ pub mod gens {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                add_contract_version_with_migration,
+                load_call_stack,
+                set_upgrade_policy,
             }
         }
     }
@@ -16,6 +16,11 @@ pub const DEFAULT_WITHDRAW_DELEGATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_READ_ERA_ID_COST: u32 = 10_000;
 pub const DEFAULT_ACTIVATE_BID_COST: u32 = 10_000;
+pub const DEFAULT_REDELEGATE_COST: u32 = 10_000;
+pub const DEFAULT_SET_DELEGATION_LIMITS_COST: u32 = 10_000;
+pub const DEFAULT_UNJAIL_COST: u32 = 10_000;
+pub const DEFAULT_CHANGE_BID_PUBLIC_KEY_COST: u32 = 10_000;
+pub const DEFAULT_SET_REWARD_DESTINATION_COST: u32 = 10_000;
 
 /// Description of costs of calling auction entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -33,6 +38,11 @@ pub struct AuctionCosts {
     pub withdraw_validator_reward: u32,
     pub read_era_id: u32,
     pub activate_bid: u32,
+    pub redelegate: u32,
+    pub set_delegation_limits: u32,
+    pub unjail: u32,
+    pub change_bid_public_key: u32,
+    pub set_reward_destination: u32,
 }
 
 impl Default for AuctionCosts {
@@ -51,6 +61,11 @@ impl Default for AuctionCosts {
             withdraw_validator_reward: DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST,
             read_era_id: DEFAULT_READ_ERA_ID_COST,
             activate_bid: DEFAULT_ACTIVATE_BID_COST,
+            redelegate: DEFAULT_REDELEGATE_COST,
+            set_delegation_limits: DEFAULT_SET_DELEGATION_LIMITS_COST,
+            unjail: DEFAULT_UNJAIL_COST,
+            change_bid_public_key: DEFAULT_CHANGE_BID_PUBLIC_KEY_COST,
+            set_reward_destination: DEFAULT_SET_REWARD_DESTINATION_COST,
         }
     }
 }
@@ -72,6 +87,11 @@ impl ToBytes for AuctionCosts {
         ret.append(&mut self.withdraw_validator_reward.to_bytes()?);
         ret.append(&mut self.read_era_id.to_bytes()?);
         ret.append(&mut self.activate_bid.to_bytes()?);
+        ret.append(&mut self.redelegate.to_bytes()?);
+        ret.append(&mut self.set_delegation_limits.to_bytes()?);
+        ret.append(&mut self.unjail.to_bytes()?);
+        ret.append(&mut self.change_bid_public_key.to_bytes()?);
+        ret.append(&mut self.set_reward_destination.to_bytes()?);
 
         Ok(ret)
     }
@@ -90,6 +110,11 @@ impl ToBytes for AuctionCosts {
             + self.withdraw_validator_reward.serialized_length()
             + self.read_era_id.serialized_length()
             + self.activate_bid.serialized_length()
+            + self.redelegate.serialized_length()
+            + self.set_delegation_limits.serialized_length()
+            + self.unjail.serialized_length()
+            + self.change_bid_public_key.serialized_length()
+            + self.set_reward_destination.serialized_length()
     }
 }
 
@@ -108,6 +133,11 @@ impl FromBytes for AuctionCosts {
         let (withdraw_validator_reward, rem) = FromBytes::from_bytes(rem)?;
         let (read_era_id, rem) = FromBytes::from_bytes(rem)?;
         let (activate_bid, rem) = FromBytes::from_bytes(rem)?;
+        let (redelegate, rem) = FromBytes::from_bytes(rem)?;
+        let (set_delegation_limits, rem) = FromBytes::from_bytes(rem)?;
+        let (unjail, rem) = FromBytes::from_bytes(rem)?;
+        let (change_bid_public_key, rem) = FromBytes::from_bytes(rem)?;
+        let (set_reward_destination, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             Self {
                 get_era_validators,
@@ -123,6 +153,11 @@ impl FromBytes for AuctionCosts {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
+                set_delegation_limits,
+                unjail,
+                change_bid_public_key,
+                set_reward_destination,
             },
             rem,
         ))
@@ -145,6 +180,11 @@ impl Distribution<AuctionCosts> for Standard {
             withdraw_validator_reward: rng.gen(),
             read_era_id: rng.gen(),
             activate_bid: rng.gen(),
+            redelegate: rng.gen(),
+            set_delegation_limits: rng.gen(),
+            unjail: rng.gen(),
+            change_bid_public_key: rng.gen(),
+            set_reward_destination: rng.gen(),
         }
     }
 }
@@ -170,6 +210,11 @@ pub mod gens {
             withdraw_validator_reward in num::u32::ANY,
             read_era_id in num::u32::ANY,
             activate_bid in num::u32::ANY,
+            redelegate in num::u32::ANY,
+            set_delegation_limits in num::u32::ANY,
+            unjail in num::u32::ANY,
+            change_bid_public_key in num::u32::ANY,
+            set_reward_destination in num::u32::ANY,
         ) -> AuctionCosts {
             AuctionCosts {
                 get_era_validators,
@@ -185,6 +230,11 @@ pub mod gens {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
+                set_delegation_limits,
+                unjail,
+                change_bid_public_key,
+                set_reward_destination,
             }
         }
     }
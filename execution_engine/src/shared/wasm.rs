@@ -29,3 +29,433 @@ pub fn do_nothing_module(preprocessor: &Preprocessor) -> Result<Module, Preproce
     let do_nothing_bytes = do_nothing_bytes();
     preprocessor.preprocess(&do_nothing_bytes)
 }
+
+#[cfg(any(feature = "gens", test))]
+pub mod gens {
+    use std::collections::BTreeMap;
+
+    use parity_wasm::elements::{
+        BlockType, CodeSection, DataSection, DataSegment, ExportEntry, ExportSection, External,
+        Func, FuncBody, FunctionSection, FunctionType, ImportEntry, ImportSection, InitExpr,
+        Instruction, Instructions, Internal, Local, MemorySection, MemoryType, Module, Section,
+        Type, TypeSection, ValueType,
+    };
+    use proptest::{collection::vec, option, prelude::*, sample};
+
+    use casper_types::contracts::DEFAULT_ENTRY_POINT_NAME;
+
+    /// Size of a Wasm memory page in bytes.
+    const WASM_PAGE_SIZE: i32 = 65_536;
+    const HOST_MODULE_NAME: &str = "env";
+    const REVERT: &str = "casper_revert";
+    const MAX_HOST_FUNCTION_CALLS: usize = 4;
+    const MAX_DATA_LENGTH: usize = 256;
+    const MAX_TYPES: usize = 4;
+    const MAX_FUNCTIONS: usize = 4;
+    const MAX_INSTRUCTIONS: usize = 32;
+    const MAX_BYTES: usize = 512;
+    const MAX_MUTATIONS: usize = 8;
+
+    /// A host function as imported by session and contract code.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct HostFunctionImport {
+        pub name: &'static str,
+        pub param_count: usize,
+        pub has_result: bool,
+    }
+
+    const fn import(
+        name: &'static str,
+        param_count: usize,
+        has_result: bool,
+    ) -> HostFunctionImport {
+        HostFunctionImport {
+            name,
+            param_count,
+            has_result,
+        }
+    }
+
+    /// Host functions resolved by the engine, except for `casper_print`, which only exists with the
+    /// "test-support" feature.  All parameters and results are `i32`s.
+    pub const HOST_FUNCTION_IMPORTS: &[HostFunctionImport] = &[
+        import("casper_read_value", 3, true),
+        import("casper_load_named_keys", 2, true),
+        import("casper_write", 4, false),
+        import("casper_add", 4, false),
+        import("casper_new_uref", 3, false),
+        import("casper_ret", 2, false),
+        import("casper_get_key", 5, true),
+        import("casper_has_key", 2, true),
+        import("casper_put_key", 4, false),
+        import("gas", 1, false),
+        import("casper_is_valid_uref", 2, true),
+        import(REVERT, 1, false),
+        import("casper_add_associated_key", 3, true),
+        import("casper_remove_associated_key", 2, true),
+        import("casper_update_associated_key", 3, true),
+        import("casper_set_action_threshold", 2, true),
+        import("casper_remove_key", 2, false),
+        import("casper_get_caller", 1, true),
+        import("casper_get_blocktime", 1, false),
+        import("casper_create_purse", 2, true),
+        import("casper_transfer_to_account", 7, true),
+        import("casper_transfer_from_purse_to_account", 9, true),
+        import("casper_transfer_from_purse_to_purse", 8, true),
+        import("casper_get_balance", 3, true),
+        import("casper_get_phase", 1, false),
+        import("casper_get_system_contract", 3, true),
+        import("casper_get_main_purse", 1, false),
+        import("casper_read_host_buffer", 3, true),
+        import("casper_create_contract_package_at_hash", 3, false),
+        import("casper_create_contract_user_group", 8, true),
+        import("casper_add_contract_version", 10, true),
+        import("casper_disable_contract_version", 4, true),
+        import("casper_call_contract", 7, true),
+        import("casper_call_versioned_contract", 9, true),
+        import("casper_get_named_arg_size", 3, true),
+        import("casper_get_named_arg", 4, true),
+        import("casper_remove_contract_user_group", 4, true),
+        import("casper_provision_contract_user_group_uref", 5, true),
+        import("casper_remove_contract_user_group_urefs", 6, true),
+        import("casper_blake2b", 4, true),
+        import("casper_record_transfer", 10, true),
+        import("casper_record_era_info", 4, true),
+        import("casper_add_contract_version_with_migration", 12, true),
+        import("casper_load_call_stack", 2, true),
+        import("casper_set_upgrade_policy", 4, true),
+    ];
+
+    impl HostFunctionImport {
+        fn function_type(&self) -> FunctionType {
+            let result = if self.has_result {
+                Some(ValueType::I32)
+            } else {
+                None
+            };
+            FunctionType::new(vec![ValueType::I32; self.param_count], result)
+        }
+    }
+
+    /// A single call to a host function.
+    #[derive(Debug, Clone)]
+    pub struct HostFunctionCall {
+        pub import: HostFunctionImport,
+        pub args: Vec<i32>,
+    }
+
+    /// Session code which calls host functions in sequence, reverting with the first non-zero
+    /// result.
+    ///
+    /// Its memory starts with `data`, so pointers into it read arbitrary bytes.
+    #[derive(Debug, Clone)]
+    pub struct HostFunctionModule {
+        pub data: Vec<u8>,
+        pub calls: Vec<HostFunctionCall>,
+    }
+
+    impl HostFunctionModule {
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let revert = HOST_FUNCTION_IMPORTS
+                .iter()
+                .find(|import| import.name == REVERT)
+                .expect("should have revert");
+
+            let mut imports = vec![*revert];
+            let mut function_indices = BTreeMap::new();
+            function_indices.insert(revert.name, 0);
+            for call in &self.calls {
+                if !function_indices.contains_key(call.import.name) {
+                    function_indices.insert(call.import.name, imports.len() as u32);
+                    imports.push(call.import);
+                }
+            }
+
+            let mut types: Vec<Type> = imports
+                .iter()
+                .map(|import| Type::Function(import.function_type()))
+                .collect();
+            let import_entries = imports
+                .iter()
+                .enumerate()
+                .map(|(index, import)| {
+                    ImportEntry::new(
+                        HOST_MODULE_NAME.to_string(),
+                        import.name.to_string(),
+                        External::Function(index as u32),
+                    )
+                })
+                .collect();
+            let call_type_index = types.len() as u32;
+            types.push(Type::Function(FunctionType::new(Vec::new(), None)));
+
+            let revert_index = function_indices[revert.name];
+            let mut instructions = Vec::new();
+            for call in &self.calls {
+                instructions.extend(call.args.iter().map(|arg| Instruction::I32Const(*arg)));
+                instructions.push(Instruction::Call(function_indices[call.import.name]));
+                if call.import.has_result {
+                    instructions.extend(vec![
+                        Instruction::SetLocal(0),
+                        Instruction::GetLocal(0),
+                        Instruction::If(BlockType::NoResult),
+                        Instruction::GetLocal(0),
+                        Instruction::Call(revert_index),
+                        Instruction::End,
+                    ]);
+                }
+            }
+            instructions.push(Instruction::End);
+
+            let call_index = imports.len() as u32;
+            let module = Module::new(vec![
+                Section::Type(TypeSection::with_types(types)),
+                Section::Import(ImportSection::with_entries(import_entries)),
+                Section::Function(FunctionSection::with_entries(vec![Func::new(
+                    call_type_index,
+                )])),
+                Section::Memory(MemorySection::with_entries(vec![MemoryType::new(1, None)])),
+                Section::Export(ExportSection::with_entries(vec![ExportEntry::new(
+                    DEFAULT_ENTRY_POINT_NAME.to_string(),
+                    Internal::Function(call_index),
+                )])),
+                Section::Code(CodeSection::with_bodies(vec![FuncBody::new(
+                    vec![Local::new(1, ValueType::I32)],
+                    Instructions::new(instructions),
+                )])),
+                Section::Data(DataSection::with_entries(vec![DataSegment::new(
+                    0,
+                    Some(InitExpr::new(vec![
+                        Instruction::I32Const(0),
+                        Instruction::End,
+                    ])),
+                    self.data.clone(),
+                )])),
+            ]);
+            parity_wasm::serialize(module).expect("should serialize")
+        }
+    }
+
+    /// Arguments which are likely to hit edge cases when used as pointers or lengths.
+    pub fn host_function_arg_arb() -> impl Strategy<Value = i32> {
+        prop_oneof![
+            Just(0),
+            Just(1),
+            Just(-1),
+            Just(i32::MAX),
+            Just(i32::MIN),
+            0..MAX_DATA_LENGTH as i32,
+            (WASM_PAGE_SIZE - 64)..(WASM_PAGE_SIZE + 64),
+            any::<i32>(),
+        ]
+    }
+
+    /// A call to any host function but `casper_revert`, which generated modules use to report
+    /// errors.
+    pub fn host_function_call_arb() -> impl Strategy<Value = HostFunctionCall> {
+        let imports: Vec<HostFunctionImport> = HOST_FUNCTION_IMPORTS
+            .iter()
+            .filter(|import| import.name != REVERT)
+            .copied()
+            .collect();
+        sample::select(imports).prop_flat_map(|import| {
+            vec(host_function_arg_arb(), import.param_count)
+                .prop_map(move |args| HostFunctionCall { import, args })
+        })
+    }
+
+    prop_compose! {
+        pub fn host_function_module_arb()(
+            data in vec(any::<u8>(), 0..MAX_DATA_LENGTH),
+            calls in vec(host_function_call_arb(), 1..=MAX_HOST_FUNCTION_CALLS),
+        ) -> HostFunctionModule {
+            HostFunctionModule { data, calls }
+        }
+    }
+
+    fn value_type_arb() -> impl Strategy<Value = ValueType> {
+        prop_oneof![Just(ValueType::I32), Just(ValueType::I64)]
+    }
+
+    fn function_type_arb() -> impl Strategy<Value = FunctionType> {
+        (vec(value_type_arb(), 0..3), option::of(value_type_arb()))
+            .prop_map(|(params, result)| FunctionType::new(params, result))
+    }
+
+    fn block_type_arb() -> impl Strategy<Value = BlockType> {
+        prop_oneof![
+            Just(BlockType::NoResult),
+            value_type_arb().prop_map(BlockType::Value)
+        ]
+    }
+
+    /// Instructions which are not guaranteed to be well-typed or balanced.
+    fn instruction_arb() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            any::<i32>().prop_map(Instruction::I32Const),
+            any::<i64>().prop_map(Instruction::I64Const),
+            (0..4u32).prop_map(Instruction::GetLocal),
+            (0..4u32).prop_map(Instruction::SetLocal),
+            (0..8u32).prop_map(Instruction::Call),
+            block_type_arb().prop_map(Instruction::Block),
+            block_type_arb().prop_map(Instruction::Loop),
+            block_type_arb().prop_map(Instruction::If),
+            Just(Instruction::Else),
+            Just(Instruction::End),
+            (0..4u32).prop_map(Instruction::Br),
+            (0..4u32).prop_map(Instruction::BrIf),
+            Just(Instruction::Return),
+            Just(Instruction::Unreachable),
+            Just(Instruction::Nop),
+            Just(Instruction::Drop),
+            Just(Instruction::Select),
+            Just(Instruction::I32Add),
+            Just(Instruction::I64Mul),
+            Just(Instruction::I32DivU),
+            (0..3u32, any::<u32>()).prop_map(|(align, offset)| Instruction::I32Load(align, offset)),
+            (0..3u32, any::<u32>())
+                .prop_map(|(align, offset)| Instruction::I32Store(align, offset)),
+            Just(Instruction::CurrentMemory(0)),
+            Just(Instruction::GrowMemory(0)),
+        ]
+    }
+
+    fn func_body_arb() -> impl Strategy<Value = FuncBody> {
+        (
+            vec((1..3u32, value_type_arb()), 0..3),
+            vec(instruction_arb(), 0..MAX_INSTRUCTIONS),
+        )
+            .prop_map(|(locals, mut instructions)| {
+                let locals = locals
+                    .into_iter()
+                    .map(|(count, value_type)| Local::new(count, value_type))
+                    .collect();
+                instructions.push(Instruction::End);
+                FuncBody::new(locals, Instructions::new(instructions))
+            })
+    }
+
+    fn import_entry_arb() -> impl Strategy<Value = ImportEntry> {
+        let field = prop_oneof![
+            sample::select(HOST_FUNCTION_IMPORTS).prop_map(|import| import.name.to_string()),
+            "[a-z_]{1,16}",
+        ];
+        (field, 0..MAX_TYPES as u32).prop_map(|(field, type_index)| {
+            ImportEntry::new(
+                HOST_MODULE_NAME.to_string(),
+                field,
+                External::Function(type_index),
+            )
+        })
+    }
+
+    fn memory_type_arb() -> impl Strategy<Value = MemoryType> {
+        (0..4u32, option::of(0..8u32)).prop_map(|(min, max)| MemoryType::new(min, max))
+    }
+
+    prop_compose! {
+        /// Modules which deserialize, but whose indices, types and control flow may be invalid.
+        pub fn module_arb()(
+            types in vec(function_type_arb(), 0..MAX_TYPES),
+            imports in vec(import_entry_arb(), 0..3),
+            functions in vec(0..MAX_TYPES as u32, 0..MAX_FUNCTIONS),
+            memory in option::of(vec(memory_type_arb(), 0..2)),
+            exports in vec(0..MAX_FUNCTIONS as u32, 0..3),
+            bodies in vec(func_body_arb(), 0..MAX_FUNCTIONS),
+        ) -> Module {
+            let mut sections = vec![
+                Section::Type(TypeSection::with_types(
+                    types.into_iter().map(Type::Function).collect(),
+                )),
+                Section::Import(ImportSection::with_entries(imports)),
+                Section::Function(FunctionSection::with_entries(
+                    functions.into_iter().map(Func::new).collect(),
+                )),
+            ];
+            if let Some(memory) = memory {
+                sections.push(Section::Memory(MemorySection::with_entries(memory)));
+            }
+            let exports = exports
+                .into_iter()
+                .enumerate()
+                .map(|(index, function_index)| {
+                    let field = if index == 0 {
+                        DEFAULT_ENTRY_POINT_NAME.to_string()
+                    } else {
+                        format!("export_{}", index)
+                    };
+                    ExportEntry::new(field, Internal::Function(function_index))
+                })
+                .collect();
+            sections.push(Section::Export(ExportSection::with_entries(exports)));
+            sections.push(Section::Code(CodeSection::with_bodies(bodies)));
+            Module::new(sections)
+        }
+    }
+
+    fn serialized_module_arb() -> impl Strategy<Value = Vec<u8>> {
+        module_arb().prop_map(|module| parity_wasm::serialize(module).expect("should serialize"))
+    }
+
+    /// Bytes which are either arbitrary, a generated module, or a generated module with some of
+    /// its bytes overwritten.
+    pub fn module_bytes_arb() -> impl Strategy<Value = Vec<u8>> {
+        let mutations = vec((any::<sample::Index>(), any::<u8>()), 1..MAX_MUTATIONS);
+        prop_oneof![
+            vec(any::<u8>(), 0..MAX_BYTES),
+            serialized_module_arb(),
+            (serialized_module_arb(), mutations).prop_map(|(mut bytes, mutations)| {
+                if !bytes.is_empty() {
+                    for (index, byte) in mutations {
+                        let index = index.index(bytes.len());
+                        bytes[index] = byte;
+                    }
+                }
+                bytes
+            }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use wasmi::{ModuleImportResolver, Signature, ValueType};
+
+    use casper_types::ProtocolVersion;
+
+    use super::gens::{self, HOST_FUNCTION_IMPORTS};
+    use crate::{
+        core::resolvers,
+        shared::{wasm_config::WasmConfig, wasm_prep::Preprocessor},
+    };
+
+    #[test]
+    fn host_function_imports_should_match_resolver() {
+        let resolver =
+            resolvers::create_module_resolver(ProtocolVersion::V1_0_0, &WasmConfig::default())
+                .expect("should create resolver");
+        for import in HOST_FUNCTION_IMPORTS {
+            let result = if import.has_result {
+                Some(ValueType::I32)
+            } else {
+                None
+            };
+            let signature = Signature::new(vec![ValueType::I32; import.param_count], result);
+            let func_ref = resolver
+                .resolve_func(import.name, &signature)
+                .unwrap_or_else(|error| panic!("should resolve {}: {:?}", import.name, error));
+            assert_eq!(func_ref.signature(), &signature, "{}", import.name);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_preprocess_host_function_module(module in gens::host_function_module_arb()) {
+            let preprocessor = Preprocessor::new(WasmConfig::default());
+            preprocessor
+                .preprocess(&module.to_bytes())
+                .expect("should preprocess");
+        }
+    }
+}
@@ -31,10 +31,13 @@ impl Display for PreprocessingError {
     }
 }
 
-/// Checks if given wasm module contains a memory section.
+/// Checks if given wasm module contains a non-empty memory section.
 fn memory_section(module: &Module) -> Option<&MemorySection> {
     for section in module.sections() {
         if let Section::Memory(section) = section {
+            if section.entries().is_empty() {
+                return None;
+            }
             return Some(section);
         }
     }
@@ -54,8 +57,8 @@ impl Preprocessor {
         let module = deserialize(module_bytes)?;
 
         if memory_section(&module).is_none() {
-            // `pwasm_utils::externalize_mem` expects a memory section to exist in the module, and
-            // panics otherwise.
+            // `pwasm_utils::externalize_mem` expects a memory section with an entry to exist in the
+            // module, and panics otherwise.
             return Err(PreprocessingError::MissingMemorySection);
         }
 
@@ -73,3 +76,60 @@ impl Preprocessor {
 pub fn deserialize(module_bytes: &[u8]) -> Result<Module, PreprocessingError> {
     parity_wasm::deserialize_buffer::<Module>(module_bytes).map_err(Into::into)
 }
+
+#[cfg(test)]
+mod tests {
+    use parity_wasm::elements::{External, MemoryType};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    use crate::shared::wasm::gens;
+
+    /// Name of the function imported by the gas counter injected into every module.
+    const GAS_FUNCTION_NAME: &str = "gas";
+
+    fn has_gas_import(module: &Module) -> bool {
+        module.import_section().map_or(false, |import_section| {
+            import_section.entries().iter().any(|entry| {
+                entry.field() == GAS_FUNCTION_NAME
+                    && matches!(entry.external(), External::Function(_))
+            })
+        })
+    }
+
+    #[test]
+    fn should_not_preprocess_module_with_empty_memory_section() {
+        let module = Module::new(vec![Section::Memory(MemorySection::with_entries(
+            Vec::new(),
+        ))]);
+        let module_bytes = parity_wasm::serialize(module).expect("should serialize");
+        let preprocessor = Preprocessor::new(WasmConfig::default());
+        assert!(matches!(
+            preprocessor.preprocess(&module_bytes),
+            Err(PreprocessingError::MissingMemorySection)
+        ));
+
+        let module = Module::new(vec![Section::Memory(MemorySection::with_entries(vec![
+            MemoryType::new(1, None),
+        ]))]);
+        let module_bytes = parity_wasm::serialize(module).expect("should serialize");
+        let module = preprocessor
+            .preprocess(&module_bytes)
+            .expect("should preprocess");
+        assert!(has_gas_import(&module));
+    }
+
+    proptest! {
+        #[test]
+        fn should_not_panic_preprocessing_arbitrary_bytes(module_bytes in vec(any::<u8>(), 0..1024)) {
+            let _ = Preprocessor::new(WasmConfig::default()).preprocess(&module_bytes);
+        }
+
+        #[test]
+        fn should_inject_gas_counter_into_arbitrary_module(module_bytes in gens::module_bytes_arb()) {
+            if let Ok(module) = Preprocessor::new(WasmConfig::default()).preprocess(&module_bytes) {
+                prop_assert!(has_gas_import(&module));
+            }
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use std::{ops::Deref, path::Path, sync::Arc};
+
+use crate::shared::{
+    additive_map::AdditiveMap,
+    newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
+    transform::Transform,
+};
+use casper_types::{Key, ProtocolVersion};
+
+use crate::storage::{
+    error,
+    global_state::{commit, CommitResult, StateProvider, StateReader},
+    protocol_data::ProtocolData,
+    protocol_data_store::forked::ForkedProtocolDataStore,
+    store::Store,
+    transaction_source::{
+        forked::{ForkedEnvironment, ForkedReadTransaction, ForkedReadWriteTransaction},
+        lmdb::LmdbEnvironment,
+        Transaction, TransactionSource,
+    },
+    trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
+    trie_store::{
+        forked::ForkedTrieStore,
+        operations::{
+            keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
+        },
+    },
+};
+
+/// Global state forked from an existing LMDB global state, such as a copy of a node's
+/// `contract_runtime` data.
+///
+/// The LMDB environment is opened read-only; everything committed to the fork is held in memory
+/// and discarded when the fork is dropped.
+pub struct ForkedGlobalState {
+    pub environment: Arc<ForkedEnvironment>,
+    pub trie_store: Arc<ForkedTrieStore>,
+    pub protocol_data_store: Arc<ForkedProtocolDataStore>,
+    pub empty_root_hash: Blake2bHash,
+}
+
+/// Represents a "view" of global state at a particular root hash.
+pub struct ForkedGlobalStateView {
+    pub environment: Arc<ForkedEnvironment>,
+    pub store: Arc<ForkedTrieStore>,
+    pub root_hash: Blake2bHash,
+}
+
+impl ForkedGlobalState {
+    /// Opens the LMDB global state in `path` read-only and forks it.
+    pub fn open<P: AsRef<Path>>(path: P, max_readers: u32) -> Result<Self, error::Error> {
+        let base = Arc::new(LmdbEnvironment::open_read_only(path, max_readers)?);
+        Self::fork(base)
+    }
+
+    /// Forks an existing LMDB environment, which must contain the default trie and protocol data
+    /// stores.
+    pub fn fork(base: Arc<LmdbEnvironment>) -> Result<Self, error::Error> {
+        let environment = Arc::new(ForkedEnvironment::new(base));
+        let trie_store = Arc::new(ForkedTrieStore::open(&environment, None)?);
+        let protocol_data_store = Arc::new(ForkedProtocolDataStore::open(&environment, None)?);
+        let empty_root_hash: Blake2bHash = {
+            let (root_hash, root) = create_hashed_empty_trie::<Key, StoredValue>()?;
+            let mut txn = environment.create_read_write_txn()?;
+            trie_store.put(&mut txn, &root_hash, &root)?;
+            txn.commit()?;
+            root_hash
+        };
+        Ok(ForkedGlobalState {
+            environment,
+            trie_store,
+            protocol_data_store,
+            empty_root_hash,
+        })
+    }
+}
+
+impl StateReader<Key, StoredValue> for ForkedGlobalStateView {
+    type Error = error::Error;
+
+    fn read(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read::<Key, StoredValue, ForkedReadTransaction, ForkedTrieStore, Self::Error>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            ReadResult::Found(value) => Some(value),
+            ReadResult::NotFound => None,
+            ReadResult::RootNotFound => panic!("ForkedGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_with_proof::<
+            Key,
+            StoredValue,
+            ForkedReadTransaction,
+            ForkedTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            ReadResult::Found(value) => Some(value),
+            ReadResult::NotFound => None,
+            ReadResult::RootNotFound => panic!("ForkedGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let keys_iter = keys_with_prefix::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+        );
+        let mut ret = Vec::new();
+        for result in keys_iter {
+            match result {
+                Ok(key) => ret.push(key),
+                Err(error) => return Err(error),
+            }
+        }
+        txn.commit()?;
+        Ok(ret)
+    }
+}
+
+impl StateProvider for ForkedGlobalState {
+    type Error = error::Error;
+
+    type Reader = ForkedGlobalStateView;
+
+    fn checkout(&self, state_hash: Blake2bHash) -> Result<Option<Self::Reader>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let maybe_root: Option<Trie<Key, StoredValue>> = self.trie_store.get(&txn, &state_hash)?;
+        let maybe_state = maybe_root.map(|_| ForkedGlobalStateView {
+            environment: Arc::clone(&self.environment),
+            store: Arc::clone(&self.trie_store),
+            root_hash: state_hash,
+        });
+        txn.commit()?;
+        Ok(maybe_state)
+    }
+
+    fn commit(
+        &self,
+        correlation_id: CorrelationId,
+        prestate_hash: Blake2bHash,
+        effects: AdditiveMap<Key, Transform>,
+    ) -> Result<CommitResult, Self::Error> {
+        let commit_result = commit::<ForkedEnvironment, ForkedTrieStore, _, Self::Error>(
+            &self.environment,
+            &self.trie_store,
+            correlation_id,
+            prestate_hash,
+            effects,
+        )?;
+        Ok(commit_result)
+    }
+
+    fn put_protocol_data(
+        &self,
+        protocol_version: ProtocolVersion,
+        protocol_data: &ProtocolData,
+    ) -> Result<(), Self::Error> {
+        let mut txn = self.environment.create_read_write_txn()?;
+        self.protocol_data_store
+            .put(&mut txn, &protocol_version, protocol_data)?;
+        txn.commit().map_err(Into::into)
+    }
+
+    fn get_protocol_data(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<ProtocolData>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let result = self.protocol_data_store.get(&txn, &protocol_version)?;
+        txn.commit()?;
+        Ok(result)
+    }
+
+    fn empty_root(&self) -> Blake2bHash {
+        self.empty_root_hash
+    }
+
+    fn read_trie(
+        &self,
+        _correlation_id: CorrelationId,
+        trie_key: &Blake2bHash,
+    ) -> Result<Option<Trie<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret: Option<Trie<Key, StoredValue>> = self.trie_store.get(&txn, trie_key)?;
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn put_trie(
+        &self,
+        correlation_id: CorrelationId,
+        trie: &Trie<Key, StoredValue>,
+    ) -> Result<Blake2bHash, Self::Error> {
+        let mut txn = self.environment.create_read_write_txn()?;
+        let trie_hash = put_trie::<
+            Key,
+            StoredValue,
+            ForkedReadWriteTransaction,
+            ForkedTrieStore,
+            Self::Error,
+        >(correlation_id, &mut txn, &self.trie_store, trie)?;
+        txn.commit()?;
+        Ok(trie_hash)
+    }
+
+    /// Finds all of the keys of missing descendant `Trie<K,V>` values
+    fn missing_trie_keys(
+        &self,
+        correlation_id: CorrelationId,
+        trie_key: Blake2bHash,
+    ) -> Result<Vec<Blake2bHash>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let missing_descendants =
+            missing_trie_keys::<
+                Key,
+                StoredValue,
+                ForkedReadTransaction,
+                ForkedTrieStore,
+                Self::Error,
+            >(correlation_id, &txn, self.trie_store.deref(), trie_key)?;
+        txn.commit()?;
+        Ok(missing_descendants)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lmdb::DatabaseFlags;
+    use tempfile::tempdir;
+
+    use casper_types::{account::AccountHash, CLValue};
+
+    use super::*;
+    use crate::storage::{
+        global_state::lmdb::LmdbGlobalState,
+        protocol_data_store::lmdb::LmdbProtocolDataStore,
+        trie_store::{
+            lmdb::LmdbTrieStore,
+            operations::{write, WriteResult},
+        },
+        DEFAULT_TEST_MAX_DB_SIZE, DEFAULT_TEST_MAX_READERS,
+    };
+
+    const KEY_1: Key = Key::Account(AccountHash::new([1_u8; 32]));
+    const KEY_2: Key = Key::Account(AccountHash::new([2_u8; 32]));
+
+    fn value(value: i32) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    /// Writes `KEY_1 => 1` into a fresh LMDB global state in `path` and returns its root hash.
+    fn create_base_state(path: &Path) -> Blake2bHash {
+        let correlation_id = CorrelationId::new();
+        let environment = Arc::new(
+            LmdbEnvironment::new(path, DEFAULT_TEST_MAX_DB_SIZE, DEFAULT_TEST_MAX_READERS).unwrap(),
+        );
+        let trie_store =
+            Arc::new(LmdbTrieStore::new(&environment, None, DatabaseFlags::empty()).unwrap());
+        let protocol_data_store = Arc::new(
+            LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty()).unwrap(),
+        );
+        let state =
+            LmdbGlobalState::empty(Arc::clone(&environment), trie_store, protocol_data_store)
+                .unwrap();
+
+        let mut txn = environment.create_read_write_txn().unwrap();
+        let root_hash = match write::<_, _, _, LmdbTrieStore, error::Error>(
+            correlation_id,
+            &mut txn,
+            &state.trie_store,
+            &state.empty_root_hash,
+            &KEY_1,
+            &value(1),
+        )
+        .unwrap()
+        {
+            WriteResult::Written(root_hash) => root_hash,
+            _ => panic!("should write"),
+        };
+        txn.commit().unwrap();
+        root_hash
+    }
+
+    #[test]
+    fn reads_base_state_and_overlays_writes() {
+        let correlation_id = CorrelationId::new();
+        let temp_dir = tempdir().unwrap();
+        let base_root_hash = create_base_state(temp_dir.path());
+
+        let fork = ForkedGlobalState::open(temp_dir.path(), DEFAULT_TEST_MAX_READERS).unwrap();
+
+        let base_view = fork.checkout(base_root_hash).unwrap().unwrap();
+        assert_eq!(
+            base_view.read(correlation_id, &KEY_1).unwrap(),
+            Some(value(1))
+        );
+
+        let effects = {
+            let mut effects = AdditiveMap::new();
+            effects.insert(KEY_1, Transform::Write(value(10)));
+            effects.insert(KEY_2, Transform::Write(value(2)));
+            effects
+        };
+        let fork_root_hash = match fork
+            .commit(correlation_id, base_root_hash, effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root } => state_root,
+            other => panic!("commit should succeed: {}", other),
+        };
+
+        let fork_view = fork.checkout(fork_root_hash).unwrap().unwrap();
+        assert_eq!(
+            fork_view.read(correlation_id, &KEY_1).unwrap(),
+            Some(value(10))
+        );
+        assert_eq!(
+            fork_view.read(correlation_id, &KEY_2).unwrap(),
+            Some(value(2))
+        );
+
+        // The base state is unchanged, both through the fork and on disk.
+        assert_eq!(
+            base_view.read(correlation_id, &KEY_1).unwrap(),
+            Some(value(1))
+        );
+        drop(base_view);
+        drop(fork_view);
+        drop(fork);
+
+        let reopened = ForkedGlobalState::open(temp_dir.path(), DEFAULT_TEST_MAX_READERS).unwrap();
+        assert!(reopened.checkout(fork_root_hash).unwrap().is_none());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod forked;
 pub mod in_memory;
 pub mod lmdb;
 
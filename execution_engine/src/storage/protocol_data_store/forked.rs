@@ -0,0 +1,37 @@
+use casper_types::ProtocolVersion;
+
+use crate::storage::{
+    error,
+    protocol_data::ProtocolData,
+    protocol_data_store::{
+        in_memory::InMemoryProtocolDataStore, lmdb::LmdbProtocolDataStore, ProtocolDataStore,
+    },
+    store::Store,
+    transaction_source::forked::{ForkedEnvironment, ForkedHandle},
+};
+
+/// A protocol data store for a [`ForkedEnvironment`].
+pub struct ForkedProtocolDataStore {
+    overlay: InMemoryProtocolDataStore,
+    base: LmdbProtocolDataStore,
+}
+
+impl ForkedProtocolDataStore {
+    pub fn open(env: &ForkedEnvironment, maybe_name: Option<&str>) -> Result<Self, error::Error> {
+        let overlay = InMemoryProtocolDataStore::new(env.overlay(), maybe_name);
+        let base = LmdbProtocolDataStore::open(env.base(), maybe_name)?;
+        Ok(ForkedProtocolDataStore { overlay, base })
+    }
+}
+
+impl Store<ProtocolVersion, ProtocolData> for ForkedProtocolDataStore {
+    type Error = error::Error;
+
+    type Handle = ForkedHandle;
+
+    fn handle(&self) -> Self::Handle {
+        ForkedHandle::new(self.overlay.handle(), self.base.handle())
+    }
+}
+
+impl ProtocolDataStore for ForkedProtocolDataStore {}
@@ -2,6 +2,7 @@
 //! protocol versions.
 use casper_types::ProtocolVersion;
 
+pub mod forked;
 pub mod in_memory;
 pub mod lmdb;
 #[cfg(test)]
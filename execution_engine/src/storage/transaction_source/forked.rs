@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use casper_types::bytesrepr::Bytes;
+use lmdb::{Database, RoTransaction};
+
+use crate::storage::{
+    error,
+    transaction_source::{
+        in_memory::{InMemoryEnvironment, InMemoryReadTransaction, InMemoryReadWriteTransaction},
+        lmdb::LmdbEnvironment,
+        Readable, Transaction, TransactionSource, Writable,
+    },
+};
+
+/// A handle to a database in a [`ForkedEnvironment`]: the in-memory overlay database which
+/// receives all writes, and the LMDB database which is read when the overlay has no value.
+#[derive(Debug, Clone)]
+pub struct ForkedHandle {
+    overlay: Option<String>,
+    base: Database,
+}
+
+impl ForkedHandle {
+    pub fn new(overlay: Option<String>, base: Database) -> Self {
+        ForkedHandle { overlay, base }
+    }
+}
+
+/// A read transaction for the forked trie store.
+pub struct ForkedReadTransaction<'a> {
+    overlay: InMemoryReadTransaction,
+    base: RoTransaction<'a>,
+}
+
+impl<'a> Transaction for ForkedReadTransaction<'a> {
+    type Error = error::Error;
+
+    type Handle = ForkedHandle;
+
+    fn commit(self) -> Result<(), Self::Error> {
+        self.overlay.commit()?;
+        self.base.commit()?;
+        Ok(())
+    }
+}
+
+impl<'a> Readable for ForkedReadTransaction<'a> {
+    fn read(&self, handle: Self::Handle, key: &[u8]) -> Result<Option<Bytes>, Self::Error> {
+        match self.overlay.read(handle.overlay, key)? {
+            Some(bytes) => Ok(Some(bytes)),
+            None => self.base.read(handle.base, key).map_err(Into::into),
+        }
+    }
+}
+
+/// A read-write transaction for the forked trie store.
+///
+/// Writes only ever reach the in-memory overlay; the underlying LMDB is never modified.
+pub struct ForkedReadWriteTransaction<'a> {
+    overlay: InMemoryReadWriteTransaction<'a>,
+    base: RoTransaction<'a>,
+}
+
+impl<'a> Transaction for ForkedReadWriteTransaction<'a> {
+    type Error = error::Error;
+
+    type Handle = ForkedHandle;
+
+    fn commit(self) -> Result<(), Self::Error> {
+        self.overlay.commit()?;
+        self.base.commit()?;
+        Ok(())
+    }
+}
+
+impl<'a> Readable for ForkedReadWriteTransaction<'a> {
+    fn read(&self, handle: Self::Handle, key: &[u8]) -> Result<Option<Bytes>, Self::Error> {
+        match self.overlay.read(handle.overlay, key)? {
+            Some(bytes) => Ok(Some(bytes)),
+            None => self.base.read(handle.base, key).map_err(Into::into),
+        }
+    }
+}
+
+impl<'a> Writable for ForkedReadWriteTransaction<'a> {
+    fn write(&mut self, handle: Self::Handle, key: &[u8], value: &[u8]) -> Result<(), Self::Error> {
+        self.overlay
+            .write(handle.overlay, key, value)
+            .map_err(Into::into)
+    }
+}
+
+/// An environment which reads through an in-memory overlay to an existing, read-only LMDB
+/// environment (e.g. a copy of a node's global state).
+pub struct ForkedEnvironment {
+    overlay: InMemoryEnvironment,
+    base: Arc<LmdbEnvironment>,
+}
+
+impl ForkedEnvironment {
+    pub fn new(base: Arc<LmdbEnvironment>) -> Self {
+        ForkedEnvironment {
+            overlay: InMemoryEnvironment::new(),
+            base,
+        }
+    }
+
+    pub fn overlay(&self) -> &InMemoryEnvironment {
+        &self.overlay
+    }
+
+    pub fn base(&self) -> &LmdbEnvironment {
+        &self.base
+    }
+}
+
+impl<'a> TransactionSource<'a> for ForkedEnvironment {
+    type Error = error::Error;
+
+    type Handle = ForkedHandle;
+
+    type ReadTransaction = ForkedReadTransaction<'a>;
+
+    type ReadWriteTransaction = ForkedReadWriteTransaction<'a>;
+
+    fn create_read_txn(&'a self) -> Result<ForkedReadTransaction<'a>, Self::Error> {
+        Ok(ForkedReadTransaction {
+            overlay: self.overlay.create_read_txn()?,
+            base: self.base.create_read_txn()?,
+        })
+    }
+
+    fn create_read_write_txn(&'a self) -> Result<ForkedReadWriteTransaction<'a>, Self::Error> {
+        Ok(ForkedReadWriteTransaction {
+            overlay: self.overlay.create_read_write_txn()?,
+            base: self.base.create_read_txn()?,
+        })
+    }
+}
@@ -85,6 +85,17 @@ impl LmdbEnvironment {
         Ok(LmdbEnvironment { env })
     }
 
+    /// Opens an existing environment, e.g. a copy of a node's global state, which will never be
+    /// written to.
+    pub fn open_read_only<P: AsRef<Path>>(path: P, max_readers: u32) -> Result<Self, error::Error> {
+        let env = Environment::new()
+            .set_flags(EnvironmentFlags::NO_SUB_DIR | EnvironmentFlags::READ_ONLY)
+            .set_max_dbs(MAX_DBS)
+            .set_max_readers(max_readers)
+            .open(&path.as_ref().join(EE_DB_FILENAME))?;
+        Ok(LmdbEnvironment { env })
+    }
+
     pub fn env(&self) -> &Environment {
         &self.env
     }
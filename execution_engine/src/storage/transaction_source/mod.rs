@@ -1,5 +1,6 @@
 use casper_types::bytesrepr::Bytes;
 
+pub mod forked;
 pub mod in_memory;
 pub mod lmdb;
 
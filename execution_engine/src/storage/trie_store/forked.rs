@@ -0,0 +1,43 @@
+//! A trie store which overlays in-memory writes on top of an existing LMDB trie store.
+
+use crate::shared::newtypes::Blake2bHash;
+
+use crate::storage::{
+    error,
+    store::Store,
+    transaction_source::forked::{ForkedEnvironment, ForkedHandle},
+    trie::Trie,
+    trie_store::{in_memory::InMemoryTrieStore, lmdb::LmdbTrieStore, TrieStore},
+};
+
+/// A trie store for a [`ForkedEnvironment`].
+///
+/// Tries are content-addressed, so a trie found in either the overlay or the underlying LMDB
+/// store is always the same trie.
+pub struct ForkedTrieStore {
+    overlay: InMemoryTrieStore,
+    base: LmdbTrieStore,
+}
+
+impl ForkedTrieStore {
+    pub fn open(env: &ForkedEnvironment, maybe_name: Option<&str>) -> Result<Self, error::Error> {
+        let overlay = InMemoryTrieStore::new(env.overlay(), maybe_name);
+        let base = LmdbTrieStore::open(env.base(), maybe_name)?;
+        Ok(ForkedTrieStore { overlay, base })
+    }
+}
+
+impl<K, V> Store<Blake2bHash, Trie<K, V>> for ForkedTrieStore {
+    type Error = error::Error;
+
+    type Handle = ForkedHandle;
+
+    fn handle(&self) -> Self::Handle {
+        ForkedHandle::new(
+            Store::<Blake2bHash, Trie<K, V>>::handle(&self.overlay),
+            Store::<Blake2bHash, Trie<K, V>>::handle(&self.base),
+        )
+    }
+}
+
+impl<K, V> TrieStore<K, V> for ForkedTrieStore {}
@@ -1,7 +1,10 @@
 use std::{collections::BTreeSet, path::Path};
 
 use casper_execution_engine::{
-    core::engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
+    core::engine_state::{
+        deploy_item::DeployItem,
+        executable_deploy_item::{BatchedCall, ExecutableDeployItem},
+    },
     shared::newtypes::Blake2bHash,
 };
 use casper_types::{
@@ -18,6 +21,7 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    pub fee_payer: Option<AccountHash>,
 }
 
 pub struct DeployItemBuilder {
@@ -157,6 +161,11 @@ impl DeployItemBuilder {
         self
     }
 
+    pub fn with_batched_session(mut self, calls: Vec<BatchedCall>) -> Self {
+        self.deploy_item.session_code = Some(ExecutableDeployItem::Batch { calls });
+        self
+    }
+
     pub fn with_stored_versioned_payment_contract_by_name(
         mut self,
         key_name: &str,
@@ -199,6 +208,11 @@ impl DeployItemBuilder {
         self
     }
 
+    pub fn with_fee_payer(mut self, fee_payer: AccountHash) -> Self {
+        self.deploy_item.fee_payer = Some(fee_payer);
+        self
+    }
+
     pub fn with_deploy_hash(mut self, hash: [u8; 32]) -> Self {
         let digest: Blake2bHash = hash.into();
         self.deploy_item.deploy_hash = DeployHash::new(digest.value());
@@ -222,6 +236,7 @@ impl DeployItemBuilder {
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
+            fee_payer: self.deploy_item.fee_payer,
         }
     }
 }
@@ -0,0 +1,477 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use num_rational::Ratio;
+
+use casper_execution_engine::{
+    core::{
+        engine_state::{
+            self,
+            step::{EvictItem, RewardItem, SlashItem},
+            ExecuteRequest, GetBidsRequest,
+        },
+        execution,
+    },
+    shared::{
+        newtypes::{Blake2bHash, CorrelationId},
+        stored_value::StoredValue,
+    },
+    storage::global_state::StateProvider,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::{
+        auction::{
+            Bid, Bids, DelegationRate, EraId, EraInfo, UnbondingPurses, ARG_AMOUNT,
+            ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_VALIDATOR, BLOCK_REWARD,
+            METHOD_UNDELEGATE, METHOD_WITHDRAW_BID, UNBONDING_DELAY_KEY, UNBONDING_PURSES_KEY,
+        },
+        mint,
+    },
+    Key, PublicKey, RuntimeArgs, U512,
+};
+
+use crate::internal::{
+    ExecuteRequestBuilder, StepRequestBuilder, WasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_PROTOCOL_VERSION, TIMESTAMP_MILLIS_INCREMENT,
+};
+
+const CONTRACT_ADD_BID: &str = "add_bid.wasm";
+const CONTRACT_DELEGATE: &str = "delegate.wasm";
+
+/// Drives a [`WasmTestBuilder`] through eras the way a node would.
+///
+/// Every era consists of `blocks_per_era` blocks, each of which executes the deploys scheduled
+/// for its height.  The era then ends with a step which rewards the era's validators in
+/// proportion to their weight and participation, slashes equivocators and evicts validators which
+/// didn't participate at all.
+///
+/// The state root at the start of every era is kept, so total supply, balances, stakes, rewards
+/// and unbonding payouts can be checked for any past era, e.g.
+///
+/// ```ignore
+/// assert_eq!(simulator.advance_to_era(10).balance(delegator), expected_balance);
+/// assert_eq!(simulator.reward(7, &validator), expected_reward);
+/// ```
+pub struct EraSimulator<S> {
+    builder: WasmTestBuilder<S>,
+    blocks_per_era: u64,
+    era_duration_millis: u64,
+    timestamp_millis: u64,
+    block_height: u64,
+    participation: BTreeMap<PublicKey, Ratio<u64>>,
+    equivocators: BTreeSet<PublicKey>,
+    scheduled_deploys: BTreeMap<u64, Vec<ExecuteRequest>>,
+    era_state_roots: BTreeMap<EraId, Blake2bHash>,
+    unbonding_payouts: BTreeMap<EraId, BTreeMap<PublicKey, U512>>,
+}
+
+impl<S> EraSimulator<S>
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    /// Creates a simulator starting from the current state of `builder`, which must have run
+    /// genesis.
+    pub fn new(mut builder: WasmTestBuilder<S>) -> Self {
+        let era_id = builder.get_era();
+        let mut era_state_roots = BTreeMap::new();
+        era_state_roots.insert(era_id, builder.get_post_state_hash());
+        EraSimulator {
+            builder,
+            blocks_per_era: 1,
+            era_duration_millis: TIMESTAMP_MILLIS_INCREMENT,
+            timestamp_millis: DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+            block_height: 0,
+            participation: BTreeMap::new(),
+            equivocators: BTreeSet::new(),
+            scheduled_deploys: BTreeMap::new(),
+            era_state_roots,
+            unbonding_payouts: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_blocks_per_era(mut self, blocks_per_era: u64) -> Self {
+        self.blocks_per_era = blocks_per_era;
+        self
+    }
+
+    pub fn with_era_duration_millis(mut self, era_duration_millis: u64) -> Self {
+        self.era_duration_millis = era_duration_millis;
+        self
+    }
+
+    pub fn builder(&self) -> &WasmTestBuilder<S> {
+        &self.builder
+    }
+
+    pub fn builder_mut(&mut self) -> &mut WasmTestBuilder<S> {
+        &mut self.builder
+    }
+
+    pub fn into_builder(self) -> WasmTestBuilder<S> {
+        self.builder
+    }
+
+    /// Returns the current era.
+    pub fn era(&mut self) -> EraId {
+        self.builder.get_era()
+    }
+
+    /// Returns the height of the next block.
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    /// Sets the fraction of an era's rounds `validator` takes part in, from the current era on.
+    ///
+    /// Validators participate fully unless set otherwise; a validator which doesn't participate
+    /// at all gets no rewards and is evicted at the end of the era.
+    pub fn set_participation(
+        &mut self,
+        validator: PublicKey,
+        participation: Ratio<u64>,
+    ) -> &mut Self {
+        assert!(
+            participation <= Ratio::from_integer(1),
+            "participation can't be greater than 1"
+        );
+        self.participation.insert(validator, participation);
+        self
+    }
+
+    /// Marks `validator` as having equivocated in the current era, so it is slashed and gets no
+    /// rewards when the era ends.
+    pub fn equivocate(&mut self, validator: PublicKey) -> &mut Self {
+        self.equivocators.insert(validator);
+        self
+    }
+
+    /// Schedules `exec_request` to be executed in the block at `block_height`.
+    ///
+    /// Scheduled deploys are committed whether or not they succeed, as they would be on a node;
+    /// their results can be checked through [`EraSimulator::builder`].
+    pub fn schedule_deploy(
+        &mut self,
+        block_height: u64,
+        exec_request: ExecuteRequest,
+    ) -> &mut Self {
+        assert!(
+            block_height >= self.block_height,
+            "can't schedule a deploy in a past block"
+        );
+        self.scheduled_deploys
+            .entry(block_height)
+            .or_default()
+            .push(exec_request);
+        self
+    }
+
+    /// Executes `exec_request` right away, in the current block, and expects it to succeed.
+    pub fn exec(&mut self, exec_request: ExecuteRequest) -> &mut Self {
+        self.builder.exec(exec_request).expect_success().commit();
+        self
+    }
+
+    /// Transfers `amount` from the default account to `target`, creating its account if needed.
+    pub fn fund(&mut self, target: AccountHash, amount: U512) -> &mut Self {
+        let exec_request = ExecuteRequestBuilder::transfer(
+            *DEFAULT_ACCOUNT_ADDR,
+            runtime_args! {
+                mint::ARG_TARGET => target,
+                mint::ARG_AMOUNT => amount,
+                mint::ARG_ID => <Option<u64>>::None,
+            },
+        )
+        .build();
+        self.exec(exec_request)
+    }
+
+    pub fn add_bid(
+        &mut self,
+        validator: PublicKey,
+        amount: U512,
+        delegation_rate: DelegationRate,
+    ) -> &mut Self {
+        let exec_request = ExecuteRequestBuilder::standard(
+            AccountHash::from(&validator),
+            CONTRACT_ADD_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => validator,
+                ARG_AMOUNT => amount,
+                ARG_DELEGATION_RATE => delegation_rate,
+            },
+        )
+        .build();
+        self.exec(exec_request)
+    }
+
+    pub fn withdraw_bid(&mut self, validator: PublicKey, amount: U512) -> &mut Self {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            AccountHash::from(&validator),
+            self.builder.get_auction_contract_hash(),
+            METHOD_WITHDRAW_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => validator,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        self.exec(exec_request)
+    }
+
+    pub fn delegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+    ) -> &mut Self {
+        let exec_request = ExecuteRequestBuilder::standard(
+            AccountHash::from(&delegator),
+            CONTRACT_DELEGATE,
+            runtime_args! {
+                ARG_DELEGATOR => delegator,
+                ARG_VALIDATOR => validator,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        self.exec(exec_request)
+    }
+
+    pub fn undelegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+    ) -> &mut Self {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            AccountHash::from(&delegator),
+            self.builder.get_auction_contract_hash(),
+            METHOD_UNDELEGATE,
+            runtime_args! {
+                ARG_DELEGATOR => delegator,
+                ARG_VALIDATOR => validator,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build();
+        self.exec(exec_request)
+    }
+
+    /// Produces the remaining blocks of the current era and ends it.
+    pub fn advance_era(&mut self) -> &mut Self {
+        let era_id = self.builder.get_era();
+
+        let era_end_height = (era_id + 1) * self.blocks_per_era;
+        while self.block_height < era_end_height {
+            self.produce_block();
+        }
+
+        let due_unbonding_payouts = self.due_unbonding_payouts(era_id);
+        self.step(era_id);
+        self.unbonding_payouts.insert(era_id, due_unbonding_payouts);
+
+        self.era_state_roots
+            .insert(era_id + 1, self.builder.get_post_state_hash());
+        self
+    }
+
+    /// Advances eras until `era_id` is the current era.
+    pub fn advance_to_era(&mut self, era_id: EraId) -> &mut Self {
+        while self.builder.get_era() < era_id {
+            self.advance_era();
+        }
+        self
+    }
+
+    /// Returns the total supply at the start of `era_id`.
+    pub fn total_supply_at(&self, era_id: EraId) -> U512 {
+        self.builder.total_supply(Some(self.era_state_root(era_id)))
+    }
+
+    /// Returns the current total supply.
+    pub fn total_supply(&self) -> U512 {
+        self.builder.total_supply(None)
+    }
+
+    /// Returns the main purse balance of `account_hash` at the start of `era_id`.
+    pub fn balance_at(&self, era_id: EraId, account_hash: AccountHash) -> U512 {
+        self.balance_in_state(Some(self.era_state_root(era_id)), account_hash)
+    }
+
+    /// Returns the current main purse balance of `account_hash`.
+    pub fn balance(&self, account_hash: AccountHash) -> U512 {
+        self.balance_in_state(None, account_hash)
+    }
+
+    /// Returns the bid of `validator` at the start of `era_id`.
+    pub fn bid_at(&self, era_id: EraId, validator: &PublicKey) -> Option<Bid> {
+        self.bids_in_state(self.era_state_root(era_id))
+            .remove(validator)
+    }
+
+    /// Returns the current bid of `validator`.
+    pub fn bid(&self, validator: &PublicKey) -> Option<Bid> {
+        self.bids_in_state(self.builder.get_post_state_hash())
+            .remove(validator)
+    }
+
+    /// Returns the amount `delegator` has staked with `validator` at the start of `era_id`.
+    pub fn delegator_stake_at(
+        &self,
+        era_id: EraId,
+        validator: &PublicKey,
+        delegator: &PublicKey,
+    ) -> U512 {
+        self.bid_at(era_id, validator)
+            .and_then(|bid| bid.delegators().get(delegator).cloned())
+            .map(|delegator| *delegator.staked_amount())
+            .unwrap_or_default()
+    }
+
+    /// Returns the seigniorage allocations made at the end of `era_id`.
+    pub fn era_info(&self, era_id: EraId) -> EraInfo {
+        match self.builder.query(None, Key::EraInfo(era_id), &[]) {
+            Ok(StoredValue::EraInfo(era_info)) => era_info,
+            Ok(stored_value) => panic!("expected era info, got {:?}", stored_value),
+            Err(_) => EraInfo::new(),
+        }
+    }
+
+    /// Returns the total reward allocated to `public_key` at the end of `era_id`, whether as a
+    /// validator or as a delegator.
+    pub fn reward(&self, era_id: EraId, public_key: &PublicKey) -> U512 {
+        self.era_info(era_id)
+            .select(*public_key)
+            .map(|allocation| *allocation.amount())
+            .fold(U512::zero(), |total, amount| total + amount)
+    }
+
+    /// Returns the total amount unbonded to `unbonder` at the end of `era_id`.
+    ///
+    /// Amounts are those of the unbonding purses as they were when the era ended, i.e. before any
+    /// slashing applied in the same step.
+    pub fn unbonding_payout(&self, era_id: EraId, unbonder: &PublicKey) -> U512 {
+        self.unbonding_payouts
+            .get(&era_id)
+            .and_then(|payouts| payouts.get(unbonder))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn produce_block(&mut self) {
+        if let Some(exec_requests) = self.scheduled_deploys.remove(&self.block_height) {
+            for exec_request in exec_requests {
+                self.builder.exec(exec_request).commit();
+            }
+        }
+        self.block_height += 1;
+    }
+
+    fn step(&mut self, era_id: EraId) {
+        let validator_weights = self
+            .builder
+            .get_validator_weights(era_id)
+            .unwrap_or_default();
+        let total_weight = validator_weights
+            .values()
+            .fold(U512::zero(), |total, weight| total + *weight);
+
+        let mut step_request_builder = StepRequestBuilder::new()
+            .with_parent_state_hash(self.builder.get_post_state_hash())
+            .with_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+            .with_next_era_id(era_id + 1)
+            .with_era_end_timestamp_millis(self.timestamp_millis);
+
+        for (validator, weight) in validator_weights {
+            let participation = self
+                .participation
+                .get(&validator)
+                .copied()
+                .unwrap_or_else(|| Ratio::from_integer(1));
+
+            let reward_factor = if self.equivocators.contains(&validator) || total_weight.is_zero()
+            {
+                0
+            } else {
+                let reward_factor =
+                    U512::from(BLOCK_REWARD) * weight * U512::from(*participation.numer())
+                        / (total_weight * U512::from(*participation.denom()));
+                reward_factor.as_u64()
+            };
+            step_request_builder =
+                step_request_builder.with_reward_item(RewardItem::new(validator, reward_factor));
+
+            if *participation.numer() == 0 {
+                step_request_builder =
+                    step_request_builder.with_evict_item(EvictItem::new(validator));
+            }
+        }
+
+        for validator in std::mem::take(&mut self.equivocators) {
+            step_request_builder = step_request_builder.with_slash_item(SlashItem::new(validator));
+        }
+
+        self.builder.step(step_request_builder.build());
+        self.timestamp_millis += self.era_duration_millis;
+    }
+
+    /// Returns the unbonding purses which are paid out when `era_id` ends, per unbonder.
+    fn due_unbonding_payouts(&mut self, era_id: EraId) -> BTreeMap<PublicKey, U512> {
+        let auction = self.builder.get_auction_contract_hash();
+        let unbonding_delay: u64 = self.builder.get_value(auction, UNBONDING_DELAY_KEY);
+        let unbonding_purses: UnbondingPurses =
+            self.builder.get_value(auction, UNBONDING_PURSES_KEY);
+
+        let mut payouts = BTreeMap::new();
+        for unbonding_purse in unbonding_purses.values().flatten() {
+            if unbonding_purse.era_of_creation() + unbonding_delay > era_id {
+                continue;
+            }
+            let payout = payouts
+                .entry(*unbonding_purse.unbonder_public_key())
+                .or_insert_with(U512::zero);
+            *payout += *unbonding_purse.amount();
+        }
+        payouts
+    }
+
+    fn era_state_root(&self, era_id: EraId) -> Blake2bHash {
+        *self
+            .era_state_roots
+            .get(&era_id)
+            .unwrap_or_else(|| panic!("era {} has not been reached", era_id))
+    }
+
+    fn balance_in_state(
+        &self,
+        maybe_post_state: Option<Blake2bHash>,
+        account_hash: AccountHash,
+    ) -> U512 {
+        let main_purse = match self
+            .builder
+            .query(maybe_post_state, Key::Account(account_hash), &[])
+        {
+            Ok(StoredValue::Account(account)) => account.main_purse(),
+            _ => return U512::zero(),
+        };
+        self.builder
+            .query(maybe_post_state, Key::Balance(main_purse.addr()), &[])
+            .ok()
+            .and_then(|stored_value| stored_value.as_cl_value().cloned())
+            .map(|cl_value| cl_value.into_t::<U512>())
+            .expect("should have balance")
+            .expect("should parse balance into a U512")
+    }
+
+    fn bids_in_state(&self, state_root_hash: Blake2bHash) -> Bids {
+        let get_bids_result = self
+            .builder
+            .get_engine_state()
+            .get_bids(CorrelationId::new(), GetBidsRequest::new(state_root_hash))
+            .expect("should get bids");
+        get_bids_result.bids().cloned().expect("should have bids")
+    }
+}
@@ -15,8 +15,8 @@ use casper_execution_engine::{
     storage::{global_state::StateProvider, protocol_data::ProtocolData},
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, BlockTime, CLTyped, DeployHash, EntryPointType,
-    Key, Phase, ProtocolVersion, RuntimeArgs, URef, U512,
+    account::AccountHash, bytesrepr::FromBytes, system::CallStackElement, BlockTime, CLTyped,
+    DeployHash, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, URef, U512,
 };
 
 use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_CONFIG};
@@ -143,6 +143,7 @@ where
             &protocol_version,
             &protocol_data,
             phase,
+            BlockTime::new(block_time),
         )
         .expect("should get wasm module");
 
@@ -152,7 +153,16 @@ where
         runtime::instance_and_memory(module.clone(), protocol_version, &wasm_config)
             .expect("should be able to make wasm instance from module");
 
-    let mut runtime = Runtime::new(config, Default::default(), memory, module, context);
+    let call_stack = vec![CallStackElement::session(address)];
+
+    let mut runtime = Runtime::new(
+        config,
+        Default::default(),
+        memory,
+        module,
+        context,
+        call_stack,
+    );
 
     match instance.invoke_export(entry_point_name, &[], &mut runtime) {
         Ok(_) => None,
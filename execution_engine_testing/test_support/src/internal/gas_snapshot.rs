@@ -0,0 +1,221 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use num_rational::Ratio;
+
+use casper_execution_engine::shared::gas::Gas;
+use casper_types::U512;
+
+/// When set, [`GasSnapshot::assert_matches`] overwrites snapshot files with the recorded costs
+/// instead of checking them.
+pub const UPDATE_GAS_SNAPSHOTS_ENV_VAR: &str = "UPDATE_GAS_SNAPSHOTS";
+
+/// Default fraction by which a recorded cost may differ from its snapshot.
+pub const DEFAULT_GAS_SNAPSHOT_TOLERANCE: Ratio<u64> = Ratio::new_raw(1, 100);
+
+const SNAPSHOT_HEADER: &str =
+    "# Gas costs of named scenarios. Regenerate with `make update-gas-snapshots`.\n";
+
+/// Gas costs of named scenarios, checked against a snapshot file.
+///
+/// Costs are recorded with [`GasSnapshot::record`] and compared with the snapshot file by
+/// [`GasSnapshot::assert_matches`], which fails if a cost moved by more than the tolerance, if a
+/// scenario in the file was not recorded, or if a recorded scenario is missing from the file.
+///
+/// The file is only ever written when the `UPDATE_GAS_SNAPSHOTS` environment variable is set, in
+/// which case it is rewritten with the recorded costs.
+#[derive(Debug)]
+pub struct GasSnapshot {
+    path: PathBuf,
+    tolerance: Ratio<u64>,
+    expected: BTreeMap<String, U512>,
+    actual: BTreeMap<String, U512>,
+}
+
+impl GasSnapshot {
+    /// Opens the snapshot file at `path`, which doesn't need to exist yet.
+    pub fn open<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let expected = match fs::read_to_string(&path) {
+            Ok(contents) => parse(&contents).unwrap_or_else(|error| {
+                panic!("invalid gas snapshot {}: {}", path.display(), error)
+            }),
+            Err(_) => BTreeMap::new(),
+        };
+        GasSnapshot {
+            path,
+            tolerance: DEFAULT_GAS_SNAPSHOT_TOLERANCE,
+            expected,
+            actual: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: Ratio<u64>) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Records the cost of `scenario`.
+    pub fn record(&mut self, scenario: &str, gas: Gas) -> &mut Self {
+        assert!(
+            is_valid_scenario_name(scenario),
+            "invalid gas snapshot scenario name {:?}",
+            scenario
+        );
+        let previous = self.actual.insert(scenario.to_string(), gas.value());
+        assert!(
+            previous.is_none(),
+            "gas snapshot scenario {} recorded twice",
+            scenario
+        );
+        self
+    }
+
+    /// Checks the recorded costs against the snapshot file, or updates it.
+    pub fn assert_matches(self) {
+        if env::var_os(UPDATE_GAS_SNAPSHOTS_ENV_VAR).is_some() {
+            self.write(&self.actual);
+            return;
+        }
+
+        let mut failures = Vec::new();
+
+        for (scenario, actual) in &self.actual {
+            match self.expected.get(scenario) {
+                Some(expected) if !is_within_tolerance(*expected, *actual, self.tolerance) => {
+                    failures.push(format!(
+                        "{}: expected {}, got {}",
+                        scenario, expected, actual
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    failures.push(format!("{}: no snapshot, got {}", scenario, actual));
+                }
+            }
+        }
+
+        for scenario in self.expected.keys() {
+            if !self.actual.contains_key(scenario) {
+                failures.push(format!("{}: not recorded", scenario));
+            }
+        }
+
+        if !failures.is_empty() {
+            panic!(
+                "gas costs don't match snapshot {} (tolerance {}):\n  {}\nrun `make \
+                 update-gas-snapshots` or set {} to accept the new costs",
+                self.path.display(),
+                self.tolerance,
+                failures.join("\n  "),
+                UPDATE_GAS_SNAPSHOTS_ENV_VAR,
+            );
+        }
+    }
+
+    fn write(&self, snapshot: &BTreeMap<String, U512>) {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .unwrap_or_else(|_| panic!("Expected to create {}", parent.display()));
+        }
+        fs::write(&self.path, render(snapshot))
+            .unwrap_or_else(|_| panic!("Expected to write {}", self.path.display()));
+    }
+}
+
+fn is_valid_scenario_name(scenario: &str) -> bool {
+    !scenario.is_empty()
+        && scenario
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
+fn is_within_tolerance(expected: U512, actual: U512, tolerance: Ratio<u64>) -> bool {
+    let difference = if actual > expected {
+        actual - expected
+    } else {
+        expected - actual
+    };
+    difference * U512::from(*tolerance.denom()) <= expected * U512::from(*tolerance.numer())
+}
+
+fn parse(contents: &str) -> Result<BTreeMap<String, U512>, String> {
+    let mut snapshot = BTreeMap::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=');
+        let scenario = parts.next().unwrap_or_default().trim();
+        let cost = parts
+            .next()
+            .ok_or_else(|| format!("line {}: expected `<scenario> = <cost>`", index + 1))?
+            .trim();
+        let cost = U512::from_dec_str(cost)
+            .map_err(|error| format!("line {}: invalid cost: {:?}", index + 1, error))?;
+        if snapshot.insert(scenario.to_string(), cost).is_some() {
+            return Err(format!(
+                "line {}: duplicate scenario {}",
+                index + 1,
+                scenario
+            ));
+        }
+    }
+    Ok(snapshot)
+}
+
+fn render(snapshot: &BTreeMap<String, U512>) -> String {
+    let mut contents = String::from(SNAPSHOT_HEADER);
+    for (scenario, cost) in snapshot {
+        writeln!(contents, "{} = {}", scenario, cost).expect("should write to string");
+    }
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_roundtrip_snapshot() {
+        let mut snapshot = BTreeMap::new();
+        snapshot.insert("transfer".to_string(), U512::from(10_000));
+        snapshot.insert("counter.inc".to_string(), U512::from(123_456_789));
+
+        let contents = render(&snapshot);
+        assert!(contents.starts_with(SNAPSHOT_HEADER));
+        assert_eq!(parse(&contents), Ok(snapshot));
+    }
+
+    #[test]
+    fn should_not_parse_invalid_snapshot() {
+        assert!(parse("transfer 10000").is_err());
+        assert!(parse("transfer = ten").is_err());
+        assert!(parse("transfer = 1\ntransfer = 2").is_err());
+    }
+
+    #[test]
+    fn should_check_tolerance() {
+        let tolerance = Ratio::new(1, 100);
+        let expected = U512::from(10_000);
+        assert!(is_within_tolerance(expected, U512::from(10_100), tolerance));
+        assert!(is_within_tolerance(expected, U512::from(9_900), tolerance));
+        assert!(!is_within_tolerance(
+            expected,
+            U512::from(10_101),
+            tolerance
+        ));
+        assert!(!is_within_tolerance(expected, U512::from(9_899), tolerance));
+        assert!(!is_within_tolerance(
+            U512::zero(),
+            U512::one(),
+            Ratio::from_integer(0)
+        ));
+    }
+}
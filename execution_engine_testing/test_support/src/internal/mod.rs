@@ -1,7 +1,9 @@
 mod additive_map_diff;
 mod deploy_item_builder;
+mod era_simulator;
 pub mod exec_with_return;
 mod execute_request_builder;
+mod gas_snapshot;
 mod step_request_builder;
 mod upgrade_request_builder;
 pub mod utils;
@@ -25,11 +27,14 @@ use super::DEFAULT_ACCOUNT_INITIAL_BALANCE;
 
 pub use additive_map_diff::AdditiveMapDiff;
 pub use deploy_item_builder::DeployItemBuilder;
+pub use era_simulator::EraSimulator;
 pub use execute_request_builder::ExecuteRequestBuilder;
+pub use gas_snapshot::{GasSnapshot, DEFAULT_GAS_SNAPSHOT_TOLERANCE, UPDATE_GAS_SNAPSHOTS_ENV_VAR};
 pub use step_request_builder::StepRequestBuilder;
 pub use upgrade_request_builder::UpgradeRequestBuilder;
 pub use wasm_test_builder::{
-    InMemoryWasmTestBuilder, LmdbWasmTestBuilder, WasmTestBuilder, WasmTestResult,
+    ForkedWasmTestBuilder, InMemoryWasmTestBuilder, LmdbWasmTestBuilder, WasmTestBuilder,
+    WasmTestResult,
 };
 
 pub const DEFAULT_VALIDATOR_SLOTS: u32 = 5;
@@ -47,6 +52,12 @@ pub const DEFAULT_UNBONDING_DELAY: u64 = 14;
 ///
 /// (1+0.02)^((2^14)/31536000000)-1 is expressed as a fraction below.
 pub const DEFAULT_ROUND_SEIGNIORAGE_RATE: Ratio<u64> = Ratio::new_raw(6414, 623437335209);
+/// Default fraction of a slashed validator's stake and of its unbonding entries that is burned.
+pub const DEFAULT_SLASHING_RATE: Ratio<u64> = Ratio::new_raw(1, 1);
+/// Default fraction of the active delegations to a slashed validator that is burned.
+pub const DEFAULT_DELEGATION_SLASHING_RATE: Ratio<u64> = Ratio::new_raw(0, 1);
+/// Default number of eras a slashed validator is jailed for.
+pub const DEFAULT_JAIL_PERIOD: u64 = 0;
 
 pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP_MILLIS: u64 = 0;
@@ -102,6 +113,9 @@ pub static DEFAULT_EXEC_CONFIG: Lazy<ExecConfig> = Lazy::new(|| {
         DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_SLASHING_RATE,
+        DEFAULT_DELEGATION_SLASHING_RATE,
+        DEFAULT_JAIL_PERIOD,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     )
 });
@@ -24,6 +24,9 @@ pub struct UpgradeRequestBuilder {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_slashing_rate: Option<Ratio<u64>>,
+    new_delegation_slashing_rate: Option<Ratio<u64>>,
+    new_jail_period: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -79,6 +82,24 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_slashing_rate(mut self, slashing_rate: Ratio<u64>) -> Self {
+        self.new_slashing_rate = Some(slashing_rate);
+        self
+    }
+
+    pub fn with_new_delegation_slashing_rate(
+        mut self,
+        delegation_slashing_rate: Ratio<u64>,
+    ) -> Self {
+        self.new_delegation_slashing_rate = Some(delegation_slashing_rate);
+        self
+    }
+
+    pub fn with_new_jail_period(mut self, jail_period: u64) -> Self {
+        self.new_jail_period = Some(jail_period);
+        self
+    }
+
     pub fn with_new_system_config(mut self, new_system_config: SystemConfig) -> Self {
         self.new_system_config = Some(new_system_config);
         self
@@ -110,6 +131,9 @@ impl UpgradeRequestBuilder {
             self.new_locked_funds_period_millis,
             self.new_round_seigniorage_rate,
             self.new_unbonding_delay,
+            self.new_slashing_rate,
+            self.new_delegation_slashing_rate,
+            self.new_jail_period,
             self.global_state_update,
         )
     }
@@ -3,7 +3,7 @@ use std::{
     ffi::OsStr,
     fs,
     ops::Deref,
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::Arc,
 };
@@ -38,7 +38,8 @@ use casper_execution_engine::{
     },
     storage::{
         global_state::{
-            in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, CommitResult, StateProvider,
+            forked::ForkedGlobalState, in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState,
+            CommitResult, StateProvider,
         },
         protocol_data_store::lmdb::LmdbProtocolDataStore,
         transaction_source::lmdb::LmdbEnvironment,
@@ -58,11 +59,13 @@ use casper_types::{
         mint::TOTAL_SUPPLY_KEY,
     },
     CLTyped, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
-    DeployHash, DeployInfo, Key, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, U512,
+    DeployHash, DeployInfo, Key, ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferAddr,
+    URef, U512,
 };
 
 use crate::internal::{
-    utils, ExecuteRequestBuilder, DEFAULT_PROPOSER_ADDR, DEFAULT_PROTOCOL_VERSION,
+    utils, DeployItemBuilder, ExecuteRequestBuilder, DEFAULT_PROPOSER_ADDR,
+    DEFAULT_PROTOCOL_VERSION,
 };
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
@@ -80,6 +83,7 @@ const GLOBAL_STATE_DIR: &str = "global_state";
 
 pub type InMemoryWasmTestBuilder = WasmTestBuilder<InMemoryGlobalState>;
 pub type LmdbWasmTestBuilder = WasmTestBuilder<LmdbGlobalState>;
+pub type ForkedWasmTestBuilder = WasmTestBuilder<ForkedGlobalState>;
 
 /// Builder for simple WASM test
 pub struct WasmTestBuilder<S> {
@@ -307,6 +311,45 @@ impl LmdbWasmTestBuilder {
     }
 }
 
+impl ForkedWasmTestBuilder {
+    /// Creates a new instance of builder on top of an existing global state, e.g. a copy of a
+    /// node's `contract_runtime` data or the `global_state` dir of an [`LmdbWasmTestBuilder`].
+    ///
+    /// `global_state_dir` must contain the `data.lmdb` file.  It is opened read-only and all
+    /// changes made through the builder are kept in memory, so the same data can be forked
+    /// repeatedly.  The system contract hashes are read from the protocol data stored for
+    /// `protocol_version`.
+    pub fn open_fork<P: AsRef<Path>>(
+        global_state_dir: P,
+        engine_config: EngineConfig,
+        state_root_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        Self::initialize_logging();
+        let global_state = ForkedGlobalState::open(global_state_dir, DEFAULT_MAX_READERS)
+            .expect("should open ForkedGlobalState");
+        let engine_state = EngineState::new(global_state, engine_config);
+        let protocol_data = engine_state
+            .get_protocol_data(protocol_version)
+            .expect("should read protocol data")
+            .expect("should have protocol data stored");
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            exec_results: Vec::new(),
+            upgrade_results: Vec::new(),
+            genesis_hash: None,
+            post_state_hash: Some(state_root_hash),
+            transforms: Vec::new(),
+            genesis_account: None,
+            genesis_transforms: None,
+            mint_contract_hash: Some(protocol_data.mint()),
+            handle_payment_contract_hash: Some(protocol_data.handle_payment()),
+            standard_payment_hash: Some(protocol_data.standard_payment()),
+            auction_contract_hash: Some(protocol_data.auction()),
+        }
+    }
+}
+
 impl<S> WasmTestBuilder<S>
 where
     S: StateProvider,
@@ -734,6 +777,21 @@ where
         self.get_purse_balance(proposer_account.main_purse())
     }
 
+    /// Returns a [`DeployItemBuilder`] which sends deploys from `account_hash`, authorized by all
+    /// of its associated keys, so that any existing account can be used as the deploy signer.
+    pub fn impersonate(&self, account_hash: AccountHash) -> DeployItemBuilder {
+        let account = self
+            .get_account(account_hash)
+            .expect("should have account to impersonate");
+        let authorization_keys: Vec<AccountHash> = account
+            .associated_keys()
+            .map(|(account_hash, _weight)| *account_hash)
+            .collect();
+        DeployItemBuilder::new()
+            .with_address(account_hash)
+            .with_authorization_keys(&authorization_keys)
+    }
+
     pub fn get_account(&self, account_hash: AccountHash) -> Option<Account> {
         match self.query(None, Key::Account(account_hash), &[]) {
             Ok(account_value) => match account_value {
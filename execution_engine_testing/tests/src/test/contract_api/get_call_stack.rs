@@ -0,0 +1,104 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{engine_state, execution};
+use casper_types::{system::CallStackElement, ApiError, ContractHash, RuntimeArgs};
+
+const CONTRACT_CALL_STACK: &str = "call_stack.wasm";
+const CALL_STACK_CONTRACT_HASH_KEY_NAME: &str = "call_stack_contract_hash";
+const CALL_STACK_KEY_NAME: &str = "call_stack";
+const RECORD_CALL_STACK_ENTRY_POINT: &str = "record_call_stack";
+const REENTER_ENTRY_POINT: &str = "reenter";
+
+fn install_call_stack_contract(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALL_STACK,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CALL_STACK_CONTRACT_HASH_KEY_NAME)
+        .expect("should have contract hash key")
+        .into_hash()
+        .expect("should be hash")
+        .into()
+}
+
+#[ignore]
+#[test]
+fn should_get_call_stack_of_stored_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let contract_hash = install_call_stack_contract(&mut builder);
+    let contract_package_hash = builder
+        .get_contract(contract_hash)
+        .expect("should have contract")
+        .contract_package_hash();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        RECORD_CALL_STACK_ENTRY_POINT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let call_stack: Vec<CallStackElement> = builder
+        .query(None, contract_hash.into(), &[CALL_STACK_KEY_NAME])
+        .expect("should have call stack")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be call stack");
+
+    assert_eq!(
+        call_stack,
+        vec![
+            CallStackElement::session(*DEFAULT_ACCOUNT_ADDR),
+            CallStackElement::stored_contract(
+                contract_package_hash,
+                contract_hash,
+                RECORD_CALL_STACK_ENTRY_POINT
+            ),
+        ]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reject_reentrant_call_of_non_reentrant_entry_point() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let contract_hash = install_call_stack_contract(&mut builder);
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        REENTER_ENTRY_POINT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result");
+    let exec_response = response.last().expect("should have response");
+    let error = exec_response.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::Revert(ApiError::ReentrantCall))
+    );
+}
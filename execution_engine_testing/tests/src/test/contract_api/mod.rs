@@ -3,6 +3,7 @@ mod blake2b;
 mod create_purse;
 mod get_arg;
 mod get_blocktime;
+mod get_call_stack;
 mod get_caller;
 mod get_phase;
 mod list_named_keys;
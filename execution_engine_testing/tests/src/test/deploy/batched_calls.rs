@@ -0,0 +1,197 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{executable_deploy_item::BatchedCall, Error, ExecuteRequest},
+    execution,
+};
+use casper_types::{runtime_args, ApiError, RuntimeArgs};
+
+const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
+const HASH_KEY_NAME: &str = "counter_package_hash";
+const COUNTER_VALUE_UREF: &str = "counter";
+const ENTRYPOINT_COUNTER: &str = "counter";
+const COUNTER_CONTRACT_HASH_KEY_NAME: &str = "counter_contract_hash";
+const ARG_AMOUNT: &str = "amount";
+const ARG_COUNTER_METHOD: &str = "method";
+const METHOD_INC: &str = "inc";
+const METHOD_INVALID: &str = "invalid";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_COUNTER_DEFINE,
+        RuntimeArgs::new(),
+    )
+    .build();
+
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder
+}
+
+fn counter_value(builder: &InMemoryWasmTestBuilder) -> i32 {
+    let counter_contract_hash_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(COUNTER_CONTRACT_HASH_KEY_NAME)
+        .expect("should have counter contract hash key");
+
+    builder
+        .query(
+            None,
+            counter_contract_hash_key,
+            &[COUNTER_VALUE_UREF.to_string()],
+        )
+        .expect("should have counter value")
+        .as_cl_value()
+        .expect("should be CLValue")
+        .clone()
+        .into_t()
+        .expect("should cast CLValue to integer")
+}
+
+fn counter_call_by_name(method: &str) -> BatchedCall {
+    BatchedCall::StoredContractByName {
+        name: COUNTER_CONTRACT_HASH_KEY_NAME.to_string(),
+        entry_point: ENTRYPOINT_COUNTER.to_string(),
+        args: runtime_args! { ARG_COUNTER_METHOD => method },
+    }
+}
+
+fn counter_call_by_package_name(method: &str) -> BatchedCall {
+    BatchedCall::StoredVersionedContractByName {
+        name: HASH_KEY_NAME.to_string(),
+        version: None,
+        entry_point: ENTRYPOINT_COUNTER.to_string(),
+        args: runtime_args! { ARG_COUNTER_METHOD => method },
+    }
+}
+
+fn batched_request(deploy_hash: [u8; 32], calls: Vec<BatchedCall>) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_batched_session(calls)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn last_exec_error(builder: &InMemoryWasmTestBuilder) -> Option<Error> {
+    builder
+        .get_exec_results()
+        .last()
+        .and_then(|results| results.first())
+        .and_then(|result| result.as_error().cloned())
+}
+
+#[ignore]
+#[test]
+fn should_run_batched_calls_in_order() {
+    let mut builder = setup();
+
+    let exec_request = batched_request(
+        [1; 32],
+        vec![
+            counter_call_by_name(METHOD_INC),
+            counter_call_by_package_name(METHOD_INC),
+            counter_call_by_name(METHOD_INC),
+        ],
+    );
+
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(counter_value(&builder), 3);
+}
+
+#[ignore]
+#[test]
+fn should_charge_for_all_batched_calls() {
+    let mut builder = setup();
+
+    builder
+        .exec(batched_request(
+            [1; 32],
+            vec![counter_call_by_name(METHOD_INC)],
+        ))
+        .expect_success()
+        .commit();
+    let single_call_cost = builder.last_exec_gas_cost();
+
+    builder
+        .exec(batched_request(
+            [2; 32],
+            vec![
+                counter_call_by_name(METHOD_INC),
+                counter_call_by_name(METHOD_INC),
+            ],
+        ))
+        .expect_success()
+        .commit();
+    let two_calls_cost = builder.last_exec_gas_cost();
+
+    assert!(
+        two_calls_cost > single_call_cost,
+        "batch of two calls should cost more than a single call"
+    );
+    assert_eq!(counter_value(&builder), 3);
+}
+
+#[ignore]
+#[test]
+fn should_revert_all_batched_calls_if_one_fails() {
+    let mut builder = setup();
+
+    let exec_request = batched_request(
+        [1; 32],
+        vec![
+            counter_call_by_name(METHOD_INC),
+            counter_call_by_package_name(METHOD_INC),
+            counter_call_by_name(METHOD_INVALID),
+        ],
+    );
+
+    builder.exec(exec_request).commit();
+
+    assert_matches!(
+        last_exec_error(&builder),
+        Some(Error::Exec(execution::Error::Revert(
+            ApiError::InvalidArgument
+        )))
+    );
+    assert_eq!(
+        counter_value(&builder),
+        0,
+        "effects of earlier calls in a failed batch should be discarded"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_run_empty_batch() {
+    let mut builder = setup();
+
+    builder.exec(batched_request([1; 32], vec![])).commit();
+
+    assert_matches!(
+        last_exec_error(&builder),
+        Some(Error::InvalidDeployItemVariant(_))
+    );
+    assert_eq!(counter_value(&builder), 0);
+}
@@ -1,5 +1,7 @@
+mod batched_calls;
 mod context_association;
 mod non_standard_payment;
 mod preconditions;
 mod receipts;
+mod sponsored;
 mod stored_contracts;
@@ -0,0 +1,138 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::core::engine_state::Error;
+use casper_types::{account::AccountHash, runtime_args, system::mint, RuntimeArgs, U512};
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const ARG_AMOUNT: &str = "amount";
+const ARG_TARGET: &str = "target";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE) },
+    )
+    .build();
+
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_charge_fee_payer_for_sponsored_deploy() {
+    let mut builder = setup();
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    let fee_payer_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let account_1_starting_balance = builder.get_purse_balance(account_1_purse);
+    let fee_payer_starting_balance = builder.get_purse_balance(fee_payer_purse);
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(ACCOUNT_1_ADDR)
+            .with_fee_payer(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[ACCOUNT_1_ADDR, *DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(
+        builder.get_purse_balance(account_1_purse),
+        account_1_starting_balance,
+        "sponsored account should not pay for its deploy"
+    );
+    assert!(
+        builder.get_purse_balance(fee_payer_purse) < fee_payer_starting_balance,
+        "fee payer should pay for the deploy"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_authorization_failure_without_fee_payer_key() {
+    let mut builder = setup();
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(ACCOUNT_1_ADDR)
+            .with_fee_payer(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            // the fee payer didn't authorize the deploy
+            .with_authorization_keys(&[ACCOUNT_1_ADDR])
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_result(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(precondition_failure, Error::Authorization);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_sponsored_wasmless_transfer() {
+    let mut builder = setup();
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(ACCOUNT_1_ADDR)
+            .with_fee_payer(*DEFAULT_ACCOUNT_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args! {
+                mint::ARG_TARGET => *DEFAULT_ACCOUNT_ADDR,
+                mint::ARG_AMOUNT => U512::one(),
+                mint::ARG_ID => <Option<u64>>::None
+            })
+            .with_authorization_keys(&[ACCOUNT_1_ADDR, *DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::from_deploy_item(deploy).build()
+    };
+
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_result(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(precondition_failure, Error::TransferWithFeePayer);
+}
@@ -0,0 +1,89 @@
+use tempfile::TempDir;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, ForkedWasmTestBuilder, LmdbWasmTestBuilder,
+        DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::shared::newtypes::Blake2bHash;
+use casper_types::{account::AccountHash, runtime_args, system::mint, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const GLOBAL_STATE_DIR: &str = "global_state";
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+
+/// Runs genesis in an LMDB backed builder and returns its data dir and the genesis state root.
+fn setup() -> (TempDir, Blake2bHash) {
+    let data_dir = TempDir::new().expect("should create temp dir");
+    let mut builder = LmdbWasmTestBuilder::new(data_dir.path());
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let state_root_hash = builder.get_post_state_hash();
+    (data_dir, state_root_hash)
+}
+
+fn open_fork(data_dir: &TempDir, state_root_hash: Blake2bHash) -> ForkedWasmTestBuilder {
+    ForkedWasmTestBuilder::open_fork(
+        data_dir.path().join(GLOBAL_STATE_DIR),
+        Default::default(),
+        state_root_hash,
+        *DEFAULT_PROTOCOL_VERSION,
+    )
+}
+
+#[ignore]
+#[test]
+fn should_exec_as_impersonated_account_in_fork() {
+    let (data_dir, state_root_hash) = setup();
+    let mut builder = open_fork(&data_dir, state_root_hash);
+
+    let deploy_item = builder
+        .impersonate(*DEFAULT_ACCOUNT_ADDR)
+        .with_empty_payment_bytes(runtime_args! {})
+        .with_transfer_args(runtime_args! {
+            mint::ARG_TARGET => ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            mint::ARG_ID => <Option<u64>>::None
+        })
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1 in fork");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_write_fork_changes_to_base_state() {
+    let (data_dir, state_root_hash) = setup();
+
+    {
+        let mut builder = open_fork(&data_dir, state_root_hash);
+        let deploy_item = builder
+            .impersonate(*DEFAULT_ACCOUNT_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args! {
+                mint::ARG_TARGET => ACCOUNT_1_ADDR,
+                mint::ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+                mint::ARG_ID => <Option<u64>>::None
+            })
+            .build();
+        let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let builder = open_fork(&data_dir, state_root_hash);
+    assert!(
+        builder.get_account(ACCOUNT_1_ADDR).is_none(),
+        "a new fork should not see changes made in a previous fork"
+    );
+    assert!(builder.get_account(*DEFAULT_ACCOUNT_ADDR).is_some());
+}
@@ -0,0 +1,103 @@
+//! Fuzz tests feeding generated session code to the engine.
+//!
+//! Each test runs `PROPTEST_CASES` cases (256 by default), e.g. `make fuzz-engine` runs more.
+
+use proptest::{prop_assert, prop_assert_eq, prop_assert_ne, test_runner::TestRunner};
+
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{Error, ExecuteRequest},
+        execution,
+    },
+    shared::wasm::{self, gens},
+};
+use casper_types::{runtime_args, ApiError, RuntimeArgs};
+
+fn make_request_with_session_bytes(session_code: Vec<u8>) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_bytes(session_code, RuntimeArgs::new())
+        .with_empty_payment_bytes(runtime_args! {
+            ARG_AMOUNT => *DEFAULT_PAYMENT
+        })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder
+}
+
+/// Calls host functions with adversarial arguments and checks that the engine doesn't panic, that
+/// the session is charged for, and that every error code returned by a host function is a known
+/// `ApiError`.
+///
+/// Generated modules revert with the first non-zero result of a host function, which the engine
+/// decodes as `ApiError::Unhandled` if it isn't a known code.  Host functions never return
+/// `ApiError::Unhandled` themselves.
+#[ignore]
+#[test]
+fn should_fuzz_host_functions() {
+    let builder = setup();
+
+    let do_nothing_cost = {
+        let mut builder = builder.clone();
+        let exec_request = make_request_with_session_bytes(wasm::do_nothing_bytes());
+        builder.exec(exec_request).expect_success();
+        builder.last_exec_gas_cost()
+    };
+
+    TestRunner::default()
+        .run(&gens::host_function_module_arb(), |module| {
+            let mut builder = builder.clone();
+            let exec_request = make_request_with_session_bytes(module.to_bytes());
+            builder.exec(exec_request);
+
+            let exec_result = builder
+                .get_exec_results()
+                .last()
+                .expect("should have results")
+                .get(0)
+                .expect("should have first result")
+                .clone();
+            prop_assert!(
+                exec_result.cost() > do_nothing_cost,
+                "session should be charged for, cost {:?}",
+                exec_result.cost()
+            );
+            if let Some(Error::Exec(execution::Error::Revert(api_error))) = exec_result.as_error() {
+                prop_assert_ne!(*api_error, ApiError::Unhandled);
+            }
+            Ok(())
+        })
+        .unwrap_or_else(|error| panic!("{}", error));
+}
+
+/// Executes arbitrary, mostly invalid, modules as session code and checks that the engine doesn't
+/// panic.
+#[ignore]
+#[test]
+fn should_fuzz_session_modules() {
+    let builder = setup();
+
+    TestRunner::default()
+        .run(&gens::module_bytes_arb(), |module_bytes| {
+            let mut builder = builder.clone();
+            let exec_request = make_request_with_session_bytes(module_bytes);
+            builder.exec(exec_request);
+            prop_assert_eq!(builder.get_exec_results_count(), 1);
+            Ok(())
+        })
+        .unwrap_or_else(|error| panic!("{}", error));
+}
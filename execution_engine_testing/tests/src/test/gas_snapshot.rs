@@ -0,0 +1,267 @@
+use std::path::PathBuf;
+
+use num_traits::Zero;
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, GasSnapshot, InMemoryWasmTestBuilder,
+        DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use casper_execution_engine::{
+    core::engine_state::{genesis::GenesisValidator, GenesisAccount},
+    shared::{motes::Motes, wasm},
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::{
+        auction::{self, DelegationRate},
+        mint,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const SYSTEM_ENTRY_POINTS_SNAPSHOT: &str = "system_entry_points.snap";
+const CONTRACTS_SNAPSHOT: &str = "contracts.snap";
+
+const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_ADD_BID: &str = "add_bid.wasm";
+const CONTRACT_DELEGATE: &str = "delegate.wasm";
+const COUNTER_PACKAGE_HASH_KEY_NAME: &str = "counter_package_hash";
+const ENTRYPOINT_SESSION: &str = "session";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+const VALIDATOR_1_STAKE: u64 = 250_000;
+const BID_AMOUNT: u64 = 99;
+const DELEGATE_AMOUNT: u64 = 42;
+const TRANSFER_AMOUNT: u64 = 123;
+const BID_DELEGATION_RATE: DelegationRate = auction::DELEGATION_RATE_DENOMINATOR;
+
+static VALIDATOR_1: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([123; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([1; 32]));
+
+static GAS_SNAPSHOTS_PATH: Lazy<PathBuf> =
+    Lazy::new(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("gas_snapshots"));
+
+/// Runs genesis with an additional genesis validator to delegate to.
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let validator_1 = GenesisAccount::account(
+            *VALIDATOR_1,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                DelegationRate::zero(),
+            )),
+        );
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(validator_1);
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
+#[ignore]
+#[test]
+fn gas_snapshot_system_entry_points() {
+    let mut builder = setup();
+    let mut snapshot = GasSnapshot::open(GAS_SNAPSHOTS_PATH.join(SYSTEM_ENTRY_POINTS_SNAPSHOT));
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let mint_hash = builder.get_mint_contract_hash();
+
+    let auction_calls = vec![
+        (
+            auction::METHOD_ADD_BID,
+            runtime_args! {
+                auction::ARG_PUBLIC_KEY => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+                auction::ARG_AMOUNT => U512::from(BID_AMOUNT),
+                auction::ARG_DELEGATION_RATE => BID_DELEGATION_RATE,
+            },
+        ),
+        (
+            auction::METHOD_WITHDRAW_BID,
+            runtime_args! {
+                auction::ARG_PUBLIC_KEY => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+                auction::ARG_AMOUNT => U512::from(BID_AMOUNT),
+            },
+        ),
+        (
+            auction::METHOD_DELEGATE,
+            runtime_args! {
+                auction::ARG_DELEGATOR => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+                auction::ARG_VALIDATOR => *VALIDATOR_1,
+                auction::ARG_AMOUNT => U512::from(DELEGATE_AMOUNT),
+            },
+        ),
+        (
+            auction::METHOD_UNDELEGATE,
+            runtime_args! {
+                auction::ARG_DELEGATOR => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+                auction::ARG_VALIDATOR => *VALIDATOR_1,
+                auction::ARG_AMOUNT => U512::from(DELEGATE_AMOUNT),
+            },
+        ),
+    ];
+
+    for (entry_point, args) in auction_calls {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            auction_hash,
+            entry_point,
+            args,
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+        snapshot.record(
+            &format!("auction.{}", entry_point),
+            builder.last_exec_gas_cost(),
+        );
+    }
+
+    let source = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let target = builder
+        .get_account(*VALIDATOR_1_ADDR)
+        .expect("should have validator account")
+        .main_purse();
+    let mint_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        mint_hash,
+        mint::METHOD_TRANSFER,
+        runtime_args! {
+            mint::ARG_TO => Some(*VALIDATOR_1_ADDR),
+            mint::ARG_SOURCE => source,
+            mint::ARG_TARGET => target,
+            mint::ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            mint::ARG_ID => Some(0u64),
+        },
+    )
+    .build();
+    builder
+        .exec(mint_transfer_request)
+        .expect_success()
+        .commit();
+    snapshot.record(
+        &format!("mint.{}", mint::METHOD_TRANSFER),
+        builder.last_exec_gas_cost(),
+    );
+
+    let wasmless_transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => *ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder
+        .exec(wasmless_transfer_request)
+        .expect_success()
+        .commit();
+    snapshot.record("wasmless_transfer", builder.last_exec_gas_cost());
+
+    snapshot.assert_matches();
+}
+
+#[ignore]
+#[test]
+fn gas_snapshot_contracts() {
+    let mut builder = setup();
+    let mut snapshot = GasSnapshot::open(GAS_SNAPSHOTS_PATH.join(CONTRACTS_SNAPSHOT));
+
+    let do_nothing_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_bytes(wasm::do_nothing_bytes(), RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash([42; 32])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+    builder.exec(do_nothing_request).expect_success().commit();
+    snapshot.record("do_nothing", builder.last_exec_gas_cost());
+
+    let counter_define_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_COUNTER_DEFINE,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder
+        .exec(counter_define_request)
+        .expect_success()
+        .commit();
+    snapshot.record("counter_define", builder.last_exec_gas_cost());
+
+    let counter_session_request = ExecuteRequestBuilder::versioned_contract_call_by_hash_key_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        COUNTER_PACKAGE_HASH_KEY_NAME,
+        None,
+        ENTRYPOINT_SESSION,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder
+        .exec(counter_session_request)
+        .expect_success()
+        .commit();
+    snapshot.record("counter.session", builder.last_exec_gas_cost());
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+    snapshot.record("transfer_to_account_u512", builder.last_exec_gas_cost());
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            auction::ARG_PUBLIC_KEY => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+            auction::ARG_AMOUNT => U512::from(BID_AMOUNT),
+            auction::ARG_DELEGATION_RATE => BID_DELEGATION_RATE,
+        },
+    )
+    .build();
+    builder.exec(add_bid_request).expect_success().commit();
+    snapshot.record("add_bid", builder.last_exec_gas_cost());
+
+    let delegate_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => *DEFAULT_ACCOUNT_PUBLIC_KEY,
+            auction::ARG_VALIDATOR => *VALIDATOR_1,
+            auction::ARG_AMOUNT => U512::from(DELEGATE_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+    snapshot.record("delegate", builder.last_exec_gas_cost());
+
+    snapshot.assert_matches();
+}
@@ -4,7 +4,10 @@ mod contract_context;
 mod counter;
 mod deploy;
 mod explorer;
+mod forked_state;
+mod fuzz;
 mod gas_counter;
+mod gas_snapshot;
 mod get_balance;
 mod groups;
 mod host_function_costs;
@@ -249,7 +249,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
 
     let bids: Bids = builder.get_bids();
     let validator_1_bid = bids.get(&VALIDATOR_1).unwrap();
-    assert!(validator_1_bid.inactive());
+    assert!(validator_1_bid.is_jailed());
     assert!(validator_1_bid.staked_amount().is_zero());
 
     let total_supply_after_slashing: U512 =
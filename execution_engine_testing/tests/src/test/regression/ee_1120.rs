@@ -290,7 +290,7 @@ fn should_run_ee_1120_slash_delegators() {
     assert_ne!(bids_before, bids_after);
     assert_eq!(bids_after.len(), 2);
     let validator_2_bid = bids_after.get(&VALIDATOR_2).unwrap();
-    assert!(validator_2_bid.inactive());
+    assert!(validator_2_bid.is_jailed());
     assert!(validator_2_bid.staked_amount().is_zero());
 
     assert!(bids_after.contains_key(&VALIDATOR_1));
@@ -345,7 +345,7 @@ fn should_run_ee_1120_slash_delegators() {
     let bids_after: Bids = builder.get_bids();
     assert_eq!(bids_after.len(), 2);
     let validator_1_bid = bids_after.get(&VALIDATOR_1).unwrap();
-    assert!(validator_1_bid.inactive());
+    assert!(validator_1_bid.is_jailed());
     assert!(validator_1_bid.staked_amount().is_zero());
 
     let unbond_purses_after: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
@@ -116,7 +116,7 @@ fn should_step() {
 
     let bids_after_slashing: Bids = builder.get_bids();
     let account_1_bid = bids_after_slashing.get(&ACCOUNT_1_PK).unwrap();
-    assert!(account_1_bid.inactive());
+    assert!(account_1_bid.is_jailed());
     assert!(account_1_bid.staked_amount().is_zero());
 
     let bids_after_slashing: Bids = builder.get_bids();
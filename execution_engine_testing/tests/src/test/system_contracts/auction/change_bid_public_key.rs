@@ -0,0 +1,258 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::{
+        auction::{
+            self, BidPublicKeyChanges, EraId, SlashingRecords, ARG_NEW_PUBLIC_KEY, ARG_PUBLIC_KEY,
+            ARG_VALIDATOR_PUBLIC_KEYS, BID_PUBLIC_KEY_CHANGES_KEY, ERA_ID_KEY,
+            METHOD_CHANGE_BID_PUBLIC_KEY, METHOD_SLASH, SLASHING_RECORDS_KEY,
+        },
+        mint::TOTAL_SUPPLY_KEY,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use super::{get_auction_error, run_auctions, setup, upgrade_from_auction_without_entry_points};
+
+const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+
+const ADD_BID_AMOUNT: u64 = 100_000;
+const DELEGATE_AMOUNT: u64 = 50_000;
+
+static VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_PK));
+
+static NEW_VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([4; SecretKey::ED25519_LENGTH]).into());
+
+static OTHER_VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([5; SecretKey::ED25519_LENGTH]).into());
+
+static DELEGATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_PK));
+
+static ACCOUNTS: Lazy<Vec<AccountHash>> =
+    Lazy::new(|| vec![SYSTEM_ADDR, *VALIDATOR_ADDR, *DELEGATOR_ADDR]);
+static BIDS: Lazy<Vec<(AccountHash, PublicKey, u64)>> =
+    Lazy::new(|| vec![(*VALIDATOR_ADDR, *VALIDATOR_PK, ADD_BID_AMOUNT)]);
+static DELEGATIONS: Lazy<Vec<(AccountHash, PublicKey, PublicKey, u64)>> = Lazy::new(|| {
+    vec![(
+        *DELEGATOR_ADDR,
+        *DELEGATOR_PK,
+        *VALIDATOR_PK,
+        DELEGATE_AMOUNT,
+    )]
+});
+
+fn change_bid_public_key_request(
+    builder: &InMemoryWasmTestBuilder,
+    new_public_key: PublicKey,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_CHANGE_BID_PUBLIC_KEY,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_PK,
+            ARG_NEW_PUBLIC_KEY => new_public_key,
+        },
+    )
+    .build()
+}
+
+fn slash_request(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        SYSTEM_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_SLASH,
+        runtime_args! {
+            ARG_VALIDATOR_PUBLIC_KEYS => vec![public_key]
+        },
+    )
+    .build()
+}
+
+fn get_bid_public_key_changes(builder: &InMemoryWasmTestBuilder) -> BidPublicKeyChanges {
+    builder.get_value(
+        builder.get_auction_contract_hash(),
+        BID_PUBLIC_KEY_CHANGES_KEY,
+    )
+}
+
+#[ignore]
+#[test]
+fn should_move_bid_and_delegators_to_new_public_key() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, 1);
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let era_id: EraId = builder.get_value(auction_hash, ERA_ID_KEY);
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *NEW_VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    let new_bid = &bids[&NEW_VALIDATOR_PK];
+    assert_eq!(new_bid.validator_public_key(), &*NEW_VALIDATOR_PK);
+    assert_eq!(new_bid.staked_amount(), &U512::from(ADD_BID_AMOUNT));
+    let delegator = &new_bid.delegators()[&DELEGATOR_PK];
+    assert_eq!(delegator.staked_amount(), &U512::from(DELEGATE_AMOUNT));
+    assert_eq!(delegator.validator_public_key(), &*NEW_VALIDATOR_PK);
+
+    let former_bid = &bids[&VALIDATOR_PK];
+    assert!(former_bid.inactive());
+    assert!(former_bid.staked_amount().is_zero());
+    assert!(former_bid.delegators().is_empty());
+    assert_ne!(former_bid.bonding_purse(), new_bid.bonding_purse());
+
+    let bid_public_key_changes = get_bid_public_key_changes(&builder);
+    assert_eq!(bid_public_key_changes[&VALIDATOR_PK], *NEW_VALIDATOR_PK);
+
+    // The eras decided before the change keep the former public key, the following auction
+    // decides on the new one.
+    run_auctions(&mut builder, &mut timestamp_millis, 1);
+    let era_validators = builder.get_era_validators();
+    assert!(era_validators[&(era_id + DEFAULT_AUCTION_DELAY)].contains_key(&VALIDATOR_PK));
+    let effective_era_validators = &era_validators[&(era_id + DEFAULT_AUCTION_DELAY + 1)];
+    assert!(!effective_era_validators.contains_key(&VALIDATOR_PK));
+    assert_eq!(
+        effective_era_validators[&NEW_VALIDATOR_PK],
+        U512::from(ADD_BID_AMOUNT + DELEGATE_AMOUNT)
+    );
+
+    // The change is forgotten once the former public key has left the snapshot.
+    for _ in 1..DEFAULT_AUCTION_DELAY {
+        run_auctions(&mut builder, &mut timestamp_millis, 1);
+        assert!(get_bid_public_key_changes(&builder).contains_key(&VALIDATOR_PK));
+    }
+    run_auctions(&mut builder, &mut timestamp_millis, 1);
+    assert!(get_bid_public_key_changes(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_slash_moved_bid_for_former_public_key() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *NEW_VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    builder
+        .exec(slash_request(&builder, *VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    let new_bid = &bids[&NEW_VALIDATOR_PK];
+    assert!(new_bid.is_jailed());
+    assert!(new_bid.staked_amount().is_zero());
+    assert_eq!(
+        new_bid.delegators()[&DELEGATOR_PK].staked_amount(),
+        &U512::from(DELEGATE_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_move_bid_to_public_key_with_bid() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *VALIDATOR_PK))
+        .commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::ValidatorPublicKeyInUse as u8
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_move_former_bid_again() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *NEW_VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *OTHER_VALIDATOR_PK))
+        .commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::BidPublicKeyChanged as u8
+    );
+
+    let bids = builder.get_bids();
+    assert!(!bids.contains_key(&OTHER_VALIDATOR_PK));
+    assert_eq!(
+        bids[&NEW_VALIDATOR_PK].staked_amount(),
+        &U512::from(ADD_BID_AMOUNT)
+    );
+    assert_eq!(
+        get_bid_public_key_changes(&builder)[&VALIDATOR_PK],
+        *NEW_VALIDATOR_PK
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_record_slashing_of_public_key_without_bid() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let mint_hash = builder.get_mint_contract_hash();
+    let total_supply_before: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+
+    builder
+        .exec(slash_request(&builder, *OTHER_VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    let slashing_records: SlashingRecords = builder.get_value(auction_hash, SLASHING_RECORDS_KEY);
+    assert!(slashing_records.is_empty());
+
+    let total_supply_after: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply_before, total_supply_after);
+
+    let bids = builder.get_bids();
+    assert!(!bids.contains_key(&OTHER_VALIDATOR_PK));
+}
+
+#[ignore]
+#[test]
+fn should_change_bid_public_key_after_upgrade_from_auction_without_change_bid_public_key() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    upgrade_from_auction_without_entry_points(&mut builder, &[METHOD_CHANGE_BID_PUBLIC_KEY]);
+
+    builder
+        .exec(change_bid_public_key_request(&builder, *NEW_VALIDATOR_PK))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    assert_eq!(
+        bids[&NEW_VALIDATOR_PK].staked_amount(),
+        &U512::from(ADD_BID_AMOUNT)
+    );
+    assert_eq!(
+        get_bid_public_key_changes(&builder)[&VALIDATOR_PK],
+        *NEW_VALIDATOR_PK
+    );
+}
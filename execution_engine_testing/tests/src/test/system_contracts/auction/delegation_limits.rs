@@ -0,0 +1,201 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        self, ARG_MAXIMUM_DELEGATION_AMOUNT, ARG_MAXIMUM_DELEGATORS, ARG_MINIMUM_DELEGATION_AMOUNT,
+        ARG_PUBLIC_KEY, METHOD_SET_DELEGATION_LIMITS,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use super::{
+    delegate_request, get_auction_error, setup, upgrade_from_auction_without_entry_points,
+};
+
+const ADD_BID_AMOUNT: u64 = 95_000;
+const MINIMUM_DELEGATION_AMOUNT: u64 = 10_000;
+const MAXIMUM_DELEGATION_AMOUNT: u64 = 100_000;
+const MAXIMUM_DELEGATORS: u32 = 1;
+
+static VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_PK));
+
+static DELEGATOR_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1_PK));
+
+static DELEGATOR_2_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([206; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_2_PK));
+
+static ACCOUNTS: Lazy<Vec<AccountHash>> =
+    Lazy::new(|| vec![*VALIDATOR_ADDR, *DELEGATOR_1_ADDR, *DELEGATOR_2_ADDR]);
+static BIDS: Lazy<Vec<(AccountHash, PublicKey, u64)>> =
+    Lazy::new(|| vec![(*VALIDATOR_ADDR, *VALIDATOR_PK, ADD_BID_AMOUNT)]);
+
+fn set_delegation_limits_request(
+    builder: &InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    minimum_delegation_amount: Option<U512>,
+    maximum_delegation_amount: Option<U512>,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        builder.get_auction_contract_hash(),
+        METHOD_SET_DELEGATION_LIMITS,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_PK,
+            ARG_MINIMUM_DELEGATION_AMOUNT => minimum_delegation_amount,
+            ARG_MAXIMUM_DELEGATION_AMOUNT => maximum_delegation_amount,
+            ARG_MAXIMUM_DELEGATORS => Some(MAXIMUM_DELEGATORS),
+        },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_enforce_delegation_limits() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &[]);
+
+    let request = set_delegation_limits_request(
+        &builder,
+        *VALIDATOR_ADDR,
+        Some(U512::from(MINIMUM_DELEGATION_AMOUNT)),
+        Some(U512::from(MAXIMUM_DELEGATION_AMOUNT)),
+    );
+    builder.exec(request).commit().expect_success();
+
+    let bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_PK];
+    assert_eq!(
+        bid.minimum_delegation_amount(),
+        Some(&U512::from(MINIMUM_DELEGATION_AMOUNT))
+    );
+    assert_eq!(
+        bid.maximum_delegation_amount(),
+        Some(&U512::from(MAXIMUM_DELEGATION_AMOUNT))
+    );
+    assert_eq!(bid.maximum_delegators(), Some(MAXIMUM_DELEGATORS));
+
+    let request = delegate_request(
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_1_PK,
+        *VALIDATOR_PK,
+        MINIMUM_DELEGATION_AMOUNT - 1,
+    );
+    builder.exec(request).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::DelegationAmountTooSmall as u8
+    );
+
+    let request = delegate_request(
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_1_PK,
+        *VALIDATOR_PK,
+        MAXIMUM_DELEGATION_AMOUNT + 1,
+    );
+    builder.exec(request).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::DelegationAmountTooLarge as u8
+    );
+
+    let request = delegate_request(
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_1_PK,
+        *VALIDATOR_PK,
+        MINIMUM_DELEGATION_AMOUNT,
+    );
+    builder.exec(request).commit().expect_success();
+
+    // A top-up below the minimum is fine as long as the total stays within the limits.
+    let request = delegate_request(*DELEGATOR_1_ADDR, *DELEGATOR_1_PK, *VALIDATOR_PK, 1);
+    builder.exec(request).commit().expect_success();
+
+    let request = delegate_request(
+        *DELEGATOR_2_ADDR,
+        *DELEGATOR_2_PK,
+        *VALIDATOR_PK,
+        MINIMUM_DELEGATION_AMOUNT,
+    );
+    builder.exec(request).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::ExceededDelegatorSizeLimit as u8
+    );
+
+    let bids = builder.get_bids();
+    let delegators = bids[&VALIDATOR_PK].delegators();
+    assert_eq!(delegators.len(), 1);
+    assert_eq!(
+        delegators[&DELEGATOR_1_PK].staked_amount(),
+        &U512::from(MINIMUM_DELEGATION_AMOUNT + 1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_set_delegation_limits_after_upgrade_from_auction_without_delegation_limits() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &[]);
+
+    upgrade_from_auction_without_entry_points(&mut builder, &[METHOD_SET_DELEGATION_LIMITS]);
+
+    let request = set_delegation_limits_request(
+        &builder,
+        *VALIDATOR_ADDR,
+        Some(U512::from(MINIMUM_DELEGATION_AMOUNT)),
+        Some(U512::from(MAXIMUM_DELEGATION_AMOUNT)),
+    );
+    builder.exec(request).commit().expect_success();
+
+    let bids = builder.get_bids();
+    assert_eq!(
+        bids[&VALIDATOR_PK].maximum_delegators(),
+        Some(MAXIMUM_DELEGATORS)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_set_invalid_delegation_limits() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &[]);
+
+    let request = set_delegation_limits_request(
+        &builder,
+        *VALIDATOR_ADDR,
+        Some(U512::from(MAXIMUM_DELEGATION_AMOUNT)),
+        Some(U512::from(MINIMUM_DELEGATION_AMOUNT)),
+    );
+    builder.exec(request).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::InvalidDelegationLimits as u8
+    );
+
+    let request = set_delegation_limits_request(
+        &builder,
+        *DELEGATOR_1_ADDR,
+        Some(U512::from(MINIMUM_DELEGATION_AMOUNT)),
+        None,
+    );
+    builder.exec(request).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::InvalidPublicKey as u8
+    );
+
+    let bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_PK];
+    assert_eq!(bid.minimum_delegation_amount(), None);
+    assert_eq!(bid.maximum_delegation_amount(), None);
+    assert_eq!(bid.maximum_delegators(), None);
+}
@@ -17,9 +17,9 @@ use casper_types::{
     account::AccountHash,
     runtime_args,
     system::auction::{
-        self, Bid, Bids, DelegationRate, Delegator, SeigniorageAllocation, ARG_AMOUNT,
-        ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_VALIDATOR,
-        BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR, METHOD_DISTRIBUTE,
+        self, Bid, Bids, DelegationRate, Delegator, RewardDestination, SeigniorageAllocation,
+        ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS,
+        ARG_VALIDATOR, BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR, METHOD_DISTRIBUTE,
     },
     Key, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
 };
@@ -359,7 +359,7 @@ fn should_distribute_delegation_rate_zero() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_balance
     ));
 
@@ -633,7 +633,7 @@ fn should_withdraw_bids_after_distribute() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_balance
     ));
 
@@ -846,7 +846,7 @@ fn should_distribute_rewards_after_restaking_delegated_funds() {
 
     assert!(matches!(
         era_info_1.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout_1
     ));
 
@@ -1225,19 +1225,19 @@ fn should_distribute_reinvested_rewards_by_different_factor() {
 
     assert!(matches!(
         era_info_1.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
     assert!(matches!(
         era_info_1.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_payout_1
     ));
 
     assert!(matches!(
         era_info_1.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_payout_1
     ));
 
@@ -1349,19 +1349,19 @@ fn should_distribute_reinvested_rewards_by_different_factor() {
 
     assert!(matches!(
         era_info_2.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout_2
     ));
 
     assert!(matches!(
         era_info_2.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_payout_2
     ));
 
     assert!(matches!(
         era_info_2.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_payout_2
     ));
 
@@ -1599,7 +1599,7 @@ fn should_distribute_delegation_rate_half() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_balance
     ));
 
@@ -1794,7 +1794,7 @@ fn should_distribute_delegation_rate_full() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_balance
     ));
 
@@ -1996,7 +1996,7 @@ fn should_distribute_uneven_delegation_rate_zero() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
@@ -2207,19 +2207,19 @@ fn should_distribute_by_factor() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_payout
     ));
 }
@@ -2417,19 +2417,19 @@ fn should_distribute_by_factor_regardless_of_stake() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_payout
     ));
 }
@@ -2625,19 +2625,19 @@ fn should_distribute_by_factor_uneven() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_balance
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_payout
     ));
 }
@@ -2930,19 +2930,19 @@ fn should_distribute_with_multiple_validators_and_delegators() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == validator_1_updated_stake
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == validator_2_updated_stake
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == validator_3_updated_stake
     ));
 
@@ -3286,19 +3286,19 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
 
     assert!(matches!(
         era_info.select(*VALIDATOR_1).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_1 && *amount == expected_validator_1_payout
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_2).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_2 && *amount == expected_validator_2_balance
     ));
 
     assert!(matches!(
         era_info.select(*VALIDATOR_3).next(),
-        Some(SeigniorageAllocation::Validator { validator_public_key, amount })
+        Some(SeigniorageAllocation::Validator { validator_public_key, amount, .. })
         if *validator_public_key == *VALIDATOR_3 && *amount == expected_validator_3_updated_stake
     ));
 
@@ -3312,6 +3312,7 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
             *DELEGATOR_1,
             *VALIDATOR_1,
             expected_delegator_1_validator_1_payout,
+            RewardDestination::Compound,
         ))
     );
 
@@ -3320,6 +3321,7 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
             *DELEGATOR_1,
             *VALIDATOR_2,
             expected_delegator_1_validator_2_payout,
+            RewardDestination::Compound,
         ))
     );
 
@@ -3328,6 +3330,7 @@ fn should_distribute_with_multiple_validators_and_shared_delegator() {
             *DELEGATOR_1,
             *VALIDATOR_3,
             expected_delegator_1_validator_3_payout,
+            RewardDestination::Compound,
         ))
     );
 }
@@ -1,2 +1,213 @@
 mod bids;
+mod change_bid_public_key;
+mod delegation_limits;
 mod distribute;
+mod redelegate;
+mod reward_destination;
+mod simulation;
+mod slashing;
+
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_PROTOCOL_VERSION, TIMESTAMP_MILLIS_INCREMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{
+            self, run_genesis_request::RunGenesisRequest, upgrade::ActivationPoint, ExecuteRequest,
+        },
+        execution,
+    },
+    shared::stored_value::StoredValue,
+};
+use casper_types::{
+    account::AccountHash,
+    api_error::ApiError,
+    contracts::EntryPoints,
+    runtime_args,
+    system::auction::{
+        DelegationRate, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY,
+        ARG_VALIDATOR,
+    },
+    Contract, Key, ProtocolVersion, PublicKey, RuntimeArgs, U512,
+};
+
+const ARG_TARGET: &str = "target";
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_ADD_BID: &str = "add_bid.wasm";
+const CONTRACT_DELEGATE: &str = "delegate.wasm";
+
+const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE + 1000;
+const ADD_BID_DELEGATION_RATE: DelegationRate = 10;
+
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+/// Runs genesis, funds the `accounts` from the default account, then adds the `bids`, given as
+/// validator account, public key and amount, and the `delegations`, given as delegator account,
+/// delegator public key, validator public key and amount.
+fn setup(
+    run_genesis_request: &RunGenesisRequest,
+    accounts: &[AccountHash],
+    bids: &[(AccountHash, PublicKey, u64)],
+    delegations: &[(AccountHash, PublicKey, PublicKey, u64)],
+) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(run_genesis_request);
+
+    for target in accounts {
+        let transfer_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! {
+                ARG_TARGET => *target,
+                ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+            },
+        )
+        .build();
+        builder.exec(transfer_request).commit().expect_success();
+    }
+
+    for (validator_addr, validator_pk, amount) in bids {
+        let add_bid_request = ExecuteRequestBuilder::standard(
+            *validator_addr,
+            CONTRACT_ADD_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => *validator_pk,
+                ARG_AMOUNT => U512::from(*amount),
+                ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE,
+            },
+        )
+        .build();
+        builder.exec(add_bid_request).commit().expect_success();
+    }
+
+    for (delegator_addr, delegator_pk, validator_pk, amount) in delegations {
+        let request = delegate_request(*delegator_addr, *delegator_pk, *validator_pk, *amount);
+        builder.exec(request).commit().expect_success();
+    }
+
+    builder
+}
+
+fn delegate_request(
+    delegator_addr: AccountHash,
+    delegator_pk: PublicKey,
+    validator_pk: PublicKey,
+    amount: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        delegator_addr,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(amount),
+            ARG_VALIDATOR => validator_pk,
+            ARG_DELEGATOR => delegator_pk,
+        },
+    )
+    .build()
+}
+
+/// Runs `count` auctions, advancing `timestamp_millis` past each of them.
+fn run_auctions(builder: &mut InMemoryWasmTestBuilder, timestamp_millis: &mut u64, count: u64) {
+    for _ in 0..count {
+        builder.run_auction(*timestamp_millis, Vec::new());
+        *timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+}
+
+/// Upgrades from `current_protocol_version` to its next patch version, applying the
+/// `global_state_update`, and returns the new protocol version.
+fn upgrade_to_next_patch_version(
+    builder: &mut InMemoryWasmTestBuilder,
+    current_protocol_version: ProtocolVersion,
+    global_state_update: BTreeMap<Key, StoredValue>,
+) -> ProtocolVersion {
+    let sem_ver = current_protocol_version.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(current_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(global_state_update)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    new_protocol_version
+}
+
+/// Strips the auction contract of the entry points named in `removed_entry_points` through an
+/// upgrade, as if it had been installed by a protocol version preceding them, then upgrades again
+/// so the current entry points are installed.
+///
+/// Asserts the entry points were missing in between.
+fn upgrade_from_auction_without_entry_points(
+    builder: &mut InMemoryWasmTestBuilder,
+    removed_entry_points: &[&str],
+) {
+    let auction_hash = builder.get_auction_contract_hash();
+    let auction = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+
+    let mut entry_points = EntryPoints::new();
+    for entry_point in auction.entry_points().clone().take_entry_points() {
+        if !removed_entry_points
+            .iter()
+            .any(|name| *name == entry_point.name())
+        {
+            entry_points.add_entry_point(entry_point);
+        }
+    }
+    let stripped_auction = Contract::new(
+        auction.contract_package_hash(),
+        auction.contract_wasm_hash(),
+        auction.named_keys().clone(),
+        entry_points,
+        auction.protocol_version(),
+    );
+
+    let mut global_state_update = BTreeMap::new();
+    global_state_update.insert(
+        Key::from(auction_hash),
+        StoredValue::Contract(stripped_auction),
+    );
+
+    let protocol_version =
+        upgrade_to_next_patch_version(builder, *DEFAULT_PROTOCOL_VERSION, global_state_update);
+
+    let auction = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    for name in removed_entry_points {
+        assert!(!auction.has_entry_point(name));
+    }
+
+    upgrade_to_next_patch_version(builder, protocol_version, BTreeMap::new());
+}
+
+/// Returns the auction error the last executed request reverted with.
+fn get_auction_error(builder: &InMemoryWasmTestBuilder) -> u8 {
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result");
+    let exec_response = response.last().expect("should have response");
+    match exec_response.as_error().expect("should have error") {
+        engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(
+            auction_error,
+        ))) => *auction_error,
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
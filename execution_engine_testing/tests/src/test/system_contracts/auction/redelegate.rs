@@ -0,0 +1,272 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+    DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
+};
+use casper_execution_engine::core::engine_state;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        self, Redelegations, UnbondingPurses, ARG_AMOUNT, ARG_DELEGATOR, ARG_NEW_VALIDATOR,
+        ARG_PUBLIC_KEY, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEYS, METHOD_REDELEGATE, METHOD_SLASH,
+        REDELEGATIONS_KEY, UNBONDING_PURSES_KEY,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use super::{get_auction_error, run_auctions, setup, upgrade_from_auction_without_entry_points};
+
+const CONTRACT_WITHDRAW_BID: &str = "withdraw_bid.wasm";
+const CONTRACT_REDELEGATE: &str = "redelegate.wasm";
+
+const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+
+const ADD_BID_AMOUNT: u64 = 95_000;
+const DELEGATE_AMOUNT: u64 = 125_000;
+const REDELEGATE_AMOUNT: u64 = 35_000;
+
+static VALIDATOR_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1_PK));
+
+static VALIDATOR_2_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([4; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_2_PK));
+
+static DELEGATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_PK));
+
+static ACCOUNTS: Lazy<Vec<AccountHash>> = Lazy::new(|| {
+    vec![
+        SYSTEM_ADDR,
+        *VALIDATOR_1_ADDR,
+        *VALIDATOR_2_ADDR,
+        *DELEGATOR_ADDR,
+    ]
+});
+static BIDS: Lazy<Vec<(AccountHash, PublicKey, u64)>> = Lazy::new(|| {
+    vec![
+        (*VALIDATOR_1_ADDR, *VALIDATOR_1_PK, ADD_BID_AMOUNT),
+        (*VALIDATOR_2_ADDR, *VALIDATOR_2_PK, ADD_BID_AMOUNT),
+    ]
+});
+static DELEGATIONS: Lazy<Vec<(AccountHash, PublicKey, PublicKey, u64)>> = Lazy::new(|| {
+    vec![(
+        *DELEGATOR_ADDR,
+        *DELEGATOR_PK,
+        *VALIDATOR_1_PK,
+        DELEGATE_AMOUNT,
+    )]
+});
+
+/// Number of auctions run after the delegation for the delegated funds to be unlocked.
+const UNLOCKING_AUCTIONS: u64 = 5;
+
+fn redelegate_request(new_validator: PublicKey) -> engine_state::ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DELEGATOR_ADDR,
+        CONTRACT_REDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(REDELEGATE_AMOUNT),
+            ARG_VALIDATOR => *VALIDATOR_1_PK,
+            ARG_DELEGATOR => *DELEGATOR_PK,
+            ARG_NEW_VALIDATOR => new_validator,
+        },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_redelegate_after_unbonding_delay() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, UNLOCKING_AUCTIONS);
+
+    builder
+        .exec(redelegate_request(*VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    let delegated_amount = *bids[&VALIDATOR_1_PK].delegators()[&DELEGATOR_PK].staked_amount();
+    assert_eq!(
+        delegated_amount,
+        U512::from(DELEGATE_AMOUNT - REDELEGATE_AMOUNT)
+    );
+    assert!(bids[&VALIDATOR_2_PK].delegators().is_empty());
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let unbonding_purses: UnbondingPurses = builder.get_value(auction_hash, UNBONDING_PURSES_KEY);
+    assert!(unbonding_purses.is_empty());
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    let redelegation_list = &redelegations[&VALIDATOR_1_PK];
+    assert_eq!(redelegation_list.len(), 1);
+    let unbonding_purse = redelegation_list[0].unbonding_purse();
+    assert_eq!(unbonding_purse.unbonder_public_key(), &*DELEGATOR_PK);
+    assert_eq!(unbonding_purse.amount(), &U512::from(REDELEGATE_AMOUNT));
+    assert_eq!(redelegation_list[0].new_validator(), &*VALIDATOR_2_PK);
+
+    let delegator_purse = builder
+        .get_account(*DELEGATOR_ADDR)
+        .expect("should have delegator account")
+        .main_purse();
+    let delegator_purse_balance_before = builder.get_purse_balance(delegator_purse);
+
+    run_auctions(
+        &mut builder,
+        &mut timestamp_millis,
+        DEFAULT_UNBONDING_DELAY + 1,
+    );
+
+    // The unbonded amount is delegated to the new validator rather than paid out.
+    assert_eq!(
+        builder.get_purse_balance(delegator_purse),
+        delegator_purse_balance_before
+    );
+
+    let bids = builder.get_bids();
+    let redelegation = &bids[&VALIDATOR_2_PK].delegators()[&DELEGATOR_PK];
+    assert_eq!(redelegation.staked_amount(), &U512::from(REDELEGATE_AMOUNT));
+    assert_eq!(redelegation.validator_public_key(), &*VALIDATOR_2_PK);
+    assert_eq!(
+        builder.get_purse_balance(*redelegation.bonding_purse()),
+        U512::from(REDELEGATE_AMOUNT)
+    );
+
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    assert!(redelegations.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_redelegation_if_new_validator_withdrew_bid() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, UNLOCKING_AUCTIONS);
+
+    builder
+        .exec(redelegate_request(*VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    let withdraw_bid_request = ExecuteRequestBuilder::standard(
+        *VALIDATOR_2_ADDR,
+        CONTRACT_WITHDRAW_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_2_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(withdraw_bid_request).commit().expect_success();
+
+    let delegator_purse = builder
+        .get_account(*DELEGATOR_ADDR)
+        .expect("should have delegator account")
+        .main_purse();
+    let delegator_purse_balance_before = builder.get_purse_balance(delegator_purse);
+
+    run_auctions(
+        &mut builder,
+        &mut timestamp_millis,
+        DEFAULT_UNBONDING_DELAY + 1,
+    );
+
+    assert_eq!(
+        builder.get_purse_balance(delegator_purse),
+        delegator_purse_balance_before + U512::from(REDELEGATE_AMOUNT)
+    );
+
+    let bids = builder.get_bids();
+    assert!(bids[&VALIDATOR_2_PK].delegators().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_slash_pending_redelegation() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, UNLOCKING_AUCTIONS);
+
+    builder
+        .exec(redelegate_request(*VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let slash_request = ExecuteRequestBuilder::contract_call_by_hash(
+        SYSTEM_ADDR,
+        auction_hash,
+        METHOD_SLASH,
+        runtime_args! {
+            ARG_VALIDATOR_PUBLIC_KEYS => vec![*VALIDATOR_1_PK]
+        },
+    )
+    .build();
+    builder.exec(slash_request).commit().expect_success();
+
+    // The whole pending amount is burned at the default slashing rate.
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    assert!(redelegations.is_empty());
+
+    run_auctions(
+        &mut builder,
+        &mut timestamp_millis,
+        DEFAULT_UNBONDING_DELAY + 1,
+    );
+
+    let bids = builder.get_bids();
+    assert!(bids[&VALIDATOR_2_PK].delegators().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_redelegate_after_upgrade_from_auction_without_redelegate() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, UNLOCKING_AUCTIONS);
+
+    upgrade_from_auction_without_entry_points(&mut builder, &[METHOD_REDELEGATE]);
+
+    builder
+        .exec(redelegate_request(*VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    assert_eq!(redelegations[&VALIDATOR_1_PK].len(), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_redelegate_to_invalid_validator() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(&mut builder, &mut timestamp_millis, UNLOCKING_AUCTIONS);
+
+    builder.exec(redelegate_request(*VALIDATOR_1_PK)).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::RedelegationToSameValidator as u8
+    );
+
+    builder.exec(redelegate_request(*DELEGATOR_PK)).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::RedelegationValidatorNotFound as u8
+    );
+
+    let bids = builder.get_bids();
+    let delegated_amount = *bids[&VALIDATOR_1_PK].delegators()[&DELEGATOR_PK].staked_amount();
+    assert_eq!(delegated_amount, U512::from(DELEGATE_AMOUNT));
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        self, EraInfo, RewardDestination, ARG_DELEGATOR, ARG_REWARD_DESTINATION,
+        ARG_REWARD_FACTORS, ARG_VALIDATOR, BLOCK_REWARD, METHOD_DISTRIBUTE,
+        METHOD_SET_REWARD_DESTINATION,
+    },
+    AccessRights, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use super::{get_auction_error, run_auctions, setup, upgrade_from_auction_without_entry_points};
+
+const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+const REWARD_RECIPIENT_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+
+const ADD_BID_AMOUNT: u64 = 100_000;
+const DELEGATE_AMOUNT: u64 = 50_000;
+
+static VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_PK));
+
+static DELEGATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_PK));
+
+static ACCOUNTS: Lazy<Vec<AccountHash>> =
+    Lazy::new(|| vec![SYSTEM_ADDR, *VALIDATOR_ADDR, *DELEGATOR_ADDR]);
+static BIDS: Lazy<Vec<(AccountHash, PublicKey, u64)>> =
+    Lazy::new(|| vec![(*VALIDATOR_ADDR, *VALIDATOR_PK, ADD_BID_AMOUNT)]);
+static DELEGATIONS: Lazy<Vec<(AccountHash, PublicKey, PublicKey, u64)>> = Lazy::new(|| {
+    vec![(
+        *DELEGATOR_ADDR,
+        *DELEGATOR_PK,
+        *VALIDATOR_PK,
+        DELEGATE_AMOUNT,
+    )]
+});
+
+fn set_reward_destination_request(
+    builder: &InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    delegator_public_key: Option<PublicKey>,
+    reward_destination: RewardDestination,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        builder.get_auction_contract_hash(),
+        METHOD_SET_REWARD_DESTINATION,
+        runtime_args! {
+            ARG_VALIDATOR => *VALIDATOR_PK,
+            ARG_DELEGATOR => delegator_public_key,
+            ARG_REWARD_DESTINATION => reward_destination,
+        },
+    )
+    .build()
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, account_hash: AccountHash) -> U512 {
+    let account = builder
+        .get_account(account_hash)
+        .expect("should have account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+#[ignore]
+#[test]
+fn should_transfer_rewards_to_chosen_destinations() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder
+        .exec(set_reward_destination_request(
+            &builder,
+            *VALIDATOR_ADDR,
+            None,
+            RewardDestination::Account(REWARD_RECIPIENT_ADDR),
+        ))
+        .commit()
+        .expect_success();
+
+    let delegator_main_purse = builder
+        .get_account(*DELEGATOR_ADDR)
+        .expect("should have delegator account")
+        .main_purse();
+    builder
+        .exec(set_reward_destination_request(
+            &builder,
+            *DELEGATOR_ADDR,
+            Some(*DELEGATOR_PK),
+            RewardDestination::Purse(delegator_main_purse),
+        ))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_PK];
+    assert_eq!(
+        bid.reward_destination(),
+        &RewardDestination::Account(REWARD_RECIPIENT_ADDR)
+    );
+    assert_eq!(
+        bid.delegators()[&DELEGATOR_PK].reward_destination(),
+        &RewardDestination::Purse(delegator_main_purse)
+    );
+
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+    run_auctions(
+        &mut builder,
+        &mut timestamp_millis,
+        DEFAULT_AUCTION_DELAY + 1,
+    );
+
+    let delegator_balance_before = get_main_purse_balance(&builder, *DELEGATOR_ADDR);
+
+    let reward_factors: BTreeMap<PublicKey, u64> = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(*VALIDATOR_PK, BLOCK_REWARD);
+        tmp
+    };
+    let distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        SYSTEM_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_DISTRIBUTE,
+        runtime_args! {
+            ARG_REWARD_FACTORS => reward_factors
+        },
+    )
+    .build();
+    builder.exec(distribute_request).commit().expect_success();
+
+    // Neither stake is increased by the rewards.
+    let bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_PK];
+    assert_eq!(bid.staked_amount(), &U512::from(ADD_BID_AMOUNT));
+    assert_eq!(
+        bid.delegators()[&DELEGATOR_PK].staked_amount(),
+        &U512::from(DELEGATE_AMOUNT)
+    );
+
+    let era_info: EraInfo = {
+        let era = builder.get_era();
+        builder
+            .query(None, Key::EraInfo(era), &[])
+            .expect("should have value")
+            .as_era_info()
+            .cloned()
+            .expect("should be era info")
+    };
+
+    let validator_allocation = era_info
+        .select(*VALIDATOR_PK)
+        .next()
+        .expect("should have validator allocation");
+    assert_eq!(
+        validator_allocation.reward_destination(),
+        &RewardDestination::Account(REWARD_RECIPIENT_ADDR)
+    );
+    assert!(!validator_allocation.amount().is_zero());
+    assert_eq!(
+        get_main_purse_balance(&builder, REWARD_RECIPIENT_ADDR),
+        *validator_allocation.amount()
+    );
+
+    let delegator_allocation = era_info
+        .select(*DELEGATOR_PK)
+        .next()
+        .expect("should have delegator allocation");
+    assert_eq!(
+        delegator_allocation.reward_destination(),
+        &RewardDestination::Purse(delegator_main_purse)
+    );
+    assert!(!delegator_allocation.amount().is_zero());
+    assert_eq!(
+        get_main_purse_balance(&builder, *DELEGATOR_ADDR),
+        delegator_balance_before + *delegator_allocation.amount()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_set_reward_destination_to_purse_that_cant_be_added_to() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    let read_only_purse = builder
+        .get_account(*DELEGATOR_ADDR)
+        .expect("should have delegator account")
+        .main_purse()
+        .with_access_rights(AccessRights::READ);
+    builder
+        .exec(set_reward_destination_request(
+            &builder,
+            *DELEGATOR_ADDR,
+            Some(*DELEGATOR_PK),
+            RewardDestination::Purse(read_only_purse),
+        ))
+        .commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::InvalidRewardDestination as u8
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_set_reward_destination_of_another_delegator() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder
+        .exec(set_reward_destination_request(
+            &builder,
+            *VALIDATOR_ADDR,
+            Some(*DELEGATOR_PK),
+            RewardDestination::Account(REWARD_RECIPIENT_ADDR),
+        ))
+        .commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::InvalidPublicKey as u8
+    );
+}
+
+#[ignore]
+#[test]
+fn should_set_reward_destination_after_upgrade_from_auction_without_set_reward_destination() {
+    let mut builder = setup(&DEFAULT_RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    upgrade_from_auction_without_entry_points(&mut builder, &[METHOD_SET_REWARD_DESTINATION]);
+
+    builder
+        .exec(set_reward_destination_request(
+            &builder,
+            *DELEGATOR_ADDR,
+            Some(*DELEGATOR_PK),
+            RewardDestination::Account(REWARD_RECIPIENT_ADDR),
+        ))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    assert_eq!(
+        bids[&VALIDATOR_PK].delegators()[&DELEGATOR_PK].reward_destination(),
+        &RewardDestination::Account(REWARD_RECIPIENT_ADDR)
+    );
+}
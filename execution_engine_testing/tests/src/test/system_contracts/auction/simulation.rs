@@ -0,0 +1,206 @@
+use num_rational::Ratio;
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        EraSimulator, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY,
+        DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
+    },
+    MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        DelegationRate, EraId, ARG_AMOUNT, ARG_DELEGATOR, ARG_VALIDATOR, METHOD_UNDELEGATE,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE + 1000;
+
+const VALIDATOR_1_STAKE: u64 = 300_000;
+const VALIDATOR_2_STAKE: u64 = 100_000;
+const DELEGATOR_STAKE: u64 = 100_000;
+const UNDELEGATE_AMOUNT: u64 = 40_000;
+const DELEGATION_RATE: DelegationRate = 10;
+
+/// The first era in which validators bonded right after genesis are rewarded.
+const FIRST_REWARDED_ERA: EraId = DEFAULT_AUCTION_DELAY + 1;
+
+static VALIDATOR_1: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_2: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([5; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR));
+
+/// Bonds two validators and a delegator right after genesis.
+fn setup() -> EraSimulator<InMemoryGlobalState> {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let mut simulator = EraSimulator::new(builder).with_blocks_per_era(2);
+    for public_key in &[*VALIDATOR_1, *VALIDATOR_2, *DELEGATOR] {
+        simulator.fund(AccountHash::from(public_key), U512::from(TRANSFER_AMOUNT));
+    }
+    simulator
+        .add_bid(*VALIDATOR_1, U512::from(VALIDATOR_1_STAKE), DELEGATION_RATE)
+        .add_bid(*VALIDATOR_2, U512::from(VALIDATOR_2_STAKE), DELEGATION_RATE)
+        .delegate(*DELEGATOR, *VALIDATOR_1, U512::from(DELEGATOR_STAKE));
+    simulator
+}
+
+#[ignore]
+#[test]
+fn should_mint_rewards_into_total_supply() {
+    let mut simulator = setup();
+    simulator.advance_to_era(FIRST_REWARDED_ERA + 3);
+
+    for era_id in 0..FIRST_REWARDED_ERA {
+        assert_eq!(
+            simulator.total_supply_at(era_id + 1),
+            simulator.total_supply_at(era_id),
+            "nothing should be minted before the validators are part of an era"
+        );
+    }
+
+    for era_id in FIRST_REWARDED_ERA..FIRST_REWARDED_ERA + 3 {
+        let rewards = simulator.reward(era_id, &VALIDATOR_1)
+            + simulator.reward(era_id, &VALIDATOR_2)
+            + simulator.reward(era_id, &DELEGATOR);
+        assert!(!rewards.is_zero());
+        assert_eq!(
+            simulator.total_supply_at(era_id + 1) - simulator.total_supply_at(era_id),
+            rewards
+        );
+    }
+
+    let delegator_rewards = (FIRST_REWARDED_ERA..FIRST_REWARDED_ERA + 3)
+        .fold(U512::zero(), |total, era_id| {
+            total + simulator.reward(era_id, &DELEGATOR)
+        });
+    assert_eq!(
+        simulator.delegator_stake_at(FIRST_REWARDED_ERA + 3, &VALIDATOR_1, &DELEGATOR),
+        U512::from(DELEGATOR_STAKE) + delegator_rewards
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reward_in_proportion_to_participation() {
+    let mut simulator = setup();
+    simulator.advance_to_era(FIRST_REWARDED_ERA);
+
+    simulator
+        .set_participation(*VALIDATOR_2, Ratio::new(1, 2))
+        .advance_era();
+    let half_participation_reward = simulator.reward(FIRST_REWARDED_ERA, &VALIDATOR_2);
+
+    simulator
+        .set_participation(*VALIDATOR_2, Ratio::from_integer(1))
+        .advance_era();
+    let full_participation_reward = simulator.reward(FIRST_REWARDED_ERA + 1, &VALIDATOR_2);
+
+    assert!(!half_participation_reward.is_zero());
+    assert!(half_participation_reward < full_participation_reward);
+}
+
+#[ignore]
+#[test]
+fn should_evict_validator_that_does_not_participate() {
+    let mut simulator = setup();
+    simulator.advance_to_era(FIRST_REWARDED_ERA);
+
+    simulator
+        .set_participation(*VALIDATOR_2, Ratio::from_integer(0))
+        .advance_era();
+
+    assert!(simulator.reward(FIRST_REWARDED_ERA, &VALIDATOR_2).is_zero());
+    assert!(!simulator.reward(FIRST_REWARDED_ERA, &VALIDATOR_1).is_zero());
+    assert!(simulator
+        .bid(&VALIDATOR_2)
+        .expect("should have validator 2 bid")
+        .inactive());
+}
+
+#[ignore]
+#[test]
+fn should_slash_equivocator() {
+    let mut simulator = setup();
+    simulator.advance_to_era(FIRST_REWARDED_ERA);
+
+    simulator.equivocate(*VALIDATOR_2).advance_era();
+
+    assert!(simulator.reward(FIRST_REWARDED_ERA, &VALIDATOR_2).is_zero());
+    let validator_2_bid = simulator
+        .bid_at(FIRST_REWARDED_ERA + 1, &VALIDATOR_2)
+        .expect("should have validator 2 bid");
+    assert!(validator_2_bid.staked_amount().is_zero());
+    assert_eq!(
+        simulator.total_supply_at(FIRST_REWARDED_ERA + 1) + U512::from(VALIDATOR_2_STAKE)
+            - simulator.reward(FIRST_REWARDED_ERA, &VALIDATOR_1)
+            - simulator.reward(FIRST_REWARDED_ERA, &DELEGATOR),
+        simulator.total_supply_at(FIRST_REWARDED_ERA),
+        "slashed stake should be burned"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_unbonded_amount_after_unbonding_delay() {
+    let mut simulator = setup();
+    simulator.advance_to_era(FIRST_REWARDED_ERA);
+
+    simulator.undelegate(*DELEGATOR, *VALIDATOR_1, U512::from(UNDELEGATE_AMOUNT));
+
+    let payout_era = FIRST_REWARDED_ERA + DEFAULT_UNBONDING_DELAY;
+    simulator.advance_to_era(payout_era + 1);
+
+    for era_id in FIRST_REWARDED_ERA..payout_era {
+        assert!(simulator.unbonding_payout(era_id, &DELEGATOR).is_zero());
+    }
+    assert_eq!(
+        simulator.unbonding_payout(payout_era, &DELEGATOR),
+        U512::from(UNDELEGATE_AMOUNT)
+    );
+    assert_eq!(
+        simulator.balance_at(payout_era + 1, *DELEGATOR_ADDR)
+            - simulator.balance_at(payout_era, *DELEGATOR_ADDR),
+        U512::from(UNDELEGATE_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_exec_scheduled_deploy_in_its_block() {
+    let mut simulator = setup();
+    let undelegate_height = simulator.block_height() + 3;
+    let undelegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DELEGATOR_ADDR,
+        simulator.builder().get_auction_contract_hash(),
+        METHOD_UNDELEGATE,
+        runtime_args! {
+            ARG_DELEGATOR => *DELEGATOR,
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_AMOUNT => U512::from(UNDELEGATE_AMOUNT),
+        },
+    )
+    .build();
+    simulator.schedule_deploy(undelegate_height, undelegate_request);
+
+    // With two blocks per era, block 3 is the last block of era 1.
+    simulator.advance_to_era(1);
+    assert_eq!(
+        simulator.delegator_stake_at(1, &VALIDATOR_1, &DELEGATOR),
+        U512::from(DELEGATOR_STAKE)
+    );
+
+    simulator.advance_era();
+    assert_eq!(
+        simulator.delegator_stake_at(2, &VALIDATOR_1, &DELEGATOR),
+        U512::from(DELEGATOR_STAKE - UNDELEGATE_AMOUNT)
+    );
+}
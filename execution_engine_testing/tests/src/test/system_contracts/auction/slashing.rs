@@ -0,0 +1,286 @@
+use num_rational::Ratio;
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_AUCTION_DELAY,
+    DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE,
+    DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    TIMESTAMP_MILLIS_INCREMENT,
+};
+use casper_execution_engine::core::engine_state::{
+    genesis::ExecConfig, run_genesis_request::RunGenesisRequest, ExecuteRequest,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::{
+        auction::{
+            self, EraId, SlashingRecords, ARG_VALIDATOR_PUBLIC_KEY, ARG_VALIDATOR_PUBLIC_KEYS,
+            ERA_ID_KEY, METHOD_SLASH, METHOD_UNJAIL, SLASHING_RECORDS_KEY,
+        },
+        mint::TOTAL_SUPPLY_KEY,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+use super::{
+    delegate_request, get_auction_error, run_auctions, setup,
+    upgrade_from_auction_without_entry_points,
+};
+
+const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+
+const ADD_BID_AMOUNT: u64 = 100_000;
+const DELEGATE_AMOUNT_1: u64 = 50_000;
+const DELEGATE_AMOUNT_2: u64 = 20_000;
+
+const SLASHING_RATE: Ratio<u64> = Ratio::new_raw(1, 10);
+const DELEGATION_SLASHING_RATE: Ratio<u64> = Ratio::new_raw(1, 20);
+const JAIL_PERIOD: u64 = 2;
+
+static VALIDATOR_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_PK));
+
+static DELEGATOR_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([204; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1_PK));
+
+static DELEGATOR_2_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([206; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_2_PK));
+
+/// Genesis with partial slashing rates and a jail period.
+static RUN_GENESIS_REQUEST: Lazy<RunGenesisRequest> = Lazy::new(|| {
+    let exec_config = ExecConfig::new(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        SLASHING_RATE,
+        DELEGATION_SLASHING_RATE,
+        JAIL_PERIOD,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+});
+
+static ACCOUNTS: Lazy<Vec<AccountHash>> = Lazy::new(|| {
+    vec![
+        SYSTEM_ADDR,
+        *VALIDATOR_ADDR,
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_2_ADDR,
+    ]
+});
+static BIDS: Lazy<Vec<(AccountHash, PublicKey, u64)>> =
+    Lazy::new(|| vec![(*VALIDATOR_ADDR, *VALIDATOR_PK, ADD_BID_AMOUNT)]);
+static DELEGATIONS: Lazy<Vec<(AccountHash, PublicKey, PublicKey, u64)>> = Lazy::new(|| {
+    vec![(
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_1_PK,
+        *VALIDATOR_PK,
+        DELEGATE_AMOUNT_1,
+    )]
+});
+
+/// Runs an auction and returns whether the validator won a slot in the newly snapshotted era.
+fn run_auction(builder: &mut InMemoryWasmTestBuilder, timestamp_millis: &mut u64) -> bool {
+    builder.run_auction(*timestamp_millis, Vec::new());
+    *timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let era_id: EraId = builder.get_value(auction_hash, ERA_ID_KEY);
+    let era_validators = builder.get_era_validators();
+    era_validators[&(era_id + DEFAULT_AUCTION_DELAY)].contains_key(&VALIDATOR_PK)
+}
+
+fn slash_request(builder: &InMemoryWasmTestBuilder) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        SYSTEM_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_SLASH,
+        runtime_args! {
+            ARG_VALIDATOR_PUBLIC_KEYS => vec![*VALIDATOR_PK]
+        },
+    )
+    .build()
+}
+
+fn unjail_request(builder: &InMemoryWasmTestBuilder) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_UNJAIL,
+        runtime_args! {
+            ARG_VALIDATOR_PUBLIC_KEY => *VALIDATOR_PK,
+        },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_slash_proportionally_and_jail_validator() {
+    let mut builder = setup(&RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+
+    assert!(run_auction(&mut builder, &mut timestamp_millis));
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let mint_hash = builder.get_mint_contract_hash();
+    let era_id: EraId = builder.get_value(auction_hash, ERA_ID_KEY);
+    let total_supply_before: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+
+    builder
+        .exec(slash_request(&builder))
+        .commit()
+        .expect_success();
+
+    let validator_amount = U512::from(ADD_BID_AMOUNT / 10);
+    let delegators_amount = U512::from(DELEGATE_AMOUNT_1 / 20);
+
+    let bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_PK];
+    assert_eq!(
+        *bid.staked_amount(),
+        U512::from(ADD_BID_AMOUNT) - validator_amount
+    );
+    assert_eq!(
+        *bid.delegators()[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1) - delegators_amount
+    );
+    assert_eq!(bid.jailed_until(), Some(era_id + JAIL_PERIOD));
+
+    let total_supply_after: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+    assert_eq!(
+        total_supply_before - total_supply_after,
+        validator_amount + delegators_amount
+    );
+
+    let slashing_records: SlashingRecords = builder.get_value(auction_hash, SLASHING_RECORDS_KEY);
+    let slashing_record = &slashing_records[&VALIDATOR_PK];
+    assert_eq!(slashing_record.len(), 1);
+    assert_eq!(slashing_record[0].era_id(), era_id);
+    assert_eq!(slashing_record[0].validator_amount(), &validator_amount);
+    assert_eq!(slashing_record[0].delegators_amount(), &delegators_amount);
+    assert!(slashing_record[0].unbonding_amount().is_zero());
+    assert_eq!(slashing_record[0].jailed_until(), era_id + JAIL_PERIOD);
+
+    // A jailed validator takes no part in auctions and can't unjail before its jail period ends.
+    assert!(!run_auction(&mut builder, &mut timestamp_millis));
+
+    builder.exec(unjail_request(&builder)).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::ValidatorJailed as u8
+    );
+
+    for _ in 1..JAIL_PERIOD {
+        assert!(!run_auction(&mut builder, &mut timestamp_millis));
+    }
+
+    builder
+        .exec(unjail_request(&builder))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    assert!(!bids[&VALIDATOR_PK].is_jailed());
+
+    assert!(run_auction(&mut builder, &mut timestamp_millis));
+}
+
+#[ignore]
+#[test]
+fn should_not_unjail_validator_that_is_not_jailed() {
+    let mut builder = setup(&RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    builder.exec(unjail_request(&builder)).commit();
+    assert_eq!(
+        get_auction_error(&builder),
+        auction::Error::ValidatorNotJailed as u8
+    );
+}
+
+#[ignore]
+#[test]
+fn should_slash_delegators_proportionally_to_their_stakes() {
+    let mut builder = setup(&RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+
+    let request = delegate_request(
+        *DELEGATOR_2_ADDR,
+        *DELEGATOR_2_PK,
+        *VALIDATOR_PK,
+        DELEGATE_AMOUNT_2,
+    );
+    builder.exec(request).commit().expect_success();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let mint_hash = builder.get_mint_contract_hash();
+    let total_supply_before: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+
+    builder
+        .exec(slash_request(&builder))
+        .commit()
+        .expect_success();
+
+    let delegator_1_amount = U512::from(DELEGATE_AMOUNT_1 / 20);
+    let delegator_2_amount = U512::from(DELEGATE_AMOUNT_2 / 20);
+
+    let bids = builder.get_bids();
+    let delegators = bids[&VALIDATOR_PK].delegators();
+    assert_eq!(
+        *delegators[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1) - delegator_1_amount
+    );
+    assert_eq!(
+        *delegators[&DELEGATOR_2_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT_2) - delegator_2_amount
+    );
+
+    let total_supply_after: U512 = builder.get_value(mint_hash, TOTAL_SUPPLY_KEY);
+    assert_eq!(
+        total_supply_before - total_supply_after,
+        U512::from(ADD_BID_AMOUNT / 10) + delegator_1_amount + delegator_2_amount
+    );
+
+    let slashing_records: SlashingRecords = builder.get_value(auction_hash, SLASHING_RECORDS_KEY);
+    assert_eq!(
+        slashing_records[&VALIDATOR_PK][0].delegators_amount(),
+        &(delegator_1_amount + delegator_2_amount)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_unjail_after_upgrade_from_auction_without_unjail() {
+    let mut builder = setup(&RUN_GENESIS_REQUEST, &ACCOUNTS, &BIDS, &DELEGATIONS);
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+
+    builder
+        .exec(slash_request(&builder))
+        .commit()
+        .expect_success();
+
+    run_auctions(&mut builder, &mut timestamp_millis, JAIL_PERIOD);
+
+    upgrade_from_auction_without_entry_points(&mut builder, &[METHOD_UNJAIL]);
+
+    builder
+        .exec(unjail_request(&builder))
+        .commit()
+        .expect_success();
+
+    let bids = builder.get_bids();
+    assert!(!bids[&VALIDATOR_PK].is_jailed());
+}
@@ -191,7 +191,7 @@ fn should_run_successful_bond_and_unbond_and_slashing() {
 
     let bids: Bids = builder.get_bids();
     let default_account_bid = bids.get(&DEFAULT_ACCOUNT_PUBLIC_KEY).unwrap();
-    assert!(default_account_bid.inactive());
+    assert!(default_account_bid.is_jailed());
     assert!(default_account_bid.staked_amount().is_zero());
 
     let account_balance_after_slashing = builder.get_purse_balance(unbonding_purse);
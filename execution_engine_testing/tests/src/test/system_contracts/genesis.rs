@@ -3,8 +3,9 @@ use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     internal::{
-        InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
+        InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_DELEGATION_SLASHING_RATE,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_JAIL_PERIOD, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SLASHING_RATE, DEFAULT_SYSTEM_CONFIG,
         DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
     },
     AccountHash,
@@ -71,6 +72,9 @@ fn should_run_genesis() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let slashing_rate = DEFAULT_SLASHING_RATE;
+    let delegation_slashing_rate = DEFAULT_DELEGATION_SLASHING_RATE;
+    let jail_period = DEFAULT_JAIL_PERIOD;
     let genesis_timestamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let exec_config = ExecConfig::new(
@@ -82,6 +86,9 @@ fn should_run_genesis() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        slashing_rate,
+        delegation_slashing_rate,
+        jail_period,
         genesis_timestamp,
     );
     let run_genesis_request =
@@ -142,6 +149,9 @@ fn should_track_total_token_supply_in_mint() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let slashing_rate = DEFAULT_SLASHING_RATE;
+    let delegation_slashing_rate = DEFAULT_DELEGATION_SLASHING_RATE;
+    let jail_period = DEFAULT_JAIL_PERIOD;
     let genesis_tiemstamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     let ee_config = ExecConfig::new(
         accounts.clone(),
@@ -152,6 +162,9 @@ fn should_track_total_token_supply_in_mint() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        slashing_rate,
+        delegation_slashing_rate,
+        jail_period,
         genesis_tiemstamp,
     );
     let run_genesis_request =
@@ -829,6 +829,9 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         remove_contract_user_group_urefs: HostFunction::fixed(0),
         print: HostFunction::fixed(0),
         blake2b: HostFunction::fixed(0),
+        add_contract_version_with_migration: HostFunction::fixed(0),
+        load_call_stack: HostFunction::fixed(0),
+        set_upgrade_policy: HostFunction::fixed(0),
     };
 
     let new_wasm_config = WasmConfig::new(
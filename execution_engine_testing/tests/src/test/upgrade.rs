@@ -1,16 +1,24 @@
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_execution_engine::shared::stored_value::StoredValue;
+use casper_execution_engine::{
+    core::{engine_state, execution},
+    shared::stored_value::StoredValue,
+};
 use casper_types::{
-    contracts::{ContractVersion, CONTRACT_INITIAL_VERSION},
-    runtime_args, CLValue, ContractPackageHash, RuntimeArgs,
+    contracts::{self, ContractVersion, VersionActivation, CONTRACT_INITIAL_VERSION},
+    runtime_args, AccessRights, ApiError, CLValue, ContractHash, ContractPackageHash,
+    ContractVersionKey, Group, Key, ProtocolVersion, RuntimeArgs, URef,
 };
 
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
 const DO_NOTHING_STORED_UPGRADER_CONTRACT_NAME: &str = "do_nothing_stored_upgrader";
 const DO_NOTHING_STORED_CALLER_CONTRACT_NAME: &str = "do_nothing_stored_caller";
+const DO_NOTHING_STORED_MIGRATING_UPGRADER_CONTRACT_NAME: &str =
+    "do_nothing_stored_migrating_upgrader";
 const ENTRY_FUNCTION_NAME: &str = "delegate";
 const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
 const DO_NOTHING_HASH_KEY_NAME: &str = "do_nothing_hash";
@@ -32,6 +40,18 @@ const ARG_CONTRACT_PACKAGE: &str = "contract_package";
 const ARG_VERSION: &str = "version";
 const ARG_NEW_PURSE_NAME: &str = "new_purse_name";
 const ARG_IS_LOCKED: &str = "is_locked";
+const ARG_REVERT_MIGRATION: &str = "revert_migration";
+const MIGRATED_KEY_NAME: &str = "migrated";
+const DO_NOTHING_STORED_UPGRADE_POLICY_CONTRACT_NAME: &str = "do_nothing_stored_upgrade_policy";
+const ARG_UPGRADE_DELAY_MILLIS: &str = "upgrade_delay_millis";
+const ARG_APPROVER_UREFS: &str = "approver_urefs";
+const UPGRADE_DELAY_MILLIS: u64 = 60_000;
+const DO_NOTHING_STORED_MANAGE_APPROVERS_CONTRACT_NAME: &str = "do_nothing_stored_manage_approvers";
+const APPROVERS_GROUP_NAME: &str = "approvers";
+const ARG_ACTION: &str = "action";
+const ARG_UREFS: &str = "urefs";
+const ACTION_PROVISION: &str = "provision";
+const ACTION_REMOVE: &str = "remove";
 
 /// Performs define and execution of versioned contracts, calling them directly from hash
 #[ignore]
@@ -645,3 +665,406 @@ fn should_fail_upgrade_for_locked_contract() {
         assert!(builder.exec(exec_request).is_error());
     }
 }
+
+/// Stores the do-nothing contract, then adds a version of it whose migration entry point reverts
+/// if `revert_migration` is set. Returns the contract package hash.
+fn store_and_upgrade_with_migration(
+    builder: &mut InMemoryWasmTestBuilder,
+    revert_migration: bool,
+) -> ContractPackageHash {
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DO_NOTHING_STORED_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            RuntimeArgs::default(),
+        )
+        .build()
+    };
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_request = {
+        let contract_name = format!(
+            "{}.wasm",
+            DO_NOTHING_STORED_MIGRATING_UPGRADER_CONTRACT_NAME
+        );
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_REVERT_MIGRATION => revert_migration,
+            },
+        )
+        .build()
+    };
+    builder.exec(exec_request).commit();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash")
+        .into_hash()
+        .expect("should have hash")
+        .into()
+}
+
+#[ignore]
+#[test]
+fn should_run_migration_when_adding_contract_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let package_hash = store_and_upgrade_with_migration(&mut builder, false);
+    builder.expect_success();
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    assert_eq!(contract_package.versions().len(), 2);
+
+    assert_eq!(
+        contract_package
+            .current_contract_version()
+            .expect("should have current version")
+            .contract_version(),
+        UPGRADED_VERSION
+    );
+
+    let contract_hash = contract_package
+        .current_contract_hash()
+        .expect("should have current contract");
+    let contract = builder
+        .get_contract(contract_hash)
+        .expect("should have contract");
+
+    // The migration is passed the package's access key.
+    assert_eq!(
+        contract.named_keys().get(MIGRATED_KEY_NAME),
+        Some(&Key::from(contract_package.access_key()))
+    );
+
+    // The upgraded version is callable once the migration has run.
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash_key_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_PACKAGE_HASH_KEY_NAME,
+        Some(UPGRADED_VERSION),
+        ENTRY_FUNCTION_NAME,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_add_contract_version_if_migration_reverts() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let package_hash = store_and_upgrade_with_migration(&mut builder, true);
+    assert!(builder.is_error());
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    assert_eq!(contract_package.versions().len(), 1);
+    assert_eq!(
+        contract_package
+            .current_contract_version()
+            .expect("should have current version")
+            .contract_version(),
+        INITIAL_VERSION
+    );
+}
+
+/// Stores the do-nothing contract and sets an upgrade policy on its package which delays new
+/// versions by `UPGRADE_DELAY_MILLIS` and requires approval from a group with `approver_urefs`
+/// URefs, the first of which is held by the default account.
+fn store_with_upgrade_policy(
+    builder: &mut InMemoryWasmTestBuilder,
+    approver_urefs: u8,
+) -> ContractPackageHash {
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DO_NOTHING_STORED_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, &contract_name, RuntimeArgs::new())
+            .build()
+    };
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DO_NOTHING_STORED_UPGRADE_POLICY_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_UPGRADE_DELAY_MILLIS => UPGRADE_DELAY_MILLIS,
+                ARG_APPROVER_UREFS => approver_urefs,
+            },
+        )
+        .build()
+    };
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash")
+        .into_hash()
+        .expect("should have hash")
+        .into()
+}
+
+fn upgrade_do_nothing_at(builder: &mut InMemoryWasmTestBuilder, block_time: u64) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DO_NOTHING_STORED_UPGRADER_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, &contract_name, RuntimeArgs::new())
+            .with_block_time(block_time)
+            .build()
+    };
+    builder.exec(exec_request).commit();
+}
+
+fn call_upgraded_version_at(builder: &mut InMemoryWasmTestBuilder, block_time: u64) {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash_key_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_PACKAGE_HASH_KEY_NAME,
+        Some(UPGRADED_VERSION),
+        ENTRY_FUNCTION_NAME,
+        runtime_args! { PURSE_NAME_ARG_NAME => PURSE_1 },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+fn call_contract_by_hash_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    block_time: u64,
+) {
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_FUNCTION_NAME,
+        runtime_args! { PURSE_NAME_ARG_NAME => PURSE_1 },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+fn call_contract_by_hash_from_session_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    block_time: u64,
+) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", PURSE_HOLDER_STORED_CALLER_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                HASH_KEY_NAME => contract_hash,
+                ENTRY_POINT_NAME => ENTRY_FUNCTION_NAME,
+                PURSE_NAME => PURSE_1,
+            },
+        )
+        .with_block_time(block_time)
+        .build()
+    };
+    builder.exec(exec_request).commit();
+}
+
+fn last_exec_error(builder: &InMemoryWasmTestBuilder) -> engine_state::Error {
+    builder
+        .get_exec_results()
+        .last()
+        .expect("should have last exec result")
+        .last()
+        .expect("should have response")
+        .as_error()
+        .cloned()
+        .expect("should have error")
+}
+
+#[ignore]
+#[test]
+fn should_only_call_upgraded_version_after_upgrade_delay() {
+    const UPGRADE_BLOCK_TIME: u64 = 1_000;
+    const ACTIVATION_BLOCK_TIME: u64 = UPGRADE_BLOCK_TIME + UPGRADE_DELAY_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let package_hash = store_with_upgrade_policy(&mut builder, 1);
+
+    upgrade_do_nothing_at(&mut builder, UPGRADE_BLOCK_TIME);
+    builder.expect_success();
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    let upgraded_version =
+        ContractVersionKey::new(ProtocolVersion::V1_0_0.value().major, UPGRADED_VERSION);
+    assert_eq!(
+        contract_package.pending_versions().get(&upgraded_version),
+        Some(&VersionActivation::Timestamp(ACTIVATION_BLOCK_TIME))
+    );
+    // Calls without a version keep going to the initial version in the meantime.
+    assert_eq!(
+        contract_package
+            .current_contract_version()
+            .expect("should have current version")
+            .contract_version(),
+        INITIAL_VERSION
+    );
+
+    call_upgraded_version_at(&mut builder, ACTIVATION_BLOCK_TIME - 1);
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::InvalidContractVersion(version))
+            if version == upgraded_version
+    );
+
+    call_upgraded_version_at(&mut builder, ACTIVATION_BLOCK_TIME);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_upgrade_without_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let package_hash = store_with_upgrade_policy(&mut builder, 0);
+
+    upgrade_do_nothing_at(&mut builder, 0);
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::Revert(api_error))
+            if api_error == ApiError::from(contracts::Error::UpgradeNotApproved)
+    );
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    assert_eq!(contract_package.versions().len(), 1);
+}
+
+fn manage_approvers(builder: &mut InMemoryWasmTestBuilder, action: &str, urefs: Vec<URef>) {
+    let exec_request = {
+        let contract_name = format!("{}.wasm", DO_NOTHING_STORED_MANAGE_APPROVERS_CONTRACT_NAME);
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &contract_name,
+            runtime_args! {
+                ARG_ACTION => action,
+                ARG_UREFS => urefs,
+            },
+        )
+        .build()
+    };
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_change_approver_urefs_without_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let package_hash = store_with_upgrade_policy(&mut builder, 0);
+
+    // Holding the package's access key alone doesn't allow granting oneself approval.
+    manage_approvers(&mut builder, ACTION_PROVISION, Vec::new());
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::Revert(api_error))
+            if api_error == ApiError::from(contracts::Error::UpgradeNotApproved)
+    );
+
+    let removed_uref = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+    manage_approvers(&mut builder, ACTION_REMOVE, vec![removed_uref]);
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::Revert(api_error))
+            if api_error == ApiError::from(contracts::Error::UpgradeNotApproved)
+    );
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    assert!(contract_package.groups()[&Group::new(APPROVERS_GROUP_NAME)].is_empty());
+
+    upgrade_do_nothing_at(&mut builder, 0);
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::Revert(api_error))
+            if api_error == ApiError::from(contracts::Error::UpgradeNotApproved)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_provision_approver_uref_with_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let package_hash = store_with_upgrade_policy(&mut builder, 1);
+
+    manage_approvers(&mut builder, ACTION_PROVISION, Vec::new());
+    builder.expect_success();
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have package");
+    assert_eq!(
+        contract_package.groups()[&Group::new(APPROVERS_GROUP_NAME)].len(),
+        2
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_call_pending_version_by_contract_hash() {
+    const UPGRADE_BLOCK_TIME: u64 = 1_000;
+    const ACTIVATION_BLOCK_TIME: u64 = UPGRADE_BLOCK_TIME + UPGRADE_DELAY_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    let package_hash = store_with_upgrade_policy(&mut builder, 1);
+
+    upgrade_do_nothing_at(&mut builder, UPGRADE_BLOCK_TIME);
+    builder.expect_success();
+
+    let upgraded_version =
+        ContractVersionKey::new(ProtocolVersion::V1_0_0.value().major, UPGRADED_VERSION);
+    let upgraded_contract_hash = *builder
+        .get_contract_package(package_hash)
+        .expect("should have package")
+        .versions()
+        .get(&upgraded_version)
+        .expect("should have upgraded version");
+
+    call_contract_by_hash_at(
+        &mut builder,
+        upgraded_contract_hash,
+        ACTIVATION_BLOCK_TIME - 1,
+    );
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::PendingContractVersion(contract_hash))
+            if contract_hash == upgraded_contract_hash
+    );
+
+    call_contract_by_hash_from_session_at(
+        &mut builder,
+        upgraded_contract_hash,
+        ACTIVATION_BLOCK_TIME - 1,
+    );
+    assert_matches!(
+        last_exec_error(&builder),
+        engine_state::Error::Exec(execution::Error::PendingContractVersion(contract_hash))
+            if contract_hash == upgraded_contract_hash
+    );
+
+    call_contract_by_hash_at(&mut builder, upgraded_contract_hash, ACTIVATION_BLOCK_TIME);
+    builder.expect_success();
+}
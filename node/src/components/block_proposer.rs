@@ -3,21 +3,27 @@
 //! The block proposer stores deploy hashes in memory, tracking their suitability for inclusion into
 //! a new block. Upon request, it returns a list of candidates that can be included.
 
+mod config;
 mod deploy_sets;
 mod event;
 mod metrics;
+mod pending_deploy;
 
 #[cfg(test)]
 mod tests;
 
 use std::{
+    cmp::Reverse,
     collections::{HashMap, HashSet},
     convert::Infallible,
+    mem,
     time::Duration,
 };
 
 use datasize::DataSize;
 use prometheus::{self, Registry};
+use serde::Serialize;
+use thiserror::Error;
 use tracing::{debug, error, info, trace, warn};
 
 use crate::{
@@ -30,10 +36,13 @@ use crate::{
     NodeRng,
 };
 use casper_execution_engine::shared::gas::Gas;
+use casper_types::PublicKey;
+pub use config::Config;
 pub(crate) use deploy_sets::BlockProposerDeploySets;
 pub(crate) use event::{DeployType, Event};
 use metrics::BlockProposerMetrics;
 use num_traits::Zero;
+pub use pending_deploy::PendingDeploy;
 
 /// Block proposer component.
 #[derive(DataSize, Debug)]
@@ -53,6 +62,21 @@ const PRUNE_INTERVAL: Duration = Duration::from_secs(10);
 /// within a threshold to break iteration of `pending` early.
 const DEPLOY_APPROX_MIN_SIZE: usize = 300;
 
+/// In the first pass over the pending deploys, a single account can only use up to this fraction
+/// of a block's deploy and transfer slots, so that it can't crowd out everyone else under load.
+const ACCOUNT_SHARE_DIVISOR: usize = 4;
+
+/// A reason why a deploy is not admitted to the buffer of pending deploys.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq, Serialize)]
+pub enum AdmissionError {
+    /// The account already has the maximum number of pending deploys.
+    #[error("the account already has {0} pending deploys")]
+    AccountLimit(u32),
+    /// The buffer is full, and the deploy has a lower priority than all pending ones.
+    #[error("too many pending deploys; a higher gas price is required")]
+    BufferFull,
+}
+
 /// The type of values expressing the block height in the chain.
 type BlockHeight = u64;
 
@@ -78,6 +102,8 @@ enum BlockProposerState {
         state_key: Vec<u8>,
         /// The deploy config from the current chainspec.
         deploy_config: DeployConfig,
+        /// The limits for pending deploys.
+        config: Config,
     },
     /// Normal operation.
     Ready(BlockProposerReady),
@@ -90,6 +116,7 @@ impl BlockProposer {
         effect_builder: EffectBuilder<REv>,
         next_finalized_block: BlockHeight,
         chainspec: &Chainspec,
+        config: Config,
     ) -> Result<(Self, Effects<Event>), prometheus::Error>
     where
         REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
@@ -114,6 +141,7 @@ impl BlockProposer {
                 pending: Vec::new(),
                 state_key,
                 deploy_config: chainspec.deploy_config,
+                config,
             },
             metrics: BlockProposerMetrics::new(registry)?,
         };
@@ -146,6 +174,7 @@ where
                     ref mut pending,
                     state_key,
                     deploy_config,
+                    config,
                 },
                 Event::Loaded {
                     sets,
@@ -154,12 +183,16 @@ where
             ) => {
                 let mut new_ready_state = BlockProposerReady {
                     sets: sets
+                        .map(BlockProposerDeploySets::with_pending_index)
                         .unwrap_or_default()
                         .with_next_finalized(next_finalized_block),
                     unhandled_finalized: Default::default(),
                     deploy_config: *deploy_config,
+                    config: *config,
                     state_key: state_key.clone(),
                     request_queue: Default::default(),
+                    evicted: 0,
+                    rejected: 0,
                 };
 
                 // Replay postponed events onto new state.
@@ -192,7 +225,16 @@ where
                 // Update metrics after the effects have been applied.
                 self.metrics
                     .pending_deploys
-                    .set(ready_state.sets.pending.len() as i64);
+                    .set(ready_state.sets.pending().len() as i64);
+                self.metrics
+                    .pending_bytes
+                    .set(ready_state.sets.pending_bytes() as i64);
+                self.metrics
+                    .evicted_deploys
+                    .inc_by(mem::take(&mut ready_state.evicted) as i64);
+                self.metrics
+                    .rejected_deploys
+                    .inc_by(mem::take(&mut ready_state.rejected) as i64);
             }
         };
 
@@ -211,10 +253,16 @@ struct BlockProposerReady {
     unhandled_finalized: HashSet<DeployHash>,
     /// We don't need the whole Chainspec here, just the deploy config.
     deploy_config: DeployConfig,
+    /// The limits for pending deploys.
+    config: Config,
     /// Key for storing the block proposer state.
     state_key: Vec<u8>,
     /// The queue of requests awaiting being handled.
     request_queue: RequestQueue,
+    /// The number of deploys evicted from the buffer since the metrics were last updated.
+    evicted: u64,
+    /// The number of deploys not admitted to the buffer since the metrics were last updated.
+    rejected: u64,
 }
 
 impl BlockProposerReady {
@@ -252,6 +300,24 @@ impl BlockProposerReady {
                         .ignore()
                 }
             }
+            Event::Request(BlockProposerRequest::CheckAdmission {
+                deploy_type,
+                responder,
+            }) => {
+                let result = self.check_admission(&deploy_type);
+                if result.is_err() {
+                    self.rejected += 1;
+                }
+                responder.respond(result).ignore()
+            }
+            Event::Request(BlockProposerRequest::GetPendingDeploys {
+                account,
+                offset,
+                limit,
+                responder,
+            }) => responder
+                .respond(self.pending_deploys(account.as_ref(), offset, limit))
+                .ignore(),
             Event::BufferDeploy { hash, deploy_type } => {
                 self.add_deploy_or_transfer(Timestamp::now(), hash, *deploy_type);
                 Effects::new()
@@ -339,9 +405,92 @@ impl BlockProposerReady {
         // only add the deploy if it isn't contained in a finalized block
         if self.sets.finalized_deploys.contains_key(&hash) {
             info!(%hash, "deploy rejected from the buffer");
+        } else if let Err(err) = self.check_account_limit(&deploy_or_transfer) {
+            info!(%hash, %err, "deploy rejected from the buffer");
+            self.rejected += 1;
         } else {
-            self.sets.pending.insert(hash, deploy_or_transfer);
+            self.sets.insert_pending(hash, deploy_or_transfer);
             info!(%hash, "added deploy to the buffer");
+            self.evict();
+        }
+    }
+
+    /// Returns an error if the account already has the maximum number of pending deploys.
+    fn check_account_limit(&self, deploy_type: &DeployType) -> Result<(), AdmissionError> {
+        let limit = self.config.max_pending_deploys_per_account;
+        let account = deploy_type.header().account();
+        if self.sets.pending_count_for(account) >= limit as usize {
+            return Err(AdmissionError::AccountLimit(limit));
+        }
+        Ok(())
+    }
+
+    /// Returns whether the deploy would be added to the buffer: Either there is enough room for
+    /// it, or it has a higher priority than some pending deploy, which would then be evicted.
+    fn check_admission(&self, deploy_type: &DeployType) -> Result<(), AdmissionError> {
+        self.check_account_limit(deploy_type)?;
+        let fits = self.sets.pending().len() < self.config.max_pending_deploys as usize
+            && self.sets.pending_bytes() + deploy_type.size() as u64
+                <= self.config.max_pending_bytes;
+        let outranks_lowest = self
+            .sets
+            .lowest_priority_pending()
+            .map_or(false, |(_, lowest)| deploy_type.priority() > lowest);
+        if fits || outranks_lowest {
+            Ok(())
+        } else {
+            Err(AdmissionError::BufferFull)
+        }
+    }
+
+    /// Returns the total number of pending deploys from the given account, or from all accounts if
+    /// `None`, and the page of at most `limit` of them starting at `offset`, in order of priority.
+    fn pending_deploys(
+        &self,
+        account: Option<&PublicKey>,
+        offset: u32,
+        limit: u32,
+    ) -> (u64, Vec<PendingDeploy>) {
+        let mut matching: Vec<(&DeployHash, &DeployType)> = self
+            .sets
+            .pending()
+            .iter()
+            .filter(|(_, deploy_type)| {
+                account.map_or(true, |account| deploy_type.header().account() == account)
+            })
+            .collect();
+        matching.sort_by_key(|(hash, deploy_type)| (Reverse(deploy_type.priority()), **hash));
+        let total = matching.len() as u64;
+        let page = matching
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(hash, deploy_type)| {
+                let unresolved_dependencies = deploy_type
+                    .header()
+                    .dependencies()
+                    .iter()
+                    .filter(|dep| !self.sets.finalized_deploys.contains_key(dep))
+                    .copied()
+                    .collect();
+                PendingDeploy::new(*hash, deploy_type, unresolved_dependencies)
+            })
+            .collect();
+        (total, page)
+    }
+
+    /// Evicts the pending deploys with the lowest priority until the buffer is within its limits.
+    fn evict(&mut self) {
+        let max_count = self.config.max_pending_deploys as usize;
+        let max_bytes = self.config.max_pending_bytes;
+        while self.sets.pending().len() > max_count || self.sets.pending_bytes() > max_bytes {
+            let hash = match self.sets.lowest_priority_pending() {
+                Some((hash, _)) => hash,
+                None => break,
+            };
+            self.sets.remove_pending(&hash);
+            self.evicted += 1;
+            info!(%hash, "evicted deploy from the buffer");
         }
     }
 
@@ -351,7 +500,7 @@ impl BlockProposerReady {
         I: IntoIterator<Item = DeployHash>,
     {
         for deploy_hash in deploys.into_iter() {
-            match self.sets.pending.remove(&deploy_hash) {
+            match self.sets.remove_pending(&deploy_hash) {
                 Some(deploy_type) => {
                     self.sets
                         .finalized_deploys
@@ -417,6 +566,10 @@ impl BlockProposerReady {
     }
 
     /// Returns a list of candidates for inclusion into a block.
+    ///
+    /// Deploys with a higher priority are preferred, see `DeployType::priority`. In a first pass,
+    /// every account can only fill a share of the block; the remaining capacity is then filled
+    /// in a second pass without that restriction.
     fn propose_proto_block(
         &mut self,
         deploy_config: DeployConfig,
@@ -428,65 +581,91 @@ impl BlockProposerReady {
         let max_deploys = deploy_config.block_max_deploy_count as usize;
         let max_block_size_bytes = deploy_config.max_block_size as usize;
         let block_gas_limit = Gas::from(deploy_config.block_gas_limit);
+        let max_transfers_per_account = (max_transfers / ACCOUNT_SHARE_DIVISOR).max(1);
+        let max_deploys_per_account = (max_deploys / ACCOUNT_SHARE_DIVISOR).max(1);
+
+        let mut candidates: Vec<(&DeployHash, &DeployType)> = self.sets.pending().iter().collect();
+        candidates.sort_by_key(|(hash, deploy_type)| (Reverse(deploy_type.priority()), **hash));
 
         let mut transfers = Vec::new();
         let mut wasm_deploys = Vec::new();
+        let mut included = HashSet::new();
+        let mut transfers_per_account: HashMap<&PublicKey, usize> = HashMap::new();
+        let mut deploys_per_account: HashMap<&PublicKey, usize> = HashMap::new();
         let mut block_gas_running_total = Gas::zero();
         let mut block_size_running_total = 0usize;
 
-        for (hash, deploy_type) in self.sets.pending.iter() {
-            let at_max_transfers = transfers.len() == max_transfers;
-            let at_max_deploys = wasm_deploys.len() == max_deploys
-                || (deploy_type.is_wasm()
-                    && block_size_running_total + DEPLOY_APPROX_MIN_SIZE >= max_block_size_bytes);
-
-            if at_max_deploys && at_max_transfers {
-                break;
-            }
+        for &limit_per_account in &[true, false] {
+            for (hash, deploy_type) in &candidates {
+                let at_max_transfers = transfers.len() == max_transfers;
+                let at_max_deploys = wasm_deploys.len() == max_deploys
+                    || (deploy_type.is_wasm()
+                        && block_size_running_total + DEPLOY_APPROX_MIN_SIZE
+                            >= max_block_size_bytes);
 
-            if !self.is_deploy_valid(
-                &deploy_type.header(),
-                block_timestamp,
-                &deploy_config,
-                &past_deploys,
-            ) || past_deploys.contains(hash)
-                || self.sets.finalized_deploys.contains_key(hash)
-            {
-                continue;
-            }
+                if at_max_deploys && at_max_transfers {
+                    break;
+                }
 
-            // always include wasm-less transfers if we are under the max for them
-            if deploy_type.is_transfer() && !at_max_transfers {
-                transfers.push(*hash);
-            } else if deploy_type.is_wasm() && !at_max_deploys {
-                if block_size_running_total + deploy_type.size() > max_block_size_bytes {
+                if included.contains(*hash)
+                    || !self.is_deploy_valid(
+                        &deploy_type.header(),
+                        block_timestamp,
+                        &deploy_config,
+                        &past_deploys,
+                    )
+                    || past_deploys.contains(*hash)
+                    || self.sets.finalized_deploys.contains_key(*hash)
+                {
                     continue;
                 }
-                let payment_amount_gas = match Gas::from_motes(
-                    deploy_type.payment_amount(),
-                    deploy_type.header().gas_price(),
-                ) {
-                    Some(value) => value,
-                    None => {
-                        error!("payment_amount couldn't be converted from motes to gas");
+
+                let account = deploy_type.header().account();
+                // always include wasm-less transfers if we are under the max for them
+                if deploy_type.is_transfer() && !at_max_transfers {
+                    let account_count = transfers_per_account.entry(account).or_default();
+                    if limit_per_account && *account_count >= max_transfers_per_account {
                         continue;
                     }
-                };
-                let gas_running_total = if let Some(gas_running_total) =
-                    block_gas_running_total.checked_add(payment_amount_gas)
-                {
-                    gas_running_total
-                } else {
-                    warn!("block gas would overflow");
-                    continue;
-                };
+                    *account_count += 1;
+                    transfers.push(**hash);
+                    included.insert(**hash);
+                } else if deploy_type.is_wasm() && !at_max_deploys {
+                    let account_count = deploys_per_account.entry(account).or_default();
+                    if limit_per_account && *account_count >= max_deploys_per_account {
+                        continue;
+                    }
+                    if block_size_running_total + deploy_type.size() > max_block_size_bytes {
+                        continue;
+                    }
+                    let payment_amount_gas = match Gas::from_motes(
+                        deploy_type.payment_amount(),
+                        deploy_type.header().gas_price(),
+                    ) {
+                        Some(value) => value,
+                        None => {
+                            error!("payment_amount couldn't be converted from motes to gas");
+                            continue;
+                        }
+                    };
+                    let gas_running_total = if let Some(gas_running_total) =
+                        block_gas_running_total.checked_add(payment_amount_gas)
+                    {
+                        gas_running_total
+                    } else {
+                        warn!("block gas would overflow");
+                        continue;
+                    };
 
-                if gas_running_total > block_gas_limit {
-                    continue;
+                    if gas_running_total > block_gas_limit {
+                        continue;
+                    }
+                    *account_count += 1;
+                    wasm_deploys.push(**hash);
+                    included.insert(**hash);
+                    block_gas_running_total = gas_running_total;
+                    block_size_running_total += deploy_type.size();
                 }
-                wasm_deploys.push(*hash);
-                block_gas_running_total = gas_running_total;
-                block_size_running_total += deploy_type.size();
             }
         }
 
@@ -0,0 +1,32 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// Default maximum number of pending deploys.
+const DEFAULT_MAX_PENDING_DEPLOYS: u32 = 10_000;
+/// Default maximum total size of pending deploys, in bytes: 64 MiB.
+const DEFAULT_MAX_PENDING_BYTES: u64 = 64 * 1024 * 1024;
+/// Default maximum number of pending deploys per account.
+const DEFAULT_MAX_PENDING_DEPLOYS_PER_ACCOUNT: u32 = 100;
+
+/// Block proposer configuration.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The maximum number of deploys waiting to be included in a block.
+    pub max_pending_deploys: u32,
+    /// The maximum total size in bytes of all deploys waiting to be included in a block.
+    pub max_pending_bytes: u64,
+    /// The maximum number of deploys from a single account waiting to be included in a block.
+    pub max_pending_deploys_per_account: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_pending_deploys: DEFAULT_MAX_PENDING_DEPLOYS,
+            max_pending_bytes: DEFAULT_MAX_PENDING_BYTES,
+            max_pending_deploys_per_account: DEFAULT_MAX_PENDING_DEPLOYS_PER_ACCOUNT,
+        }
+    }
+}
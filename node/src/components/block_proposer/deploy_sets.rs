@@ -1,11 +1,14 @@
 use std::{
-    collections::HashMap,
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
     fmt::{self, Display, Formatter},
 };
 
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use casper_types::PublicKey;
+
 use super::{event::DeployType, BlockHeight, FinalizationQueue};
 use crate::types::{Chainspec, DeployHash, DeployHeader, Timestamp};
 
@@ -13,7 +16,10 @@ use crate::types::{Chainspec, DeployHash, DeployHeader, Timestamp};
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 pub struct BlockProposerDeploySets {
     /// The collection of deploys pending for inclusion in a block.
-    pub(super) pending: HashMap<DeployHash, DeployType>,
+    pending: HashMap<DeployHash, DeployType>,
+    /// Indices of the pending deploys, kept up to date as they are added and removed.
+    #[serde(skip)]
+    pending_index: PendingIndex,
     /// The deploys that have already been included in a finalized block.
     pub(super) finalized_deploys: HashMap<DeployHash, DeployHeader>,
     /// The next block height we expect to be finalized.
@@ -29,11 +35,13 @@ pub struct BlockProposerDeploySets {
 impl Default for BlockProposerDeploySets {
     fn default() -> Self {
         let pending = HashMap::new();
+        let pending_index = Default::default();
         let finalized_deploys = Default::default();
         let next_finalized = Default::default();
         let finalization_queue = Default::default();
         BlockProposerDeploySets {
             pending,
+            pending_index,
             finalized_deploys,
             next_finalized,
             finalization_queue,
@@ -48,6 +56,97 @@ impl BlockProposerDeploySets {
             ..self
         }
     }
+
+    /// Rebuilds the indices of the pending deploys, which aren't serialized.
+    pub(super) fn with_pending_index(mut self) -> Self {
+        let mut pending_index = PendingIndex::default();
+        for (hash, deploy_type) in &self.pending {
+            pending_index.add(*hash, deploy_type);
+        }
+        self.pending_index = pending_index;
+        self
+    }
+
+    /// Returns the deploys pending for inclusion in a block.
+    pub(super) fn pending(&self) -> &HashMap<DeployHash, DeployType> {
+        &self.pending
+    }
+
+    /// Adds a deploy pending for inclusion in a block.
+    pub(super) fn insert_pending(&mut self, hash: DeployHash, deploy_type: DeployType) {
+        self.pending_index.add(hash, &deploy_type);
+        if let Some(replaced) = self.pending.insert(hash, deploy_type) {
+            self.pending_index.remove(hash, &replaced);
+        }
+    }
+
+    /// Removes a pending deploy, returning it if it was pending.
+    pub(super) fn remove_pending(&mut self, hash: &DeployHash) -> Option<DeployType> {
+        let deploy_type = self.pending.remove(hash)?;
+        self.pending_index.remove(*hash, &deploy_type);
+        Some(deploy_type)
+    }
+
+    /// Returns the total size in bytes of all pending deploys.
+    pub(super) fn pending_bytes(&self) -> u64 {
+        self.pending_index.bytes
+    }
+
+    /// Returns the number of pending deploys from the given account.
+    pub(super) fn pending_count_for(&self, account: &PublicKey) -> usize {
+        self.pending_index
+            .counts
+            .get(account)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the hash and priority of the pending deploy with the lowest priority.
+    pub(super) fn lowest_priority_pending(
+        &self,
+    ) -> Option<(DeployHash, (u64, Reverse<Timestamp>))> {
+        self.pending_index
+            .by_priority
+            .iter()
+            .next()
+            .map(|(priority, hash)| (*hash, *priority))
+    }
+}
+
+/// Indices of the pending deploys, so that the buffer limits can be checked without iterating over
+/// all of them.
+#[derive(Clone, DataSize, Debug, Default)]
+struct PendingIndex {
+    /// The total size in bytes of all pending deploys.
+    bytes: u64,
+    /// The number of pending deploys from each account.
+    counts: HashMap<PublicKey, usize>,
+    /// The pending deploys in ascending order of priority.
+    #[data_size(skip)]
+    by_priority: BTreeSet<((u64, Reverse<Timestamp>), DeployHash)>,
+}
+
+impl PendingIndex {
+    fn add(&mut self, hash: DeployHash, deploy_type: &DeployType) {
+        self.bytes += deploy_type.size() as u64;
+        *self
+            .counts
+            .entry(*deploy_type.header().account())
+            .or_default() += 1;
+        self.by_priority.insert((deploy_type.priority(), hash));
+    }
+
+    fn remove(&mut self, hash: DeployHash, deploy_type: &DeployType) {
+        self.bytes -= deploy_type.size() as u64;
+        let account = deploy_type.header().account();
+        if let Some(count) = self.counts.get_mut(account) {
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(account);
+            }
+        }
+        self.by_priority.remove(&(deploy_type.priority(), hash));
+    }
 }
 
 impl Display for BlockProposerDeploySets {
@@ -77,7 +176,16 @@ impl BlockProposerDeploySets {
     /// Prunes expired deploy information from the BlockProposerState, returns the total deploys
     /// pruned
     pub(crate) fn prune(&mut self, current_instant: Timestamp) -> usize {
-        let pending = prune_pending_deploys(&mut self.pending, current_instant);
+        let expired: Vec<DeployHash> = self
+            .pending
+            .iter()
+            .filter(|(_, deploy_type)| deploy_type.header().expired(current_instant))
+            .map(|(hash, _)| *hash)
+            .collect();
+        for hash in &expired {
+            self.remove_pending(hash);
+        }
+        let pending = expired.len();
         let finalized = prune_deploys(&mut self.finalized_deploys, current_instant);
         pending + finalized
     }
@@ -93,14 +201,3 @@ pub(super) fn prune_deploys(
     deploys.retain(|_hash, header| !header.expired(current_instant));
     initial_len - deploys.len()
 }
-
-/// Prunes expired deploy information from an individual pending deploy collection, returns the
-/// total deploys pruned
-pub(super) fn prune_pending_deploys(
-    deploys: &mut HashMap<DeployHash, DeployType>,
-    current_instant: Timestamp,
-) -> usize {
-    let initial_len = deploys.len();
-    deploys.retain(|_hash, wrapper| !wrapper.header().expired(current_instant));
-    initial_len - deploys.len()
-}
@@ -1,4 +1,7 @@
-use std::fmt::{self, Formatter};
+use std::{
+    cmp::Reverse,
+    fmt::{self, Formatter},
+};
 
 use datasize::DataSize;
 use derive_more::From;
@@ -8,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use super::{BlockHeight, BlockProposerDeploySets};
 use crate::{
     effect::requests::BlockProposerRequest,
-    types::{DeployHash, DeployHeader, ProtoBlock},
+    types::{DeployHash, DeployHeader, ProtoBlock, Timestamp},
 };
 use casper_execution_engine::shared::motes::Motes;
 
@@ -71,6 +74,13 @@ impl DeployType {
     pub fn is_wasm(&self) -> bool {
         matches!(self, DeployType::Other { .. })
     }
+
+    /// Returns the priority for inclusion in a block: Deploys with a higher gas price have a higher
+    /// priority, and among deploys with the same gas price, older ones are preferred.
+    pub fn priority(&self) -> (u64, Reverse<Timestamp>) {
+        let header = self.header();
+        (header.gas_price(), Reverse(header.timestamp()))
+    }
 }
 
 /// An event for when using the block proposer as a component.
@@ -1,5 +1,5 @@
 use datasize::DataSize;
-use prometheus::{self, IntGauge, Registry};
+use prometheus::{self, IntCounter, IntGauge, Registry};
 
 /// Metrics for the block proposer.
 #[derive(DataSize, Debug, Clone)]
@@ -7,6 +7,15 @@ pub struct BlockProposerMetrics {
     /// Amount of pending deploys
     #[data_size(skip)]
     pub(super) pending_deploys: IntGauge,
+    /// Total size in bytes of pending deploys
+    #[data_size(skip)]
+    pub(super) pending_bytes: IntGauge,
+    /// Number of pending deploys evicted to make room for ones with a higher priority
+    #[data_size(skip)]
+    pub(super) evicted_deploys: IntCounter,
+    /// Number of deploys not admitted because of the buffer limits
+    #[data_size(skip)]
+    pub(super) rejected_deploys: IntCounter,
     /// Registry stored to allow deregistration later.
     #[data_size(skip)]
     registry: Registry,
@@ -16,9 +25,27 @@ impl BlockProposerMetrics {
     /// Creates a new instance of the block proposer metrics.
     pub fn new(registry: Registry) -> Result<Self, prometheus::Error> {
         let pending_deploys = IntGauge::new("pending_deploy", "amount of pending deploys")?;
+        let pending_bytes = IntGauge::new(
+            "pending_deploy_bytes",
+            "total size in bytes of pending deploys",
+        )?;
+        let evicted_deploys = IntCounter::new(
+            "evicted_deploys",
+            "number of pending deploys evicted to make room for ones with a higher priority",
+        )?;
+        let rejected_deploys = IntCounter::new(
+            "rejected_deploys",
+            "number of deploys not admitted because of the deploy buffer limits",
+        )?;
         registry.register(Box::new(pending_deploys.clone()))?;
+        registry.register(Box::new(pending_bytes.clone()))?;
+        registry.register(Box::new(evicted_deploys.clone()))?;
+        registry.register(Box::new(rejected_deploys.clone()))?;
         Ok(BlockProposerMetrics {
             pending_deploys,
+            pending_bytes,
+            evicted_deploys,
+            rejected_deploys,
             registry,
         })
     }
@@ -29,5 +56,14 @@ impl Drop for BlockProposerMetrics {
         self.registry
             .unregister(Box::new(self.pending_deploys.clone()))
             .expect("did not expect deregistering pending_deploys to fail");
+        self.registry
+            .unregister(Box::new(self.pending_bytes.clone()))
+            .expect("did not expect deregistering pending_bytes to fail");
+        self.registry
+            .unregister(Box::new(self.evicted_deploys.clone()))
+            .expect("did not expect deregistering evicted_deploys to fail");
+        self.registry
+            .unregister(Box::new(self.rejected_deploys.clone()))
+            .expect("did not expect deregistering rejected_deploys to fail");
     }
 }
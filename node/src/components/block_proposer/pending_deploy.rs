@@ -0,0 +1,78 @@
+//! A summary of a deploy waiting in the block proposer's buffer, for inspection via the RPC.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{PublicKey, U512};
+
+use super::DeployType;
+use crate::{
+    rpcs::docs::DocExample,
+    types::{Deploy, DeployHash, TimeDiff, Timestamp},
+};
+
+static PENDING_DEPLOY: Lazy<PendingDeploy> = Lazy::new(|| {
+    let deploy = Deploy::doc_example();
+    PendingDeploy {
+        deploy_hash: *deploy.id(),
+        account: *deploy.header().account(),
+        timestamp: deploy.header().timestamp(),
+        ttl: deploy.header().ttl(),
+        size: 312,
+        payment_amount: U512::from(1_000),
+        is_transfer: true,
+        unresolved_dependencies: deploy.header().dependencies().clone(),
+    }
+});
+
+/// A deploy waiting to be included in a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PendingDeploy {
+    /// The deploy's hash.
+    pub deploy_hash: DeployHash,
+    /// The account within which the deploy will be run.
+    pub account: PublicKey,
+    /// When the deploy was created.
+    pub timestamp: Timestamp,
+    /// How long the deploy stays valid.
+    pub ttl: TimeDiff,
+    /// The deploy's serialized size in bytes.
+    pub size: u64,
+    /// The amount paid for the deploy's execution, in motes.
+    pub payment_amount: U512,
+    /// Whether the deploy is a wasm-less transfer.
+    pub is_transfer: bool,
+    /// The dependencies that have not been included in a finalized block yet.
+    pub unresolved_dependencies: Vec<DeployHash>,
+}
+
+impl PendingDeploy {
+    pub(super) fn new(
+        deploy_hash: DeployHash,
+        deploy_type: &DeployType,
+        unresolved_dependencies: Vec<DeployHash>,
+    ) -> Self {
+        let header = deploy_type.header();
+        PendingDeploy {
+            deploy_hash,
+            account: *header.account(),
+            timestamp: header.timestamp(),
+            ttl: header.ttl(),
+            size: deploy_type.size() as u64,
+            payment_amount: deploy_type.payment_amount().value(),
+            is_transfer: deploy_type.is_transfer(),
+            unresolved_dependencies,
+        }
+    }
+}
+
+impl DocExample for PendingDeploy {
+    fn doc_example() -> &'static Self {
+        &*PENDING_DEPLOY
+    }
+}
@@ -48,6 +48,7 @@ fn generate_transfer(
         gas_price,
         dependencies,
         chain_name,
+        None,
         payment,
         session,
         &secret_key,
@@ -64,6 +65,26 @@ fn generate_deploy(
     gas_price: u64,
 ) -> Deploy {
     let secret_key = SecretKey::random(rng);
+    generate_deploy_signed_by(
+        rng,
+        &secret_key,
+        timestamp,
+        ttl,
+        dependencies,
+        payment_amount,
+        gas_price,
+    )
+}
+
+fn generate_deploy_signed_by(
+    rng: &mut TestRng,
+    secret_key: &SecretKey,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    dependencies: Vec<DeployHash>,
+    payment_amount: Gas,
+    gas_price: u64,
+) -> Deploy {
     let chain_name = "chain".to_string();
     let args = runtime_args! {
         ARG_AMOUNT => payment_amount.value()
@@ -83,9 +104,10 @@ fn generate_deploy(
         gas_price,
         dependencies,
         chain_name,
+        None,
         payment,
         session,
-        &secret_key,
+        secret_key,
         rng,
     )
 }
@@ -94,9 +116,12 @@ fn create_test_proposer() -> BlockProposerReady {
     BlockProposerReady {
         sets: Default::default(),
         deploy_config: Default::default(),
+        config: Default::default(),
         state_key: b"block-proposer-test".to_vec(),
         request_queue: Default::default(),
         unhandled_finalized: Default::default(),
+        evicted: 0,
+        rejected: 0,
     }
 }
 
@@ -297,14 +322,14 @@ fn should_successfully_prune() {
     // pending => finalized
     proposer.finalized_deploys(vec![*deploy1.id()]);
 
-    assert_eq!(proposer.sets.pending.len(), 3);
+    assert_eq!(proposer.sets.pending().len(), 3);
     assert!(proposer.sets.finalized_deploys.contains_key(deploy1.id()));
 
     // test for retained values
     let pruned = proposer.prune(test_time);
     assert_eq!(pruned, 0);
 
-    assert_eq!(proposer.sets.pending.len(), 3);
+    assert_eq!(proposer.sets.pending().len(), 3);
     assert_eq!(proposer.sets.finalized_deploys.len(), 1);
     assert!(proposer.sets.finalized_deploys.contains_key(&deploy1.id()));
 
@@ -312,7 +337,7 @@ fn should_successfully_prune() {
     let pruned = proposer.prune(expired_time);
     assert_eq!(pruned, 3);
 
-    assert_eq!(proposer.sets.pending.len(), 1); // deploy4 is still valid
+    assert_eq!(proposer.sets.pending().len(), 1); // deploy4 is still valid
     assert_eq!(proposer.sets.finalized_deploys.len(), 0);
 }
 
@@ -592,11 +617,11 @@ fn test_proposer_with(
         all_deploys.len()
     );
     assert_eq!(
-        proposer.sets.pending.len(),
+        proposer.sets.pending().len(),
         remaining_pending_count,
         "should have a remaining_pending_count of {}, but got {}",
         remaining_pending_count,
-        proposer.sets.pending.len()
+        proposer.sets.pending().len()
     );
     proposer
 }
@@ -670,3 +695,378 @@ fn should_return_deploy_dependencies() {
     assert_eq!(deploys2.len(), 1);
     assert!(deploys2.contains(deploy2.id()));
 }
+
+/// Adds the deploys to the proposer and returns the wasm deploys in the proposed block.
+fn propose_wasm_deploys(
+    proposer: &mut BlockProposerReady,
+    deploys: &[Deploy],
+    max_deploy_count: u32,
+) -> Vec<DeployHash> {
+    let creation_time = Timestamp::from(100);
+    let block_time = Timestamp::from(120);
+    for deploy in deploys {
+        proposer.add_deploy_or_transfer(creation_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+    let config = DeployConfig {
+        block_max_deploy_count: max_deploy_count,
+        block_gas_limit: 1_000,
+        ..Default::default()
+    };
+    let block = proposer.propose_proto_block(config, block_time, HashSet::new(), true);
+    block.wasm_deploys().clone()
+}
+
+#[test]
+fn should_prefer_higher_gas_price_under_congestion() {
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    let deploys: Vec<Deploy> = (1..=10)
+        .map(|gas_price| {
+            let timestamp = Timestamp::from(100 - gas_price);
+            generate_deploy(
+                &mut rng,
+                timestamp,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                gas_price,
+            )
+        })
+        .collect();
+
+    let proposed = propose_wasm_deploys(&mut proposer, &deploys, 3);
+    // The most expensive deploys are proposed first, even though they are the newest.
+    let expected: Vec<DeployHash> = deploys.iter().rev().take(3).map(|d| *d.id()).collect();
+    assert_eq!(expected, proposed);
+}
+
+#[test]
+fn should_prefer_older_deploys_with_same_gas_price() {
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    let deploys: Vec<Deploy> = (0..5)
+        .map(|age| {
+            let timestamp = Timestamp::from(100 - age);
+            generate_deploy(
+                &mut rng,
+                timestamp,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                DEFAULT_TEST_GAS_PRICE,
+            )
+        })
+        .collect();
+
+    let proposed = propose_wasm_deploys(&mut proposer, &deploys, 2);
+    let expected: Vec<DeployHash> = deploys.iter().rev().take(2).map(|d| *d.id()).collect();
+    assert_eq!(expected, proposed);
+}
+
+#[test]
+fn should_limit_deploys_per_account_under_congestion() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    let max_deploy_count = 8;
+
+    // One account offers a higher gas price than everyone else, for more deploys than fit.
+    let secret_key = SecretKey::random(&mut rng);
+    let mut deploys: Vec<Deploy> = (0..max_deploy_count)
+        .map(|_| {
+            generate_deploy_signed_by(
+                &mut rng,
+                &secret_key,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                10,
+            )
+        })
+        .collect();
+    let greedy_deploys: HashSet<DeployHash> = deploys.iter().map(|d| *d.id()).collect();
+    for _ in 0..max_deploy_count {
+        deploys.push(generate_deploy(
+            &mut rng,
+            creation_time,
+            ttl,
+            vec![],
+            default_gas_payment(),
+            DEFAULT_TEST_GAS_PRICE,
+        ));
+    }
+
+    let proposed = propose_wasm_deploys(&mut proposer, &deploys, max_deploy_count);
+    assert_eq!(max_deploy_count as usize, proposed.len());
+    let greedy_count = proposed
+        .iter()
+        .filter(|hash| greedy_deploys.contains(*hash))
+        .count();
+    assert_eq!(
+        max_deploy_count as usize / ACCOUNT_SHARE_DIVISOR,
+        greedy_count
+    );
+}
+
+#[test]
+fn should_fill_block_from_single_account_without_congestion() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    let max_deploy_count = 8;
+
+    let secret_key = SecretKey::random(&mut rng);
+    let deploys: Vec<Deploy> = (0..max_deploy_count)
+        .map(|_| {
+            generate_deploy_signed_by(
+                &mut rng,
+                &secret_key,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                DEFAULT_TEST_GAS_PRICE,
+            )
+        })
+        .collect();
+
+    // If no one else wants to use the block, the account's deploys fill it in the second pass.
+    let proposed = propose_wasm_deploys(&mut proposer, &deploys, max_deploy_count);
+    assert_eq!(max_deploy_count as usize, proposed.len());
+}
+
+/// Returns a wasm deploy from a new account with the given gas price.
+fn generate_deploy_with_gas_price(rng: &mut TestRng, gas_price: u64) -> Deploy {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    generate_deploy(
+        rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        gas_price,
+    )
+}
+
+#[test]
+fn should_evict_lowest_priority_deploys_when_full() {
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_pending_deploys = 3;
+
+    let deploys: Vec<Deploy> = (1..=5)
+        .map(|gas_price| generate_deploy_with_gas_price(&mut rng, gas_price))
+        .collect();
+    for deploy in &deploys {
+        let deploy_type = deploy.deploy_type().unwrap();
+        proposer.add_deploy_or_transfer(Timestamp::from(100), *deploy.id(), deploy_type);
+    }
+
+    assert_eq!(3, proposer.sets.pending().len());
+    assert_eq!(2, proposer.evicted);
+    for deploy in &deploys[2..] {
+        assert!(proposer.sets.pending().contains_key(deploy.id()));
+    }
+}
+
+#[test]
+fn should_evict_deploys_when_over_byte_limit() {
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    let deploys: Vec<Deploy> = (1..=3)
+        .map(|gas_price| generate_deploy_with_gas_price(&mut rng, gas_price))
+        .collect();
+    let size = deploys[0].deploy_type().unwrap().size() as u64;
+    // All test deploys have the same size, so there is only room for two of them.
+    proposer.config.max_pending_bytes = 2 * size;
+    for deploy in &deploys {
+        let deploy_type = deploy.deploy_type().unwrap();
+        proposer.add_deploy_or_transfer(Timestamp::from(100), *deploy.id(), deploy_type);
+    }
+
+    assert_eq!(2 * size, proposer.sets.pending_bytes());
+    assert_eq!(1, proposer.evicted);
+    assert!(!proposer.sets.pending().contains_key(deploys[0].id()));
+}
+
+#[test]
+fn should_limit_pending_deploys_per_account() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_pending_deploys_per_account = 2;
+
+    let secret_key = SecretKey::random(&mut rng);
+    let deploys: Vec<Deploy> = (0..3)
+        .map(|_| {
+            generate_deploy_signed_by(
+                &mut rng,
+                &secret_key,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                DEFAULT_TEST_GAS_PRICE,
+            )
+        })
+        .collect();
+    for deploy in &deploys[..2] {
+        let deploy_type = deploy.deploy_type().unwrap();
+        proposer.add_deploy_or_transfer(creation_time, *deploy.id(), deploy_type);
+    }
+
+    let deploy_type = deploys[2].deploy_type().unwrap();
+    assert_eq!(
+        Err(AdmissionError::AccountLimit(2)),
+        proposer.check_admission(&deploy_type)
+    );
+    proposer.add_deploy_or_transfer(creation_time, *deploys[2].id(), deploy_type);
+    assert_eq!(2, proposer.sets.pending().len());
+    assert_eq!(1, proposer.rejected);
+
+    // Other accounts are not affected.
+    let other = generate_deploy_with_gas_price(&mut rng, DEFAULT_TEST_GAS_PRICE);
+    assert_eq!(
+        Ok(()),
+        proposer.check_admission(&other.deploy_type().unwrap())
+    );
+}
+
+#[test]
+fn should_only_admit_higher_priority_deploys_when_full() {
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_pending_deploys = 2;
+
+    for _ in 0..2 {
+        let deploy = generate_deploy_with_gas_price(&mut rng, 5);
+        let deploy_type = deploy.deploy_type().unwrap();
+        assert_eq!(Ok(()), proposer.check_admission(&deploy_type));
+        proposer.add_deploy_or_transfer(Timestamp::from(100), *deploy.id(), deploy_type);
+    }
+
+    let cheap = generate_deploy_with_gas_price(&mut rng, 1);
+    assert_eq!(
+        Err(AdmissionError::BufferFull),
+        proposer.check_admission(&cheap.deploy_type().unwrap())
+    );
+    let expensive = generate_deploy_with_gas_price(&mut rng, 10);
+    assert_eq!(
+        Ok(()),
+        proposer.check_admission(&expensive.deploy_type().unwrap())
+    );
+}
+
+#[test]
+fn should_list_pending_deploys_by_priority() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    // One dependency has been finalized, the other one is unknown.
+    let finalized = generate_deploy_with_gas_price(&mut rng, 1);
+    let unknown = *generate_deploy_with_gas_price(&mut rng, 1).id();
+    let deploy_type = finalized.deploy_type().unwrap();
+    proposer.add_deploy_or_transfer(creation_time, *finalized.id(), deploy_type);
+    proposer.finalized_deploys(vec![*finalized.id()]);
+
+    let secret_key = SecretKey::random(&mut rng);
+    let account = PublicKey::from(&secret_key);
+    let deploys: Vec<Deploy> = (1..=3)
+        .map(|gas_price| {
+            generate_deploy_signed_by(
+                &mut rng,
+                &secret_key,
+                creation_time,
+                ttl,
+                vec![*finalized.id(), unknown],
+                default_gas_payment(),
+                gas_price,
+            )
+        })
+        .collect();
+    let other = generate_deploy_with_gas_price(&mut rng, 10);
+    for deploy in deploys.iter().chain(Some(&other)) {
+        let deploy_type = deploy.deploy_type().unwrap();
+        proposer.add_deploy_or_transfer(creation_time, *deploy.id(), deploy_type);
+    }
+
+    let (total, all) = proposer.pending_deploys(None, 0, 10);
+    assert_eq!(4, total);
+    assert_eq!(*other.id(), all[0].deploy_hash);
+
+    let (total, page) = proposer.pending_deploys(Some(&account), 1, 1);
+    assert_eq!(3, total);
+    assert_eq!(1, page.len());
+    assert_eq!(*deploys[1].id(), page[0].deploy_hash);
+    assert_eq!(account, page[0].account);
+    assert_eq!(vec![unknown], page[0].unresolved_dependencies);
+}
+
+#[test]
+fn should_keep_pending_index_up_to_date() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    let secret_key = SecretKey::random(&mut rng);
+    let account = PublicKey::from(&secret_key);
+    let deploys: Vec<Deploy> = (1..=3)
+        .map(|gas_price| {
+            generate_deploy_signed_by(
+                &mut rng,
+                &secret_key,
+                creation_time,
+                ttl,
+                vec![],
+                default_gas_payment(),
+                gas_price,
+            )
+        })
+        .collect();
+    let size = deploys[0].deploy_type().unwrap().size() as u64;
+    for deploy in &deploys {
+        let deploy_type = deploy.deploy_type().unwrap();
+        proposer.add_deploy_or_transfer(creation_time, *deploy.id(), deploy_type);
+    }
+    assert_eq!(3, proposer.sets.pending_count_for(&account));
+    assert_eq!(3 * size, proposer.sets.pending_bytes());
+
+    proposer.finalized_deploys(vec![*deploys[0].id()]);
+    assert_eq!(2, proposer.sets.pending_count_for(&account));
+    assert_eq!(2 * size, proposer.sets.pending_bytes());
+    assert_eq!(
+        Some(*deploys[1].id()),
+        proposer
+            .sets
+            .lowest_priority_pending()
+            .map(|(hash, _)| hash)
+    );
+
+    // The index isn't stored, but is rebuilt when the sets are loaded.
+    let serialized = bincode::serialize(&proposer.sets).unwrap();
+    let loaded: BlockProposerDeploySets = bincode::deserialize(&serialized).unwrap();
+    let loaded = loaded.with_pending_index();
+    assert_eq!(2, loaded.pending_count_for(&account));
+    assert_eq!(2 * size, loaded.pending_bytes());
+    assert_eq!(
+        proposer.sets.lowest_priority_pending(),
+        loaded.lowest_priority_pending()
+    );
+
+    // Both pending deploys and the finalized one have expired.
+    assert_eq!(3, proposer.prune(Timestamp::from(300)));
+    assert_eq!(0, proposer.sets.pending_count_for(&account));
+    assert_eq!(0, proposer.sets.pending_bytes());
+    assert_eq!(None, proposer.sets.lowest_priority_pending());
+}
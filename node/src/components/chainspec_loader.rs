@@ -481,6 +481,9 @@ impl ChainspecLoader {
             Some(self.chainspec.core_config.locked_funds_period.millis()),
             Some(self.chainspec.core_config.round_seigniorage_rate),
             Some(self.chainspec.core_config.unbonding_delay),
+            Some(self.chainspec.core_config.slashing_rate),
+            Some(self.chainspec.core_config.delegation_slashing_rate),
+            Some(self.chainspec.core_config.jail_period),
             global_state_update,
         ))
     }
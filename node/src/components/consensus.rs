@@ -7,11 +7,13 @@ mod consensus_protocol;
 mod era_supervisor;
 #[macro_use]
 mod highway_core;
+mod json_evidence;
 mod metrics;
 mod protocols;
 #[cfg(test)]
 mod tests;
 mod traits;
+mod validator_status;
 
 use std::{
     collections::{BTreeMap, HashMap},
@@ -48,8 +50,10 @@ use crate::{
 pub use config::Config;
 pub(crate) use consensus_protocol::{BlockContext, EraReport};
 pub(crate) use era_supervisor::{EraId, EraSupervisor};
-pub(crate) use protocols::highway::HighwayProtocol;
+pub use json_evidence::{EvidenceVerificationError, JsonEvidence, JsonObservation, JsonUnit};
+pub(crate) use protocols::{highway::HighwayProtocol, new_boxed_consensus};
 use traits::NodeIdT;
+pub use validator_status::ValidatorStatus;
 
 #[derive(DataSize, Clone, Serialize, Deserialize)]
 pub enum ConsensusMessage {
@@ -360,6 +364,12 @@ where
             Event::ConsensusRequest(ConsensusRequest::Status(responder)) => {
                 handling_es.status(responder)
             }
+            Event::ConsensusRequest(ConsensusRequest::GetEvidence(era_id, pk, responder)) => {
+                handling_es.get_evidence(era_id, pk, responder)
+            }
+            Event::ConsensusRequest(ConsensusRequest::ValidatorStatus(responder)) => {
+                handling_es.validator_status(responder)
+            }
         }
     }
 }
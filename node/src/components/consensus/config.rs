@@ -9,7 +9,10 @@ use casper_types::SecretKey;
 use crate::{
     components::consensus::EraId,
     crypto::hash::Digest,
-    types::{chainspec::HighwayConfig, Chainspec, TimeDiff, Timestamp},
+    types::{
+        chainspec::{ConsensusProtocolName, HighwayConfig},
+        Chainspec, TimeDiff, Timestamp,
+    },
     utils::External,
 };
 
@@ -20,7 +23,12 @@ use crate::{
 pub struct Config {
     /// Path to secret key file.
     pub secret_key_path: External<SecretKey>,
-    /// Path to the folder where unit hash files will be stored.
+    /// Path to the secret key file of the public key the validator's bid is being moved to, if
+    /// any. It is used instead of the secret key above in eras in which only the new public key is
+    /// a validator.
+    pub next_secret_key_path: Option<External<SecretKey>>,
+    /// Path to the folder where unit hash files and the write-ahead logs of the protocol state
+    /// will be stored.
     pub unit_hashes_folder: PathBuf,
     /// The duration for which incoming vertices with missing dependencies are kept in a queue.
     pub pending_vertex_timeout: TimeDiff,
@@ -33,6 +41,7 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             secret_key_path: External::Missing,
+            next_secret_key_path: None,
             unit_hashes_folder: Default::default(),
             pending_vertex_timeout: "10sec".parse().unwrap(),
             max_execution_delay: 3,
@@ -43,6 +52,8 @@ impl Default for Config {
 /// Consensus protocol configuration.
 #[derive(DataSize, Debug)]
 pub(crate) struct ProtocolConfig {
+    /// The consensus protocol to use in each era from `last_activation_point` on.
+    pub(crate) consensus_protocol: ConsensusProtocolName,
     pub(crate) highway_config: HighwayConfig,
     pub(crate) era_duration: TimeDiff,
     pub(crate) minimum_era_height: u64,
@@ -67,6 +78,7 @@ pub(crate) struct ProtocolConfig {
 impl From<&Chainspec> for ProtocolConfig {
     fn from(chainspec: &Chainspec) -> Self {
         ProtocolConfig {
+            consensus_protocol: chainspec.core_config.consensus_protocol,
             highway_config: chainspec.highway_config,
             era_duration: chainspec.core_config.era_duration,
             minimum_era_height: chainspec.core_config.minimum_era_height,
@@ -7,12 +7,15 @@
 
 mod era;
 mod era_id;
+#[cfg(test)]
+mod tests;
 
 use std::{
-    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     convert::TryInto,
     fmt::{self, Debug, Formatter},
-    path::PathBuf,
+    fs, io,
+    path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
 };
@@ -32,26 +35,30 @@ use tracing::{debug, error, info, trace, warn};
 use casper_types::{AsymmetricType, PublicKey, SecretKey, U512};
 
 use crate::{
-    components::consensus::{
-        candidate_block::CandidateBlock,
-        cl_context::{ClContext, Keypair},
-        config::ProtocolConfig,
-        consensus_protocol::{
-            BlockContext, ConsensusProtocol, EraReport, FinalizedBlock as CpFinalizedBlock,
-            ProtocolOutcome,
+    components::{
+        consensus::{
+            candidate_block::CandidateBlock,
+            cl_context::{ClContext, Keypair},
+            config::ProtocolConfig,
+            consensus_protocol::{
+                BlockContext, ConsensusProtocol, EraReport, FinalizedBlock as CpFinalizedBlock,
+                ProtocolOutcome,
+            },
+            metrics::ConsensusMetrics,
+            traits::NodeIdT,
+            ActionId, Config, ConsensusMessage, Event, HighwayProtocol, JsonEvidence,
+            ReactorEventT, TimerId, ValidatorStatus,
         },
-        metrics::ConsensusMetrics,
-        traits::NodeIdT,
-        ActionId, Config, ConsensusMessage, Event, ReactorEventT, TimerId,
+        small_network::ValidatorProof,
     },
     crypto::hash::Digest,
     effect::{requests::ConsensusRequest, EffectBuilder, EffectExt, Effects, Responder},
     fatal,
     types::{
         ActivationPoint, Block, BlockHash, BlockHeader, BlockLike, FinalitySignature,
-        FinalizedBlock, ProtoBlock, TimeDiff, Timestamp,
+        FinalizedBlock, NodeId, ProtoBlock, TimeDiff, Timestamp,
     },
-    utils::WithDir,
+    utils::{External, WithDir},
     NodeRng,
 };
 
@@ -71,6 +78,8 @@ type ConsensusConstructor<I> = dyn Fn(
     Timestamp,                                    // start time for this era
     u64,                                          // random seed
     Timestamp,                                    // now timestamp
+    Option<PathBuf>,                              // the write-ahead log of the protocol state
+    &mut NodeRng,                                 // random number generator
 ) -> (
     Box<dyn ConsensusProtocol<I, ClContext>>,
     Vec<ProtocolOutcome<I, ClContext>>,
@@ -86,6 +95,9 @@ pub struct EraSupervisor<I> {
     active_eras: HashMap<EraId, Era<I>>,
     secret_signing_key: Arc<SecretKey>,
     pub(super) public_signing_key: PublicKey,
+    /// The signing key of the public key our bid is being moved to, if any. It is used in eras in
+    /// which it is a validator, but the current signing key is not.
+    next_signing_key: Option<(Arc<SecretKey>, PublicKey)>,
     current_era: EraId,
     protocol_config: ProtocolConfig,
     config: Config,
@@ -111,7 +123,7 @@ pub struct EraSupervisor<I> {
     metrics: ConsensusMetrics,
     // TODO: discuss this quick fix
     finished_joining: bool,
-    /// The path to the folder where unit hash files will be stored.
+    /// The path to the folder where unit hash files and write-ahead logs will be stored.
     unit_hashes_folder: PathBuf,
     /// The next upgrade activation point. When the era immediately before the activation point is
     /// deactivated, the era supervisor indicates that the node should stop running to allow an
@@ -160,9 +172,10 @@ where
         }
         let unit_hashes_folder = config.with_dir(config.value().unit_hashes_folder.clone());
         let (root, config) = config.into_parts();
-        let secret_signing_key = Arc::new(config.secret_key_path.clone().load(root)?);
+        let secret_signing_key = Arc::new(config.secret_key_path.clone().load(&root)?);
         let public_signing_key = PublicKey::from(secret_signing_key.as_ref());
         info!(our_id = %public_signing_key, "EraSupervisor pubkey",);
+        let next_signing_key = load_next_signing_key(&root, config.next_secret_key_path.clone())?;
         let bonded_eras: u64 = protocol_config.unbonding_delay - protocol_config.auction_delay;
         let metrics = ConsensusMetrics::new(registry)
             .expect("failure to setup and register ConsensusMetrics");
@@ -172,6 +185,7 @@ where
             active_eras: Default::default(),
             secret_signing_key,
             public_signing_key,
+            next_signing_key,
             current_era,
             protocol_config,
             config,
@@ -286,6 +300,7 @@ where
         seed: u64,
         start_time: Timestamp,
         start_height: u64,
+        rng: &mut NodeRng,
     ) -> Vec<ProtocolOutcome<I, ClContext>> {
         if self.active_eras.contains_key(&era_id) {
             panic!("{} already exists", era_id);
@@ -306,7 +321,7 @@ where
 
         // Activate the era if this node was already running when the era began, it is still
         // ongoing based on its minimum duration, and we are one of the validators.
-        let our_id = self.public_signing_key;
+        let (our_secret, our_id) = self.signing_key_for(&validators);
         let should_activate = if !validators.contains_key(&our_id) {
             info!(era = era_id.0, %our_id, "not voting; not a validator");
             false
@@ -332,14 +347,16 @@ where
             start_time,
             seed,
             timestamp,
+            Some(self.write_ahead_log_path(instance_id)),
+            rng,
         );
 
         if should_activate {
-            let secret = Keypair::new(self.secret_signing_key.clone(), our_id);
+            let secret = Keypair::new(our_secret, our_id);
             let unit_hash_file = self.unit_hashes_folder.join(format!(
                 "unit_hash_{:?}_{}.dat",
                 instance_id,
-                self.public_signing_key.to_hex()
+                our_id.to_hex()
             ));
             outcomes.extend(consensus.activate_validator(
                 our_id,
@@ -363,7 +380,14 @@ where
         // the oldest bonded era could still receive blocks that refer to bonded_eras before that.
         if let Some(obsolete_era_id) = era_id.checked_sub(2 * self.bonded_eras + 1) {
             trace!(era = obsolete_era_id.0, "removing obsolete era");
-            self.active_eras.remove(&obsolete_era_id);
+            if let Some(era) = self.active_eras.remove(&obsolete_era_id) {
+                let wal_path = self.write_ahead_log_path(*era.consensus.instance_id());
+                if let Err(err) = fs::remove_file(&wal_path) {
+                    if err.kind() != io::ErrorKind::NotFound {
+                        warn!(?wal_path, %err, "failed to remove obsolete write-ahead log");
+                    }
+                }
+            }
         }
         // Clear the obsolete data from the era whose validators are unbonded now. We only retain
         // the information necessary to validate evidence that units in still-bonded eras may refer
@@ -378,11 +402,42 @@ where
         outcomes
     }
 
+    /// Returns the path to the write-ahead log of the protocol state with the given instance ID.
+    fn write_ahead_log_path(&self, instance_id: Digest) -> PathBuf {
+        self.unit_hashes_folder
+            .join(format!("protocol_state_{:?}.wal", instance_id))
+    }
+
     /// Returns `true` if the specified era is active and bonded.
     fn is_bonded(&self, era_id: EraId) -> bool {
         era_id.0 + self.bonded_eras >= self.current_era.0 && era_id <= self.current_era
     }
 
+    /// Returns the signing key pair we use in an era with the given validators: the next signing
+    /// key if it is a validator there and the current one is not, otherwise the current one.
+    fn signing_key_for(
+        &self,
+        validators: &BTreeMap<PublicKey, U512>,
+    ) -> (Arc<SecretKey>, PublicKey) {
+        match &self.next_signing_key {
+            Some((next_secret_key, next_public_key))
+                if !validators.contains_key(&self.public_signing_key)
+                    && validators.contains_key(next_public_key) =>
+            {
+                (next_secret_key.clone(), *next_public_key)
+            }
+            _ => (self.secret_signing_key.clone(), self.public_signing_key),
+        }
+    }
+
+    /// Returns the signing key pair we use in the given era.
+    fn era_signing_key(&self, era_id: EraId) -> (Arc<SecretKey>, PublicKey) {
+        match self.active_eras.get(&era_id) {
+            Some(era) => self.signing_key_for(era.validators()),
+            None => (self.secret_signing_key.clone(), self.public_signing_key),
+        }
+    }
+
     /// Returns whether the validator with the given public key is bonded in that era.
     fn is_validator_in(&self, pub_key: &PublicKey, era_id: EraId) -> bool {
         let has_validator = |era: &Era<I>| era.validators().contains_key(&pub_key);
@@ -401,8 +456,8 @@ where
         now: Timestamp,
     ) -> Vec<ProtocolOutcome<I, ClContext>> {
         self.finished_joining = true;
-        let secret = Keypair::new(self.secret_signing_key.clone(), self.public_signing_key);
-        let public_key = self.public_signing_key;
+        let (secret_key, public_key) = self.era_signing_key(self.current_era);
+        let secret = Keypair::new(secret_key, public_key);
         let unit_hashes_folder = self.unit_hashes_folder.clone();
         self.active_eras
             .get_mut(&self.current_era)
@@ -463,6 +518,31 @@ where
     pub(crate) fn is_initialized(&self) -> bool {
         self.is_initialized
     }
+
+    /// Returns the validators of the current era, if it has been initialized already.
+    pub(crate) fn current_era_validators(&self) -> Option<BTreeSet<PublicKey>> {
+        self.active_eras
+            .get(&self.current_era)
+            .map(|era| era.validators().keys().cloned().collect())
+    }
+
+    /// Returns our validator's status in the given era, if we are bonded in it.
+    fn validator_status(&self, era_id: EraId) -> Option<ValidatorStatus> {
+        let (_, public_key) = self.era_signing_key(era_id);
+        self.active_eras
+            .get(&era_id)?
+            .consensus
+            .as_any()
+            .downcast_ref::<HighwayProtocol<I, ClContext>>()?
+            .validator_status(era_id, &public_key)
+    }
+
+    /// Returns a proof that the node with the given network ID is operated by the owner of our
+    /// signing key, to be presented to other validators in the network handshake.
+    pub(crate) fn validator_proof(&self, node_id: &NodeId, rng: &mut NodeRng) -> ValidatorProof {
+        let (secret_key, public_key) = self.era_signing_key(self.current_era);
+        ValidatorProof::new(node_id, &secret_key, public_key, rng)
+    }
 }
 
 /// A mutable `EraSupervisor` reference, together with an `EffectBuilder`.
@@ -600,9 +680,8 @@ where
                 ));
             return Effects::new();
         }
-        let our_pk = self.era_supervisor.public_signing_key;
-        let our_sk = self.era_supervisor.secret_signing_key.clone();
         let era_id = block.header().era_id();
+        let (our_sk, our_pk) = self.era_supervisor.era_signing_key(era_id);
         self.era_supervisor.executed_block(block.header());
         let maybe_fin_sig = if self.era_supervisor.is_validator_in(&our_pk, era_id) {
             let block_hash = block.hash();
@@ -743,6 +822,7 @@ where
                 seed,
                 era_start_time,
                 start_height,
+                self.rng,
             );
 
             effects.extend(
@@ -758,6 +838,7 @@ where
         self.era_supervisor.is_initialized = true;
         self.era_supervisor.next_block_height =
             self.era_supervisor.active_eras[&current_era].start_height;
+        effects.extend(self.announce_active_validators(current_era));
 
         effects
     }
@@ -805,12 +886,22 @@ where
             seed,
             block.header().timestamp(),
             block.height() + 1,
+            self.rng,
         );
         let mut effects = self.handle_consensus_outcomes(era_id, outcomes);
+        effects.extend(self.announce_active_validators(era_id));
         effects.extend(self.effect_builder.announce_block_handled(block).ignore());
         effects
     }
 
+    /// Announces the validators of the given era as the active validator set.
+    fn announce_active_validators(&self, era_id: EraId) -> Effects<Event<I>> {
+        let validators = self.era(era_id).validators().keys().cloned().collect();
+        self.effect_builder
+            .announce_active_validators_changed(era_id, validators)
+            .ignore()
+    }
+
     pub(super) fn resolve_validity(
         &mut self,
         era_id: EraId,
@@ -992,6 +1083,9 @@ where
                 }
                 // Request execution of the finalized block.
                 effects.extend(self.effect_builder.execute_block(finalized_block).ignore());
+                if let Some(status) = self.era_supervisor.validator_status(era_id) {
+                    self.era_supervisor.metrics.validator_status(&status);
+                }
                 self.era_supervisor.update_consensus_pause();
                 effects
             }
@@ -1125,7 +1219,9 @@ where
         &self,
         responder: Responder<(PublicKey, Option<TimeDiff>)>,
     ) -> Effects<Event<I>> {
-        let public_key = self.era_supervisor.public_signing_key;
+        let (_, public_key) = self
+            .era_supervisor
+            .era_signing_key(self.era_supervisor.current_era);
         let round_length = self
             .era_supervisor
             .active_eras
@@ -1134,6 +1230,44 @@ where
         responder.respond((public_key, round_length)).ignore()
     }
 
+    /// Returns the evidence against the given validator in an era, if we have any.
+    pub(super) fn get_evidence(
+        &self,
+        era_id: EraId,
+        vid: PublicKey,
+        responder: Responder<Option<JsonEvidence>>,
+    ) -> Effects<Event<I>> {
+        let evidence = self
+            .era_supervisor
+            .active_eras
+            .get(&era_id)
+            .and_then(|era| {
+                era.consensus
+                    .as_any()
+                    .downcast_ref::<HighwayProtocol<I, ClContext>>()
+            })
+            .and_then(|highway| highway.json_evidence(&vid));
+        responder.respond(evidence).ignore()
+    }
+
+    /// Returns our public key and our validator's status in every active era in which we are
+    /// bonded.
+    pub(super) fn validator_status(
+        &self,
+        responder: Responder<(PublicKey, Vec<ValidatorStatus>)>,
+    ) -> Effects<Event<I>> {
+        let (_, public_key) = self
+            .era_supervisor
+            .era_signing_key(self.era_supervisor.current_era);
+        let mut era_ids: Vec<EraId> = self.era_supervisor.active_eras.keys().cloned().collect();
+        era_ids.sort();
+        let statuses = era_ids
+            .into_iter()
+            .filter_map(|era_id| self.era_supervisor.validator_status(era_id))
+            .collect();
+        responder.respond((public_key, statuses)).ignore()
+    }
+
     fn disconnect(&self, sender: I) -> Effects<Event<I>> {
         self.effect_builder
             .announce_disconnect_from_peer(sender)
@@ -1149,6 +1283,21 @@ where
 }
 
 /// Computes the instance ID for an era, given the era ID and the chainspec hash.
+/// Loads the signing key of the public key our bid is being moved to, if a path to it is given.
+fn load_next_signing_key(
+    root: &Path,
+    next_secret_key_path: Option<External<SecretKey>>,
+) -> Result<Option<(Arc<SecretKey>, PublicKey)>, Error> {
+    let next_secret_key_path = match next_secret_key_path {
+        Some(next_secret_key_path) => next_secret_key_path,
+        None => return Ok(None),
+    };
+    let next_secret_key = Arc::new(next_secret_key_path.load(root)?);
+    let next_public_key = PublicKey::from(next_secret_key.as_ref());
+    info!(next_id = %next_public_key, "EraSupervisor next pubkey",);
+    Ok(Some((next_secret_key, next_public_key)))
+}
+
 fn instance_id(protocol_config: &ProtocolConfig, era_id: EraId) -> Digest {
     let mut result = [0; Digest::LENGTH];
     let mut hasher = VarBlake2b::new(Digest::LENGTH).expect("should create hasher");
@@ -0,0 +1,178 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
+
+use prometheus::Registry;
+
+use casper_types::{PublicKey, SecretKey, U512};
+
+use super::{instance_id, load_next_signing_key, Era, EraId, EraSupervisor};
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        metrics::ConsensusMetrics,
+        new_boxed_consensus,
+        tests::utils::{
+            new_test_chainspec, ALICE_PUBLIC_KEY, ALICE_SECRET_KEY, BOB_PRIVATE_KEY, BOB_PUBLIC_KEY,
+        },
+    },
+    crypto::AsymmetricKeyExt,
+    testing::TestRng,
+    types::Timestamp,
+    utils::External,
+};
+
+type NodeId = u8;
+
+/// Returns an era supervisor signing with Alice's key, and with the given next signing key.
+fn new_era_supervisor(
+    next_signing_key: Option<(Arc<SecretKey>, PublicKey)>,
+) -> EraSupervisor<NodeId> {
+    let chainspec = new_test_chainspec(vec![(*ALICE_PUBLIC_KEY, 100)]);
+    let protocol_config = ProtocolConfig::from(&chainspec);
+    let bonded_eras = protocol_config.unbonding_delay - protocol_config.auction_delay;
+    EraSupervisor {
+        active_eras: Default::default(),
+        secret_signing_key: Arc::new(ALICE_SECRET_KEY.clone()),
+        public_signing_key: *ALICE_PUBLIC_KEY,
+        next_signing_key,
+        current_era: EraId(0),
+        protocol_config,
+        config: Config::default(),
+        new_consensus: Box::new(new_boxed_consensus),
+        node_start_time: Timestamp::now(),
+        bonded_eras,
+        next_block_height: 0,
+        next_executed_height: 0,
+        metrics: ConsensusMetrics::new(&Registry::new()).unwrap(),
+        finished_joining: true,
+        unit_hashes_folder: Default::default(),
+        next_upgrade_activation_point: None,
+        stop_for_upgrade: false,
+        is_initialized: true,
+        enqueued_requests: Default::default(),
+    }
+}
+
+fn bob_signing_key() -> Option<(Arc<SecretKey>, PublicKey)> {
+    Some((Arc::new(BOB_PRIVATE_KEY.clone()), *BOB_PUBLIC_KEY))
+}
+
+fn validators(public_keys: &[PublicKey]) -> BTreeMap<PublicKey, U512> {
+    public_keys
+        .iter()
+        .map(|public_key| (*public_key, U512::from(100)))
+        .collect()
+}
+
+fn add_era(
+    era_supervisor: &mut EraSupervisor<NodeId>,
+    era_id: EraId,
+    validators: BTreeMap<PublicKey, U512>,
+) {
+    let start_time = era_supervisor.protocol_config.timestamp;
+    let (consensus, _) = (era_supervisor.new_consensus)(
+        instance_id(&era_supervisor.protocol_config, era_id),
+        validators.clone(),
+        &HashSet::new(),
+        &era_supervisor.protocol_config,
+        &era_supervisor.config,
+        None,
+        start_time,
+        0,
+        start_time,
+        None,
+        &mut TestRng::new(),
+    );
+    let era = Era::new(consensus, start_time, 0, vec![], HashSet::new(), validators);
+    let _ = era_supervisor.active_eras.insert(era_id, era);
+}
+
+#[test]
+fn signing_key_for_without_next_key() {
+    let era_supervisor = new_era_supervisor(None);
+    for public_keys in &[
+        vec![*ALICE_PUBLIC_KEY],
+        vec![*BOB_PUBLIC_KEY],
+        vec![*ALICE_PUBLIC_KEY, *BOB_PUBLIC_KEY],
+    ] {
+        let (_, public_key) = era_supervisor.signing_key_for(&validators(public_keys));
+        assert_eq!(public_key, *ALICE_PUBLIC_KEY);
+    }
+}
+
+#[test]
+fn signing_key_for_with_next_key() {
+    let era_supervisor = new_era_supervisor(bob_signing_key());
+
+    // The next key is only used if it is a validator and the current one is not.
+    let (secret_key, public_key) = era_supervisor.signing_key_for(&validators(&[*BOB_PUBLIC_KEY]));
+    assert_eq!(public_key, *BOB_PUBLIC_KEY);
+    assert_eq!(PublicKey::from(secret_key.as_ref()), *BOB_PUBLIC_KEY);
+
+    for public_keys in &[
+        vec![*ALICE_PUBLIC_KEY],
+        vec![*ALICE_PUBLIC_KEY, *BOB_PUBLIC_KEY],
+        vec![],
+    ] {
+        let (secret_key, public_key) = era_supervisor.signing_key_for(&validators(public_keys));
+        assert_eq!(public_key, *ALICE_PUBLIC_KEY);
+        assert_eq!(PublicKey::from(secret_key.as_ref()), *ALICE_PUBLIC_KEY);
+    }
+}
+
+#[test]
+fn era_signing_key_follows_era_validators() {
+    let mut era_supervisor = new_era_supervisor(bob_signing_key());
+    add_era(
+        &mut era_supervisor,
+        EraId(0),
+        validators(&[*ALICE_PUBLIC_KEY]),
+    );
+    add_era(
+        &mut era_supervisor,
+        EraId(1),
+        validators(&[*BOB_PUBLIC_KEY]),
+    );
+
+    let (_, public_key) = era_supervisor.era_signing_key(EraId(0));
+    assert_eq!(public_key, *ALICE_PUBLIC_KEY);
+    let (_, public_key) = era_supervisor.era_signing_key(EraId(1));
+    assert_eq!(public_key, *BOB_PUBLIC_KEY);
+    // Eras that aren't active use the current key.
+    let (_, public_key) = era_supervisor.era_signing_key(EraId(2));
+    assert_eq!(public_key, *ALICE_PUBLIC_KEY);
+}
+
+#[test]
+fn should_load_next_signing_key() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let next_secret_key_path = "next_secret_key.pem";
+    BOB_PRIVATE_KEY
+        .to_file(tempdir.path().join(next_secret_key_path))
+        .unwrap();
+
+    let (secret_key, public_key) = load_next_signing_key(
+        tempdir.path(),
+        Some(External::from_path(next_secret_key_path)),
+    )
+    .unwrap()
+    .expect("should load next signing key");
+    assert_eq!(public_key, *BOB_PUBLIC_KEY);
+    assert_eq!(PublicKey::from(secret_key.as_ref()), *BOB_PUBLIC_KEY);
+
+    assert!(load_next_signing_key(tempdir.path(), None)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn should_fail_to_load_missing_next_signing_key() {
+    let tempdir = tempfile::tempdir().unwrap();
+    assert!(load_next_signing_key(
+        tempdir.path(),
+        Some(External::from_path("missing_secret_key.pem"))
+    )
+    .is_err());
+}
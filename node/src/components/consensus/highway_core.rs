@@ -33,13 +33,13 @@
 mod test_macros;
 
 pub(crate) mod active_validator;
+pub(crate) mod endorsement;
+pub(crate) mod evidence;
 pub(crate) mod finality_detector;
 pub(crate) mod highway;
 pub(crate) mod state;
 pub(crate) mod validators;
 
-mod endorsement;
-mod evidence;
 #[cfg(test)]
 pub(crate) mod highway_testing;
 
@@ -608,6 +608,11 @@ impl<C: Context> ActiveValidator<C> {
         }
     }
 
+    /// Returns the round exponent of our next round.
+    pub(crate) fn next_round_exp(&self) -> u8 {
+        self.next_round_exp
+    }
+
     pub(crate) fn next_round_length(&self) -> TimeDiff {
         state::round_len(self.next_round_exp)
     }
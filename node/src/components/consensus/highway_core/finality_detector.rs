@@ -1,7 +1,7 @@
 mod horizon;
 mod rewards;
 
-use std::iter;
+use std::{collections::HashMap, iter};
 
 use datasize::DataSize;
 use tracing::{trace, warn};
@@ -37,6 +37,8 @@ where
     last_finalized: Option<C::Hash>,
     /// The fault tolerance threshold.
     ftt: Weight,
+    /// The number of finalized blocks proposed by each validator.
+    finalized_proposals: HashMap<ValidatorIndex, u64>,
 }
 
 impl<C: Context> FinalityDetector<C> {
@@ -45,6 +47,7 @@ impl<C: Context> FinalityDetector<C> {
         FinalityDetector {
             last_finalized: None,
             ftt,
+            finalized_proposals: HashMap::new(),
         }
     }
 
@@ -94,6 +97,10 @@ impl<C: Context> FinalityDetector<C> {
             let lvl = self.find_summit(target_lvl, candidate, state);
             if lvl == target_lvl {
                 self.last_finalized = Some(*candidate);
+                *self
+                    .finalized_proposals
+                    .entry(state.unit(candidate).creator)
+                    .or_default() += 1;
                 let elapsed = start_time.elapsed();
                 trace!(%elapsed, "found finalized block");
                 return Some(candidate);
@@ -158,6 +165,14 @@ impl<C: Context> FinalityDetector<C> {
         self.last_finalized.as_ref()
     }
 
+    /// Returns the number of finalized blocks proposed by the given validator.
+    pub(crate) fn finalized_proposals(&self, vidx: ValidatorIndex) -> u64 {
+        self.finalized_proposals
+            .get(&vidx)
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Returns the configured fault tolerance threshold of this detector.
     pub(crate) fn fault_tolerance_threshold(&self) -> Weight {
         self.ftt
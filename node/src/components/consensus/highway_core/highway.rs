@@ -659,6 +659,11 @@ impl<C: Context> Highway<C> {
             .as_ref()
             .map(|av| av.next_round_length())
     }
+
+    /// Returns our next round exponent, if we are an active validator.
+    pub(crate) fn next_round_exp(&self) -> Option<u8> {
+        self.active_validator.as_ref().map(|av| av.next_round_exp())
+    }
 }
 
 #[cfg(test)]
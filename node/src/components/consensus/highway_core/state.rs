@@ -46,10 +46,9 @@ use crate::{
 use block::Block;
 use tallies::Tallies;
 
-// TODO: The restart mechanism only persists and loads our own latest unit, so that we don't
-// equivocate after a restart. It doesn't yet persist our latest endorsed units, so we could
-// accidentally endorse conflicting votes. Fix this and enable slashing for conflicting
-// endorsements again.
+// TODO: The protocol state, including our own endorsements, is now persisted in a write-ahead log
+// and restored after a restart, so we can't accidentally endorse conflicting votes anymore. Enable
+// slashing for conflicting endorsements again once all validators run with the write-ahead log.
 pub(super) const TODO_ENDORSEMENT_EVIDENCE_DISABLED: bool = true;
 
 /// Number of maximum-length rounds after which a validator counts as offline, if we haven't heard
@@ -135,6 +134,19 @@ impl<C: Context> Fault<C> {
     }
 }
 
+/// The number of units, proposals and endorsements a validator contributed to the protocol state.
+///
+/// These are counted as they are added, so that they can be reported without traversing the state.
+#[derive(Clone, Copy, DataSize, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Activity {
+    /// The number of units created by the validator.
+    pub(crate) units: u64,
+    /// The number of units created by the validator that contain a new block.
+    pub(crate) proposals: u64,
+    /// The number of units endorsed by the validator.
+    pub(crate) endorsements: u64,
+}
+
 /// A passive instance of the Highway protocol, containing its local state.
 ///
 /// Both observers and active validators must instantiate this, pass in all incoming vertices from
@@ -179,6 +191,8 @@ where
     incomplete_endorsements: HashMap<C::Hash, BTreeMap<ValidatorIndex, C::Signature>>,
     /// Timestamp of the last ping or unit we received from each validator.
     pings: ValidatorMap<Timestamp>,
+    /// The number of units, proposals and endorsements we received from each validator.
+    activity: ValidatorMap<Activity>,
     /// Clock to measure time spent in fork choice computation.
     #[data_size(skip)] // Not implemented for Clock; probably negligible.
     clock: Clock,
@@ -215,6 +229,9 @@ impl<C: Context> State<C> {
         let pings = iter::repeat(params.start_timestamp())
             .take(weights.len())
             .collect();
+        let activity = iter::repeat(Activity::default())
+            .take(weights.len())
+            .collect();
         State {
             params,
             weights,
@@ -226,6 +243,7 @@ impl<C: Context> State<C> {
             endorsements: HashMap::new(),
             incomplete_endorsements: HashMap::new(),
             pings,
+            activity,
             clock: Clock::new(),
         }
     }
@@ -411,9 +429,11 @@ impl<C: Context> State<C> {
         let instance_id = wunit.instance_id;
         let fork_choice = self.fork_choice(&wunit.panorama).cloned();
         let (unit, maybe_value) = Unit::new(swunit, fork_choice.as_ref(), self);
+        self.activity[unit.creator].units += 1;
         if let Some(value) = maybe_value {
             let block = Block::new(fork_choice, value, self);
             self.blocks.insert(hash, block);
+            self.activity[unit.creator].proposals += 1;
         }
         self.add_ping(unit.creator, unit.timestamp);
         self.units.insert(hash, unit);
@@ -473,10 +493,12 @@ impl<C: Context> State<C> {
             return; // We already have a sufficient number of endorsements.
         }
         info!("Received endorsements of {:?}", uhash);
-        self.incomplete_endorsements
-            .entry(uhash)
-            .or_default()
-            .extend(endorsements.endorsers);
+        let incomplete = self.incomplete_endorsements.entry(uhash).or_default();
+        for (vidx, signature) in endorsements.endorsers {
+            if incomplete.insert(vidx, signature).is_none() {
+                self.activity[vidx].endorsements += 1;
+            }
+        }
         let endorsed: Weight = self.incomplete_endorsements[&uhash]
             .keys()
             .map(|vidx| self.weight(*vidx))
@@ -508,6 +530,11 @@ impl<C: Context> State<C> {
                 .unwrap_or(false)
     }
 
+    /// Returns the number of units, proposals and endorsements we received from `vidx`.
+    pub(crate) fn activity(&self, vidx: ValidatorIndex) -> &Activity {
+        &self.activity[vidx]
+    }
+
     /// Updates `self.pings` with the given timestamp.
     pub(crate) fn add_ping(&mut self, creator: ValidatorIndex, timestamp: Timestamp) {
         self.pings[creator] = self.pings[creator].max(timestamp);
@@ -905,6 +932,9 @@ impl<C: Context> State<C> {
         }
         self.endorsements.clear();
         self.incomplete_endorsements.clear();
+        for activity in self.activity.iter_mut() {
+            *activity = Activity::default();
+        }
     }
 
     /// Validates whether a unit with the given panorama and `endorsed` set satsifies the
@@ -0,0 +1,380 @@
+//! A JSON representation of evidence against faulty validators, for use outside of consensus.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use casper_types::{AsymmetricType, PublicKey, SecretKey, Signature};
+
+use crate::{
+    components::consensus::{
+        cl_context::ClContext,
+        highway_core::{
+            endorsement::{Endorsement, SignedEndorsement},
+            evidence::Evidence,
+            highway::{HashedWireUnit, SignedWireUnit, WireUnit},
+            state::{Observation, Panorama},
+            validators::{ValidatorIndex, Validators},
+        },
+    },
+    crypto::{hash::Digest, AsymmetricKeyExt},
+    rpcs::docs::DocExample,
+    types::{TimeDiff, Timestamp},
+};
+
+static JSON_EVIDENCE: Lazy<JsonEvidence> = Lazy::new(|| {
+    let signature = Signature::from_hex(
+        "012dbf03817a51794a8e19e0724884075e6d1fbec326b766ecfa6658b41f81290da85e23b24e88b1c8d976\
+            1185c961daee1adab0649912a6477bcd2e69bd91bd08"
+            .as_bytes(),
+    )
+    .unwrap();
+    let unit = |timestamp: Timestamp| {
+        let wunit = WireUnit {
+            panorama: Panorama::new(1),
+            creator: ValidatorIndex(0),
+            instance_id: Digest::from([1u8; Digest::LENGTH]),
+            value: None,
+            seq_number: 0,
+            timestamp,
+            round_exp: 14,
+            endorsed: Default::default(),
+        };
+        JsonUnit::new(&SignedWireUnit {
+            hashed_wire_unit: HashedWireUnit::new(wunit),
+            signature,
+        })
+    };
+    let timestamp = *Timestamp::doc_example();
+    JsonEvidence::Equivocation {
+        perpetrator: PublicKey::from(SecretKey::doc_example()),
+        unit1: unit(timestamp),
+        unit2: unit(timestamp + TimeDiff::from(1)),
+    }
+});
+
+/// An error verifying a piece of `JsonEvidence`.
+#[derive(Debug, Error)]
+pub enum EvidenceVerificationError {
+    /// A unit is not valid hex.
+    #[error("invalid hex encoding of unit: {0}")]
+    Hex(#[from] hex::FromHexError),
+    /// A unit could not be deserialized.
+    #[error("failed to deserialize unit: {0}")]
+    Deserialization(#[from] bincode::Error),
+    /// The fields of a unit don't match its serialized form.
+    #[error("unit {0} does not match its serialized form")]
+    UnitMismatch(Digest),
+    /// The perpetrator is not the validator whose fault is proven by the evidence.
+    #[error("the evidence is not against {0}")]
+    WrongPerpetrator(PublicKey),
+    /// The evidence doesn't prove that the perpetrator is faulty.
+    #[error("invalid evidence: {0}")]
+    Invalid(String),
+}
+
+/// A validator's latest unit, as seen by the creator of another unit.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum JsonObservation {
+    /// No unit by that validator was seen.
+    None,
+    /// The hash of the validator's latest unit.
+    Correct(Digest),
+    /// The validator was seen to be faulty.
+    Faulty,
+}
+
+impl From<&Observation<ClContext>> for JsonObservation {
+    fn from(obs: &Observation<ClContext>) -> Self {
+        match obs {
+            Observation::None => JsonObservation::None,
+            Observation::Correct(hash) => JsonObservation::Correct(*hash),
+            Observation::Faulty => JsonObservation::Faulty,
+        }
+    }
+}
+
+/// A signed unit that is part of a piece of evidence.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonUnit {
+    /// The unit's hash, i.e. the hash of `bytes`.
+    pub hash: Digest,
+    /// The creator's index in the era's validator set, ordered by public key.
+    pub creator_index: u32,
+    /// The number of earlier units by the same creator.
+    pub seq_number: u64,
+    /// The time at which the unit was created.
+    pub timestamp: Timestamp,
+    /// The latest unit of each validator the creator had seen, ordered like the validator set.
+    pub panorama: Vec<JsonObservation>,
+    /// The creator's signature of the unit's hash.
+    pub signature: Signature,
+    /// The hex-encoded serialized unit.
+    pub bytes: String,
+}
+
+impl JsonUnit {
+    fn new(swunit: &SignedWireUnit<ClContext>) -> Self {
+        let wunit = swunit.wire_unit();
+        JsonUnit {
+            hash: swunit.hash(),
+            creator_index: wunit.creator.0,
+            seq_number: wunit.seq_number,
+            timestamp: wunit.timestamp,
+            panorama: wunit.panorama.iter().map(JsonObservation::from).collect(),
+            signature: swunit.signature,
+            bytes: hex::encode(bincode::serialize(wunit).expect("should serialize unit")),
+        }
+    }
+
+    /// Deserializes the unit and checks that it matches the other fields.
+    fn to_signed_wire_unit(&self) -> Result<SignedWireUnit<ClContext>, EvidenceVerificationError> {
+        let wunit: WireUnit<ClContext> = bincode::deserialize(&hex::decode(&self.bytes)?)?;
+        let hwunit = HashedWireUnit::new(wunit);
+        let swunit = SignedWireUnit {
+            hashed_wire_unit: hwunit,
+            signature: self.signature,
+        };
+        if JsonUnit::new(&swunit) != *self {
+            return Err(EvidenceVerificationError::UnitMismatch(self.hash));
+        }
+        Ok(swunit)
+    }
+}
+
+/// Evidence that a validator is faulty, as returned by the `info_get_evidence` RPC.
+///
+/// Every unit contains its serialized form, so that the evidence can be verified offline, given
+/// only the era's validator set.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum JsonEvidence {
+    /// The validator created two different units with the same sequence number.
+    Equivocation {
+        /// The faulty validator.
+        perpetrator: PublicKey,
+        /// The first unit.
+        unit1: JsonUnit,
+        /// The second unit, with the same creator and sequence number.
+        unit2: JsonUnit,
+    },
+    /// The validator endorsed two conflicting units.
+    Endorsements {
+        /// The faulty validator.
+        perpetrator: PublicKey,
+        /// The unit with the lower (or equal) sequence number.
+        unit1: JsonUnit,
+        /// The perpetrator's signature endorsing `unit1`.
+        endorsement1: Signature,
+        /// The unit with the higher (or equal) sequence number, on a conflicting fork.
+        unit2: JsonUnit,
+        /// The perpetrator's signature endorsing `unit2`.
+        endorsement2: Signature,
+        /// The predecessors of `unit2`, back to the same sequence number as `unit1`, in reverse
+        /// chronological order.
+        swimlane2: Vec<JsonUnit>,
+    },
+}
+
+impl DocExample for JsonEvidence {
+    fn doc_example() -> &'static Self {
+        &*JSON_EVIDENCE
+    }
+}
+
+impl JsonEvidence {
+    /// Creates the JSON representation of the given evidence, or returns `None` if the perpetrator
+    /// is not one of the `validators`.
+    pub(crate) fn new(
+        evidence: &Evidence<ClContext>,
+        validators: &Validators<PublicKey>,
+    ) -> Option<Self> {
+        let perpetrator = *validators.id(evidence.perpetrator())?;
+        Some(match evidence {
+            Evidence::Equivocation(unit1, unit2) => JsonEvidence::Equivocation {
+                perpetrator,
+                unit1: JsonUnit::new(unit1),
+                unit2: JsonUnit::new(unit2),
+            },
+            Evidence::Endorsements {
+                endorsement1,
+                unit1,
+                endorsement2,
+                unit2,
+                swimlane2,
+            } => JsonEvidence::Endorsements {
+                perpetrator,
+                unit1: JsonUnit::new(unit1),
+                endorsement1: *endorsement1.signature(),
+                unit2: JsonUnit::new(unit2),
+                endorsement2: *endorsement2.signature(),
+                swimlane2: swimlane2.iter().map(JsonUnit::new).collect(),
+            },
+        })
+    }
+
+    /// Returns the faulty validator.
+    pub fn perpetrator(&self) -> &PublicKey {
+        match self {
+            JsonEvidence::Equivocation { perpetrator, .. }
+            | JsonEvidence::Endorsements { perpetrator, .. } => perpetrator,
+        }
+    }
+
+    /// Verifies that the evidence proves the perpetrator faulty, given the public keys of all
+    /// validators in the era.
+    pub fn verify<I>(&self, validator_keys: I) -> Result<(), EvidenceVerificationError>
+    where
+        I: IntoIterator<Item = PublicKey>,
+    {
+        // Validator indices refer to the validator set ordered by public key; the weights don't
+        // matter for the evidence.
+        let validators: Validators<PublicKey> =
+            validator_keys.into_iter().map(|key| (key, 1u64)).collect();
+        let wrong_perpetrator = || EvidenceVerificationError::WrongPerpetrator(*self.perpetrator());
+        let vidx = validators
+            .get_index(self.perpetrator())
+            .ok_or_else(wrong_perpetrator)?;
+        let evidence = self.to_evidence(vidx)?;
+        if evidence.perpetrator() != vidx {
+            return Err(wrong_perpetrator());
+        }
+        let instance_id = match &evidence {
+            Evidence::Equivocation(unit1, _) | Evidence::Endorsements { unit1, .. } => {
+                unit1.wire_unit().instance_id
+            }
+        };
+        evidence
+            .validate_proof(&validators, &instance_id)
+            .map_err(|err| EvidenceVerificationError::Invalid(err.to_string()))
+    }
+
+    /// Deserializes the units and returns the evidence, with `vidx` as the endorser if it is
+    /// evidence for conflicting endorsements.
+    fn to_evidence(
+        &self,
+        vidx: ValidatorIndex,
+    ) -> Result<Evidence<ClContext>, EvidenceVerificationError> {
+        Ok(match self {
+            JsonEvidence::Equivocation { unit1, unit2, .. } => {
+                Evidence::Equivocation(unit1.to_signed_wire_unit()?, unit2.to_signed_wire_unit()?)
+            }
+            JsonEvidence::Endorsements {
+                unit1,
+                endorsement1,
+                unit2,
+                endorsement2,
+                swimlane2,
+                ..
+            } => {
+                let unit1 = unit1.to_signed_wire_unit()?;
+                let unit2 = unit2.to_signed_wire_unit()?;
+                let endorsement1 =
+                    SignedEndorsement::new(Endorsement::new(unit1.hash(), vidx), *endorsement1);
+                let endorsement2 =
+                    SignedEndorsement::new(Endorsement::new(unit2.hash(), vidx), *endorsement2);
+                let swimlane2 = swimlane2
+                    .iter()
+                    .map(JsonUnit::to_signed_wire_unit)
+                    .collect::<Result<_, _>>()?;
+                Evidence::Endorsements {
+                    endorsement1,
+                    unit1,
+                    endorsement2,
+                    unit2,
+                    swimlane2,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        components::consensus::{cl_context::Keypair, traits::ValidatorSecret},
+        testing::TestRng,
+    };
+
+    #[test]
+    fn verify_equivocation() {
+        let mut rng = TestRng::new();
+        let secret_keys: Vec<Arc<SecretKey>> = (0..3)
+            .map(|_| Arc::new(SecretKey::random(&mut rng)))
+            .collect();
+        let validators: Validators<PublicKey> = secret_keys
+            .iter()
+            .map(|sk| (PublicKey::from(sk.as_ref()), 1u64))
+            .collect();
+        let public_keys: Vec<PublicKey> = validators.iter().map(|v| *v.id()).collect();
+        let vidx = ValidatorIndex(1);
+        let perpetrator = validators.id(vidx).unwrap();
+        let keypair = secret_keys
+            .iter()
+            .find(|sk| PublicKey::from(sk.as_ref()) == *perpetrator)
+            .map(|sk| Keypair::from(Arc::clone(sk)))
+            .unwrap();
+
+        let mut sign_unit = |timestamp: u64| {
+            let wunit = WireUnit {
+                panorama: Panorama::new(3),
+                creator: vidx,
+                instance_id: Digest::from([1u8; Digest::LENGTH]),
+                value: None,
+                seq_number: 0,
+                timestamp: timestamp.into(),
+                round_exp: 14,
+                endorsed: Default::default(),
+            };
+            let hwunit = HashedWireUnit::new(wunit);
+            let signature = keypair.sign(&hwunit.hash(), &mut rng);
+            SignedWireUnit {
+                hashed_wire_unit: hwunit,
+                signature,
+            }
+        };
+        let evidence = Evidence::Equivocation(sign_unit(1000), sign_unit(1001));
+
+        let json_evidence = JsonEvidence::new(&evidence, &validators).unwrap();
+        assert_eq!(perpetrator, json_evidence.perpetrator());
+        let serialized = serde_json::to_string(&json_evidence).unwrap();
+        let deserialized: JsonEvidence = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(json_evidence, deserialized);
+        deserialized
+            .verify(public_keys.iter().copied())
+            .expect("evidence should be valid");
+
+        // Without the perpetrator the validator indices don't match.
+        let other_keys = public_keys.iter().filter(|pk| *pk != perpetrator).copied();
+        assert!(deserialized.verify(other_keys).is_err());
+
+        // If a field doesn't match the serialized unit, the evidence is invalid.
+        let mut tampered = json_evidence.clone();
+        if let JsonEvidence::Equivocation { unit2, .. } = &mut tampered {
+            unit2.seq_number = 1;
+        }
+        assert!(matches!(
+            tampered.verify(public_keys.iter().copied()),
+            Err(EvidenceVerificationError::UnitMismatch(_))
+        ));
+
+        // Two copies of the same unit are not an equivocation.
+        let mut duplicate = json_evidence;
+        if let JsonEvidence::Equivocation { unit1, unit2, .. } = &mut duplicate {
+            *unit2 = unit1.clone();
+        }
+        assert!(matches!(
+            duplicate.verify(public_keys),
+            Err(EvidenceVerificationError::Invalid(_))
+        ));
+    }
+}
@@ -1,6 +1,9 @@
 use prometheus::{Gauge, IntGauge, Registry};
 
-use crate::types::{FinalizedBlock, Timestamp};
+use crate::{
+    components::consensus::ValidatorStatus,
+    types::{FinalizedBlock, Timestamp},
+};
 
 /// Network metrics to track Consensus
 #[derive(Debug)]
@@ -15,6 +18,18 @@ pub struct ConsensusMetrics {
     time_of_last_finalized_block: IntGauge,
     /// The Current era.
     pub current_era: IntGauge,
+    /// Our validator's next round exponent.
+    validator_round_exponent: IntGauge,
+    /// The number of failed rounds among our validator's recent ones.
+    validator_failed_rounds: IntGauge,
+    /// The number of units our validator created in the era.
+    validator_units_produced: IntGauge,
+    /// The number of units our validator endorsed in the era.
+    validator_endorsements: IntGauge,
+    /// The number of blocks our validator proposed in the era.
+    validator_proposals: IntGauge,
+    /// The number of blocks our validator proposed in the era that were finalized.
+    validator_finalized_proposals: IntGauge,
     /// registry component.
     registry: Registry,
 }
@@ -36,17 +51,54 @@ impl ConsensusMetrics {
             "timestamp of the most recently finalized block",
         )?;
         let current_era = IntGauge::new("current_era", "The current era")?;
+        let validator_round_exponent = IntGauge::new(
+            "validator_round_exponent",
+            "the round exponent of our validator's next round, or -1 if it is not active",
+        )?;
+        let validator_failed_rounds = IntGauge::new(
+            "validator_failed_rounds",
+            "the number of failed rounds among our validator's recent ones",
+        )?;
+        let validator_units_produced = IntGauge::new(
+            "validator_units_produced",
+            "the number of units our validator created in the era of the latest finalized block",
+        )?;
+        let validator_endorsements = IntGauge::new(
+            "validator_endorsements",
+            "the number of units our validator endorsed in the era of the latest finalized block",
+        )?;
+        let validator_proposals = IntGauge::new(
+            "validator_proposals",
+            "the number of blocks our validator proposed in the era of the latest finalized block",
+        )?;
+        let validator_finalized_proposals = IntGauge::new(
+            "validator_finalized_proposals",
+            "the number of finalized blocks proposed by our validator in the era of the latest \
+             finalized block",
+        )?;
         registry.register(Box::new(finalization_time.clone()))?;
         registry.register(Box::new(finalized_block_count.clone()))?;
         registry.register(Box::new(current_era.clone()))?;
         registry.register(Box::new(time_of_last_proposed_block.clone()))?;
         registry.register(Box::new(time_of_last_finalized_block.clone()))?;
+        registry.register(Box::new(validator_round_exponent.clone()))?;
+        registry.register(Box::new(validator_failed_rounds.clone()))?;
+        registry.register(Box::new(validator_units_produced.clone()))?;
+        registry.register(Box::new(validator_endorsements.clone()))?;
+        registry.register(Box::new(validator_proposals.clone()))?;
+        registry.register(Box::new(validator_finalized_proposals.clone()))?;
         Ok(ConsensusMetrics {
             finalization_time,
             finalized_block_count,
             time_of_last_proposed_block,
             time_of_last_finalized_block,
             current_era,
+            validator_round_exponent,
+            validator_failed_rounds,
+            validator_units_produced,
+            validator_endorsements,
+            validator_proposals,
+            validator_finalized_proposals,
             registry: registry.clone(),
         })
     }
@@ -61,6 +113,20 @@ impl ConsensusMetrics {
             .set(finalized_block.height() as i64);
     }
 
+    /// Updates the metrics with our validator's status in the era of the latest finalized block.
+    pub(crate) fn validator_status(&mut self, status: &ValidatorStatus) {
+        self.validator_round_exponent
+            .set(status.round_exponent.map_or(-1, i64::from));
+        self.validator_failed_rounds
+            .set(i64::from(status.failed_rounds));
+        self.validator_units_produced
+            .set(status.units_produced as i64);
+        self.validator_endorsements.set(status.endorsements as i64);
+        self.validator_proposals.set(status.proposals as i64);
+        self.validator_finalized_proposals
+            .set(status.finalized_proposals as i64);
+    }
+
     /// Updates the metrics and records a newly proposed block.
     pub(crate) fn proposed_block(&mut self) {
         self.time_of_last_proposed_block
@@ -85,5 +151,23 @@ impl Drop for ConsensusMetrics {
         self.registry
             .unregister(Box::new(self.time_of_last_proposed_block.clone()))
             .expect("did not expect deregistering time_of_last_proposed_block to fail");
+        self.registry
+            .unregister(Box::new(self.validator_round_exponent.clone()))
+            .expect("did not expect deregistering validator_round_exponent to fail");
+        self.registry
+            .unregister(Box::new(self.validator_failed_rounds.clone()))
+            .expect("did not expect deregistering validator_failed_rounds to fail");
+        self.registry
+            .unregister(Box::new(self.validator_units_produced.clone()))
+            .expect("did not expect deregistering validator_units_produced to fail");
+        self.registry
+            .unregister(Box::new(self.validator_endorsements.clone()))
+            .expect("did not expect deregistering validator_endorsements to fail");
+        self.registry
+            .unregister(Box::new(self.validator_proposals.clone()))
+            .expect("did not expect deregistering validator_proposals to fail");
+        self.registry
+            .unregister(Box::new(self.validator_finalized_proposals.clone()))
+            .expect("did not expect deregistering validator_finalized_proposals to fail");
     }
 }
@@ -1 +1,84 @@
 pub(crate) mod highway;
+pub(crate) mod simple_bft;
+mod write_ahead_log;
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    hash::Hash,
+    path::PathBuf,
+};
+
+use num_traits::AsPrimitive;
+
+use casper_types::U512;
+
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{ConsensusProtocol, ProtocolOutcome},
+        highway_core::validators::Validators,
+        traits::{Context, NodeIdT},
+    },
+    types::{chainspec::ConsensusProtocolName, Timestamp},
+    NodeRng,
+};
+
+use self::{highway::HighwayProtocol, simple_bft::SimpleBftProtocol};
+
+/// Creates a new boxed instance of the consensus protocol that is configured in the chainspec.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn new_boxed_consensus<I: NodeIdT, C: Context + 'static>(
+    instance_id: C::InstanceId,
+    validator_stakes: BTreeMap<C::ValidatorId, U512>,
+    slashed: &HashSet<C::ValidatorId>,
+    protocol_config: &ProtocolConfig,
+    config: &Config,
+    prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
+    era_start_time: Timestamp,
+    seed: u64,
+    now: Timestamp,
+    write_ahead_log: Option<PathBuf>,
+    rng: &mut NodeRng,
+) -> (Box<dyn ConsensusProtocol<I, C>>, Vec<ProtocolOutcome<I, C>>) {
+    let new_boxed = match protocol_config.consensus_protocol {
+        ConsensusProtocolName::Highway => HighwayProtocol::<I, C>::new_boxed,
+        ConsensusProtocolName::SimpleBft => SimpleBftProtocol::<I, C>::new_boxed,
+    };
+    new_boxed(
+        instance_id,
+        validator_stakes,
+        slashed,
+        protocol_config,
+        config,
+        prev_cp,
+        era_start_time,
+        seed,
+        now,
+        write_ahead_log,
+        rng,
+    )
+}
+
+/// Returns the validators with their stakes scaled down to `u64` weights, and the slashed ones
+/// banned.
+fn validators<VID>(validator_stakes: BTreeMap<VID, U512>, slashed: &HashSet<VID>) -> Validators<VID>
+where
+    VID: Ord + Hash + Clone,
+{
+    let sum_stakes: U512 = validator_stakes.iter().map(|(_, stake)| *stake).sum();
+    assert!(
+        !sum_stakes.is_zero(),
+        "cannot start era with total weight 0"
+    );
+    // We need u64 weights. Scale down by  sum / u64::MAX,  rounded up.
+    // If we round up the divisor, the resulting sum is guaranteed to be  <= u64::MAX.
+    let scaling_factor = (sum_stakes + U512::from(u64::MAX) - 1) / U512::from(u64::MAX);
+    let scale_stake =
+        |(key, stake): (VID, U512)| (key, AsPrimitive::<u64>::as_(stake / scaling_factor));
+    let mut validators: Validators<VID> = validator_stakes.into_iter().map(scale_stake).collect();
+
+    for vid in slashed {
+        validators.ban(vid);
+    }
+    validators
+}
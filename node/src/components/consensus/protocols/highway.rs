@@ -14,14 +14,14 @@ use std::{
 
 use datasize::DataSize;
 use itertools::Itertools;
-use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, trace, warn};
 
-use casper_types::{system::auction::BLOCK_REWARD, U512};
+use casper_types::{system::auction::BLOCK_REWARD, PublicKey, U512};
 
 use crate::{
     components::consensus::{
+        cl_context::ClContext,
         config::{Config, ProtocolConfig},
         consensus_protocol::{BlockContext, ConsensusProtocol, ProtocolOutcome},
         highway_core::{
@@ -29,10 +29,13 @@ use crate::{
             finality_detector::{FinalityDetector, FttExceeded},
             highway::{Dependency, GetDepOutcome, Highway, Params, ValidVertex, Vertex},
             state::{Observation, Panorama},
-            validators::{ValidatorIndex, Validators},
+            validators::ValidatorIndex,
         },
+        json_evidence::JsonEvidence,
+        protocols::{self, write_ahead_log::WriteAheadLog},
         traits::{ConsensusValueT, Context, NodeIdT},
-        ActionId, TimerId,
+        validator_status::ValidatorStatus,
+        ActionId, EraId, TimerId,
     },
     types::{TimeDiff, Timestamp},
     NodeRng,
@@ -72,6 +75,8 @@ where
     round_success_meter: RoundSuccessMeter<C>,
     synchronizer: Synchronizer<I, C>,
     evidence_only: bool,
+    /// The log of all vertices added to the protocol state, used to restore it after a restart.
+    write_ahead_log: Option<WriteAheadLog>,
 }
 
 impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
@@ -87,24 +92,10 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         era_start_time: Timestamp,
         seed: u64,
         now: Timestamp,
+        write_ahead_log: Option<PathBuf>,
+        rng: &mut NodeRng,
     ) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
-        let sum_stakes: U512 = validator_stakes.iter().map(|(_, stake)| *stake).sum();
-        assert!(
-            !sum_stakes.is_zero(),
-            "cannot start era with total weight 0"
-        );
-        // For Highway, we need u64 weights. Scale down by  sum / u64::MAX,  rounded up.
-        // If we round up the divisor, the resulting sum is guaranteed to be  <= u64::MAX.
-        let scaling_factor = (sum_stakes + U512::from(u64::MAX) - 1) / U512::from(u64::MAX);
-        let scale_stake = |(key, stake): (C::ValidatorId, U512)| {
-            (key, AsPrimitive::<u64>::as_(stake / scaling_factor))
-        };
-        let mut validators: Validators<C::ValidatorId> =
-            validator_stakes.into_iter().map(scale_stake).collect();
-
-        for vid in slashed {
-            validators.ban(vid);
-        }
+        let validators = protocols::validators(validator_stakes, slashed);
 
         // TODO: Apply all upgrades with a height less than or equal to the start height.
         let highway_config = &protocol_config.highway_config;
@@ -181,17 +172,64 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
             .unwrap_or_else(|| {
                 RoundSuccessMeter::new(round_exp, min_round_exp, max_round_exp, start_timestamp)
             });
-        let hw_proto = Box::new(HighwayProtocol {
+        let mut hw_proto = Box::new(HighwayProtocol {
             pending_values: HashMap::new(),
             finality_detector: FinalityDetector::new(ftt),
             highway: Highway::new(instance_id, validators, params),
             round_success_meter,
             synchronizer: Synchronizer::new(config.pending_vertex_timeout),
             evidence_only: false,
+            write_ahead_log: None,
         });
+        if let Some(path) = write_ahead_log {
+            outcomes.extend(hw_proto.replay_write_ahead_log(WriteAheadLog::new(path), now, rng));
+        }
         (hw_proto, outcomes)
     }
 
+    /// Adds all vertices from the write-ahead log to the protocol state, and from then on appends
+    /// every new vertex to it.
+    ///
+    /// The log only contains vertices that were already validated, so they are added directly,
+    /// without waiting for consensus value validation.
+    fn replay_write_ahead_log(
+        &mut self,
+        wal: WriteAheadLog,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let vertices: Vec<Vertex<C>> = wal.read_all().unwrap_or_else(|err| {
+            panic!(
+                "should successfully read write-ahead log {:?}, got {:?}",
+                wal.path(),
+                err
+            )
+        });
+        if !vertices.is_empty() {
+            info!(count = vertices.len(), path = ?wal.path(), "replaying write-ahead log");
+        }
+        let mut outcomes = Vec::new();
+        for vertex in vertices {
+            let pvv = match self.highway.pre_validate_vertex(vertex) {
+                Ok(pvv) => pvv,
+                Err((vertex, err)) => {
+                    warn!(?vertex, ?err, "invalid vertex in write-ahead log");
+                    continue;
+                }
+            };
+            if let Some(dep) = self.highway.missing_dependency(&pvv) {
+                warn!(?dep, "missing dependency of vertex in write-ahead log");
+                continue;
+            }
+            match self.highway.validate_vertex(pvv) {
+                Ok(vv) => outcomes.extend(self.add_valid_vertex(vv, rng, now)),
+                Err((pvv, err)) => warn!(?pvv, ?err, "invalid vertex in write-ahead log"),
+            }
+        }
+        self.write_ahead_log = Some(wal);
+        outcomes
+    }
+
     fn process_av_effects<E>(&mut self, av_effects: E) -> ProtocolOutcomes<I, C>
     where
         E: IntoIterator<Item = AvEffect<C>>,
@@ -205,6 +243,9 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
     fn process_av_effect(&mut self, effect: AvEffect<C>) -> ProtocolOutcomes<I, C> {
         match effect {
             AvEffect::NewVertex(vv) => {
+                // This is our own vertex, or evidence we found. Make sure it is on disk before we
+                // gossip it, so that we never contradict it after a restart.
+                self.log_vertex(vv.inner(), true);
                 self.calculate_round_exponent(&vv);
                 self.process_new_vertex(vv.into())
             }
@@ -363,10 +404,26 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         // round has finished, we now have all the vertices from that round in the state, and no
         // newer ones.
         self.calculate_round_exponent(&vv);
+        if !self.highway.has_vertex(vv.inner()) {
+            self.log_vertex(vv.inner(), false);
+        }
         let av_effects = self.highway.add_valid_vertex(vv, rng, now);
         self.process_av_effects(av_effects)
     }
 
+    /// Appends the vertex to the write-ahead log, if there is one.
+    fn log_vertex(&mut self, vertex: &Vertex<C>, sync: bool) {
+        if let Some(wal) = self.write_ahead_log.as_mut() {
+            wal.append(vertex, sync).unwrap_or_else(|err| {
+                panic!(
+                    "should successfully write vertex to {:?}, got {:?}",
+                    wal.path(),
+                    err
+                )
+            });
+        }
+    }
+
     /// Returns the median round exponent of all the validators that haven't been observed to be
     /// malicious, as seen by the current panorama.
     /// Returns `None` if there are no correct validators in the panorama.
@@ -425,6 +482,42 @@ enum HighwayMessage<C: Context> {
     LatestStateRequest(Panorama<C>),
 }
 
+impl<I: NodeIdT> HighwayProtocol<I, ClContext> {
+    /// Returns the evidence against the given validator, if we have any.
+    pub(crate) fn json_evidence(&self, vid: &PublicKey) -> Option<JsonEvidence> {
+        let validators = self.highway.validators();
+        let evidence = self
+            .highway
+            .state()
+            .maybe_evidence(validators.get_index(vid)?)?;
+        JsonEvidence::new(evidence, validators)
+    }
+
+    /// Returns statistics about the given validator's participation in this era, or `None` if it
+    /// is not a validator here.
+    pub(crate) fn validator_status(
+        &self,
+        era_id: EraId,
+        vid: &PublicKey,
+    ) -> Option<ValidatorStatus> {
+        let state = self.highway.state();
+        let vidx = self.highway.validators().get_index(vid)?;
+        let activity = state.activity(vidx);
+        Some(ValidatorStatus {
+            era_id: era_id.0,
+            active: self.highway.is_active(),
+            faulty: state.is_faulty(vidx),
+            round_exponent: self.highway.next_round_exp(),
+            recent_rounds: self.round_success_meter.recent_rounds() as u32,
+            failed_rounds: self.round_success_meter.count_failures() as u32,
+            units_produced: activity.units,
+            endorsements: activity.endorsements,
+            proposals: activity.proposals,
+            finalized_proposals: self.finality_detector.finalized_proposals(vidx),
+        })
+    }
+}
+
 impl<C: Context> HighwayMessage<C> {
     fn serialize(&self) -> Vec<u8> {
         bincode::serialize(self).expect("should serialize message")
@@ -158,7 +158,13 @@ impl<C: Context> RoundSuccessMeter<C> {
         }
     }
 
-    fn count_failures(&self) -> usize {
+    /// Returns the number of recent rounds whose success is being tracked.
+    pub(crate) fn recent_rounds(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Returns the number of failed rounds among the recent ones.
+    pub(crate) fn count_failures(&self) -> usize {
         self.rounds.iter().filter(|&success| !success).count()
     }
 
@@ -1,4 +1,4 @@
-use std::{collections::BTreeSet, sync::Arc};
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
 
 use datasize::DataSize;
 use derive_more::Display;
@@ -21,7 +21,7 @@ use crate::{
         protocols::highway::{HighwayMessage, ACTION_ID_VERTEX},
         tests::utils::{new_test_chainspec, ALICE_PUBLIC_KEY, ALICE_SECRET_KEY, BOB_PUBLIC_KEY},
         traits::Context,
-        HighwayProtocol,
+        EraId, HighwayProtocol,
     },
     testing::TestRng,
     types::{ProtoBlock, Timestamp},
@@ -58,6 +58,28 @@ pub(crate) fn new_test_highway_protocol<I1, I2, T>(
     weights: I1,
     init_slashed: I2,
 ) -> Box<dyn ConsensusProtocol<NodeId, ClContext>>
+where
+    I1: IntoIterator<Item = (PublicKey, T)>,
+    I2: IntoIterator<Item = PublicKey>,
+    T: Into<U512>,
+{
+    let (hw_proto, outcomes) = new_test_highway_protocol_with_wal(weights, init_slashed, None);
+    // We expect only the vertex purge timer and participation log timer outcomes.
+    // If there are more, the tests might need to handle them.
+    assert_eq!(2, outcomes.len());
+    hw_proto
+}
+
+/// Creates a new `HighwayProtocol` that restores its state from and persists it to the given
+/// write-ahead log.
+fn new_test_highway_protocol_with_wal<I1, I2, T>(
+    weights: I1,
+    init_slashed: I2,
+    write_ahead_log: Option<PathBuf>,
+) -> (
+    Box<dyn ConsensusProtocol<NodeId, ClContext>>,
+    Vec<ProtocolOutcome<NodeId, ClContext>>,
+)
 where
     I1: IntoIterator<Item = (PublicKey, T)>,
     I2: IntoIterator<Item = PublicKey>,
@@ -70,13 +92,14 @@ where
     let chainspec = new_test_chainspec(weights.clone());
     let config = Config {
         secret_key_path: Default::default(),
+        next_secret_key_path: None,
         unit_hashes_folder: Default::default(),
         pending_vertex_timeout: "1min".parse().unwrap(),
         max_execution_delay: 3,
     };
     // Timestamp of the genesis era start and test start.
     let start_timestamp: Timestamp = 0.into();
-    let (hw_proto, outcomes) = HighwayProtocol::<NodeId, ClContext>::new_boxed(
+    HighwayProtocol::<NodeId, ClContext>::new_boxed(
         ClContext::hash(INSTANCE_ID_DATA),
         weights.into_iter().collect(),
         &init_slashed.into_iter().collect(),
@@ -86,11 +109,9 @@ where
         start_timestamp,
         0,
         start_timestamp,
-    );
-    // We expect only the vertex purge timer and participation log timer outcomes.
-    // If there are more, the tests might need to handle them.
-    assert_eq!(2, outcomes.len());
-    hw_proto
+        write_ahead_log,
+        &mut TestRng::new(),
+    )
 }
 
 #[test]
@@ -270,3 +291,159 @@ fn detect_doppelganger() {
     }
     panic!("failed to return DoppelgangerDetected effect");
 }
+
+#[test]
+fn restore_state_from_write_ahead_log() {
+    let creator: ValidatorIndex = ValidatorIndex(0);
+    let validators = vec![(*ALICE_PUBLIC_KEY, 100), (*BOB_PUBLIC_KEY, 100)];
+    let state: State<ClContext> = new_test_state(validators.iter().map(|(_pk, w)| *w), 0);
+    let panorama: Panorama<ClContext> = Panorama::from(vec![N, N]);
+    let seq_number = panorama.next_seq_num(&state, creator);
+    let mut rng = TestRng::new();
+    let timestamp = 0.into();
+    let wunit: WireUnit<ClContext> = WireUnit {
+        panorama,
+        creator,
+        instance_id: ClContext::hash(INSTANCE_ID_DATA),
+        value: Some(CandidateBlock::new(
+            ProtoBlock::new(vec![], vec![], false),
+            timestamp,
+            vec![],
+        )),
+        seq_number,
+        timestamp,
+        round_exp: 14,
+        endorsed: BTreeSet::new(),
+    };
+    let alice_keypair: Keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let vertex = Vertex::Unit(SignedWireUnit::new(
+        wunit.into_hashed(),
+        &alice_keypair,
+        &mut rng,
+    ));
+    let msg = bincode::serialize(&HighwayMessage::NewVertex(vertex.clone())).unwrap();
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let wal_path = tempdir.path().join("protocol_state.wal");
+    let (mut highway_protocol, _) =
+        new_test_highway_protocol_with_wal(validators.clone(), vec![], Some(wal_path.clone()));
+    let mut outcomes = highway_protocol.handle_message(NodeId(123), msg, &mut rng);
+    while let Some(outcome) = outcomes.pop() {
+        if let ProtocolOutcome::QueueAction(ACTION_ID_VERTEX) = outcome {
+            outcomes.extend(highway_protocol.handle_action(ACTION_ID_VERTEX, &mut rng))
+        }
+    }
+    drop(highway_protocol);
+
+    // A new instance using the same log should start out with the unit already in its state.
+    let (highway_protocol, _) =
+        new_test_highway_protocol_with_wal(validators, vec![], Some(wal_path));
+    let highway_protocol = highway_protocol
+        .as_any()
+        .downcast_ref::<HighwayProtocol<NodeId, ClContext>>()
+        .unwrap();
+    assert!(highway_protocol.highway.has_vertex(&vertex));
+}
+
+/// Handles the vertex and all resulting actions, and returns the number of finalized blocks.
+fn add_vertex_and_count_finalized(
+    highway_protocol: &mut dyn ConsensusProtocol<NodeId, ClContext>,
+    vertex: Vertex<ClContext>,
+    rng: &mut TestRng,
+) -> u64 {
+    let msg = bincode::serialize(&HighwayMessage::NewVertex(vertex)).unwrap();
+    let mut finalized = 0;
+    let mut outcomes = highway_protocol.handle_message(NodeId(123), msg, rng);
+    while let Some(outcome) = outcomes.pop() {
+        match outcome {
+            ProtocolOutcome::FinalizedBlock(_) => finalized += 1,
+            ProtocolOutcome::QueueAction(ACTION_ID_VERTEX) => {
+                outcomes.extend(highway_protocol.handle_action(ACTION_ID_VERTEX, rng))
+            }
+            _ => (),
+        }
+    }
+    finalized
+}
+
+#[test]
+fn validator_status_counts_units_and_proposals() {
+    let creator: ValidatorIndex = ValidatorIndex(0);
+    let validators = vec![(*ALICE_PUBLIC_KEY, 100)];
+    let mut rng = TestRng::new();
+    let instance_id = ClContext::hash(INSTANCE_ID_DATA);
+    let alice_keypair: Keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let mut highway_protocol = new_test_highway_protocol(validators, vec![]);
+    let era_id = EraId(0);
+
+    let validator_status = |highway_protocol: &dyn ConsensusProtocol<NodeId, ClContext>| {
+        highway_protocol
+            .as_any()
+            .downcast_ref::<HighwayProtocol<NodeId, ClContext>>()
+            .unwrap()
+            .validator_status(era_id, &ALICE_PUBLIC_KEY)
+            .expect("Alice is a validator")
+    };
+    let initial_status = validator_status(highway_protocol.as_ref());
+    assert_eq!(initial_status.units_produced, 0);
+    assert_eq!(initial_status.proposals, 0);
+    assert_eq!(initial_status.finalized_proposals, 0);
+    assert!(!initial_status.faulty);
+
+    // Alice proposes a block at the start of the round…
+    let timestamp = 0.into();
+    let proposal: WireUnit<ClContext> = WireUnit {
+        panorama: Panorama::from(vec![N]),
+        creator,
+        instance_id,
+        value: Some(CandidateBlock::new(
+            ProtoBlock::new(vec![], vec![], false),
+            timestamp,
+            vec![],
+        )),
+        seq_number: 0,
+        timestamp,
+        round_exp: 14,
+        endorsed: BTreeSet::new(),
+    };
+    let proposal = SignedWireUnit::new(proposal.into_hashed(), &alice_keypair, &mut rng);
+    let proposal_hash = proposal.hash();
+    let mut finalized =
+        add_vertex_and_count_finalized(highway_protocol.as_mut(), Vertex::Unit(proposal), &mut rng);
+
+    // … and creates a witness unit later in the same round.
+    let witness: WireUnit<ClContext> = WireUnit {
+        panorama: Panorama::from(vec![Observation::Correct(proposal_hash)]),
+        creator,
+        instance_id,
+        value: None,
+        seq_number: 1,
+        timestamp: 1000.into(),
+        round_exp: 14,
+        endorsed: BTreeSet::new(),
+    };
+    let witness = SignedWireUnit::new(witness.into_hashed(), &alice_keypair, &mut rng);
+    finalized +=
+        add_vertex_and_count_finalized(highway_protocol.as_mut(), Vertex::Unit(witness), &mut rng);
+
+    let status = validator_status(highway_protocol.as_ref());
+    assert_eq!(status.era_id, 0);
+    assert_eq!(status.units_produced, 2);
+    assert_eq!(status.proposals, 1);
+    assert_eq!(status.finalized_proposals, finalized);
+    assert_eq!(status.endorsements, 0);
+    assert!(!status.faulty);
+    assert!(!status.active);
+}
+
+#[test]
+fn validator_status_is_none_for_non_validators() {
+    let highway_protocol = new_test_highway_protocol(vec![(*ALICE_PUBLIC_KEY, 100)], vec![]);
+    let highway_protocol = highway_protocol
+        .as_any()
+        .downcast_ref::<HighwayProtocol<NodeId, ClContext>>()
+        .unwrap();
+    assert!(highway_protocol
+        .validator_status(EraId(0), &BOB_PUBLIC_KEY)
+        .is_none());
+}
@@ -0,0 +1,1561 @@
+//! A simple round-based BFT consensus protocol with a single leader per round and instant
+//! finality, following the Tendermint algorithm.
+//!
+//! Each height is decided in one or more rounds. In every round the leader proposes a value, and
+//! the validators vote on it in two steps: with a _prevote_ and then with a _precommit_. As soon
+//! as validators with more than two thirds of the total weight precommit to the same proposal,
+//! its value is finalized. A validator locks on a value when it precommits to it, and only
+//! prevotes for a different value if a quorum prevoted for that one in a later round. That makes it
+//! impossible to finalize two different values at the same height unless more than a third of the
+//! weight is faulty.
+//!
+//! Unlike Highway, every validator needs to sign several messages per block, so this is only
+//! suitable for small, fixed validator sets.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashSet},
+    fmt::{self, Debug, Formatter},
+    path::PathBuf,
+};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, info, trace, warn};
+
+use casper_types::{system::auction::BLOCK_REWARD, U512};
+
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{
+            BlockContext, ConsensusProtocol, FinalizedBlock, ProtocolOutcome, TerminalBlockData,
+        },
+        highway_core::validators::{ValidatorIndex, ValidatorMap, Validators},
+        protocols::{self, write_ahead_log::WriteAheadLog},
+        traits::{ConsensusValueT, Context, NodeIdT, ValidatorSecret},
+        ActionId, TimerId,
+    },
+    types::{TimeDiff, Timestamp},
+    NodeRng,
+};
+
+/// The timer for the end of the proposal step.
+const TIMER_ID_PROPOSE: TimerId = TimerId(0);
+/// The timer for the end of the prevote step.
+const TIMER_ID_PREVOTE: TimerId = TimerId(1);
+/// The timer for the end of the precommit step, i.e. of the round.
+const TIMER_ID_PRECOMMIT: TimerId = TimerId(2);
+
+/// The action of proposing a value, if we are the current round's leader.
+const ACTION_ID_PROPOSE: ActionId = ActionId(0);
+
+/// Messages for rounds further ahead of the current one are dropped.
+const MAX_FUTURE_ROUNDS: RoundId = 10;
+
+type ProtocolOutcomes<I, C> = Vec<ProtocolOutcome<I, C>>;
+
+/// The number of a round within a height.
+type RoundId = u32;
+
+/// The hash of a consensus value.
+type ValueHash<C> = <<C as Context>::ConsensusValue as ConsensusValueT>::Hash;
+
+/// The steps of a round.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+enum Step {
+    /// Waiting for the leader's proposal.
+    Propose,
+    /// Waiting for a quorum of prevotes.
+    Prevote,
+    /// Waiting for a quorum of precommits.
+    Precommit,
+}
+
+/// The content of a signed message.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+enum Content<C: Context> {
+    /// The round leader's proposal. If `valid_round` is set, a quorum prevoted for the same value
+    /// in that earlier round. The `equivocators` are the validators the leader has evidence
+    /// against; they are reported as equivocators if the proposal is finalized.
+    Proposal {
+        value: C::ConsensusValue,
+        timestamp: Timestamp,
+        valid_round: Option<RoundId>,
+        equivocators: Vec<ValidatorIndex>,
+    },
+    /// A vote for the proposed value with the given hash, or for none.
+    Prevote(Option<ValueHash<C>>),
+    /// A vote to finalize the proposed value with the given hash, or none.
+    Precommit(Option<ValueHash<C>>),
+}
+
+/// A proposal or vote, signed by a validator.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct SignedMessage<C: Context> {
+    instance_id: C::InstanceId,
+    height: u64,
+    round: RoundId,
+    creator: ValidatorIndex,
+    content: Content<C>,
+    signature: C::Signature,
+}
+
+impl<C: Context> SignedMessage<C> {
+    /// Creates and signs a new message.
+    fn new(
+        instance_id: C::InstanceId,
+        height: u64,
+        round: RoundId,
+        creator: ValidatorIndex,
+        content: Content<C>,
+        secret: &C::ValidatorSecret,
+        rng: &mut NodeRng,
+    ) -> Self {
+        let hash = Self::hash_fields(&instance_id, height, round, creator, &content);
+        let signature = secret.sign(&hash, rng);
+        SignedMessage {
+            instance_id,
+            height,
+            round,
+            creator,
+            content,
+            signature,
+        }
+    }
+
+    /// Returns whether the signature is valid, given the creator's ID.
+    fn verify_signature(&self, creator_id: &C::ValidatorId) -> bool {
+        let hash = Self::hash_fields(
+            &self.instance_id,
+            self.height,
+            self.round,
+            self.creator,
+            &self.content,
+        );
+        C::verify_signature(&hash, creator_id, &self.signature)
+    }
+
+    /// Returns the hash of the message's fields, which the creator signs.
+    fn hash_fields(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: RoundId,
+        creator: ValidatorIndex,
+        content: &Content<C>,
+    ) -> C::Hash {
+        let data = (instance_id, height, round, creator, content);
+        C::hash(&bincode::serialize(&data).expect("should serialize message"))
+    }
+
+    /// Returns the proposed value and its timestamp, if this is a proposal.
+    fn proposed_value(&self) -> Option<(&C::ConsensusValue, Timestamp)> {
+        match &self.content {
+            Content::Proposal {
+                value, timestamp, ..
+            } => Some((value, *timestamp)),
+            Content::Prevote(_) | Content::Precommit(_) => None,
+        }
+    }
+
+    /// Returns the validators the proposal reports as equivocators, or none if this is a vote.
+    fn equivocators(&self) -> &[ValidatorIndex] {
+        match &self.content {
+            Content::Proposal { equivocators, .. } => equivocators,
+            Content::Prevote(_) | Content::Precommit(_) => &[],
+        }
+    }
+
+    /// Returns whether both messages are of the same kind, and for the same height and round.
+    fn is_same_kind(&self, other: &SignedMessage<C>) -> bool {
+        let same_content_kind = matches!(
+            (&self.content, &other.content),
+            (Content::Proposal { .. }, Content::Proposal { .. })
+                | (Content::Prevote(_), Content::Prevote(_))
+                | (Content::Precommit(_), Content::Precommit(_))
+        );
+        same_content_kind
+            && self.instance_id == other.instance_id
+            && self.height == other.height
+            && self.round == other.round
+    }
+}
+
+/// The messages received in a single round.
+#[derive(DataSize, Debug)]
+struct Round<C: Context> {
+    proposal: Option<SignedMessage<C>>,
+    prevotes: BTreeMap<ValidatorIndex, SignedMessage<C>>,
+    precommits: BTreeMap<ValidatorIndex, SignedMessage<C>>,
+}
+
+impl<C: Context> Round<C> {
+    fn new() -> Self {
+        Round {
+            proposal: None,
+            prevotes: BTreeMap::new(),
+            precommits: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the message of the same kind as `content` by `creator`, if there is one.
+    fn existing(&self, creator: ValidatorIndex, content: &Content<C>) -> Option<&SignedMessage<C>> {
+        match content {
+            Content::Proposal { .. } => self.proposal.as_ref(),
+            Content::Prevote(_) => self.prevotes.get(&creator),
+            Content::Precommit(_) => self.precommits.get(&creator),
+        }
+    }
+
+    /// Adds the message, replacing the existing one of the same kind by the same creator.
+    fn insert(&mut self, msg: SignedMessage<C>) {
+        match msg.content {
+            Content::Proposal { .. } => self.proposal = Some(msg),
+            Content::Prevote(_) => {
+                self.prevotes.insert(msg.creator, msg);
+            }
+            Content::Precommit(_) => {
+                self.precommits.insert(msg.creator, msg);
+            }
+        }
+    }
+
+    /// Returns an iterator over all messages in this round.
+    fn messages(&self) -> impl Iterator<Item = &SignedMessage<C>> {
+        self.proposal
+            .iter()
+            .chain(self.prevotes.values())
+            .chain(self.precommits.values())
+    }
+}
+
+/// A proposal, together with a quorum of precommits for it.
+#[derive(Clone, DataSize, Debug, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct Decision<C: Context> {
+    proposal: SignedMessage<C>,
+    precommits: Vec<SignedMessage<C>>,
+}
+
+impl<C: Context> Decision<C> {
+    fn value(&self) -> &C::ConsensusValue {
+        self.proposal
+            .proposed_value()
+            .expect("decision must contain a proposal")
+            .0
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.proposal
+            .proposed_value()
+            .expect("decision must contain a proposal")
+            .1
+    }
+}
+
+/// Two conflicting messages signed by the same validator.
+#[derive(Clone, DataSize, Debug, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct Equivocation<C: Context> {
+    first: SignedMessage<C>,
+    second: SignedMessage<C>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+enum SimpleBftMessage<C: Context> {
+    /// A proposal or vote.
+    Signed(SignedMessage<C>),
+    /// A proof that a value was finalized.
+    Decision(Decision<C>),
+    /// Evidence that a validator is faulty.
+    Evidence(Equivocation<C>),
+    /// A request for all decisions from the given height on, and all messages for the current one.
+    SyncRequest(u64),
+}
+
+impl<C: Context> SimpleBftMessage<C> {
+    fn serialize(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("should serialize message")
+    }
+}
+
+/// A record in the write-ahead log.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+enum WalEntry<C: Context> {
+    Message(SignedMessage<C>),
+    Decision(Decision<C>),
+    Evidence(Equivocation<C>),
+    /// The value with this hash was validated.
+    ValidValue(ValueHash<C>),
+}
+
+/// An error due to an invalid incoming message.
+#[derive(Debug, Error)]
+enum MessageError {
+    #[error("the message belongs to a different protocol instance")]
+    InstanceId,
+    #[error("the creator is not a validator")]
+    UnknownCreator,
+    #[error("invalid signature")]
+    Signature,
+    #[error("the proposal's creator is not the round leader")]
+    NotLeader,
+    #[error("the proposal's valid round is not earlier than its round")]
+    ValidRound,
+    #[error("the proposal's equivocators are not distinct validators in ascending order")]
+    Equivocators,
+    #[error("the messages are not conflicting")]
+    NotConflicting,
+    #[error("the precommits are not a quorum for the proposal")]
+    NoQuorum,
+}
+
+/// A timeout at the end of a step.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+struct Timeout {
+    height: u64,
+    round: RoundId,
+    deadline: Timestamp,
+}
+
+/// Our own validator index and secret key, if we are an active validator.
+#[derive(DataSize)]
+struct ActiveValidator<C: Context> {
+    idx: ValidatorIndex,
+    secret: C::ValidatorSecret,
+}
+
+impl<C: Context> Debug for ActiveValidator<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActiveValidator")
+            .field("idx", &self.idx)
+            .field("secret", &"<hidden>")
+            .finish()
+    }
+}
+
+#[derive(DataSize, Debug)]
+pub(crate) struct SimpleBftProtocol<I, C>
+where
+    I: DataSize,
+    C: Context,
+{
+    instance_id: C::InstanceId,
+    validators: Validators<C::ValidatorId>,
+    /// The validators' weights. Banned validators have weight 0.
+    weights: ValidatorMap<u64>,
+    /// The validators that are not banned, in the order in which they lead rounds.
+    leaders: Vec<ValidatorIndex>,
+    /// The total weight of all validators that are not banned.
+    total_weight: u64,
+    seed: u64,
+    /// The duration of each step in the first round. Later rounds take proportionally longer.
+    step_duration: TimeDiff,
+    era_start_time: Timestamp,
+    /// The earliest timestamp of the switch block.
+    era_end_time: Timestamp,
+    minimum_era_height: u64,
+    /// The finalized values in this era, with the messages proving their finality.
+    finalized: Vec<Decision<C>>,
+    /// Decisions for future heights, received from peers that are ahead of us.
+    decisions: BTreeMap<u64, Decision<C>>,
+    /// The proposals and votes for the current and the next height, by height and round.
+    rounds: BTreeMap<(u64, RoundId), Round<C>>,
+    round: RoundId,
+    step: Step,
+    /// The latest round in which we precommitted to a value at this height, and that value's hash.
+    locked: Option<(RoundId, ValueHash<C>)>,
+    /// The latest round in which a quorum prevoted for the proposal, and the proposed value's
+    /// hash.
+    valid: Option<(RoundId, ValueHash<C>)>,
+    /// The scheduled step timeouts, by timer ID.
+    timeouts: BTreeMap<u8, Timeout>,
+    /// Values that were validated, or that don't need validation.
+    valid_values: HashSet<ValueHash<C>>,
+    /// Values that failed validation.
+    invalid_values: HashSet<ValueHash<C>>,
+    /// Values for which we requested validation.
+    pending_values: HashSet<ValueHash<C>>,
+    /// Validators known to be faulty, with the evidence if we have it.
+    faults: BTreeMap<ValidatorIndex, Option<Equivocation<C>>>,
+    active_validator: Option<ActiveValidator<C>>,
+    /// The height and round for which we requested a new block to propose.
+    pending_proposal: Option<(u64, RoundId)>,
+    /// The peers we sent a sync request to at the current height.
+    sync_requested_from: HashSet<I>,
+    paused: bool,
+    evidence_only: bool,
+    /// The log of all messages added to the protocol state, used to restore it after a restart.
+    write_ahead_log: Option<WriteAheadLog>,
+}
+
+impl<I: NodeIdT, C: Context + 'static> SimpleBftProtocol<I, C> {
+    /// Creates a new boxed `SimpleBftProtocol` instance.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(crate) fn new_boxed(
+        instance_id: C::InstanceId,
+        validator_stakes: BTreeMap<C::ValidatorId, U512>,
+        slashed: &HashSet<C::ValidatorId>,
+        protocol_config: &ProtocolConfig,
+        _config: &Config,
+        _prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
+        era_start_time: Timestamp,
+        seed: u64,
+        now: Timestamp,
+        write_ahead_log: Option<PathBuf>,
+        rng: &mut NodeRng,
+    ) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
+        let validators = protocols::validators(validator_stakes, slashed);
+        // We use the minimum Highway round length as the step duration in the first round.
+        let min_round_exp = protocol_config.highway_config.minimum_round_exponent;
+        let step_duration = TimeDiff::from(1 << min_round_exp);
+        info!(%step_duration, "initializing simple BFT instance");
+        let (mut sbft_proto, mut outcomes) = SimpleBftProtocol::new(
+            instance_id,
+            validators,
+            seed,
+            step_duration,
+            era_start_time,
+            era_start_time + protocol_config.era_duration,
+            protocol_config.minimum_era_height,
+            now,
+        );
+        if let Some(path) = write_ahead_log {
+            outcomes.extend(sbft_proto.replay_write_ahead_log(WriteAheadLog::new(path), now, rng));
+        }
+        (Box::new(sbft_proto), outcomes)
+    }
+
+    /// Creates a new `SimpleBftProtocol` instance, starting the first round.
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        instance_id: C::InstanceId,
+        validators: Validators<C::ValidatorId>,
+        seed: u64,
+        step_duration: TimeDiff,
+        era_start_time: Timestamp,
+        era_end_time: Timestamp,
+        minimum_era_height: u64,
+        now: Timestamp,
+    ) -> (Self, ProtocolOutcomes<I, C>) {
+        let banned: HashSet<ValidatorIndex> = validators.iter_banned_idx().collect();
+        let weights: ValidatorMap<u64> = validators
+            .enumerate_ids()
+            .zip(validators.iter())
+            .map(|((idx, _), validator)| {
+                if banned.contains(&idx) {
+                    0
+                } else {
+                    validator.weight().0
+                }
+            })
+            .collect();
+        let leaders: Vec<ValidatorIndex> = weights
+            .enumerate()
+            .filter(|(_, weight)| **weight > 0)
+            .map(|(idx, _)| idx)
+            .collect();
+        let total_weight = weights.iter().sum();
+        assert!(total_weight > 0, "cannot start era with total weight 0");
+        let mut sbft_proto = SimpleBftProtocol {
+            instance_id,
+            validators,
+            weights,
+            leaders,
+            total_weight,
+            seed,
+            step_duration,
+            era_start_time,
+            era_end_time,
+            minimum_era_height,
+            finalized: Vec::new(),
+            decisions: BTreeMap::new(),
+            rounds: BTreeMap::new(),
+            round: 0,
+            step: Step::Propose,
+            locked: None,
+            valid: None,
+            timeouts: BTreeMap::new(),
+            valid_values: HashSet::new(),
+            invalid_values: HashSet::new(),
+            pending_values: HashSet::new(),
+            faults: BTreeMap::new(),
+            active_validator: None,
+            pending_proposal: None,
+            sync_requested_from: HashSet::new(),
+            paused: false,
+            evidence_only: false,
+            write_ahead_log: None,
+        };
+        let outcomes = sbft_proto.schedule_timeout(TIMER_ID_PROPOSE, now);
+        (sbft_proto, outcomes)
+    }
+
+    /// Adds all messages from the write-ahead log to the protocol state, and from then on appends
+    /// every new message to it.
+    fn replay_write_ahead_log(
+        &mut self,
+        wal: WriteAheadLog,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let entries: Vec<WalEntry<C>> = wal.read_all().unwrap_or_else(|err| {
+            panic!(
+                "should successfully read write-ahead log {:?}, got {:?}",
+                wal.path(),
+                err
+            )
+        });
+        if !entries.is_empty() {
+            info!(count = entries.len(), path = ?wal.path(), "replaying write-ahead log");
+        }
+        let mut outcomes = Vec::new();
+        for entry in entries {
+            match entry {
+                WalEntry::Message(msg) => {
+                    if msg.height >= self.height() {
+                        outcomes.extend(self.add_message(msg));
+                    }
+                }
+                WalEntry::Decision(decision) => {
+                    if decision.proposal.height >= self.height() {
+                        self.decisions.insert(decision.proposal.height, decision);
+                    }
+                }
+                WalEntry::Evidence(equivocation) => {
+                    outcomes.extend(self.add_evidence(equivocation))
+                }
+                WalEntry::ValidValue(hash) => {
+                    self.valid_values.insert(hash);
+                }
+            }
+            outcomes.extend(self.update(now, rng));
+        }
+        self.write_ahead_log = Some(wal);
+        outcomes
+    }
+
+    /// Appends the entry to the write-ahead log, if there is one.
+    fn log(&mut self, entry: &WalEntry<C>, sync: bool) {
+        if let Some(wal) = self.write_ahead_log.as_mut() {
+            wal.append(entry, sync).unwrap_or_else(|err| {
+                panic!(
+                    "should successfully write entry to {:?}, got {:?}",
+                    wal.path(),
+                    err
+                )
+            });
+        }
+    }
+
+    /// Returns the current height, i.e. the number of values finalized so far in this era.
+    fn height(&self) -> u64 {
+        self.finalized.len() as u64
+    }
+
+    /// Returns the leader of the given round.
+    fn leader(&self, height: u64, round: RoundId) -> ValidatorIndex {
+        let offset = self
+            .seed
+            .wrapping_add(height)
+            .wrapping_add(u64::from(round));
+        self.leaders[(offset % self.leaders.len() as u64) as usize]
+    }
+
+    /// Returns the weight of the given validator.
+    fn weight(&self, idx: ValidatorIndex) -> u64 {
+        *self.weights.get(idx)
+    }
+
+    /// Returns the total weight of the creators of the given messages.
+    fn sum_weight<'a, T>(&self, msgs: T) -> u64
+    where
+        T: IntoIterator<Item = &'a SignedMessage<C>>,
+    {
+        msgs.into_iter().map(|msg| self.weight(msg.creator)).sum()
+    }
+
+    /// Returns whether the weight is more than two thirds of the total weight.
+    fn is_quorum(&self, weight: u64) -> bool {
+        3 * u128::from(weight) > 2 * u128::from(self.total_weight)
+    }
+
+    /// Returns whether the weight is more than one third of the total weight, i.e. includes at
+    /// least one correct validator.
+    fn exceeds_ftt(&self, weight: u64) -> bool {
+        3 * u128::from(weight) > u128::from(self.total_weight)
+    }
+
+    /// Returns the duration of each step in the given round.
+    fn step_duration(&self, round: RoundId) -> TimeDiff {
+        self.step_duration * (u64::from(round) + 1)
+    }
+
+    /// Returns whether the switch block has already been finalized.
+    fn finalized_switch_block(&self) -> bool {
+        self.finalized
+            .last()
+            .map_or(false, |decision| self.is_terminal(decision))
+    }
+
+    /// Returns whether the decision contains the last block of this era.
+    fn is_terminal(&self, decision: &Decision<C>) -> bool {
+        decision.proposal.height + 1 >= self.minimum_era_height
+            && decision.timestamp() >= self.era_end_time
+    }
+
+    /// Returns whether the value with the given hash is valid in a proposal with this timestamp, or
+    /// `None` if that is not known yet.
+    fn validity(&self, hash: &ValueHash<C>, timestamp: Timestamp) -> Option<bool> {
+        let after_last_block = self
+            .finalized
+            .last()
+            .map_or(self.era_start_time, |decision| {
+                decision.timestamp() + TimeDiff::from(1)
+            });
+        if timestamp < after_last_block || self.invalid_values.contains(hash) {
+            Some(false)
+        } else if self.valid_values.contains(hash) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether we have evidence against every validator the proposal reports as an
+    /// equivocator. We only vote for proposals we can check, so that honest validators never
+    /// finalize an accusation that isn't backed by evidence.
+    fn has_evidence_for_equivocators(&self, proposal: &SignedMessage<C>) -> bool {
+        proposal
+            .equivocators()
+            .iter()
+            .all(|idx| matches!(self.faults.get(idx), Some(Some(_))))
+    }
+
+    /// Returns the validators we have evidence against.
+    fn equivocators(&self) -> Vec<ValidatorIndex> {
+        self.faults
+            .iter()
+            .filter(|(_, evidence)| evidence.is_some())
+            .map(|(idx, _)| *idx)
+            .collect()
+    }
+
+    /// Schedules the timeout for the current step.
+    fn schedule_timeout(&mut self, timer_id: TimerId, now: Timestamp) -> ProtocolOutcomes<I, C> {
+        let timeout = Timeout {
+            height: self.height(),
+            round: self.round,
+            deadline: now + self.step_duration(self.round),
+        };
+        self.timeouts.insert(timer_id.0, timeout);
+        vec![ProtocolOutcome::ScheduleTimer(timeout.deadline, timer_id)]
+    }
+
+    /// Returns whether the timeout was already scheduled in the current round.
+    fn is_timeout_scheduled(&self, timer_id: TimerId) -> bool {
+        self.timeouts.get(&timer_id.0).map_or(false, |timeout| {
+            timeout.height == self.height() && timeout.round == self.round
+        })
+    }
+
+    /// Signs, logs and gossips a new message in the current round, unless we are not an active
+    /// validator, or we already created a message of that kind in this round.
+    fn create_message(&mut self, content: Content<C>, rng: &mut NodeRng) -> ProtocolOutcomes<I, C> {
+        let (height, round) = (self.height(), self.round);
+        let av = match &self.active_validator {
+            Some(av) if !self.paused => av,
+            _ => return vec![],
+        };
+        // After a restart, our earlier messages have been restored from the write-ahead log. We
+        // must not contradict them.
+        if let Some(round) = self.rounds.get(&(height, round)) {
+            if round.existing(av.idx, &content).is_some() {
+                return vec![];
+            }
+        }
+        let msg = SignedMessage::new(
+            self.instance_id,
+            height,
+            round,
+            av.idx,
+            content,
+            &av.secret,
+            rng,
+        );
+        // Make sure the message is on disk before we gossip it.
+        self.log(&WalEntry::Message(msg.clone()), true);
+        let serialized = SimpleBftMessage::Signed(msg.clone()).serialize();
+        self.rounds
+            .entry((height, round))
+            .or_insert_with(Round::new)
+            .insert(msg);
+        vec![ProtocolOutcome::CreatedGossipMessage(serialized)]
+    }
+
+    /// Checks the instance ID, creator and signature of a message.
+    fn verify(&self, msg: &SignedMessage<C>) -> Result<(), MessageError> {
+        if msg.instance_id != self.instance_id {
+            return Err(MessageError::InstanceId);
+        }
+        let creator_id = self
+            .validators
+            .id(msg.creator)
+            .ok_or(MessageError::UnknownCreator)?;
+        if !msg.verify_signature(creator_id) {
+            return Err(MessageError::Signature);
+        }
+        if let Content::Proposal {
+            valid_round,
+            equivocators,
+            ..
+        } = &msg.content
+        {
+            if msg.creator != self.leader(msg.height, msg.round) {
+                return Err(MessageError::NotLeader);
+            }
+            if valid_round.map_or(false, |vr| vr >= msg.round) {
+                return Err(MessageError::ValidRound);
+            }
+            if equivocators.windows(2).any(|pair| pair[0] >= pair[1])
+                || equivocators
+                    .iter()
+                    .any(|idx| self.validators.id(*idx).is_none())
+            {
+                return Err(MessageError::Equivocators);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the decision contains a valid proposal and a quorum of precommits for it.
+    fn verify_decision(&self, decision: &Decision<C>) -> Result<(), MessageError> {
+        let proposal = &decision.proposal;
+        self.verify(proposal)?;
+        let hash = match proposal.proposed_value() {
+            Some((value, _)) => value.hash(),
+            None => return Err(MessageError::NoQuorum),
+        };
+        let mut creators = HashSet::new();
+        for precommit in &decision.precommits {
+            self.verify(precommit)?;
+            if precommit.height != proposal.height
+                || precommit.round != proposal.round
+                || precommit.content != Content::Precommit(Some(hash))
+                || !creators.insert(precommit.creator)
+            {
+                return Err(MessageError::NoQuorum);
+            }
+        }
+        let weight = creators.into_iter().map(|idx| self.weight(idx)).sum();
+        if !self.is_quorum(weight) {
+            return Err(MessageError::NoQuorum);
+        }
+        Ok(())
+    }
+
+    /// Requests validation of the proposed value, unless it is already known or requested.
+    fn request_validation(
+        &mut self,
+        sender: I,
+        proposal: &SignedMessage<C>,
+    ) -> ProtocolOutcomes<I, C> {
+        let (value, timestamp) = match proposal.proposed_value() {
+            Some(value_and_timestamp) => value_and_timestamp,
+            None => return vec![],
+        };
+        let hash = value.hash();
+        if self.valid_values.contains(&hash)
+            || self.invalid_values.contains(&hash)
+            || !self.pending_values.insert(hash)
+        {
+            return vec![];
+        }
+        vec![ProtocolOutcome::ValidateConsensusValue(
+            sender,
+            value.clone(),
+            timestamp,
+        )]
+    }
+
+    /// Adds a message with a valid signature to the protocol state, or evidence if it conflicts
+    /// with an earlier message by the same creator.
+    fn add_message(&mut self, msg: SignedMessage<C>) -> ProtocolOutcomes<I, C> {
+        if let Some((value, _)) = msg.proposed_value() {
+            if !value.needs_validation() {
+                self.valid_values.insert(value.hash());
+            }
+        }
+        let existing = self
+            .rounds
+            .get(&(msg.height, msg.round))
+            .and_then(|round| round.existing(msg.creator, &msg.content))
+            .cloned();
+        match existing {
+            Some(existing) if existing == msg => vec![],
+            Some(existing) => self.add_evidence(Equivocation {
+                first: existing,
+                second: msg,
+            }),
+            None => {
+                self.log(&WalEntry::Message(msg.clone()), false);
+                self.rounds
+                    .entry((msg.height, msg.round))
+                    .or_insert_with(Round::new)
+                    .insert(msg);
+                vec![]
+            }
+        }
+    }
+
+    /// Marks the creator of the conflicting messages as faulty, and gossips the evidence.
+    fn add_evidence(&mut self, equivocation: Equivocation<C>) -> ProtocolOutcomes<I, C> {
+        let idx = equivocation.first.creator;
+        if matches!(self.faults.get(&idx), Some(Some(_))) {
+            return vec![];
+        }
+        let vid = match self.validators.id(idx) {
+            Some(vid) => vid.clone(),
+            None => return vec![],
+        };
+        self.log(&WalEntry::Evidence(equivocation.clone()), false);
+        let msg = SimpleBftMessage::Evidence(equivocation.clone());
+        self.faults.insert(idx, Some(equivocation));
+        let mut outcomes = Vec::new();
+        if self.active_validator.as_ref().map(|av| av.idx) == Some(idx) {
+            error!("this validator is faulty");
+            outcomes.push(ProtocolOutcome::WeAreFaulty);
+        }
+        outcomes.push(ProtocolOutcome::NewEvidence(vid));
+        outcomes.push(ProtocolOutcome::CreatedGossipMessage(msg.serialize()));
+        outcomes
+    }
+
+    /// Handles an incoming proposal or vote.
+    fn handle_signed(
+        &mut self,
+        sender: I,
+        msg: SignedMessage<C>,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let height = self.height();
+        if msg.height < height
+            || self.evidence_only
+            || self.finalized_switch_block()
+            || self.faults.contains_key(&msg.creator)
+            || self.weight(msg.creator) == 0
+        {
+            trace!(?msg, "received an irrelevant message");
+            return vec![];
+        }
+        if msg.height > height + 1 {
+            // We are behind: Ask the sender for the decisions we are missing.
+            return self.request_sync(sender);
+        }
+        let max_round = if msg.height == height {
+            self.round.saturating_add(MAX_FUTURE_ROUNDS)
+        } else {
+            MAX_FUTURE_ROUNDS
+        };
+        if msg.round > max_round {
+            trace!(
+                ?msg,
+                "received a message from a round too far in the future"
+            );
+            return vec![];
+        }
+        let mut outcomes = Vec::new();
+        if let Some(our_idx) = self.active_validator.as_ref().map(|av| av.idx) {
+            let is_ours = |round: &Round<C>| round.existing(our_idx, &msg.content) == Some(&msg);
+            if msg.creator == our_idx
+                && !self
+                    .rounds
+                    .get(&(msg.height, msg.round))
+                    .map_or(false, is_ours)
+            {
+                error!(
+                    "received a message from a doppelganger. \
+                     Are you running multiple nodes with the same validator key?",
+                );
+                self.active_validator = None;
+                outcomes.push(ProtocolOutcome::DoppelgangerDetected);
+            }
+        }
+        outcomes.extend(self.request_validation(sender, &msg));
+        outcomes.extend(self.add_message(msg));
+        outcomes.extend(self.update(Timestamp::now(), rng));
+        outcomes
+    }
+
+    /// Handles a decision sent by a peer that is ahead of us.
+    fn handle_decision(
+        &mut self,
+        sender: I,
+        decision: Decision<C>,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let height = decision.proposal.height;
+        if height < self.height()
+            || self.evidence_only
+            || self.finalized_switch_block()
+            || self.decisions.contains_key(&height)
+        {
+            trace!(%height, "received an irrelevant decision");
+            return vec![];
+        }
+        self.log(&WalEntry::Decision(decision.clone()), false);
+        let mut outcomes = self.request_validation(sender, &decision.proposal);
+        if let Some((value, _)) = decision.proposal.proposed_value() {
+            if !value.needs_validation() {
+                self.valid_values.insert(value.hash());
+            }
+        }
+        self.decisions.insert(height, decision);
+        outcomes.extend(self.update(Timestamp::now(), rng));
+        outcomes
+    }
+
+    /// Handles a request for all decisions from `height` on, and our messages at our height.
+    fn handle_sync_request(&mut self, sender: I, height: u64) -> ProtocolOutcomes<I, C> {
+        if self.evidence_only {
+            return vec![];
+        }
+        if height > self.height() {
+            // The sender is ahead of us.
+            return self.request_sync(sender);
+        }
+        let decisions = self.finalized[height as usize..]
+            .iter()
+            .cloned()
+            .map(SimpleBftMessage::Decision);
+        let current = self.height();
+        let messages = self
+            .rounds
+            .range((current, 0)..(current + 1, 0))
+            .flat_map(|(_, round)| round.messages())
+            .cloned()
+            .map(SimpleBftMessage::Signed);
+        decisions
+            .chain(messages)
+            .map(|msg| ProtocolOutcome::CreatedTargetedMessage(msg.serialize(), sender.clone()))
+            .collect()
+    }
+
+    /// Requests the missing decisions from the peer, unless we already did at this height.
+    fn request_sync(&mut self, peer: I) -> ProtocolOutcomes<I, C> {
+        if !self.sync_requested_from.insert(peer.clone()) {
+            return vec![];
+        }
+        let msg = SimpleBftMessage::<C>::SyncRequest(self.height());
+        vec![ProtocolOutcome::CreatedTargetedMessage(
+            msg.serialize(),
+            peer,
+        )]
+    }
+
+    /// Applies the protocol rules until nothing changes anymore.
+    fn update(&mut self, now: Timestamp, rng: &mut NodeRng) -> ProtocolOutcomes<I, C> {
+        let mut outcomes = Vec::new();
+        loop {
+            if self.evidence_only || self.finalized_switch_block() {
+                return outcomes;
+            }
+            if let Some(decision) = self.find_decision() {
+                outcomes.extend(self.finalize(decision, now, rng));
+                continue;
+            }
+            if let Some(round) = self.round_to_skip_to() {
+                outcomes.extend(self.start_round(round, now, rng));
+                continue;
+            }
+            let (progress, step_outcomes) = self.apply_step_rules(now, rng);
+            outcomes.extend(step_outcomes);
+            if !progress {
+                return outcomes;
+            }
+        }
+    }
+
+    /// Returns a valid proposal at the current height with a quorum of precommits, if any.
+    fn find_decision(&mut self) -> Option<Decision<C>> {
+        let height = self.height();
+        if let Some(decision) = self.decisions.get(&height) {
+            let (value, timestamp) = decision.proposal.proposed_value()?;
+            if self.validity(&value.hash(), timestamp) == Some(true) {
+                return self.decisions.remove(&height);
+            }
+        }
+        for round in self
+            .rounds
+            .range((height, 0)..(height + 1, 0))
+            .map(|(_, r)| r)
+        {
+            let proposal = match &round.proposal {
+                Some(proposal) => proposal,
+                None => continue,
+            };
+            let (value, timestamp) = proposal.proposed_value()?;
+            let hash = value.hash();
+            if self.validity(&hash, timestamp) != Some(true) {
+                continue;
+            }
+            let precommits: Vec<_> = round
+                .precommits
+                .values()
+                .filter(|precommit| precommit.content == Content::Precommit(Some(hash)))
+                .cloned()
+                .collect();
+            if self.is_quorum(self.sum_weight(&precommits)) {
+                return Some(Decision {
+                    proposal: proposal.clone(),
+                    precommits,
+                });
+            }
+        }
+        None
+    }
+
+    /// Returns the latest future round at the current height in which validators with more than
+    /// a third of the weight sent messages, if any.
+    fn round_to_skip_to(&self) -> Option<RoundId> {
+        let height = self.height();
+        self.rounds
+            .range((height, self.round + 1)..(height + 1, 0))
+            .rev()
+            .find(|(_, round)| {
+                let creators: HashSet<ValidatorIndex> =
+                    round.messages().map(|msg| msg.creator).collect();
+                self.exceeds_ftt(creators.into_iter().map(|idx| self.weight(idx)).sum())
+            })
+            .map(|((_, round_id), _)| *round_id)
+    }
+
+    /// Returns the total weight of the prevotes in the round that satisfy the predicate.
+    fn prevote_weight<F>(&self, round_id: RoundId, predicate: F) -> u64
+    where
+        F: Fn(&Option<ValueHash<C>>) -> bool,
+    {
+        let round = match self.rounds.get(&(self.height(), round_id)) {
+            Some(round) => round,
+            None => return 0,
+        };
+        self.sum_weight(round.prevotes.values().filter(|msg| match &msg.content {
+            Content::Prevote(vote) => predicate(vote),
+            Content::Proposal { .. } | Content::Precommit(_) => false,
+        }))
+    }
+
+    /// Returns the hash of the current round's proposed value if it is valid and a quorum
+    /// prevoted for it.
+    fn quorum_prevoted_proposal(&self) -> Option<ValueHash<C>> {
+        let round = self.rounds.get(&(self.height(), self.round))?;
+        let proposal = round.proposal.as_ref()?;
+        let (value, timestamp) = proposal.proposed_value()?;
+        let hash = value.hash();
+        if self.validity(&hash, timestamp) != Some(true)
+            || !self.has_evidence_for_equivocators(proposal)
+        {
+            return None;
+        }
+        let weight = self.prevote_weight(self.round, |vote| *vote == Some(hash));
+        if self.is_quorum(weight) {
+            Some(hash)
+        } else {
+            None
+        }
+    }
+
+    /// Returns our prevote for the current round's proposal, or `None` if we can't decide yet.
+    fn proposal_prevote(&self) -> Option<Option<ValueHash<C>>> {
+        let round = self.rounds.get(&(self.height(), self.round))?;
+        let proposal = round.proposal.as_ref()?;
+        let valid_round = match proposal.content {
+            Content::Proposal { valid_round, .. } => valid_round,
+            Content::Prevote(_) | Content::Precommit(_) => return None,
+        };
+        let (value, timestamp) = proposal.proposed_value()?;
+        let hash = value.hash();
+        if !self.validity(&hash, timestamp)? {
+            return Some(None);
+        }
+        if !self.has_evidence_for_equivocators(proposal) {
+            // The leader gossiped the evidence, so we will probably receive it soon.
+            return None;
+        }
+        let vote = match valid_round {
+            None => self.locked.map_or(true, |(_, locked)| locked == hash),
+            Some(vr) => {
+                if !self.is_quorum(self.prevote_weight(vr, |vote| *vote == Some(hash))) {
+                    return None;
+                }
+                self.locked
+                    .map_or(true, |(lr, locked)| lr <= vr || locked == hash)
+            }
+        };
+        Some(if vote { Some(hash) } else { None })
+    }
+
+    /// Applies the rules for the current step. Returns `true` if the step changed.
+    fn apply_step_rules(
+        &mut self,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> (bool, ProtocolOutcomes<I, C>) {
+        let mut outcomes = Vec::new();
+        match self.step {
+            Step::Propose => {
+                if let Some(vote) = self.proposal_prevote() {
+                    self.step = Step::Prevote;
+                    outcomes.extend(self.create_message(Content::Prevote(vote), rng));
+                    return (true, outcomes);
+                }
+            }
+            Step::Prevote => {
+                if let Some(hash) = self.quorum_prevoted_proposal() {
+                    self.locked = Some((self.round, hash));
+                    self.valid = Some((self.round, hash));
+                    self.step = Step::Precommit;
+                    outcomes.extend(self.create_message(Content::Precommit(Some(hash)), rng));
+                    return (true, outcomes);
+                }
+                if self.is_quorum(self.prevote_weight(self.round, Option::is_none)) {
+                    self.step = Step::Precommit;
+                    outcomes.extend(self.create_message(Content::Precommit(None), rng));
+                    return (true, outcomes);
+                }
+                if !self.is_timeout_scheduled(TIMER_ID_PREVOTE)
+                    && self.is_quorum(self.prevote_weight(self.round, |_| true))
+                {
+                    outcomes.extend(self.schedule_timeout(TIMER_ID_PREVOTE, now));
+                }
+            }
+            Step::Precommit => {
+                if let Some(hash) = self.quorum_prevoted_proposal() {
+                    self.valid = Some((self.round, hash));
+                }
+            }
+        }
+        let precommit_weight = self
+            .rounds
+            .get(&(self.height(), self.round))
+            .map_or(0, |round| self.sum_weight(round.precommits.values()));
+        if !self.is_timeout_scheduled(TIMER_ID_PRECOMMIT) && self.is_quorum(precommit_weight) {
+            outcomes.extend(self.schedule_timeout(TIMER_ID_PRECOMMIT, now));
+        }
+        (false, outcomes)
+    }
+
+    /// Starts the given round at the current height.
+    fn start_round(
+        &mut self,
+        round: RoundId,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        trace!(height = %self.height(), %round, "starting round");
+        self.round = round;
+        self.step = Step::Propose;
+        let mut outcomes = self.schedule_timeout(TIMER_ID_PROPOSE, now);
+        outcomes.extend(self.propose_if_leader(now, rng));
+        outcomes
+    }
+
+    /// Proposes a value if we are the current round's leader: the valid value if there is one,
+    /// otherwise a new block.
+    fn propose_if_leader(&mut self, now: Timestamp, rng: &mut NodeRng) -> ProtocolOutcomes<I, C> {
+        let (height, round) = (self.height(), self.round);
+        match &self.active_validator {
+            Some(av) if !self.paused && self.leader(height, round) == av.idx => (),
+            _ => return vec![],
+        }
+        if self.step != Step::Propose
+            || self.pending_proposal == Some((height, round))
+            || self
+                .rounds
+                .get(&(height, round))
+                .map_or(false, |round| round.proposal.is_some())
+        {
+            return vec![];
+        }
+        if let Some((valid_round, hash)) = self.valid {
+            let valid_proposal = self
+                .rounds
+                .get(&(height, valid_round))
+                .and_then(|round| round.proposal.as_ref())
+                .and_then(SignedMessage::proposed_value)
+                .filter(|(value, _)| value.hash() == hash)
+                .map(|(value, timestamp)| (value.clone(), timestamp));
+            if let Some((value, timestamp)) = valid_proposal {
+                let content = Content::Proposal {
+                    value,
+                    timestamp,
+                    valid_round: Some(valid_round),
+                    equivocators: self.equivocators(),
+                };
+                return self.create_message(content, rng);
+            }
+        }
+        let earliest = self
+            .finalized
+            .last()
+            .map_or(self.era_start_time, |decision| {
+                decision.timestamp() + TimeDiff::from(1)
+            });
+        self.pending_proposal = Some((height, round));
+        // All earlier values in this era are already finalized.
+        vec![ProtocolOutcome::CreateNewBlock {
+            block_context: BlockContext::new(now.max(earliest), height),
+            past_values: vec![],
+        }]
+    }
+
+    /// Finalizes the decision's value and starts the next height.
+    fn finalize(
+        &mut self,
+        decision: Decision<C>,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let height = self.height();
+        let terminal_block_data = if self.is_terminal(&decision) {
+            Some(self.terminal_block_data(height + 1))
+        } else {
+            None
+        };
+        let proposer = self
+            .validators
+            .id(decision.proposal.creator)
+            .expect("proposer must be a validator")
+            .clone();
+        // The equivocators are part of the finalized proposal, so all validators agree on them.
+        let equivocators = decision
+            .proposal
+            .equivocators()
+            .iter()
+            .filter_map(|idx| self.validators.id(*idx))
+            .cloned()
+            .collect();
+        let finalized_block = FinalizedBlock {
+            value: decision.value().clone(),
+            timestamp: decision.timestamp(),
+            height,
+            equivocators,
+            terminal_block_data,
+            proposer,
+        };
+        trace!(%height, "finalized a value");
+        self.finalized.push(decision);
+        // Keep only the messages for the new height and the one after it.
+        self.rounds = self.rounds.split_off(&(height + 1, 0));
+        self.decisions = self.decisions.split_off(&(height + 1));
+        self.locked = None;
+        self.valid = None;
+        self.sync_requested_from.clear();
+        let mut outcomes = vec![ProtocolOutcome::FinalizedBlock(finalized_block)];
+        if !self.finalized_switch_block() {
+            outcomes.extend(self.start_round(0, now, rng));
+        }
+        outcomes
+    }
+
+    /// Returns the rewards for an era with the given number of blocks: Every validator that is not
+    /// banned gets a share of each block reward proportional to their weight.
+    fn terminal_block_data(&self, block_count: u64) -> TerminalBlockData<C> {
+        let rewards = self
+            .leaders
+            .iter()
+            .filter_map(|idx| {
+                let vid = self.validators.id(*idx)?.clone();
+                let reward = u128::from(BLOCK_REWARD) * u128::from(self.weight(*idx))
+                    / u128::from(self.total_weight)
+                    * u128::from(block_count);
+                Some((vid, reward as u64))
+            })
+            .collect();
+        TerminalBlockData {
+            rewards,
+            inactive_validators: vec![],
+        }
+    }
+}
+
+impl<I, C> ConsensusProtocol<I, C> for SimpleBftProtocol<I, C>
+where
+    I: NodeIdT,
+    C: Context + 'static,
+{
+    fn handle_message(
+        &mut self,
+        sender: I,
+        msg: Vec<u8>,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let result = match bincode::deserialize(msg.as_slice()) {
+            Err(err) => Err(err.into()),
+            Ok(SimpleBftMessage::Signed(signed)) => self
+                .verify(&signed)
+                .map(|()| self.handle_signed(sender.clone(), signed, rng))
+                .map_err(Into::into),
+            Ok(SimpleBftMessage::Decision(decision)) => self
+                .verify_decision(&decision)
+                .map(|()| self.handle_decision(sender.clone(), decision, rng))
+                .map_err(Into::into),
+            Ok(SimpleBftMessage::Evidence(equivocation)) => {
+                let Equivocation { first, second } = &equivocation;
+                if !first.is_same_kind(second)
+                    || first.creator != second.creator
+                    || first.content == second.content
+                {
+                    Err(MessageError::NotConflicting.into())
+                } else {
+                    self.verify(first)
+                        .and_then(|()| self.verify(second))
+                        .map(|()| self.add_evidence(equivocation))
+                        .map_err(Into::into)
+                }
+            }
+            Ok(SimpleBftMessage::SyncRequest(height)) => {
+                Ok(self.handle_sync_request(sender.clone(), height))
+            }
+        };
+        result.unwrap_or_else(|err: anyhow::Error| {
+            vec![ProtocolOutcome::InvalidIncomingMessage(msg, sender, err)]
+        })
+    }
+
+    fn handle_new_peer(&mut self, peer_id: I) -> ProtocolOutcomes<I, C> {
+        trace!(?peer_id, "connected to a new peer");
+        if self.evidence_only {
+            return vec![];
+        }
+        let msg = SimpleBftMessage::<C>::SyncRequest(self.height());
+        vec![ProtocolOutcome::CreatedTargetedMessage(
+            msg.serialize(),
+            peer_id,
+        )]
+    }
+
+    fn handle_timer(
+        &mut self,
+        timestamp: Timestamp,
+        timer_id: TimerId,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let is_due = self.is_timeout_scheduled(timer_id)
+            && self
+                .timeouts
+                .get(&timer_id.0)
+                .map_or(false, |timeout| timeout.deadline <= timestamp);
+        if !is_due || self.evidence_only || self.finalized_switch_block() {
+            return vec![];
+        }
+        let mut outcomes = match timer_id {
+            TIMER_ID_PROPOSE if self.step == Step::Propose => {
+                self.step = Step::Prevote;
+                self.create_message(Content::Prevote(None), rng)
+            }
+            TIMER_ID_PREVOTE if self.step == Step::Prevote => {
+                self.step = Step::Precommit;
+                self.create_message(Content::Precommit(None), rng)
+            }
+            TIMER_ID_PRECOMMIT => self.start_round(self.round + 1, timestamp, rng),
+            TIMER_ID_PROPOSE | TIMER_ID_PREVOTE => vec![],
+            _ => unreachable!("unexpected timer ID"),
+        };
+        outcomes.extend(self.update(timestamp, rng));
+        outcomes
+    }
+
+    fn handle_action(&mut self, action_id: ActionId, rng: &mut NodeRng) -> ProtocolOutcomes<I, C> {
+        match action_id {
+            ACTION_ID_PROPOSE => {
+                let now = Timestamp::now();
+                let mut outcomes = self.propose_if_leader(now, rng);
+                outcomes.extend(self.update(now, rng));
+                outcomes
+            }
+            _ => unreachable!("unexpected action ID"),
+        }
+    }
+
+    fn propose(
+        &mut self,
+        value: C::ConsensusValue,
+        block_context: BlockContext,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let current = (self.height(), self.round);
+        if self.pending_proposal.take() != Some(current) || self.step != Step::Propose {
+            warn!(?value, "proposal is too late; dropping");
+            return vec![];
+        }
+        self.valid_values.insert(value.hash());
+        let content = Content::Proposal {
+            value,
+            timestamp: block_context.timestamp(),
+            valid_round: None,
+            equivocators: self.equivocators(),
+        };
+        let mut outcomes = self.create_message(content, rng);
+        outcomes.extend(self.update(Timestamp::now(), rng));
+        outcomes
+    }
+
+    fn resolve_validity(
+        &mut self,
+        value: &C::ConsensusValue,
+        valid: bool,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let hash = value.hash();
+        self.pending_values.remove(&hash);
+        if valid {
+            if self.valid_values.insert(hash) {
+                self.log(&WalEntry::ValidValue(hash), false);
+            }
+        } else {
+            // We don't disconnect from the sender or consider the proposer faulty here: The block
+            // validator considers the value "invalid" even if it just couldn't download the
+            // deploys, which could just be because the original sender went offline.
+            warn!(?value, "consensus value is invalid");
+            self.invalid_values.insert(hash);
+        }
+        self.update(Timestamp::now(), rng)
+    }
+
+    fn activate_validator(
+        &mut self,
+        our_id: C::ValidatorId,
+        secret: C::ValidatorSecret,
+        _timestamp: Timestamp,
+        _unit_hash_file: Option<PathBuf>,
+    ) -> ProtocolOutcomes<I, C> {
+        let idx = match self.validators.get_index(&our_id) {
+            Some(idx) => idx,
+            None => {
+                warn!(?our_id, "we are not a validator in this era");
+                return vec![];
+            }
+        };
+        self.active_validator = Some(ActiveValidator { idx, secret });
+        vec![ProtocolOutcome::QueueAction(ACTION_ID_PROPOSE)]
+    }
+
+    fn deactivate_validator(&mut self) {
+        self.active_validator = None;
+    }
+
+    fn set_evidence_only(&mut self) {
+        self.finalized.clear();
+        self.decisions.clear();
+        self.rounds.clear();
+        self.timeouts.clear();
+        self.valid_values.clear();
+        self.invalid_values.clear();
+        self.pending_values.clear();
+        self.evidence_only = true;
+    }
+
+    fn has_evidence(&self, vid: &C::ValidatorId) -> bool {
+        self.validators
+            .get_index(vid)
+            .map_or(false, |idx| matches!(self.faults.get(&idx), Some(Some(_))))
+    }
+
+    fn mark_faulty(&mut self, vid: &C::ValidatorId) {
+        if let Some(idx) = self.validators.get_index(vid) {
+            self.faults.entry(idx).or_insert(None);
+        }
+    }
+
+    fn request_evidence(&self, sender: I, vid: &C::ValidatorId) -> ProtocolOutcomes<I, C> {
+        self.validators
+            .get_index(vid)
+            .and_then(|idx| self.faults.get(&idx))
+            .and_then(Option::as_ref)
+            .map(|equivocation| {
+                let msg = SimpleBftMessage::Evidence(equivocation.clone());
+                ProtocolOutcome::CreatedTargetedMessage(msg.serialize(), sender)
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    fn validators_with_evidence(&self) -> Vec<&C::ValidatorId> {
+        self.equivocators()
+            .into_iter()
+            .filter_map(|idx| self.validators.id(idx))
+            .collect()
+    }
+
+    fn has_received_messages(&self) -> bool {
+        !self.rounds.is_empty()
+            || !self.finalized.is_empty()
+            || !self.decisions.is_empty()
+            || self.faults.values().any(Option::is_some)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_active(&self) -> bool {
+        self.active_validator.is_some()
+    }
+
+    fn instance_id(&self) -> &C::InstanceId {
+        &self.instance_id
+    }
+
+    fn recreate_timers(&self) -> Vec<ProtocolOutcome<I, C>> {
+        let timer_ids = [TIMER_ID_PROPOSE, TIMER_ID_PREVOTE, TIMER_ID_PRECOMMIT];
+        let mut outcomes: ProtocolOutcomes<I, C> = timer_ids
+            .iter()
+            .filter(|timer_id| self.is_timeout_scheduled(**timer_id))
+            .filter_map(|timer_id| {
+                let timeout = self.timeouts.get(&timer_id.0)?;
+                Some(ProtocolOutcome::ScheduleTimer(timeout.deadline, *timer_id))
+            })
+            .collect();
+        if self.is_active() {
+            outcomes.push(ProtocolOutcome::QueueAction(ACTION_ID_PROPOSE));
+        }
+        outcomes
+    }
+
+    fn next_round_length(&self) -> Option<TimeDiff> {
+        self.active_validator
+            .as_ref()
+            .map(|_| self.step_duration(self.round) * 3)
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    components::consensus::{
+        consensus_protocol::{ConsensusProtocol, ProtocolOutcome},
+        highway_core::{
+            highway_testing::{TestContext, TestSecret, TEST_INSTANCE_ID},
+            validators::{ValidatorIndex, Validators},
+        },
+        tests::consensus_des_testing::{
+            Message, Node, Target, TargetedMessage, ValidatorId, VirtualNet,
+        },
+        ActionId, TimerId,
+    },
+    testing::TestRng,
+    types::{TimeDiff, Timestamp},
+};
+
+use super::{Content, Equivocation, SignedMessage, SimpleBftMessage, SimpleBftProtocol};
+
+type SbftProtocol = SimpleBftProtocol<ValidatorId, TestContext>;
+type ConsensusValue = Vec<u8>;
+
+/// The number of blocks in each test era.
+const ERA_HEIGHT: u64 = 5;
+
+/// The maximum number of messages to deliver before a test run is considered stuck.
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum TestMessage {
+    /// A serialized protocol message from another validator.
+    Protocol(Vec<u8>),
+    /// A timer that fires.
+    Timer(u8),
+    /// A queued action.
+    Action(u8),
+}
+
+/// Returns a new protocol instance for four validators with equal weights, and its outcomes.
+fn new_protocol(
+    start: Timestamp,
+) -> (SbftProtocol, Vec<ProtocolOutcome<ValidatorId, TestContext>>) {
+    let validators: Validators<ValidatorId> = (0..4).map(|id| (ValidatorId(id), 10u64)).collect();
+    SimpleBftProtocol::new(
+        TEST_INSTANCE_ID,
+        validators,
+        0,
+        TimeDiff::from(1000),
+        start,
+        start,
+        ERA_HEIGHT,
+        start,
+    )
+}
+
+/// A network of simple BFT validators, running in the DES test harness.
+struct TestNet {
+    virtual_net: VirtualNet<ConsensusValue, TestMessage, SbftProtocol>,
+    /// Validators whose outgoing messages are dropped.
+    mute: HashSet<ValidatorId>,
+    /// Validators that emitted a terminal block.
+    terminal: HashSet<ValidatorId>,
+    /// The equivocators every finalized block is expected to report.
+    equivocators: Vec<ValidatorId>,
+    /// The era's start time.
+    start: Timestamp,
+    rng: TestRng,
+}
+
+impl TestNet {
+    fn new(mute: HashSet<ValidatorId>) -> Self {
+        let start = Timestamp::now();
+        let mut nodes = Vec::new();
+        let mut init_outcomes = Vec::new();
+        for id in (0..4).map(ValidatorId) {
+            let (mut sbft, mut outcomes) = new_protocol(start);
+            outcomes.extend(sbft.activate_validator(id, TestSecret(id.0), start, None));
+            init_outcomes.push((id, outcomes));
+            nodes.push(Node::new(id, sbft));
+        }
+        let mut test_net = TestNet {
+            virtual_net: VirtualNet::new(nodes, vec![]),
+            mute,
+            terminal: HashSet::new(),
+            equivocators: vec![],
+            start,
+            rng: TestRng::new(),
+        };
+        for (id, outcomes) in init_outcomes {
+            test_net.process_outcomes(id, start, outcomes);
+        }
+        test_net
+    }
+
+    /// Delivers messages until the queue is empty.
+    fn run(&mut self) {
+        for _ in 0..MAX_STEPS {
+            let entry = match self.virtual_net.pop_message() {
+                Some(entry) => entry,
+                None => return,
+            };
+            let now = entry.delivery_time;
+            let recipient = entry.recipient;
+            let Message { sender, payload } = entry.message;
+            let sbft = self
+                .virtual_net
+                .node_mut(&recipient)
+                .expect("recipient should exist")
+                .validator_mut();
+            let outcomes = match payload {
+                TestMessage::Protocol(msg) => sbft.handle_message(sender, msg, &mut self.rng),
+                TestMessage::Timer(id) => sbft.handle_timer(now, TimerId(id), &mut self.rng),
+                TestMessage::Action(id) => sbft.handle_action(ActionId(id), &mut self.rng),
+            };
+            self.process_outcomes(recipient, now, outcomes);
+        }
+        panic!("test network did not terminate after {} steps", MAX_STEPS);
+    }
+
+    /// Handles the outcomes of a protocol instance: sends messages, schedules timers, proposes
+    /// and validates values, and records finalized values.
+    fn process_outcomes(
+        &mut self,
+        id: ValidatorId,
+        now: Timestamp,
+        outcomes: Vec<ProtocolOutcome<ValidatorId, TestContext>>,
+    ) {
+        let delivery_time = now + TimeDiff::from(10);
+        let mut queue: VecDeque<_> = outcomes.into();
+        let mut messages = Vec::new();
+        while let Some(outcome) = queue.pop_front() {
+            let node = self.virtual_net.node_mut(&id).expect("node should exist");
+            match outcome {
+                ProtocolOutcome::CreatedGossipMessage(msg) => {
+                    if !self.mute.contains(&id) {
+                        let message = Message::new(id, TestMessage::Protocol(msg));
+                        let target = Target::AllExcept(id);
+                        messages.push((TargetedMessage::new(message, target), delivery_time));
+                    }
+                }
+                ProtocolOutcome::CreatedTargetedMessage(msg, recipient) => {
+                    if !self.mute.contains(&id) {
+                        let message = Message::new(id, TestMessage::Protocol(msg));
+                        let target = Target::SingleValidator(recipient);
+                        messages.push((TargetedMessage::new(message, target), delivery_time));
+                    }
+                }
+                ProtocolOutcome::ScheduleTimer(timestamp, timer_id) => {
+                    let message = Message::new(id, TestMessage::Timer(timer_id.0));
+                    let target = Target::SingleValidator(id);
+                    messages.push((TargetedMessage::new(message, target), timestamp));
+                }
+                ProtocolOutcome::QueueAction(action_id) => {
+                    let message = Message::new(id, TestMessage::Action(action_id.0));
+                    let target = Target::SingleValidator(id);
+                    messages.push((TargetedMessage::new(message, target), now));
+                }
+                ProtocolOutcome::CreateNewBlock { block_context, .. } => {
+                    let value = vec![id.0 as u8, block_context.height() as u8];
+                    let sbft = node.validator_mut();
+                    queue.extend(sbft.propose(value, block_context, &mut self.rng));
+                }
+                ProtocolOutcome::ValidateConsensusValue(_, value, _) => {
+                    let sbft = node.validator_mut();
+                    queue.extend(sbft.resolve_validity(&value, true, &mut self.rng));
+                }
+                ProtocolOutcome::FinalizedBlock(finalized_block) => {
+                    assert_eq!(finalized_block.height, node.finalized_count() as u64);
+                    assert_eq!(self.equivocators, finalized_block.equivocators);
+                    if let Some(tbd) = finalized_block.terminal_block_data {
+                        assert_eq!(4, tbd.rewards.len());
+                        self.terminal.insert(id);
+                    }
+                    node.push_finalized(finalized_block.value);
+                }
+                ProtocolOutcome::InvalidIncomingMessage(_, sender, err) => {
+                    panic!("invalid message from {}: {:?}", sender, err)
+                }
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            }
+        }
+        self.virtual_net.dispatch_messages(messages);
+    }
+
+    /// Asserts that every node finalized the same values, ending with a terminal block.
+    fn assert_all_finalized(&self) {
+        let expected: Vec<_> = self
+            .virtual_net
+            .validator(&ValidatorId(0))
+            .expect("node should exist")
+            .finalized_values()
+            .cloned()
+            .collect();
+        assert_eq!(ERA_HEIGHT as usize, expected.len());
+        for node in self.virtual_net.validators() {
+            assert!(node.finalized_values().eq(&expected));
+            assert!(self.terminal.contains(&node.id));
+        }
+    }
+}
+
+#[test]
+fn honest_validators_finalize_the_same_values() {
+    let mut test_net = TestNet::new(HashSet::new());
+    test_net.run();
+    test_net.assert_all_finalized();
+}
+
+#[test]
+fn finalize_despite_a_mute_leader() {
+    // With seed 0, validator 0 leads the first round.
+    let mut test_net = TestNet::new(vec![ValidatorId(0)].into_iter().collect());
+    test_net.run();
+    test_net.assert_all_finalized();
+    // None of the mute validator's proposals can have been finalized.
+    let node = test_net.virtual_net.validator(&ValidatorId(1)).unwrap();
+    assert!(node.finalized_values().all(|value| value[0] != 0));
+}
+
+#[test]
+fn finalized_blocks_report_equivocators() {
+    // Validator 3 is mute, and all others have evidence that it equivocated.
+    let faulty = ValidatorId(3);
+    let mut test_net = TestNet::new(vec![faulty].into_iter().collect());
+    let mut rng = TestRng::new();
+    let prevote = |vote: Option<u64>, rng: &mut TestRng| {
+        SignedMessage::<TestContext>::new(
+            TEST_INSTANCE_ID,
+            0,
+            0,
+            ValidatorIndex(3),
+            Content::Prevote(vote),
+            &TestSecret(faulty.0),
+            rng,
+        )
+    };
+    let equivocation = Equivocation {
+        first: prevote(Some(1), &mut rng),
+        second: prevote(None, &mut rng),
+    };
+    for id in (0..3).map(ValidatorId) {
+        let node = test_net.virtual_net.node_mut(&id).unwrap();
+        let _ = node.validator_mut().add_evidence(equivocation.clone());
+    }
+    test_net.equivocators = vec![faulty];
+    test_net.run();
+    test_net.assert_all_finalized();
+}
+
+#[test]
+fn catch_up_with_sync_request() {
+    let mut test_net = TestNet::new(HashSet::new());
+    test_net.run();
+
+    // A new, passive instance asks a peer for everything from height 0.
+    let mut rng = TestRng::new();
+    let (mut sbft, _) = new_protocol(test_net.start);
+    let sender = ValidatorId(1);
+    let request = SimpleBftMessage::<TestContext>::SyncRequest(0).serialize();
+    let node = test_net.virtual_net.node_mut(&sender).unwrap();
+    let responses = node
+        .validator_mut()
+        .handle_message(ValidatorId(0), request, &mut rng);
+    assert_eq!(ERA_HEIGHT as usize, responses.len());
+
+    let mut finalized = Vec::new();
+    let mut queue: VecDeque<_> = responses.into();
+    while let Some(outcome) = queue.pop_front() {
+        match outcome {
+            ProtocolOutcome::CreatedTargetedMessage(msg, _) => {
+                queue.extend(sbft.handle_message(sender, msg, &mut rng))
+            }
+            ProtocolOutcome::ValidateConsensusValue(_, value, _) => {
+                queue.extend(sbft.resolve_validity(&value, true, &mut rng))
+            }
+            ProtocolOutcome::FinalizedBlock(finalized_block) => {
+                finalized.push(finalized_block.value)
+            }
+            ProtocolOutcome::ScheduleTimer(..) => {}
+            outcome => panic!("unexpected outcome {:?}", outcome),
+        }
+    }
+    let expected: Vec<_> = node.finalized_values().cloned().collect();
+    assert_eq!(expected, finalized);
+}
+
+#[test]
+fn detect_equivocation() {
+    let mut rng = TestRng::new();
+    let start = Timestamp::now();
+    let (mut sbft, _) = new_protocol(start);
+    let (mut other_sbft, _) = new_protocol(start);
+    let faulty = ValidatorId(2);
+
+    let prevote = |vote: Option<u64>, rng: &mut TestRng| {
+        let content = Content::Prevote(vote);
+        let msg = SignedMessage::<TestContext>::new(
+            TEST_INSTANCE_ID,
+            0,
+            0,
+            ValidatorIndex(2),
+            content,
+            &TestSecret(faulty.0),
+            rng,
+        );
+        SimpleBftMessage::Signed(msg).serialize()
+    };
+
+    let outcomes = sbft.handle_message(faulty, prevote(Some(1), &mut rng), &mut rng);
+    assert!(outcomes.is_empty(), "unexpected outcomes: {:?}", outcomes);
+    assert!(!sbft.has_evidence(&faulty));
+
+    // A conflicting prevote in the same round is evidence against the validator.
+    let outcomes = sbft.handle_message(faulty, prevote(None, &mut rng), &mut rng);
+    let evidence_msg = match outcomes.as_slice() {
+        [ProtocolOutcome::NewEvidence(vid), ProtocolOutcome::CreatedGossipMessage(msg)]
+            if *vid == faulty =>
+        {
+            msg.clone()
+        }
+        _ => panic!("unexpected outcomes: {:?}", outcomes),
+    };
+    assert!(sbft.has_evidence(&faulty));
+    assert_eq!(vec![&faulty], sbft.validators_with_evidence());
+    assert_eq!(1, sbft.request_evidence(ValidatorId(3), &faulty).len());
+
+    // The evidence convinces other instances, too.
+    let outcomes = other_sbft.handle_message(ValidatorId(3), evidence_msg, &mut rng);
+    assert!(matches!(
+        outcomes.first(),
+        Some(ProtocolOutcome::NewEvidence(vid)) if *vid == faulty
+    ));
+    assert!(other_sbft.has_evidence(&faulty));
+
+    // Messages by the faulty validator are ignored from now on.
+    let outcomes = other_sbft.handle_message(faulty, prevote(Some(2), &mut rng), &mut rng);
+    assert!(outcomes.is_empty(), "unexpected outcomes: {:?}", outcomes);
+}
@@ -0,0 +1,93 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use datasize::DataSize;
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::warn;
+
+/// An append-only file of records, used to persist the messages of a consensus protocol instance.
+///
+/// Records are only ever appended after all their dependencies, so reading the log front to back
+/// and adding each record in turn restores the protocol state.
+#[derive(DataSize, Debug)]
+pub(crate) struct WriteAheadLog {
+    /// The path to the log file.
+    path: PathBuf,
+    /// The open file handle, if we have already appended to the log.
+    #[data_size(skip)]
+    file: Option<File>,
+}
+
+impl WriteAheadLog {
+    /// Creates a new write-ahead log at the given path. The file is created on the first append.
+    pub(crate) fn new(path: PathBuf) -> Self {
+        WriteAheadLog { path, file: None }
+    }
+
+    /// Returns the path to the log file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads all records from the log, in the order in which they were appended.
+    ///
+    /// If the log ends in an incomplete or corrupt record, e.g. because the node crashed while
+    /// writing it, that record and everything after it is discarded and truncated from the file.
+    pub(crate) fn read_all<T: DeserializeOwned>(&self) -> io::Result<Vec<T>> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut remaining = bytes.as_slice();
+        let mut records = Vec::new();
+        while !remaining.is_empty() {
+            let valid_len = bytes.len() - remaining.len();
+            match bincode::deserialize_from(&mut remaining) {
+                Ok(record) => records.push(record),
+                Err(err) => {
+                    warn!(
+                        path = ?self.path, %valid_len, %err,
+                        "truncating invalid record at the end of the write-ahead log"
+                    );
+                    OpenOptions::new()
+                        .write(true)
+                        .open(&self.path)?
+                        .set_len(valid_len as u64)?;
+                    break;
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Appends a record to the log.
+    ///
+    /// If `sync` is `true`, this only returns once the record has been written to disk.
+    pub(crate) fn append<T: Serialize>(&mut self, record: &T, sync: bool) -> io::Result<()> {
+        let bytes = bincode::serialize(record)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let file = match self.file.as_mut() {
+            Some(file) => file,
+            None => {
+                // Create the file (and its parents) as necessary.
+                if let Some(parent_directory) = self.path.parent() {
+                    fs::create_dir_all(parent_directory)?;
+                }
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)?;
+                self.file.get_or_insert(file)
+            }
+        };
+        file.write_all(&bytes)?;
+        if sync {
+            file.sync_data()?;
+        }
+        Ok(())
+    }
+}
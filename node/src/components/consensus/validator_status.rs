@@ -0,0 +1,57 @@
+//! A summary of our own validator's performance in an era, for monitoring.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rpcs::docs::DocExample;
+
+static VALIDATOR_STATUS: Lazy<ValidatorStatus> = Lazy::new(|| ValidatorStatus {
+    era_id: 1,
+    active: true,
+    faulty: false,
+    round_exponent: Some(16),
+    recent_rounds: 40,
+    failed_rounds: 2,
+    units_produced: 312,
+    endorsements: 0,
+    proposals: 9,
+    finalized_proposals: 8,
+});
+
+/// Our own validator's performance in an era, as seen by the consensus protocol.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorStatus {
+    /// The era these statistics refer to.
+    pub era_id: u64,
+    /// Whether we are currently participating as an active validator in this era.
+    pub active: bool,
+    /// Whether we have been observed to be faulty in this era.
+    pub faulty: bool,
+    /// The round exponent we will use for our next round, if we are active: rounds are
+    /// `2^round_exponent` milliseconds long.
+    pub round_exponent: Option<u8>,
+    /// The number of recent rounds considered when adjusting the round exponent.
+    pub recent_rounds: u32,
+    /// The number of those recent rounds in which none of the proposals were finalized in time.
+    pub failed_rounds: u32,
+    /// The number of units we created in this era.
+    pub units_produced: u64,
+    /// The number of units we endorsed in this era.
+    pub endorsements: u64,
+    /// The number of blocks we proposed in this era.
+    pub proposals: u64,
+    /// The number of blocks we proposed that have been finalized.
+    pub finalized_proposals: u64,
+}
+
+impl DocExample for ValidatorStatus {
+    fn doc_example() -> &'static Self {
+        &*VALIDATOR_STATUS
+    }
+}
@@ -1,23 +1,26 @@
 mod config;
 mod event;
+#[cfg(test)]
+mod tests;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{collections::BTreeSet, convert::Infallible, fmt::Debug};
 
 use thiserror::Error;
 use tracing::{debug, error, info};
 
 use crate::{
-    components::Component,
+    components::{block_proposer::AdmissionError, Component},
     effect::{
         announcements::DeployAcceptorAnnouncement,
-        requests::{ContractRuntimeRequest, StorageRequest},
+        requests::{BlockProposerRequest, ContractRuntimeRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
     types::{chainspec::DeployConfig, Chainspec, Deploy, DeployValidationFailure, NodeId},
     utils::Source,
     NodeRng,
 };
-use casper_types::Key;
+use casper_execution_engine::shared::account::Account;
+use casper_types::{account::AccountHash, Key};
 
 use crate::effect::Responder;
 pub use config::Config;
@@ -34,6 +37,111 @@ pub enum Error {
     /// A deploy was sent from account with insufficient balance.
     #[error("insufficient balance")]
     InsufficientBalance,
+    /// The deploy is approved by keys that are not associated with the account.
+    #[error("deploy approved by keys not associated with the account")]
+    UnauthorizedApprovals,
+    /// The approvals' combined weight doesn't meet the account's deployment threshold.
+    #[error(
+        "deploy approvals have a total weight of {approvals_weight}, but the account's deployment \
+        threshold is {deployment_threshold}"
+    )]
+    InsufficientApprovalWeight {
+        /// The combined weight of the keys that approved the deploy.
+        approvals_weight: u8,
+        /// The weight required to send a deploy from the account.
+        deployment_threshold: u8,
+    },
+    /// A deploy was sent with a fee payer account that doesn't exist.
+    #[error("invalid fee payer account")]
+    InvalidFeePayer,
+    /// A deploy was sent with a fee payer account with insufficient balance.
+    #[error("fee payer has insufficient balance")]
+    InsufficientFeePayerBalance,
+    /// The fee payer's approvals' combined weight doesn't meet its deployment threshold.
+    #[error(
+        "deploy approvals by the fee payer have a total weight of {approvals_weight}, but its \
+        deployment threshold is {deployment_threshold}"
+    )]
+    InsufficientFeePayerApprovalWeight {
+        /// The combined weight of the fee payer's keys that approved the deploy.
+        approvals_weight: u8,
+        /// The weight required to send a deploy from the fee payer's account.
+        deployment_threshold: u8,
+    },
+    /// The payment amount is missing or invalid.
+    #[error("invalid payment amount")]
+    InvalidPayment,
+    /// The deploy buffer has no room for the deploy.
+    #[error("deploy buffer limit reached: {0}")]
+    BufferLimit(AdmissionError),
+}
+
+/// Checks that the deploy's approvals would authorize it in execution: All signers must be
+/// associated keys of the account, and their combined weight must meet the deployment threshold.
+///
+/// For a deploy paid for by a separate fee payer, signers may be associated keys of either account,
+/// and each account's own keys must meet its deployment threshold.
+fn check_approvals(
+    deploy: &Deploy,
+    account: &Account,
+    maybe_fee_payer: Option<&Account>,
+) -> Result<(), Error> {
+    let authorization_keys: BTreeSet<AccountHash> = deploy
+        .approvals()
+        .iter()
+        .map(|approval| approval.signer().to_account_hash())
+        .collect();
+    let fee_payer = match maybe_fee_payer {
+        None => {
+            if !account.can_authorize(&authorization_keys) {
+                return Err(Error::UnauthorizedApprovals);
+            }
+            return check_deployment_threshold(account, &authorization_keys).map_err(
+                |(approvals_weight, deployment_threshold)| Error::InsufficientApprovalWeight {
+                    approvals_weight,
+                    deployment_threshold,
+                },
+            );
+        }
+        Some(fee_payer) => fee_payer,
+    };
+
+    let account_keys = account.associated_authorization_keys(&authorization_keys);
+    let fee_payer_keys = fee_payer.associated_authorization_keys(&authorization_keys);
+    if account_keys.union(&fee_payer_keys).count() != authorization_keys.len() {
+        return Err(Error::UnauthorizedApprovals);
+    }
+    check_deployment_threshold(account, &account_keys).map_err(
+        |(approvals_weight, deployment_threshold)| Error::InsufficientApprovalWeight {
+            approvals_weight,
+            deployment_threshold,
+        },
+    )?;
+    check_deployment_threshold(fee_payer, &fee_payer_keys).map_err(
+        |(approvals_weight, deployment_threshold)| Error::InsufficientFeePayerApprovalWeight {
+            approvals_weight,
+            deployment_threshold,
+        },
+    )
+}
+
+/// Checks that the combined weight of the given keys meets the account's deployment threshold,
+/// returning the weight and the threshold otherwise.
+fn check_deployment_threshold(
+    account: &Account,
+    authorization_keys: &BTreeSet<AccountHash>,
+) -> Result<(), (u8, u8)> {
+    if account.can_deploy_with(authorization_keys) {
+        return Ok(());
+    }
+    let approvals_weight = account
+        .associated_keys()
+        .filter(|(account_hash, _)| authorization_keys.contains(account_hash))
+        .fold(0u8, |total, (_, weight)| {
+            total.saturating_add(weight.value())
+        });
+    let deployment_threshold = account.action_thresholds().deployment().value();
+    Err((approvals_weight, deployment_threshold))
 }
 
 /// A helper trait constraining `DeployAcceptor` compatible reactor events.
@@ -42,6 +150,7 @@ pub trait ReactorEventT:
     + From<DeployAcceptorAnnouncement<NodeId>>
     + From<StorageRequest>
     + From<ContractRuntimeRequest>
+    + From<BlockProposerRequest>
     + Send
 {
 }
@@ -51,6 +160,7 @@ impl<REv> ReactorEventT for REv where
         + From<DeployAcceptorAnnouncement<NodeId>>
         + From<StorageRequest>
         + From<ContractRuntimeRequest>
+        + From<BlockProposerRequest>
         + Send
 {
 }
@@ -109,19 +219,11 @@ impl DeployAcceptor {
         // skip account verification if deploy not received from client or node is configured to
         // not verify accounts
         if !source.from_client() || !self.verify_accounts {
-            return effect_builder
-                .immediately()
-                .event(move |_| Event::AccountVerificationResult {
-                    deploy,
-                    source,
-                    account_key,
-                    verified: Some(true),
-                    maybe_responder,
-                });
+            return self.request_admission(effect_builder, deploy, source, maybe_responder);
         }
 
         effect_builder
-            .is_verified_account(account_key)
+            .get_verified_account(account_key)
             .event(move |verified| Event::AccountVerificationResult {
                 deploy,
                 source,
@@ -137,31 +239,49 @@ impl DeployAcceptor {
         deploy: Box<Deploy>,
         source: Source<NodeId>,
         account_key: Key,
-        verified: Option<bool>,
+        verified: Option<(Box<Account>, bool)>,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     ) -> Effects<Event> {
         let mut effects = Effects::new();
 
-        match verified {
-            Some(true) => {
-                // The client submitted a valid deploy. Return an Ok status to the RPC component via
-                // the responder.
-                if let Some(responder) = maybe_responder {
-                    effects.extend(responder.respond(Ok(())).ignore());
-                }
-
-                effects.extend(effect_builder.put_deploy_to_storage(deploy.clone()).event(
-                    move |is_new| Event::PutToStorageResult {
+        // A sponsored deploy's account doesn't need a balance of its own: the fee payer's account
+        // is verified next.
+        let maybe_fee_payer_key: Option<Key> = deploy
+            .header()
+            .fee_payer()
+            .map(|fee_payer| fee_payer.to_account_hash().into());
+        let verified = match (verified, maybe_fee_payer_key) {
+            (Some((account, _)), Some(fee_payer_key)) => {
+                return effect_builder
+                    .get_verified_account(fee_payer_key)
+                    .event(move |verified| Event::FeePayerVerificationResult {
                         deploy,
                         source,
-                        is_new,
-                    },
-                ));
-
-                return effects;
+                        account,
+                        fee_payer_key,
+                        verified,
+                        maybe_responder,
+                    });
             }
+            (verified, _) => verified,
+        };
 
-            Some(false) => {
+        match verified {
+            Some((account, true)) => match check_approvals(&deploy, &account, None) {
+                Ok(()) => {
+                    return self.request_admission(effect_builder, deploy, source, maybe_responder);
+                }
+                Err(error) => {
+                    info!(%error, "received deploy from account {}", account_key);
+                    // The deploy would fail authorization during execution. Return an error
+                    // message to the RPC component via the responder.
+                    if let Some(responder) = maybe_responder {
+                        effects.extend(responder.respond(Err(error)).ignore());
+                    }
+                }
+            },
+
+            Some((_, false)) => {
                 info! {
                     "Received deploy from account {} that does not have minimum balance required", account_key
                 };
@@ -192,6 +312,114 @@ impl DeployAcceptor {
         effects
     }
 
+    fn fee_payer_verification<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        account: Box<Account>,
+        fee_payer_key: Key,
+        verified: Option<(Box<Account>, bool)>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        let error = match verified {
+            Some((fee_payer, true)) => match check_approvals(&deploy, &account, Some(&fee_payer)) {
+                Ok(()) => {
+                    return self.request_admission(effect_builder, deploy, source, maybe_responder);
+                }
+                Err(error) => error,
+            },
+            Some((_, false)) => Error::InsufficientFeePayerBalance,
+            None => Error::InvalidFeePayer,
+        };
+
+        info!(%error, "received deploy paid for by fee payer {}", fee_payer_key);
+        // The deploy would fail during execution. Return an error message to the RPC component via
+        // the responder.
+        let mut effects = Effects::new();
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Err(error)).ignore());
+        }
+        effects.extend(
+            effect_builder
+                .announce_invalid_deploy(deploy, source)
+                .ignore(),
+        );
+        effects
+    }
+
+    /// Asks the block proposer whether it has room for the deploy before storing it.
+    fn request_admission<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        match deploy.deploy_type() {
+            Ok(deploy_type) => {
+                effect_builder
+                    .check_deploy_admission(deploy_type)
+                    .event(move |result| Event::AdmissionResult {
+                        deploy,
+                        source,
+                        result,
+                        maybe_responder,
+                    })
+            }
+            Err(error) => {
+                info!(%error, "received deploy with invalid payment");
+                let mut effects = Effects::new();
+                if let Some(responder) = maybe_responder {
+                    effects.extend(responder.respond(Err(Error::InvalidPayment)).ignore());
+                }
+                effects.extend(
+                    effect_builder
+                        .announce_invalid_deploy(deploy, source)
+                        .ignore(),
+                );
+                effects
+            }
+        }
+    }
+
+    fn admission<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        result: Result<(), AdmissionError>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        let mut effects = Effects::new();
+        if let Err(error) = result {
+            // The deploy is valid, but there is no room for it at the moment: It must not be
+            // announced as invalid, so that peers don't get blocked for sending it.
+            info!(deploy_hash = %deploy.id(), %error, "deploy not admitted to the buffer");
+            if let Some(responder) = maybe_responder {
+                effects.extend(responder.respond(Err(Error::BufferLimit(error))).ignore());
+            }
+            return effects;
+        }
+
+        // The client submitted a valid deploy. Return an Ok status to the RPC component via the
+        // responder.
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Ok(())).ignore());
+        }
+
+        effects.extend(
+            effect_builder
+                .put_deploy_to_storage(deploy.clone())
+                .event(move |is_new| Event::PutToStorageResult {
+                    deploy,
+                    source,
+                    is_new,
+                }),
+        );
+        effects
+    }
+
     fn handle_put_to_storage<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -244,6 +472,28 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 verified,
                 maybe_responder,
             ),
+            Event::FeePayerVerificationResult {
+                deploy,
+                source,
+                account,
+                fee_payer_key,
+                verified,
+                maybe_responder,
+            } => self.fee_payer_verification(
+                effect_builder,
+                deploy,
+                source,
+                account,
+                fee_payer_key,
+                verified,
+                maybe_responder,
+            ),
+            Event::AdmissionResult {
+                deploy,
+                source,
+                result,
+                maybe_responder,
+            } => self.admission(effect_builder, deploy, source, result, maybe_responder),
         }
     }
 }
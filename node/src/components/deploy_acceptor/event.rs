@@ -4,10 +4,11 @@ use serde::Serialize;
 
 use super::Source;
 use crate::{
-    components::deploy_acceptor::Error,
+    components::{block_proposer::AdmissionError, deploy_acceptor::Error},
     effect::{announcements::RpcServerAnnouncement, Responder},
     types::{Deploy, NodeId},
 };
+use casper_execution_engine::shared::account::Account;
 use casper_types::Key;
 
 /// `DeployAcceptor` events.
@@ -30,7 +31,30 @@ pub enum Event {
         deploy: Box<Deploy>,
         source: Source<NodeId>,
         account_key: Key,
-        verified: Option<bool>,
+        /// The account and whether it has the minimum balance, or `None` if it doesn't exist.
+        #[serde(skip_serializing)]
+        verified: Option<(Box<Account>, bool)>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    },
+    /// The result of verifying the fee payer's `Account` of a sponsored `Deploy`.
+    FeePayerVerificationResult {
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        /// The `Deploy`'s own account, which has already been verified to exist.
+        #[serde(skip_serializing)]
+        account: Box<Account>,
+        fee_payer_key: Key,
+        /// The fee payer's account and whether it has the minimum balance, or `None` if it doesn't
+        /// exist.
+        #[serde(skip_serializing)]
+        verified: Option<(Box<Account>, bool)>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    },
+    /// The result of asking the block proposer whether it has room for the `Deploy`.
+    AdmissionResult {
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        result: Result<(), AdmissionError>,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     },
 }
@@ -66,7 +90,10 @@ impl Display for Event {
                 verified,
                 ..
             } => {
-                let prefix = if verified.unwrap_or(false) { "" } else { "in" };
+                let prefix = match verified {
+                    Some((_, true)) => "",
+                    _ => "in",
+                };
                 write!(
                     formatter,
                     "{}valid deploy {} from account {}",
@@ -75,6 +102,28 @@ impl Display for Event {
                     account_key
                 )
             }
+            Event::FeePayerVerificationResult {
+                deploy,
+                fee_payer_key,
+                verified,
+                ..
+            } => {
+                let prefix = match verified {
+                    Some((_, true)) => "",
+                    _ => "in",
+                };
+                write!(
+                    formatter,
+                    "{}valid fee payer {} for deploy {}",
+                    prefix,
+                    fee_payer_key,
+                    deploy.id()
+                )
+            }
+            Event::AdmissionResult { deploy, result, .. } => match result {
+                Ok(()) => write!(formatter, "admitted {} to the buffer", deploy.id()),
+                Err(error) => write!(formatter, "did not admit {}: {}", deploy.id(), error),
+            },
         }
     }
 }
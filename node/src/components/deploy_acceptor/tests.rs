@@ -0,0 +1,158 @@
+use casper_execution_engine::{
+    core::engine_state::executable_deploy_item::ExecutableDeployItem,
+    shared::account::{ActionThresholds, AssociatedKeys},
+};
+use casper_types::{
+    account::Weight, bytesrepr::Bytes, contracts::NamedKeys, AccessRights, PublicKey, RuntimeArgs,
+    SecretKey, URef,
+};
+
+use super::*;
+use crate::{
+    testing::TestRng,
+    types::{TimeDiff, Timestamp},
+};
+
+/// Returns an account with two associated keys of weight 1 each, which requires both of them to
+/// approve a deploy.
+fn two_key_account(key_1: &SecretKey, key_2: &SecretKey) -> Account {
+    let account_hash = PublicKey::from(key_1).to_account_hash();
+    let mut associated_keys = AssociatedKeys::new(account_hash, Weight::new(1));
+    associated_keys
+        .add_key(PublicKey::from(key_2).to_account_hash(), Weight::new(1))
+        .unwrap();
+    let action_thresholds = ActionThresholds::new(Weight::new(2), Weight::new(2)).unwrap();
+    Account::new(
+        account_hash,
+        NamedKeys::new(),
+        URef::new([0; 32], AccessRights::READ_ADD_WRITE),
+        associated_keys,
+        action_thresholds,
+    )
+}
+
+fn deploy_signed_by(
+    rng: &mut TestRng,
+    fee_payer: Option<PublicKey>,
+    secret_keys: &[&SecretKey],
+) -> Deploy {
+    let module_bytes = || ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: RuntimeArgs::new(),
+    };
+    let mut deploy = Deploy::new(
+        Timestamp::now(),
+        TimeDiff::from(60_000),
+        1,
+        vec![],
+        "chain".to_string(),
+        fee_payer,
+        module_bytes(),
+        module_bytes(),
+        secret_keys[0],
+        rng,
+    );
+    for secret_key in &secret_keys[1..] {
+        deploy.sign(secret_key, rng);
+    }
+    deploy
+}
+
+#[test]
+fn should_accept_approvals_meeting_deployment_threshold() {
+    let mut rng = crate::new_rng();
+    let key_1 = SecretKey::random(&mut rng);
+    let key_2 = SecretKey::random(&mut rng);
+    let account = two_key_account(&key_1, &key_2);
+
+    let deploy = deploy_signed_by(&mut rng, None, &[&key_1, &key_2]);
+    assert!(check_approvals(&deploy, &account, None).is_ok());
+}
+
+#[test]
+fn should_reject_approvals_below_deployment_threshold() {
+    let mut rng = crate::new_rng();
+    let key_1 = SecretKey::random(&mut rng);
+    let key_2 = SecretKey::random(&mut rng);
+    let account = two_key_account(&key_1, &key_2);
+
+    let deploy = deploy_signed_by(&mut rng, None, &[&key_1]);
+    assert!(matches!(
+        check_approvals(&deploy, &account, None),
+        Err(Error::InsufficientApprovalWeight {
+            approvals_weight: 1,
+            deployment_threshold: 2,
+        })
+    ));
+}
+
+#[test]
+fn should_reject_approvals_by_unassociated_keys() {
+    let mut rng = crate::new_rng();
+    let key_1 = SecretKey::random(&mut rng);
+    let key_2 = SecretKey::random(&mut rng);
+    let other_key = SecretKey::random(&mut rng);
+    let account = two_key_account(&key_1, &key_2);
+
+    let deploy = deploy_signed_by(&mut rng, None, &[&key_1, &key_2, &other_key]);
+    assert!(matches!(
+        check_approvals(&deploy, &account, None),
+        Err(Error::UnauthorizedApprovals)
+    ));
+}
+
+#[test]
+fn should_accept_approvals_meeting_both_deployment_thresholds() {
+    let mut rng = crate::new_rng();
+    let key_1 = SecretKey::random(&mut rng);
+    let key_2 = SecretKey::random(&mut rng);
+    let fee_payer_key_1 = SecretKey::random(&mut rng);
+    let fee_payer_key_2 = SecretKey::random(&mut rng);
+    let account = two_key_account(&key_1, &key_2);
+    let fee_payer = two_key_account(&fee_payer_key_1, &fee_payer_key_2);
+
+    let deploy = deploy_signed_by(
+        &mut rng,
+        Some(PublicKey::from(&fee_payer_key_1)),
+        &[&key_1, &key_2, &fee_payer_key_1, &fee_payer_key_2],
+    );
+    assert!(check_approvals(&deploy, &account, Some(&fee_payer)).is_ok());
+}
+
+#[test]
+fn should_reject_approvals_below_fee_payer_deployment_threshold() {
+    let mut rng = crate::new_rng();
+    let key_1 = SecretKey::random(&mut rng);
+    let key_2 = SecretKey::random(&mut rng);
+    let fee_payer_key_1 = SecretKey::random(&mut rng);
+    let fee_payer_key_2 = SecretKey::random(&mut rng);
+    let account = two_key_account(&key_1, &key_2);
+    let fee_payer = two_key_account(&fee_payer_key_1, &fee_payer_key_2);
+
+    let deploy = deploy_signed_by(
+        &mut rng,
+        Some(PublicKey::from(&fee_payer_key_1)),
+        &[&key_1, &key_2, &fee_payer_key_1],
+    );
+    assert!(matches!(
+        check_approvals(&deploy, &account, Some(&fee_payer)),
+        Err(Error::InsufficientFeePayerApprovalWeight {
+            approvals_weight: 1,
+            deployment_threshold: 2,
+        })
+    ));
+
+    // Without any of the account's own approvals, the deploy can't be authorized either.
+    let deploy = deploy_signed_by(
+        &mut rng,
+        Some(PublicKey::from(&fee_payer_key_1)),
+        &[&fee_payer_key_1, &fee_payer_key_2],
+    );
+    assert!(matches!(
+        check_approvals(&deploy, &account, Some(&fee_payer)),
+        Err(Error::InsufficientApprovalWeight {
+            approvals_weight: 0,
+            deployment_threshold: 2,
+        })
+    ));
+}
@@ -14,6 +14,7 @@ use crate::{
     components::{deploy_acceptor, in_memory_network::NetworkController, storage},
     effect::{
         announcements::{DeployAcceptorAnnouncement, NetworkAnnouncement},
+        requests::BlockProposerRequest,
         Responder,
     },
     protocol::Message,
@@ -93,6 +94,8 @@ reactor!(Reactor {
 
         // The only contract runtime request will be the commit of genesis, which we discard.
         ContractRuntimeRequest -> #;
+        // There is no block proposer, so the deploy acceptor's admission checks always succeed.
+        BlockProposerRequest -> fn admit_deploy;
     }
 
     announcements: {
@@ -107,6 +110,20 @@ reactor!(Reactor {
 });
 
 impl Reactor {
+    fn admit_deploy(
+        &mut self,
+        _effect_builder: EffectBuilder<ReactorEvent>,
+        _rng: &mut NodeRng,
+        request: BlockProposerRequest,
+    ) -> Effects<ReactorEvent> {
+        match request {
+            BlockProposerRequest::CheckAdmission { responder, .. } => {
+                responder.respond(Ok(())).ignore()
+            }
+            request => panic!("unexpected block proposer request: {}", request),
+        }
+    }
+
     fn handle_message(
         &mut self,
         effect_builder: EffectBuilder<ReactorEvent>,
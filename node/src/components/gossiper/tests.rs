@@ -27,7 +27,7 @@ use crate::{
             DeployAcceptorAnnouncement, GossiperAnnouncement, NetworkAnnouncement,
             RpcServerAnnouncement,
         },
-        requests::ContractRuntimeRequest,
+        requests::{BlockProposerRequest, ContractRuntimeRequest},
         Responder,
     },
     protocol::Message as NodeMessage,
@@ -65,6 +65,8 @@ enum Event {
     DeployGossiperAnnouncement(#[serde(skip_serializing)] GossiperAnnouncement<Deploy>),
     #[from]
     ContractRuntime(#[serde(skip_serializing)] contract_runtime::Event),
+    #[from]
+    BlockProposerRequest(#[serde(skip_serializing)] BlockProposerRequest),
 }
 
 impl From<StorageRequest> for Event {
@@ -106,6 +108,9 @@ impl Display for Event {
             Event::ContractRuntime(event) => {
                 write!(formatter, "contract-runtime event: {}", event)
             }
+            Event::BlockProposerRequest(req) => {
+                write!(formatter, "block proposer request: {}", req)
+            }
         }
     }
 }
@@ -319,6 +324,15 @@ impl reactor::Reactor for Reactor {
                 self.contract_runtime
                     .handle_event(effect_builder, rng, event),
             ),
+            Event::BlockProposerRequest(BlockProposerRequest::CheckAdmission {
+                responder, ..
+            }) => {
+                // There is no block proposer, so every deploy is admitted.
+                responder.respond(Ok(())).ignore()
+            }
+            Event::BlockProposerRequest(request) => {
+                panic!("unexpected block proposer request: {}", request)
+            }
         }
     }
 
@@ -12,6 +12,8 @@ pub(crate) struct NetworkingMetrics {
     pub(crate) queued_messages: IntGauge,
     /// Number of connected peers.
     pub(crate) peers: IntGauge,
+    /// Number of connected peers that are validators in the current era.
+    pub(crate) validator_peers: IntGauge,
 
     // Potentially temporary metrics, not supported by all networking components:
     /// Number of do-nothing futures that have not finished executing for read requests.
@@ -43,6 +45,10 @@ impl NetworkingMetrics {
             "number of messages waiting to be sent out",
         )?;
         let peers = IntGauge::new("peers", "Number of connected peers.")?;
+        let validator_peers = IntGauge::new(
+            "net_validator_peers",
+            "number of connected peers that are validators in the current era",
+        )?;
 
         let read_futures_in_flight = prometheus::Gauge::new(
             "owm_read_futures_in_flight",
@@ -66,6 +72,7 @@ impl NetworkingMetrics {
         registry.register(Box::new(open_connections.clone()))?;
         registry.register(Box::new(queued_messages.clone()))?;
         registry.register(Box::new(peers.clone()))?;
+        registry.register(Box::new(validator_peers.clone()))?;
 
         registry.register(Box::new(read_futures_in_flight.clone()))?;
         registry.register(Box::new(read_futures_total.clone()))?;
@@ -78,6 +85,7 @@ impl NetworkingMetrics {
             open_connections,
             queued_messages,
             peers,
+            validator_peers,
             read_futures_in_flight,
             read_futures_total,
             write_futures_in_flight,
@@ -104,6 +112,9 @@ impl Drop for NetworkingMetrics {
         self.registry
             .unregister(Box::new(self.peers.clone()))
             .expect("did not expect deregistering peers to fail");
+        self.registry
+            .unregister(Box::new(self.validator_peers.clone()))
+            .expect("did not expect deregistering validator_peers to fail");
 
         self.registry
             .unregister(Box::new(self.read_futures_in_flight.clone()))
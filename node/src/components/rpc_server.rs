@@ -43,8 +43,8 @@ use crate::{
     effect::{
         announcements::RpcServerAnnouncement,
         requests::{
-            ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, LinearChainRequest,
-            MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
+            BlockProposerRequest, ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest,
+            LinearChainRequest, MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
@@ -61,6 +61,7 @@ pub trait ReactorEventT:
     From<Event>
     + From<RpcRequest<NodeId>>
     + From<RpcServerAnnouncement>
+    + From<BlockProposerRequest>
     + From<ChainspecLoaderRequest>
     + From<ContractRuntimeRequest>
     + From<ConsensusRequest>
@@ -76,6 +77,7 @@ impl<REv> ReactorEventT for REv where
     REv: From<Event>
         + From<RpcRequest<NodeId>>
         + From<RpcServerAnnouncement>
+        + From<BlockProposerRequest>
         + From<ChainspecLoaderRequest>
         + From<ContractRuntimeRequest>
         + From<ConsensusRequest>
@@ -296,6 +298,32 @@ where
                 responder.respond(status_feed).await;
             }
             .ignore(),
+            Event::RpcRequest(RpcRequest::GetEvidence {
+                era_id,
+                public_key,
+                responder,
+            }) => async move {
+                let maybe_evidence = effect_builder.get_evidence(era_id, public_key).await;
+                responder.respond(maybe_evidence).await;
+            }
+            .ignore(),
+            Event::RpcRequest(RpcRequest::GetValidatorStatus { responder }) => async move {
+                let status = effect_builder.get_validator_status().await;
+                responder.respond(status).await;
+            }
+            .ignore(),
+            Event::RpcRequest(RpcRequest::GetPendingDeploys {
+                account,
+                offset,
+                limit,
+                responder,
+            }) => async move {
+                let pending = effect_builder
+                    .get_pending_deploys(account, offset, limit)
+                    .await;
+                responder.respond(pending).await;
+            }
+            .ignore(),
             Event::RpcRequest(RpcRequest::GetMetrics { responder }) => effect_builder
                 .get_metrics()
                 .event(move |text| Event::GetMetricsResult {
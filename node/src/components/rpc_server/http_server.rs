@@ -61,9 +61,17 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_item = rpcs::state::GetItem::create_filter(effect_builder, api_version.clone());
     let rpc_get_balance =
         rpcs::state::GetBalance::create_filter(effect_builder, api_version.clone());
+    let rpc_get_contract_abi =
+        rpcs::state::GetContractAbi::create_filter(effect_builder, api_version.clone());
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version.clone());
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version.clone());
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_evidence =
+        rpcs::info::GetEvidence::create_filter(effect_builder, api_version.clone());
+    let rpc_get_validator_status =
+        rpcs::info::GetValidatorStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_pending_deploys =
+        rpcs::info::GetPendingDeploys::create_filter(effect_builder, api_version.clone());
     let rpc_get_era_info =
         rpcs::chain::GetEraInfoBySwitchBlock::create_filter(effect_builder, api_version.clone());
     let rpc_get_auction_info =
@@ -95,9 +103,13 @@ pub(super) async fn run<REv: ReactorEventT>(
             .or(rpc_get_state_root_hash)
             .or(rpc_get_item)
             .or(rpc_get_balance)
+            .or(rpc_get_contract_abi)
             .or(rpc_get_deploy)
             .or(rpc_get_peers)
             .or(rpc_get_status)
+            .or(rpc_get_evidence)
+            .or(rpc_get_validator_status)
+            .or(rpc_get_pending_deploys)
             .or(rpc_get_era_info)
             .or(rpc_get_auction_info)
             .or(rpc_get_rpcs)
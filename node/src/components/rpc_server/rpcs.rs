@@ -45,6 +45,9 @@ enum ErrorCode {
     GetBalanceFailed = -32006,
     GetBalanceFailedToExecute = -32007,
     InvalidDeploy = -32008,
+    NoSuchEvidence = -32009,
+    NoSuchContract = -32010,
+    NoSuchContractVersion = -32011,
 }
 
 #[derive(Debug)]
@@ -280,8 +283,12 @@ mod common {
 
     use once_cell::sync::Lazy;
 
-    use casper_execution_engine::core::engine_state::{self, QueryResult};
-    use casper_types::bytesrepr::ToBytes;
+    use casper_execution_engine::{
+        core::engine_state::{self, QueryResult},
+        shared::stored_value::StoredValue as EngineStoredValue,
+        storage::trie::merkle_proof::TrieMerkleProof,
+    };
+    use casper_types::{bytesrepr::ToBytes, Key};
 
     use super::ErrorCode;
     use crate::types::json_compatibility::StoredValue;
@@ -305,17 +312,7 @@ mod common {
     pub(super) fn extract_query_result(
         query_result: Result<QueryResult, engine_state::Error>,
     ) -> Result<(StoredValue, Vec<u8>), (ErrorCode, String)> {
-        let (value, proof) = match query_result {
-            Ok(QueryResult::Success { value, proofs }) => (value, proofs),
-            Ok(query_result) => {
-                let error_msg = format!("state query failed: {:?}", query_result);
-                return Err((ErrorCode::QueryFailed, error_msg));
-            }
-            Err(error) => {
-                let error_msg = format!("state query failed to execute: {:?}", error);
-                return Err((ErrorCode::QueryFailedToExecute, error_msg));
-            }
-        };
+        let (value, proof) = extract_engine_query_result(query_result)?;
 
         let value_compat = match StoredValue::try_from(&*value) {
             Ok(value_compat) => value_compat,
@@ -335,4 +332,27 @@ mod common {
 
         Ok((value_compat, proof_bytes))
     }
+
+    // Extract the EE `StoredValue` and its proofs from the result, without converting either.
+    pub(super) fn extract_engine_query_result(
+        query_result: Result<QueryResult, engine_state::Error>,
+    ) -> Result<
+        (
+            Box<EngineStoredValue>,
+            Vec<TrieMerkleProof<Key, EngineStoredValue>>,
+        ),
+        (ErrorCode, String),
+    > {
+        match query_result {
+            Ok(QueryResult::Success { value, proofs }) => Ok((value, proofs)),
+            Ok(query_result) => {
+                let error_msg = format!("state query failed: {:?}", query_result);
+                Err((ErrorCode::QueryFailed, error_msg))
+            }
+            Err(error) => {
+                let error_msg = format!("state query failed to execute: {:?}", error);
+                Err((ErrorCode::QueryFailedToExecute, error_msg))
+            }
+        }
+    }
 }
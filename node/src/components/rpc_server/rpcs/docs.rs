@@ -20,8 +20,8 @@ use warp_json_rpc::Builder;
 use super::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
-    state::{GetAuctionInfo, GetBalance, GetItem},
+    info::{GetDeploy, GetEvidence, GetPeers, GetPendingDeploys, GetStatus, GetValidatorStatus},
+    state::{GetAuctionInfo, GetBalance, GetContractAbi, GetItem},
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
 };
@@ -70,6 +70,15 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
+    schema.push_with_params::<GetEvidence>(
+        "returns the evidence against a faulty validator in a given era",
+    );
+    schema.push_without_params::<GetValidatorStatus>(
+        "returns the node's performance as a validator in each active era",
+    );
+    schema.push_with_params::<GetPendingDeploys>(
+        "returns a page of the deploys waiting to be included in a block",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
@@ -79,6 +88,9 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     );
     schema.push_with_params::<GetItem>("returns a stored value from the network");
     schema.push_with_params::<GetBalance>("returns a purse's balance from the network");
+    schema.push_with_params::<GetContractAbi>(
+        "returns the entry points of a contract or of a contract package's version",
+    );
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, PublicKey, SecretKey};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
@@ -23,6 +23,11 @@ use super::{
     RpcWithoutParamsExt,
 };
 use crate::{
+    components::{
+        block_proposer::PendingDeploy,
+        consensus::{EraId, JsonEvidence, ValidatorStatus},
+    },
+    crypto::AsymmetricKeyExt,
     effect::EffectBuilder,
     reactor::QueueKind,
     types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
@@ -39,11 +44,40 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         result: ExecutionResult::example().clone(),
     }],
 });
+static GET_EVIDENCE_PARAMS: Lazy<GetEvidenceParams> = Lazy::new(|| GetEvidenceParams {
+    era_id: 1,
+    public_key: *JsonEvidence::doc_example().perpetrator(),
+});
+static GET_EVIDENCE_RESULT: Lazy<GetEvidenceResult> = Lazy::new(|| GetEvidenceResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    evidence: JsonEvidence::doc_example().clone(),
+});
+static GET_VALIDATOR_STATUS_RESULT: Lazy<GetValidatorStatusResult> =
+    Lazy::new(|| GetValidatorStatusResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        public_key: PublicKey::from(SecretKey::doc_example()),
+        eras: vec![ValidatorStatus::doc_example().clone()],
+    });
+static GET_PENDING_DEPLOYS_PARAMS: Lazy<GetPendingDeploysParams> =
+    Lazy::new(|| GetPendingDeploysParams {
+        account: Some(PendingDeploy::doc_example().account),
+        offset: 0,
+        limit: 10,
+    });
+static GET_PENDING_DEPLOYS_RESULT: Lazy<GetPendingDeploysResult> =
+    Lazy::new(|| GetPendingDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        total: 1,
+        deploys: vec![PendingDeploy::doc_example().clone()],
+    });
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     peers: GetStatusResult::doc_example().peers.clone(),
 });
 
+/// The maximum number of pending deploys returned by a single "info_get_pending_deploys" request.
+pub const MAX_PENDING_DEPLOYS_PER_PAGE: u32 = 100;
+
 /// Params for "info_get_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -226,3 +260,218 @@ impl RpcWithoutParamsExt for GetStatus {
         .boxed()
     }
 }
+
+/// Params for "info_get_evidence" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEvidenceParams {
+    /// The era in which the validator is faulty.
+    pub era_id: u64,
+    /// The public key of the faulty validator.
+    pub public_key: PublicKey,
+}
+
+impl DocExample for GetEvidenceParams {
+    fn doc_example() -> &'static Self {
+        &*GET_EVIDENCE_PARAMS
+    }
+}
+
+/// Result for "info_get_evidence" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEvidenceResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The evidence against the validator.
+    pub evidence: JsonEvidence,
+}
+
+impl DocExample for GetEvidenceResult {
+    fn doc_example() -> &'static Self {
+        &*GET_EVIDENCE_RESULT
+    }
+}
+
+/// "info_get_evidence" RPC.
+pub struct GetEvidence {}
+
+impl RpcWithParams for GetEvidence {
+    const METHOD: &'static str = "info_get_evidence";
+    type RequestParams = GetEvidenceParams;
+    type ResponseResult = GetEvidenceResult;
+}
+
+impl RpcWithParamsExt for GetEvidence {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Try to get the evidence from consensus. It is only available for active eras.
+            let maybe_evidence = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetEvidence {
+                        era_id: EraId(params.era_id),
+                        public_key: params.public_key,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let evidence = match maybe_evidence {
+                Some(evidence) => evidence,
+                None => {
+                    info!(
+                        "no evidence against {} in era {}",
+                        params.public_key, params.era_id
+                    );
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchEvidence as i64,
+                        "evidence not known",
+                    ))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                evidence,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Result for "info_get_validator_status" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetValidatorStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The node's public signing key.
+    pub public_key: PublicKey,
+    /// The node's performance as a validator in each active era in which it is bonded.
+    pub eras: Vec<ValidatorStatus>,
+}
+
+impl DocExample for GetValidatorStatusResult {
+    fn doc_example() -> &'static Self {
+        &*GET_VALIDATOR_STATUS_RESULT
+    }
+}
+
+/// "info_get_validator_status" RPC.
+pub struct GetValidatorStatus {}
+
+impl RpcWithoutParams for GetValidatorStatus {
+    const METHOD: &'static str = "info_get_validator_status";
+    type ResponseResult = GetValidatorStatusResult;
+}
+
+impl RpcWithoutParamsExt for GetValidatorStatus {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let (public_key, eras) = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetValidatorStatus { responder },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                public_key,
+                eras,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Params for "info_get_pending_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetPendingDeploysParams {
+    /// If given, only deploys from this account are returned.
+    pub account: Option<PublicKey>,
+    /// The number of deploys to skip, in order of priority.
+    pub offset: u32,
+    /// The maximum number of deploys to return. At most 100 are returned per request.
+    pub limit: u32,
+}
+
+impl DocExample for GetPendingDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_PENDING_DEPLOYS_PARAMS
+    }
+}
+
+/// Result for "info_get_pending_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetPendingDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The total number of matching deploys waiting to be included in a block.
+    pub total: u64,
+    /// The requested page of matching deploys, in the order in which they would be proposed.
+    pub deploys: Vec<PendingDeploy>,
+}
+
+impl DocExample for GetPendingDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_PENDING_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_pending_deploys" RPC.
+pub struct GetPendingDeploys {}
+
+impl RpcWithParams for GetPendingDeploys {
+    const METHOD: &'static str = "info_get_pending_deploys";
+    type RequestParams = GetPendingDeploysParams;
+    type ResponseResult = GetPendingDeploysResult;
+}
+
+impl RpcWithParamsExt for GetPendingDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let (total, deploys) = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetPendingDeploys {
+                        account: params.account,
+                        offset: params.offset,
+                        limit: params.limit.min(MAX_PENDING_DEPLOYS_PER_PAGE),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                total,
+                deploys,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
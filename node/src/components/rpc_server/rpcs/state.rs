@@ -15,8 +15,15 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_execution_engine::core::engine_state::{BalanceResult, GetBidsResult};
-use casper_types::{bytesrepr::ToBytes, CLValue, Key, ProtocolVersion, URef, U512};
+use casper_execution_engine::{
+    core::engine_state::{BalanceResult, GetBidsResult},
+    shared::stored_value::StoredValue as EngineStoredValue,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, CLType, CLTyped, CLValue, Contract, ContractHash,
+    ContractPackageHash, ContractVersion, EntryPoint, EntryPointAccess, EntryPointType, Key,
+    Parameter, ProtocolVersion, URef, U512,
+};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
@@ -56,6 +63,29 @@ static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResul
     balance_value: U512::from(123_456),
     merkle_proof: MERKLE_PROOF.clone(),
 });
+static GET_CONTRACT_ABI_PARAMS: Lazy<GetContractAbiParams> = Lazy::new(|| GetContractAbiParams {
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    key: Key::from(ContractPackageHash::new([77; 32])).to_formatted_string(),
+    version: Some(1),
+});
+static GET_CONTRACT_ABI_RESULT: Lazy<GetContractAbiResult> = Lazy::new(|| {
+    let entry_point = EntryPoint::new(
+        "transfer",
+        vec![
+            Parameter::new("recipient", AccountHash::cl_type()),
+            Parameter::new("amount", CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    GetContractAbiResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        contract_package_hash: ContractPackageHash::new([77; 32]),
+        contract_hash: ContractHash::new([42; 32]),
+        entry_points: vec![entry_point],
+    }
+});
 static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuctionInfoResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     auction_state: AuctionState::doc_example().clone(),
@@ -393,3 +423,183 @@ impl RpcWithoutParamsExt for GetAuctionInfo {
         .boxed()
     }
 }
+
+/// Params for "state_get_contract_abi" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetContractAbiParams {
+    /// Hash of the state root.
+    pub state_root_hash: Digest,
+    /// `casper_types::Key` of a contract or of a contract package as formatted string.
+    pub key: String,
+    /// The version of the contract within the contract package. Only valid with a contract package
+    /// key, where it defaults to the highest enabled version.
+    #[serde(default)]
+    pub version: Option<ContractVersion>,
+}
+
+impl DocExample for GetContractAbiParams {
+    fn doc_example() -> &'static Self {
+        &*GET_CONTRACT_ABI_PARAMS
+    }
+}
+
+/// Result for "state_get_contract_abi" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetContractAbiResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The hash of the contract package the contract belongs to.
+    pub contract_package_hash: ContractPackageHash,
+    /// The hash of the contract.
+    pub contract_hash: ContractHash,
+    /// The entry points of the contract.
+    pub entry_points: Vec<EntryPoint>,
+}
+
+impl DocExample for GetContractAbiResult {
+    fn doc_example() -> &'static Self {
+        &*GET_CONTRACT_ABI_RESULT
+    }
+}
+
+/// "state_get_contract_abi" RPC.
+pub struct GetContractAbi {}
+
+impl GetContractAbi {
+    /// Returns the value stored under the given key.
+    async fn query<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        base_key: Key,
+    ) -> Result<EngineStoredValue, (ErrorCode, String)> {
+        let query_result = effect_builder
+            .make_request(
+                |responder| RpcRequest::QueryGlobalState {
+                    state_root_hash,
+                    base_key,
+                    path: vec![],
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await;
+        let (value, _proofs) = common::extract_engine_query_result(query_result)?;
+        Ok(*value)
+    }
+
+    /// Returns the contract stored under the given key, resolving a contract package to the given
+    /// or else the highest enabled version.
+    async fn get_contract<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        key: Key,
+        version: Option<ContractVersion>,
+    ) -> Result<(ContractHash, Contract), (ErrorCode, String)> {
+        let contract_package = match Self::query(effect_builder, state_root_hash, key).await? {
+            EngineStoredValue::Contract(_) if version.is_some() => {
+                let error_msg = format!(
+                    "{} is a contract; a version can only be given for a contract package",
+                    key
+                );
+                return Err((ErrorCode::NoSuchContractVersion, error_msg));
+            }
+            EngineStoredValue::Contract(contract) => {
+                let contract_hash = ContractHash::new(key.into_hash().unwrap_or_default());
+                return Ok((contract_hash, contract));
+            }
+            EngineStoredValue::ContractPackage(contract_package) => contract_package,
+            _ => {
+                let error_msg = format!("{} is neither a contract nor a contract package", key);
+                return Err((ErrorCode::NoSuchContract, error_msg));
+            }
+        };
+
+        let maybe_contract_hash = match version {
+            Some(version) => contract_package
+                .enabled_versions()
+                .iter()
+                .rev()
+                .find(|(version_key, _)| version_key.contract_version() == version)
+                .map(|(_, contract_hash)| *contract_hash),
+            None => contract_package.current_contract_hash(),
+        };
+        let contract_hash = match maybe_contract_hash {
+            Some(contract_hash) => contract_hash,
+            None => {
+                let error_msg = match version {
+                    Some(version) => format!("{} has no enabled version {}", key, version),
+                    None => format!("{} has no enabled versions", key),
+                };
+                return Err((ErrorCode::NoSuchContractVersion, error_msg));
+            }
+        };
+
+        let contract_key = Key::from(contract_hash);
+        match Self::query(effect_builder, state_root_hash, contract_key).await? {
+            EngineStoredValue::Contract(contract) => Ok((contract_hash, contract)),
+            _ => {
+                let error_msg = format!("{} is not a contract", contract_key);
+                Err((ErrorCode::NoSuchContract, error_msg))
+            }
+        }
+    }
+}
+
+impl RpcWithParams for GetContractAbi {
+    const METHOD: &'static str = "state_get_contract_abi";
+    type RequestParams = GetContractAbiParams;
+    type ResponseResult = GetContractAbiResult;
+}
+
+impl RpcWithParamsExt for GetContractAbi {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Try to parse a `casper_types::Key` from the params.
+            let key = match Key::from_formatted_str(&params.key)
+                .map_err(|error| format!("failed to parse key: {:?}", error))
+            {
+                Ok(key) => key,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseQueryKey as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let (contract_hash, contract) = match Self::get_contract(
+                effect_builder,
+                params.state_root_hash,
+                key,
+                params.version,
+            )
+            .await
+            {
+                Ok(tuple) => tuple,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                contract_package_hash: contract.contract_package_hash(),
+                contract_hash,
+                entry_points: contract.entry_points().clone().take_entry_points(),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
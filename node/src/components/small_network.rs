@@ -31,6 +31,19 @@
 //! On losing an incoming or outgoing connection for a given peer, the other connection is closed.
 //! No explicit reconnect is attempted. Instead, if the peer is still online, the normal gossiping
 //! process will cause both peers to connect again.
+//!
+//! # Channels
+//!
+//! Each outgoing connection multiplexes two logical channels, one for consensus traffic and one for
+//! everything else. Queued consensus messages are always sent before bulk data, so that a flood of
+//! deploys cannot delay consensus.
+//!
+//! # Validator connections
+//!
+//! A validator node presents a [`ValidatorProof`] in its handshake, i.e. a signature by its
+//! consensus key over its node ID. Peers that are validators in the current era (as announced by
+//! the consensus component) are never blocklisted, and a lost outgoing connection to one of them
+//! is reestablished right away instead of waiting for address gossip.
 
 mod config;
 mod error;
@@ -41,7 +54,7 @@ mod message;
 mod tests;
 
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::Infallible,
     env,
     fmt::{self, Debug, Display, Formatter},
@@ -59,6 +72,7 @@ use anyhow::Context;
 use datasize::DataSize;
 use futures::{
     future::{select, BoxFuture, Either},
+    select_biased,
     stream::{SplitSink, SplitStream},
     FutureExt, SinkExt, StreamExt,
 };
@@ -82,8 +96,14 @@ use tokio_serde::{formats::SymmetricalMessagePack, SymmetricallyFramed};
 use tokio_util::codec::{Framed, LengthDelimitedCodec};
 use tracing::{debug, error, info, trace, warn};
 
+use casper_types::PublicKey;
+
 use self::error::Result;
-pub(crate) use self::{event::Event, gossiped_address::GossipedAddress, message::Message};
+pub(crate) use self::{
+    event::Event,
+    gossiped_address::GossipedAddress,
+    message::{Channel, Message, Payload, ValidatorProof},
+};
 use crate::{
     components::{
         network::ENABLE_LIBP2P_NET_ENV_VAR, networking_metrics::NetworkingMetrics, Component,
@@ -105,11 +125,15 @@ pub use error::Error;
 const MAX_ASYMMETRIC_CONNECTION_SEEN: u16 = 3;
 static BLOCKLIST_RETAIN_DURATION: Lazy<TimeDiff> =
     Lazy::new(|| Duration::from_secs(60 * 10).into());
+/// The delay before trying to reconnect to a validator of the current era.
+const VALIDATOR_RECONNECT_DELAY: Duration = Duration::from_secs(1);
 
 #[derive(DataSize, Debug)]
 pub(crate) struct OutgoingConnection<P> {
     #[data_size(skip)] // Unfortunately, there is no way to inspect an `UnboundedSender`.
-    sender: UnboundedSender<Message<P>>,
+    consensus_sender: UnboundedSender<Message<P>>,
+    #[data_size(skip)]
+    bulk_sender: UnboundedSender<Message<P>>,
     peer_address: SocketAddr,
 
     // for keeping track of connection asymmetry, tracking the number of times we've seen this
@@ -179,17 +203,27 @@ where
 
     /// Known addresses for this node.
     known_addresses: Vec<String>,
+
+    /// Our proof of being a validator, presented to peers in the handshake.
+    validator_proof: Option<ValidatorProof>,
+    /// The validator keys of connected peers that presented a valid proof in their handshake.
+    validator_peers: HashMap<NodeId, PublicKey>,
+    /// The validators of the current era.
+    active_validators: BTreeSet<PublicKey>,
 }
 
 impl<REv, P> SmallNetwork<REv, P>
 where
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Payload + Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
     REv: Send + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
 {
     /// Creates a new small network component instance.
     ///
     /// If `notify` is set to `false`, no systemd notifications will be sent, regardless of
     /// configuration.
+    ///
+    /// `validator_proof` should be given if the node is run by a validator, so that other
+    /// validators can recognize it.
     #[allow(clippy::type_complexity)]
     pub(crate) fn new(
         event_queue: EventQueueHandle<REv>,
@@ -198,6 +232,7 @@ where
         small_network_identity: SmallNetworkIdentity,
         network_name: String,
         notify: bool,
+        validator_proof: Option<ValidatorProof>,
     ) -> Result<(SmallNetwork<REv, P>, Effects<Event<P>>)> {
         // Assert we have at least one known address in the config.
         if cfg.known_addresses.is_empty() {
@@ -234,6 +269,9 @@ where
                 server_join_handle: None,
                 is_stopped: Arc::new(AtomicBool::new(true)),
                 net_metrics: NetworkingMetrics::new(&Registry::default())?,
+                validator_proof,
+                validator_peers: HashMap::new(),
+                active_validators: BTreeSet::new(),
             };
             return Ok((model, Effects::new()));
         }
@@ -298,6 +336,9 @@ where
             server_join_handle: Some(server_join_handle),
             is_stopped: Arc::new(AtomicBool::new(false)),
             net_metrics,
+            validator_proof,
+            validator_peers: HashMap::new(),
+            active_validators: BTreeSet::new(),
         };
 
         // Bootstrap process.
@@ -397,7 +438,11 @@ where
     fn send_message(&self, dest: NodeId, msg: Message<P>) {
         // Try to send the message.
         if let Some(connection) = self.outgoing.get(&dest) {
-            if let Err(msg) = connection.sender.send(msg) {
+            let sender = match msg.channel() {
+                Channel::Consensus => &connection.consensus_sender,
+                Channel::Bulk => &connection.bulk_sender,
+            };
+            if let Err(msg) = sender.send(msg) {
                 // We lost the connection, but that fact has not reached us yet.
                 warn!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, lost connection");
             } else {
@@ -444,9 +489,7 @@ where
                 debug!(our_id=%self.our_id, %peer_id, %peer_address, "established incoming connection");
                 // The sink is only used to send a single handshake message, then dropped.
                 let (mut sink, stream) = framed::<P>(transport).split();
-                let handshake = Message::Handshake {
-                    network_name: self.network_name.clone(),
-                };
+                let handshake = self.handshake();
                 let mut effects = async move {
                     let _ = sink.send(handshake).await;
                 }
@@ -528,10 +571,12 @@ where
         let (sink, stream) = framed::<P>(transport).split();
         debug!(our_id=%self.our_id, %peer_id, %peer_address, "established outgoing connection");
 
-        let (sender, receiver) = mpsc::unbounded_channel();
+        let (consensus_sender, consensus_receiver) = mpsc::unbounded_channel();
+        let (bulk_sender, bulk_receiver) = mpsc::unbounded_channel();
         let connection = OutgoingConnection {
             peer_address,
-            sender,
+            consensus_sender,
+            bulk_sender,
             times_seen_asymmetric: 0,
         };
         if self.outgoing.insert(peer_id.clone(), connection).is_some() {
@@ -543,13 +588,12 @@ where
 
         let mut effects = self.check_connection_complete(effect_builder, peer_id.clone());
 
-        let handshake = Message::Handshake {
-            network_name: self.network_name.clone(),
-        };
+        let handshake = self.handshake();
         let peer_id_cloned = peer_id.clone();
         effects.extend(
             message_sender(
-                receiver,
+                consensus_receiver,
+                bulk_receiver,
                 sink,
                 self.net_metrics.queued_messages.clone(),
                 handshake,
@@ -589,7 +633,17 @@ where
             } else {
                 warn!(our_id=%self.our_id, %peer_id, %peer_address, "outgoing connection closed");
             }
-            return self.remove(effect_builder, &peer_id, false);
+            let is_active_validator = self.is_active_validator(&peer_id);
+            let mut effects = self.remove(effect_builder, &peer_id, false);
+            if is_active_validator {
+                // Don't wait for the peer's address to be gossiped again; we want to stay
+                // connected to the current validators at all times.
+                debug!(our_id=%self.our_id, %peer_id, %peer_address, "reconnecting to validator");
+                effects.extend(effect_builder.set_timeout(VALIDATOR_RECONNECT_DELAY).event(
+                    move |_| Event::PeerAddressReceived(GossipedAddress::new(peer_address)),
+                ));
+            }
+            return effects;
         }
 
         // If we don't have the node ID passed in here, it was never added as an
@@ -609,6 +663,11 @@ where
         peer_id: &NodeId,
         add_to_blocklist: bool,
     ) -> Effects<Event<P>> {
+        // We never blocklist validators of the current era.
+        let add_to_blocklist = add_to_blocklist && !self.is_active_validator(peer_id);
+        if self.validator_peers.remove(peer_id).is_some() {
+            self.update_validator_peers_metric();
+        }
         if let Some(incoming) = self.incoming.remove(&peer_id) {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the incoming connections");
             let _ = self.pending.remove(&incoming.peer_address);
@@ -690,7 +749,10 @@ where
         REv: From<NetworkAnnouncement<NodeId, P>>,
     {
         match msg {
-            Message::Handshake { network_name } => {
+            Message::Handshake {
+                network_name,
+                validator_proof,
+            } => {
                 if network_name != self.network_name {
                     info!(
                         our_id=%self.our_id,
@@ -703,6 +765,26 @@ where
                     self.update_peers_metric();
                     return remove;
                 }
+                if let Some(proof) = validator_proof {
+                    match proof.verify(&peer_id) {
+                        Some(public_key) => {
+                            debug!(our_id=%self.our_id, %peer_id, %public_key, "peer is a validator");
+                            let _ = self.validator_peers.insert(peer_id, public_key);
+                            self.update_validator_peers_metric();
+                        }
+                        None => {
+                            info!(
+                                our_id=%self.our_id,
+                                %peer_id,
+                                %proof,
+                                "dropping connection due to invalid validator proof"
+                            );
+                            let remove = self.remove(effect_builder, &peer_id, true);
+                            self.update_peers_metric();
+                            return remove;
+                        }
+                    }
+                }
                 self.update_peers_metric();
                 Effects::new()
             }
@@ -716,6 +798,38 @@ where
         self.net_metrics.peers.set(self.peers().len() as i64);
     }
 
+    fn update_validator_peers_metric(&mut self) {
+        let count = self
+            .validator_peers
+            .values()
+            .filter(|public_key| self.active_validators.contains(public_key))
+            .count();
+        self.net_metrics.validator_peers.set(count as i64);
+    }
+
+    /// Returns our handshake message.
+    fn handshake(&self) -> Message<P> {
+        Message::Handshake {
+            network_name: self.network_name.clone(),
+            validator_proof: self.validator_proof.clone(),
+        }
+    }
+
+    /// Returns whether the given peer proved to be a validator in the current era.
+    fn is_active_validator(&self, peer_id: &NodeId) -> bool {
+        self.validator_peers
+            .get(peer_id)
+            .map_or(false, |public_key| {
+                self.active_validators.contains(public_key)
+            })
+    }
+
+    /// Updates the set of validators of the current era.
+    fn set_active_validators(&mut self, validators: BTreeSet<PublicKey>) {
+        self.active_validators = validators;
+        self.update_validator_peers_metric();
+    }
+
     fn connect_to_peer_if_required(&mut self, peer_address: SocketAddr) -> Effects<Event<P>> {
         self.blocklist
             .retain(|_, ts| *ts > Timestamp::now() - *BLOCKLIST_RETAIN_DURATION);
@@ -851,7 +965,7 @@ where
 impl<REv, P> Component<REv> for SmallNetwork<REv, P>
 where
     REv: Send + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Payload + Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
 {
     type Event = Event<P>;
     type ConstructionError = Infallible;
@@ -965,6 +1079,10 @@ where
             Event::PeerAddressReceived(gossiped_address) => {
                 self.connect_to_peer_if_required(gossiped_address.into())
             }
+            Event::ActiveValidatorsChanged(validators) => {
+                self.set_active_validators(*validators);
+                Effects::new()
+            }
         }
     }
 }
@@ -1197,12 +1315,15 @@ where
 
 /// Network message sender.
 ///
-/// Reads from a channel and sends all messages, until the stream is closed or an error occurs.
+/// Reads from the consensus and bulk channels and sends all messages, until the channels are
+/// closed or an error occurs. Whenever a consensus message is waiting, it is sent before any bulk
+/// message.
 ///
 /// Initially sends a handshake including the `chainspec_hash` as a final handshake step.  If the
 /// recipient's `chainspec_hash` doesn't match, the connection will be closed.
 async fn message_sender<P>(
-    mut queue: UnboundedReceiver<Message<P>>,
+    mut consensus_queue: UnboundedReceiver<Message<P>>,
+    mut bulk_queue: UnboundedReceiver<Message<P>>,
     mut sink: SplitSink<FramedTransport<P>, Message<P>>,
     counter: IntGauge,
     handshake: Message<P>,
@@ -1211,7 +1332,17 @@ where
     P: Serialize + Send,
 {
     sink.send(handshake).await.map_err(Error::MessageNotSent)?;
-    while let Some(payload) = queue.recv().await {
+    loop {
+        // `select_biased` polls the consensus queue first, so it always takes precedence.
+        let maybe_payload = select_biased! {
+            payload = consensus_queue.recv().fuse() => payload,
+            payload = bulk_queue.recv().fuse() => payload,
+        };
+        let payload = match maybe_payload {
+            Some(payload) => payload,
+            // Both senders are dropped together once the connection is removed.
+            None => break,
+        };
         counter.dec();
         // We simply error-out if the sink fails, it means that our connection broke.
         sink.send(payload).await.map_err(Error::MessageNotSent)?;
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeSet,
     fmt::{self, Debug, Display, Formatter},
     io, mem,
     net::SocketAddr,
@@ -9,6 +10,8 @@ use serde::Serialize;
 use static_assertions::const_assert;
 use tokio::net::TcpStream;
 
+use casper_types::PublicKey;
+
 use super::{Error, GossipedAddress, Message, NodeId, Transport};
 use crate::{
     effect::requests::{NetworkInfoRequest, NetworkRequest},
@@ -81,6 +84,8 @@ pub enum Event<P> {
     GossipOurAddress,
     /// We received a peer's public listening address via gossip.
     PeerAddressReceived(GossipedAddress),
+    /// A new era started, with the given validators.
+    ActiveValidatorsChanged(Box<BTreeSet<PublicKey>>),
 }
 
 impl From<NetworkRequest<NodeId, ProtocolMessage>> for Event<ProtocolMessage> {
@@ -153,6 +158,13 @@ impl<P: Display> Display for Event<P> {
             Event::PeerAddressReceived(gossiped_address) => {
                 write!(f, "received gossiped peer address {}", gossiped_address)
             }
+            Event::ActiveValidatorsChanged(validators) => {
+                write!(
+                    f,
+                    "active validators changed to {} validators",
+                    validators.len()
+                )
+            }
         }
     }
 }
@@ -1,18 +1,117 @@
 use std::fmt::{self, Debug, Display, Formatter};
 
+use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use casper_types::{PublicKey, SecretKey, Signature};
+
+use crate::{
+    crypto::{self, hash},
+    types::NodeId,
+    NodeRng,
+};
+
+/// Domain separator for validator proofs, to avoid a signature being reused in another context.
+const VALIDATOR_PROOF_PREFIX: &[u8] = b"casper-small-network-validator";
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Message<P> {
-    Handshake { network_name: String },
+    Handshake {
+        network_name: String,
+        /// Proof that the sender is operated by a validator, if it is one.
+        #[serde(default)]
+        validator_proof: Option<ValidatorProof>,
+    },
     Payload(P),
 }
 
+impl<P: Payload> Message<P> {
+    /// Returns the channel this message should be sent on.
+    pub(super) fn channel(&self) -> Channel {
+        match self {
+            Message::Handshake { .. } => Channel::Consensus,
+            Message::Payload(payload) => payload.channel(),
+        }
+    }
+}
+
 impl<P: Display> Display for Message<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Message::Handshake { network_name } => write!(f, "handshake: {}", network_name),
+            Message::Handshake {
+                network_name,
+                validator_proof,
+            } => match validator_proof {
+                Some(proof) => write!(f, "handshake: {}, validator {}", network_name, proof),
+                None => write!(f, "handshake: {}", network_name),
+            },
             Message::Payload(payload) => write!(f, "payload: {}", payload),
         }
     }
 }
+
+/// A logical channel of a connection.
+///
+/// Every outgoing connection keeps a separate queue per channel. Messages queued on the consensus
+/// channel are always sent before any message waiting on the bulk channel, so that a flood of
+/// deploys or fetch responses cannot delay consensus traffic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Channel {
+    /// Consensus traffic, which is sent with priority.
+    Consensus,
+    /// Everything else, e.g. gossip and fetcher traffic.
+    Bulk,
+}
+
+/// A payload that can be sent over the small network.
+pub(crate) trait Payload {
+    /// Returns the channel the payload should be sent on.
+    fn channel(&self) -> Channel;
+}
+
+/// A signature by a validator's consensus key over the sender's node ID.
+///
+/// Since the node ID is derived from the TLS certificate, a peer that presents a valid proof
+/// during the handshake is known to be operated by the holder of the validator key.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+pub struct ValidatorProof {
+    public_key: PublicKey,
+    signature: Signature,
+}
+
+impl ValidatorProof {
+    /// Creates a new proof that the node with the given ID is operated by the holder of
+    /// `secret_key`.
+    pub(crate) fn new(
+        node_id: &NodeId,
+        secret_key: &SecretKey,
+        public_key: PublicKey,
+        rng: &mut NodeRng,
+    ) -> Self {
+        let signature = crypto::sign(proof_digest(node_id), secret_key, &public_key, rng);
+        ValidatorProof {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Returns the validator's public key if the proof is valid for the given node ID.
+    pub(super) fn verify(&self, node_id: &NodeId) -> Option<PublicKey> {
+        crypto::verify(proof_digest(node_id), &self.signature, &self.public_key)
+            .ok()
+            .map(|()| self.public_key)
+    }
+}
+
+impl Display for ValidatorProof {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.public_key)
+    }
+}
+
+/// Returns the digest that is signed by a validator proof for the given node ID.
+fn proof_digest(node_id: &NodeId) -> hash::Digest {
+    let mut data = VALIDATOR_PROOF_PREFIX.to_vec();
+    data.extend(bincode::serialize(node_id).expect("should serialize node ID"));
+    hash::hash(data)
+}
@@ -16,7 +16,12 @@ use prometheus::Registry;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use super::{Config, Event as SmallNetworkEvent, GossipedAddress, SmallNetwork};
+use casper_types::{PublicKey, SecretKey};
+
+use super::{
+    Channel, Config, Event as SmallNetworkEvent, GossipedAddress, Payload, SmallNetwork,
+    ValidatorProof,
+};
 use crate::{
     components::{
         gossiper::{self, Gossiper},
@@ -24,6 +29,7 @@ use crate::{
         small_network::SmallNetworkIdentity,
         Component,
     },
+    crypto::AsymmetricKeyExt,
     effect::{
         announcements::{GossiperAnnouncement, NetworkAnnouncement},
         requests::{NetworkRequest, StorageRequest},
@@ -100,6 +106,12 @@ impl Display for Message {
     }
 }
 
+impl Payload for Message {
+    fn channel(&self) -> Channel {
+        Channel::Bulk
+    }
+}
+
 /// Test reactor.
 ///
 /// Runs a single small network.
@@ -128,6 +140,7 @@ impl Reactor for TestReactor {
             small_network_identity,
             "test_network".to_string(),
             false,
+            None,
         )?;
         let gossiper_config = gossiper::Config::new_with_small_timeouts();
         let address_gossiper =
@@ -432,3 +445,17 @@ async fn check_varying_size_network_connects() {
         net.finalize().await;
     }
 }
+
+/// Check that a validator proof is only valid for the node ID it was created for.
+#[test]
+fn validator_proof_is_bound_to_node_id() {
+    let mut rng = crate::new_rng();
+    let secret_key = SecretKey::random(&mut rng);
+    let public_key = PublicKey::from(&secret_key);
+    let node_id = NodeId::random_tls(&mut rng);
+    let other_node_id = NodeId::random_tls(&mut rng);
+
+    let proof = ValidatorProof::new(&node_id, &secret_key, public_key, &mut rng);
+    assert_eq!(Some(public_key), proof.verify(&node_id));
+    assert_eq!(None, proof.verify(&other_node_id));
+}
@@ -64,7 +64,7 @@ pub mod requests;
 use std::{
     any::type_name,
     borrow::Cow,
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::{self, Debug, Display, Formatter},
     future::Future,
     sync::Arc,
@@ -91,8 +91,8 @@ use casper_execution_engine::{
         MAX_PAYMENT,
     },
     shared::{
-        additive_map::AdditiveMap, newtypes::Blake2bHash, stored_value::StoredValue,
-        transform::Transform,
+        account::Account, additive_map::AdditiveMap, newtypes::Blake2bHash,
+        stored_value::StoredValue, transform::Transform,
     },
     storage::{global_state::CommitResult, protocol_data::ProtocolData, trie::Trie},
 };
@@ -102,8 +102,9 @@ use casper_types::{
 
 use crate::{
     components::{
+        block_proposer::{AdmissionError, DeployType, PendingDeploy},
         chainspec_loader::NextUpgrade,
-        consensus::{BlockContext, EraId},
+        consensus::{BlockContext, EraId, JsonEvidence, ValidatorStatus},
         contract_runtime::EraValidatorsRequest,
         deploy_acceptor,
         fetcher::FetchResult,
@@ -1064,6 +1065,47 @@ impl<REv> EffectBuilder<REv> {
         (proto_block, block_context)
     }
 
+    /// Checks whether the block proposer would admit the given deploy to its buffer.
+    pub(crate) async fn check_deploy_admission(
+        self,
+        deploy_type: DeployType,
+    ) -> Result<(), AdmissionError>
+    where
+        REv: From<BlockProposerRequest>,
+    {
+        self.make_request(
+            |responder| BlockProposerRequest::CheckAdmission {
+                deploy_type: Box::new(deploy_type),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the total number of deploys waiting to be included in a block, optionally only from
+    /// the given account, and the page of at most `limit` of them starting at `offset`.
+    pub(crate) async fn get_pending_deploys(
+        self,
+        account: Option<PublicKey>,
+        offset: u32,
+        limit: u32,
+    ) -> (u64, Vec<PendingDeploy>)
+    where
+        REv: From<BlockProposerRequest>,
+    {
+        self.make_request(
+            |responder| BlockProposerRequest::GetPendingDeploys {
+                account,
+                offset,
+                limit,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Passes a finalized proto-block to the block executor component to execute it.
     pub(crate) async fn execute_block(self, finalized_block: FinalizedBlock)
     where
@@ -1161,6 +1203,25 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Announce the validators of the era that has just become the current one.
+    pub(crate) async fn announce_active_validators_changed<I>(
+        self,
+        era_id: EraId,
+        validators: BTreeSet<PublicKey>,
+    ) where
+        REv: From<ConsensusAnnouncement<I>>,
+    {
+        self.0
+            .schedule(
+                ConsensusAnnouncement::ActiveValidatorsChanged {
+                    era_id,
+                    validators: Box::new(validators),
+                },
+                QueueKind::Regular,
+            )
+            .await
+    }
+
     /// The linear chain has stored a newly-created block.
     pub(crate) async fn announce_block_added(self, block_hash: BlockHash, block: Box<Block>)
     where
@@ -1352,7 +1413,11 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
-    pub(crate) async fn is_verified_account(self, account_key: Key) -> Option<bool>
+    /// Returns the account under `account_key` in the global state of the highest block, and
+    /// whether its main purse holds at least the maximum payment amount.
+    ///
+    /// Returns `None` if there is no such account, or if its balance could not be determined.
+    pub(crate) async fn get_verified_account(self, account_key: Key) -> Option<(Box<Account>, bool)>
     where
         REv: From<ContractRuntimeRequest>,
         REv: From<StorageRequest>,
@@ -1368,7 +1433,8 @@ impl<REv> EffectBuilder<REv> {
                     let balance_request = BalanceRequest::new(state_hash, purse_uref);
                     if let Ok(balance_result) = self.get_balance(balance_request).await {
                         if let Some(motes) = balance_result.motes() {
-                            return Some(motes >= &*MAX_PAYMENT);
+                            let sufficient_balance = motes >= &*MAX_PAYMENT;
+                            return Some((Box::new(account), sufficient_balance));
                         }
                     }
                 }
@@ -1501,6 +1567,31 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Get the evidence against a validator in the given era from consensus, if there is any.
+    pub(crate) async fn get_evidence(
+        self,
+        era_id: EraId,
+        public_key: PublicKey,
+    ) -> Option<JsonEvidence>
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(
+            |responder| ConsensusRequest::GetEvidence(era_id, public_key, responder),
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Get our public key and our validator's status in every active era in which we are bonded.
+    pub(crate) async fn get_validator_status(self) -> (PublicKey, Vec<ValidatorStatus>)
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(ConsensusRequest::ValidatorStatus, QueueKind::Regular)
+            .await
+    }
+
     /// Check if validator is bonded in the future era (`era_id`).
     /// This information is known only by the Contract Runtime since consensus component
     /// knows only about currently active eras.
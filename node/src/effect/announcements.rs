@@ -4,7 +4,7 @@
 //! module documentation for details.
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt::{self, Display, Formatter},
 };
 
@@ -141,6 +141,13 @@ pub enum ConsensusAnnouncement<I> {
     },
     /// We want to disconnect from a peer due to its transgressions.
     DisconnectFromPeer(I),
+    /// A new era has started, and its validators are now the active validator set.
+    ActiveValidatorsChanged {
+        /// The ID of the new era.
+        era_id: EraId,
+        /// The validators of the new era.
+        validators: Box<BTreeSet<PublicKey>>,
+    },
 }
 
 impl<I> Display for ConsensusAnnouncement<I>
@@ -170,6 +177,12 @@ where
             ConsensusAnnouncement::DisconnectFromPeer(peer) => {
                 write!(formatter, "Consensus wanting to disconnect from {}", peer)
             }
+            ConsensusAnnouncement::ActiveValidatorsChanged { era_id, validators } => write!(
+                formatter,
+                "{} validators are active in era {}",
+                validators.len(),
+                era_id
+            ),
         }
     }
 }
@@ -43,7 +43,8 @@ use casper_types::{
 use super::Responder;
 use crate::{
     components::{
-        consensus::EraId,
+        block_proposer::{AdmissionError, DeployType, PendingDeploy},
+        consensus::{EraId, JsonEvidence, ValidatorStatus},
         contract_runtime::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest},
         deploy_acceptor::Error,
         fetcher::FetchResult,
@@ -469,6 +470,24 @@ pub struct ProtoBlockRequest {
 pub enum BlockProposerRequest {
     /// Request a list of deploys to propose in a new block.
     RequestProtoBlock(ProtoBlockRequest),
+    /// Check whether a new deploy would be admitted to the buffer of pending deploys.
+    CheckAdmission {
+        /// The deploy's header and size.
+        deploy_type: Box<DeployType>,
+        /// Responder to call with the result.
+        responder: Responder<Result<(), AdmissionError>>,
+    },
+    /// Request a page of the deploys waiting to be included in a block.
+    GetPendingDeploys {
+        /// If given, only deploys from this account are returned.
+        account: Option<PublicKey>,
+        /// The number of deploys to skip.
+        offset: u32,
+        /// The maximum number of deploys to return.
+        limit: u32,
+        /// Responder to call with the total number of matching deploys, and the requested page.
+        responder: Responder<(u64, Vec<PendingDeploy>)>,
+    },
 }
 
 impl Display for BlockProposerRequest {
@@ -487,6 +506,28 @@ impl Display for BlockProposerRequest {
                 past_deploys.len(),
                 next_finalized
             ),
+            BlockProposerRequest::CheckAdmission { deploy_type, .. } => write!(
+                formatter,
+                "check admission of deploy with header {}",
+                deploy_type.header()
+            ),
+            BlockProposerRequest::GetPendingDeploys {
+                account,
+                offset,
+                limit,
+                ..
+            } => match account {
+                Some(account) => write!(
+                    formatter,
+                    "get {} pending deploys from {} starting at {}",
+                    limit, account, offset
+                ),
+                None => write!(
+                    formatter,
+                    "get {} pending deploys starting at {}",
+                    limit, offset
+                ),
+            },
         }
     }
 }
@@ -580,6 +621,33 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<StatusFeed<I>>,
     },
+    /// Return the evidence against a validator in the given era, if it exists, else `None`.
+    GetEvidence {
+        /// The era in which the validator is faulty.
+        era_id: EraId,
+        /// The public key of the faulty validator.
+        public_key: PublicKey,
+        /// Responder to call with the result.
+        responder: Responder<Option<JsonEvidence>>,
+    },
+    /// Return our public key and our validator's status in every active era in which we are
+    /// bonded.
+    GetValidatorStatus {
+        /// Responder to call with the result.
+        responder: Responder<(PublicKey, Vec<ValidatorStatus>)>,
+    },
+    /// Return the total number of pending deploys, optionally only from a given account, and a
+    /// page of them.
+    GetPendingDeploys {
+        /// If given, only deploys from this account are returned.
+        account: Option<PublicKey>,
+        /// The number of deploys to skip.
+        offset: u32,
+        /// The maximum number of deploys to return.
+        limit: u32,
+        /// Responder to call with the result.
+        responder: Responder<(u64, Vec<PendingDeploy>)>,
+    },
     /// Return string formatted, prometheus compatible metrics or `None` if an error occurred.
     GetMetrics {
         /// Responder to call with the result.
@@ -636,6 +704,19 @@ impl<I> Display for RpcRequest<I> {
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
+            RpcRequest::GetEvidence {
+                era_id, public_key, ..
+            } => write!(
+                formatter,
+                "get evidence against {} in {}",
+                public_key, era_id
+            ),
+            RpcRequest::GetValidatorStatus { .. } => write!(formatter, "get validator status"),
+            RpcRequest::GetPendingDeploys { offset, limit, .. } => write!(
+                formatter,
+                "get {} pending deploys starting at {}",
+                limit, offset
+            ),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
         }
     }
@@ -987,6 +1068,11 @@ pub enum ConsensusRequest {
     IsBondedValidator(EraId, PublicKey, Responder<bool>),
     /// Request for our public key, and if we're a validator, the next round length.
     Status(Responder<(PublicKey, Option<TimeDiff>)>),
+    /// Request for the evidence against a validator in the given era, if there is any.
+    GetEvidence(EraId, PublicKey, Responder<Option<JsonEvidence>>),
+    /// Request for our public key, and our validator's status in every active era in which we
+    /// are bonded.
+    ValidatorStatus(Responder<(PublicKey, Vec<ValidatorStatus>)>),
 }
 
 /// ChainspecLoader component requests.
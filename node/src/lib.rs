@@ -53,7 +53,11 @@ use signal_hook::{
 };
 
 pub use components::{
-    consensus::Config as ConsensusConfig,
+    block_proposer::{Config as BlockProposerConfig, PendingDeploy},
+    consensus::{
+        Config as ConsensusConfig, EvidenceVerificationError, JsonEvidence, JsonObservation,
+        JsonUnit, ValidatorStatus,
+    },
     contract_runtime::Config as ContractRuntimeConfig,
     deploy_acceptor::Config as DeployAcceptorConfig,
     event_stream_server::Config as EventStreamServerConfig,
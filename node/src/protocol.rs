@@ -8,7 +8,10 @@ use hex_fmt::HexFmt;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    components::{consensus, gossiper, small_network::GossipedAddress},
+    components::{
+        consensus, gossiper,
+        small_network::{Channel, GossipedAddress, Payload},
+    },
     types::{Deploy, FinalitySignature, Item, Tag},
 };
 
@@ -59,6 +62,19 @@ impl Message {
     }
 }
 
+impl Payload for Message {
+    fn channel(&self) -> Channel {
+        match self {
+            Message::Consensus(_) => Channel::Consensus,
+            Message::DeployGossiper(_)
+            | Message::AddressGossiper(_)
+            | Message::GetRequest { .. }
+            | Message::GetResponse { .. }
+            | Message::FinalitySignature(_) => Channel::Bulk,
+        }
+    }
+}
+
 impl Debug for Message {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -32,7 +32,7 @@ use crate::{
         block_executor::{self, BlockExecutor},
         block_validator::{self, BlockValidator},
         chainspec_loader::{self, ChainspecLoader},
-        consensus::{self, EraSupervisor, HighwayProtocol},
+        consensus::{self, EraSupervisor},
         contract_runtime::{self, ContractRuntime},
         deploy_acceptor::{self, DeployAcceptor},
         event_stream_server,
@@ -59,7 +59,7 @@ use crate::{
             LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest, RestRequest,
             StateStoreRequest, StorageRequest,
         },
-        EffectBuilder, Effects,
+        EffectBuilder, EffectExt, Effects,
     },
     protocol::Message,
     reactor::{
@@ -416,6 +416,7 @@ impl reactor::Reactor for Reactor {
             small_network_identity,
             network_name,
             false,
+            None,
         )?;
 
         let linear_chain_fetcher = Fetcher::new("linear_chain", config.fetcher, &registry)?;
@@ -516,7 +517,7 @@ impl reactor::Reactor for Reactor {
             chainspec_loader.chainspec().as_ref().into(),
             maybe_next_activation_point,
             registry,
-            Box::new(HighwayProtocol::new_boxed),
+            Box::new(consensus::new_boxed_consensus),
         )?;
         effects.extend(reactor::wrap_effects(
             Event::Consensus,
@@ -814,7 +815,23 @@ impl reactor::Reactor for Reactor {
                     warn!("disconnecting from a given peer not yet implemented.");
                     Effects::new()
                 }
+                ConsensusAnnouncement::ActiveValidatorsChanged {
+                    era_id: _,
+                    validators,
+                } => {
+                    let reactor_event = Event::SmallNetwork(
+                        small_network::Event::ActiveValidatorsChanged(validators),
+                    );
+                    self.dispatch_event(effect_builder, rng, reactor_event)
+                }
             },
+            Event::BlockProposerRequest(BlockProposerRequest::CheckAdmission {
+                responder, ..
+            }) => {
+                // There is no deploy buffer during joining phase, so there are no limits to
+                // enforce.
+                responder.respond(Ok(())).ignore()
+            }
             Event::BlockProposerRequest(request) => {
                 // Consensus component should not be trying to create new blocks during joining
                 // phase.
@@ -407,6 +407,8 @@ impl reactor::Reactor for Reactor {
             true,
         )?;
         let network_name = chainspec_loader.chainspec().network_config.name.clone();
+        let validator_proof =
+            consensus.validator_proof(&NodeId::from(&small_network_identity), rng);
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
             config.network,
@@ -414,6 +416,7 @@ impl reactor::Reactor for Reactor {
             small_network_identity,
             network_name,
             true,
+            Some(validator_proof),
         )?;
 
         let address_gossiper =
@@ -448,6 +451,7 @@ impl reactor::Reactor for Reactor {
                 .map(|block| block.height() + 1)
                 .unwrap_or(0),
             chainspec_loader.chainspec().as_ref(),
+            config.block_proposer,
         )?;
         let mut effects = reactor::wrap_effects(Event::BlockProposer, block_proposer_effects);
         let block_executor = BlockExecutor::new(
@@ -465,6 +469,16 @@ impl reactor::Reactor for Reactor {
             Event::SmallNetwork,
             small_network_effects,
         ));
+        // The current era's validators were announced while we were still joining, so the new
+        // small network instance needs to learn about them here.
+        if let Some(validators) = consensus.current_era_validators() {
+            effects.extend(reactor::wrap_effects(
+                Event::SmallNetwork,
+                effect_builder.immediately().event(move |_| {
+                    small_network::Event::ActiveValidatorsChanged(Box::new(validators))
+                }),
+            ));
+        }
         // This is a workaround for dropping the Era Supervisor's timer event when transitioning
         // from the joiner.
         // TODO: Remove this once the consensus component is removed from the Joiner reactor.
@@ -870,6 +884,15 @@ impl reactor::Reactor for Reactor {
                         warn!("Disconnecting from a given peer not yet implemented.");
                         Effects::new()
                     }
+                    ConsensusAnnouncement::ActiveValidatorsChanged {
+                        era_id: _,
+                        validators,
+                    } => {
+                        let reactor_event = Event::SmallNetwork(
+                            small_network::Event::ActiveValidatorsChanged(validators),
+                        );
+                        self.dispatch_event(effect_builder, rng, reactor_event)
+                    }
                 }
             }
             Event::BlockExecutorAnnouncement(BlockExecutorAnnouncement::LinearChainBlock {
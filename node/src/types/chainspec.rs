@@ -14,6 +14,7 @@ mod protocol_config;
 use std::{fmt::Debug, path::Path};
 
 use datasize::DataSize;
+use num::rational::Ratio;
 #[cfg(test)]
 use rand::Rng;
 use serde::Serialize;
@@ -28,9 +29,13 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 #[cfg(test)]
 pub(crate) use self::accounts_config::{AccountConfig, ValidatorConfig};
 pub(crate) use self::{
-    accounts_config::AccountsConfig, core_config::CoreConfig, deploy_config::DeployConfig,
-    global_state_update::GlobalStateUpdate, highway_config::HighwayConfig,
-    network_config::NetworkConfig, protocol_config::ProtocolConfig,
+    accounts_config::AccountsConfig,
+    core_config::{ConsensusProtocolName, CoreConfig},
+    deploy_config::DeployConfig,
+    global_state_update::GlobalStateUpdate,
+    highway_config::HighwayConfig,
+    network_config::NetworkConfig,
+    protocol_config::ProtocolConfig,
 };
 pub use self::{error::Error, protocol_config::ActivationPoint};
 #[cfg(test)]
@@ -77,6 +82,20 @@ impl Chainspec {
             warn!("era duration is less than minimum era height * round length!");
         }
 
+        if self.core_config.slashing_rate > Ratio::new(1, 1) {
+            panic!(
+                "Slashing rate is greater than 1! Slashing rate: {rate}",
+                rate = self.core_config.slashing_rate
+            );
+        }
+
+        if self.core_config.delegation_slashing_rate > Ratio::new(1, 1) {
+            panic!(
+                "Delegation slashing rate is greater than 1! Delegation slashing rate: {rate}",
+                rate = self.core_config.delegation_slashing_rate
+            );
+        }
+
         self.highway_config.validate_config();
     }
 
@@ -184,6 +203,9 @@ impl From<&Chainspec> for ExecConfig {
             chainspec.core_config.locked_funds_period.millis(),
             chainspec.core_config.round_seigniorage_rate,
             chainspec.core_config.unbonding_delay,
+            chainspec.core_config.slashing_rate,
+            chainspec.core_config.delegation_slashing_rate,
+            chainspec.core_config.jail_period,
             chainspec.network_config.timestamp.millis(),
         )
     }
@@ -252,6 +274,12 @@ mod tests {
             remove_contract_user_group_urefs: HostFunction::new(131, [0, 1, 2, 3, 4, 5]),
             print: HostFunction::new(123, [0, 1]),
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
+            add_contract_version_with_migration: HostFunction::new(
+                103,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            ),
+            load_call_stack: HostFunction::new(142, [0, 1]),
+            set_upgrade_policy: HostFunction::new(143, [0, 1, 2, 3]),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
@@ -289,6 +317,10 @@ mod tests {
         if is_first_version {
             assert_eq!(spec.protocol_config.version, Version::from((0, 9, 0)));
             assert_eq!(spec.network_config.accounts_config.accounts().len(), 4);
+            assert_eq!(
+                spec.core_config.consensus_protocol,
+                ConsensusProtocolName::Highway
+            );
             for index in 0..4 {
                 assert_eq!(
                     spec.network_config.accounts_config.accounts()[index].balance(),
@@ -302,6 +334,10 @@ mod tests {
         } else {
             assert_eq!(spec.protocol_config.version, Version::from((1, 0, 0)));
             assert!(spec.network_config.accounts_config.accounts().is_empty());
+            assert_eq!(
+                spec.core_config.consensus_protocol,
+                ConsensusProtocolName::SimpleBft
+            );
             assert!(spec.protocol_config.global_state_update.is_some());
             for value in spec.protocol_config.global_state_update.unwrap().0.values() {
                 assert!(StoredValue::from_bytes(value).is_ok());
@@ -313,6 +349,9 @@ mod tests {
 
         assert_eq!(spec.core_config.era_duration, TimeDiff::from(180000));
         assert_eq!(spec.core_config.minimum_era_height, 9);
+        assert_eq!(spec.core_config.slashing_rate, Ratio::new(1, 10));
+        assert_eq!(spec.core_config.delegation_slashing_rate, Ratio::new(1, 20));
+        assert_eq!(spec.core_config.jail_period, 7);
         assert_eq!(
             spec.highway_config.finality_threshold_fraction,
             Ratio::new(2, 25)
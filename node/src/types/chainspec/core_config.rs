@@ -10,6 +10,50 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 use crate::testing::TestRng;
 use crate::types::TimeDiff;
 
+/// The consensus protocol used in each era.
+#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum ConsensusProtocolName {
+    /// Highway, with rounds of exponentially varying length and probabilistic finality.
+    Highway,
+    /// A simple round-based BFT protocol with a single leader per round and instant finality.
+    SimpleBft,
+}
+
+impl Default for ConsensusProtocolName {
+    fn default() -> Self {
+        ConsensusProtocolName::Highway
+    }
+}
+
+const HIGHWAY_TAG: u8 = 0;
+const SIMPLE_BFT_TAG: u8 = 1;
+
+impl ToBytes for ConsensusProtocolName {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            ConsensusProtocolName::Highway => HIGHWAY_TAG,
+            ConsensusProtocolName::SimpleBft => SIMPLE_BFT_TAG,
+        };
+        Ok(vec![tag])
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for ConsensusProtocolName {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let name = match tag {
+            HIGHWAY_TAG => ConsensusProtocolName::Highway,
+            SIMPLE_BFT_TAG => ConsensusProtocolName::SimpleBft,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((name, remainder))
+    }
+}
+
 #[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
 #[serde(deny_unknown_fields)]
@@ -28,6 +72,20 @@ pub struct CoreConfig {
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
+    /// Fraction of a slashed validator's stake, and of its pending unbonds, that is burned.
+    #[data_size(skip)]
+    pub(crate) slashing_rate: Ratio<u64>,
+    /// Fraction of the active delegations to a slashed validator that is burned.
+    #[data_size(skip)]
+    pub(crate) delegation_slashing_rate: Ratio<u64>,
+    /// Number of eras a slashed validator is jailed for.
+    pub(crate) jail_period: u64,
+    /// The consensus protocol to use in each era from this version's activation point on.
+    ///
+    /// Eras before the activation point were run with the previous version's protocol. The node
+    /// never creates instances for those eras, so an upgrade can switch to a different protocol.
+    #[serde(default)]
+    pub(crate) consensus_protocol: ConsensusProtocolName,
 }
 
 #[cfg(test)]
@@ -44,6 +102,14 @@ impl CoreConfig {
             rng.gen_range(1, 1_000_000_000),
             rng.gen_range(1, 1_000_000_000),
         );
+        let slashing_rate = Ratio::new(rng.gen_range(0, 100), 100);
+        let delegation_slashing_rate = Ratio::new(rng.gen_range(0, 100), 100);
+        let jail_period = rng.gen_range(0, 100);
+        let consensus_protocol = if rng.gen() {
+            ConsensusProtocolName::Highway
+        } else {
+            ConsensusProtocolName::SimpleBft
+        };
 
         CoreConfig {
             era_duration,
@@ -53,6 +119,10 @@ impl CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            slashing_rate,
+            delegation_slashing_rate,
+            jail_period,
+            consensus_protocol,
         }
     }
 }
@@ -67,6 +137,10 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.slashing_rate.to_bytes()?);
+        buffer.extend(self.delegation_slashing_rate.to_bytes()?);
+        buffer.extend(self.jail_period.to_bytes()?);
+        buffer.extend(self.consensus_protocol.to_bytes()?);
         Ok(buffer)
     }
 
@@ -78,6 +152,10 @@ impl ToBytes for CoreConfig {
             + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
+            + self.slashing_rate.serialized_length()
+            + self.delegation_slashing_rate.serialized_length()
+            + self.jail_period.serialized_length()
+            + self.consensus_protocol.serialized_length()
     }
 }
 
@@ -90,6 +168,10 @@ impl FromBytes for CoreConfig {
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (slashing_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (delegation_slashing_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (jail_period, remainder) = u64::from_bytes(remainder)?;
+        let (consensus_protocol, remainder) = ConsensusProtocolName::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -98,6 +180,10 @@ impl FromBytes for CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            slashing_rate,
+            delegation_slashing_rate,
+            jail_period,
+            consensus_protocol,
         };
         Ok((config, remainder))
     }
@@ -72,6 +72,7 @@ static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
         body_hash,
         dependencies: vec![DeployHash::new(Digest::from([1u8; Digest::LENGTH]))],
         chain_name: String::from("casper-example"),
+        fee_payer: None,
     };
     let serialized_header = serialize_header(&header);
     let hash = DeployHash::new(hash::hash(&serialized_header));
@@ -178,6 +179,22 @@ pub enum DeployValidationFailure {
         /// The attempted transfer amount.
         attempted: U512,
     },
+
+    /// The fee payer is the deploy's own account.
+    #[error("the fee payer is the deploy's own account")]
+    FeePayerIsAccount,
+
+    /// Wasm-less transfers cannot be paid for by a separate fee payer.
+    #[error("wasm-less transfers cannot be paid for by a separate fee payer")]
+    TransferWithFeePayer,
+
+    /// Payment code cannot be a batch of calls.
+    #[error("payment code cannot be a batch of calls")]
+    BatchedPayment,
+
+    /// A batched session contains no calls.
+    #[error("batched session contains no calls")]
+    EmptyBatch,
 }
 
 /// Errors other than validation failures relating to `Deploy`s.
@@ -294,6 +311,8 @@ pub struct DeployHeader {
     body_hash: Digest,
     dependencies: Vec<DeployHash>,
     chain_name: String,
+    #[serde(default)]
+    fee_payer: Option<PublicKey>,
 }
 
 impl DeployHeader {
@@ -338,6 +357,11 @@ impl DeployHeader {
         &self.chain_name
     }
 
+    /// The account paying for the deploy's execution, if other than the deploy's own account.
+    pub fn fee_payer(&self) -> Option<&PublicKey> {
+        self.fee_payer.as_ref()
+    }
+
     /// Determine if this deploy header has valid values based on a `DeployConfig` and timestamp.
     pub fn is_valid(&self, deploy_config: &DeployConfig, current_timestamp: Timestamp) -> bool {
         let ttl_valid = self.ttl() <= deploy_config.max_ttl;
@@ -355,9 +379,19 @@ impl DeployHeader {
     }
 }
 
+/// Precedes a serialized `DeployHeader` which has a fee payer, in which case the fee payer
+/// follows the other fields.
+///
+/// It isn't a valid public key tag, so it can't start a header without a fee payer, which is
+/// serialized exactly as before fee payers existed and hence keeps the same deploy hash.
+const DEPLOY_HEADER_WITH_FEE_PAYER_TAG: u8 = u8::MAX;
+
 impl ToBytes for DeployHeader {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        if self.fee_payer.is_some() {
+            buffer.push(DEPLOY_HEADER_WITH_FEE_PAYER_TAG);
+        }
         buffer.extend(self.account.to_bytes()?);
         buffer.extend(self.timestamp.to_bytes()?);
         buffer.extend(self.ttl.to_bytes()?);
@@ -365,6 +399,9 @@ impl ToBytes for DeployHeader {
         buffer.extend(self.body_hash.to_bytes()?);
         buffer.extend(self.dependencies.to_bytes()?);
         buffer.extend(self.chain_name.to_bytes()?);
+        if let Some(fee_payer) = &self.fee_payer {
+            buffer.extend(fee_payer.to_bytes()?);
+        }
         Ok(buffer)
     }
 
@@ -376,18 +413,31 @@ impl ToBytes for DeployHeader {
             + self.body_hash.serialized_length()
             + self.dependencies.serialized_length()
             + self.chain_name.serialized_length()
+            + self.fee_payer.as_ref().map_or(0, |fee_payer| {
+                DEPLOY_HEADER_WITH_FEE_PAYER_TAG.serialized_length() + fee_payer.serialized_length()
+            })
     }
 }
 
 impl FromBytes for DeployHeader {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (account, remainder) = PublicKey::from_bytes(bytes)?;
+        let (has_fee_payer, remainder) = match bytes.split_first() {
+            Some((&DEPLOY_HEADER_WITH_FEE_PAYER_TAG, remainder)) => (true, remainder),
+            _ => (false, bytes),
+        };
+        let (account, remainder) = PublicKey::from_bytes(remainder)?;
         let (timestamp, remainder) = Timestamp::from_bytes(remainder)?;
         let (ttl, remainder) = TimeDiff::from_bytes(remainder)?;
         let (gas_price, remainder) = u64::from_bytes(remainder)?;
         let (body_hash, remainder) = Digest::from_bytes(remainder)?;
         let (dependencies, remainder) = Vec::<DeployHash>::from_bytes(remainder)?;
         let (chain_name, remainder) = String::from_bytes(remainder)?;
+        let (fee_payer, remainder) = if has_fee_payer {
+            let (fee_payer, remainder) = PublicKey::from_bytes(remainder)?;
+            (Some(fee_payer), remainder)
+        } else {
+            (None, remainder)
+        };
         let deploy_header = DeployHeader {
             account,
             timestamp,
@@ -396,6 +446,7 @@ impl FromBytes for DeployHeader {
             body_hash,
             dependencies,
             chain_name,
+            fee_payer,
         };
         Ok((deploy_header, remainder))
     }
@@ -405,7 +456,7 @@ impl Display for DeployHeader {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         write!(
             formatter,
-            "deploy-header[account: {}, timestamp: {}, ttl: {}, gas_price: {}, body_hash: {}, dependencies: [{}], chain_name: {}]",
+            "deploy-header[account: {}, timestamp: {}, ttl: {}, gas_price: {}, body_hash: {}, dependencies: [{}], chain_name: {}, fee_payer: {}]",
             self.account,
             self.timestamp,
             self.ttl,
@@ -413,6 +464,9 @@ impl Display for DeployHeader {
             self.body_hash,
             DisplayIter::new(self.dependencies.iter()),
             self.chain_name,
+            self.fee_payer
+                .as_ref()
+                .map_or_else(|| String::from("none"), ToString::to_string),
         )
     }
 }
@@ -484,6 +538,9 @@ pub struct Deploy {
 
 impl Deploy {
     /// Constructs a new signed `Deploy`.
+    ///
+    /// If `fee_payer` is provided, the deploy also needs to be signed by the fee payer's keys via
+    /// [`sign`](#method.sign) before it can be executed.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         timestamp: Timestamp,
@@ -491,6 +548,7 @@ impl Deploy {
         gas_price: u64,
         dependencies: Vec<DeployHash>,
         chain_name: String,
+        fee_payer: Option<PublicKey>,
         payment: ExecutableDeployItem,
         session: ExecutableDeployItem,
         secret_key: &SecretKey,
@@ -510,6 +568,7 @@ impl Deploy {
             body_hash,
             dependencies,
             chain_name,
+            fee_payer,
         };
         let serialized_header = serialize_header(&header);
         let hash = DeployHash::new(hash::hash(&serialized_header));
@@ -681,7 +740,13 @@ impl Deploy {
             });
         }
 
-        let session_args_length = self.session().args().serialized_length();
+        let session_args_length = match self.session().batched_calls() {
+            Some(calls) => calls
+                .iter()
+                .map(|call| call.args().serialized_length())
+                .sum(),
+            None => self.session().args().serialized_length(),
+        };
         if session_args_length > config.session_args_max_length as usize {
             info!(
                 session_args_length,
@@ -694,6 +759,29 @@ impl Deploy {
             });
         }
 
+        if self.payment().batched_calls().is_some() {
+            info!(deploy_hash = %self.id(), "batched payment code");
+            return Err(DeployValidationFailure::BatchedPayment);
+        }
+
+        if let Some(calls) = self.session().batched_calls() {
+            if calls.is_empty() {
+                info!(deploy_hash = %self.id(), "empty batched session");
+                return Err(DeployValidationFailure::EmptyBatch);
+            }
+        }
+
+        if let Some(fee_payer) = header.fee_payer() {
+            if fee_payer == header.account() {
+                info!(deploy_hash = %self.id(), "fee payer is the deploy's account");
+                return Err(DeployValidationFailure::FeePayerIsAccount);
+            }
+            if self.session().is_transfer() {
+                info!(deploy_hash = %self.id(), "wasm-less transfer with fee payer");
+                return Err(DeployValidationFailure::TransferWithFeePayer);
+            }
+        }
+
         if self.session().is_transfer() {
             let item = self.session().clone();
             let attempted = item
@@ -740,6 +828,7 @@ impl Deploy {
             gas_price,
             dependencies,
             chain_name,
+            None,
             payment,
             session,
             &secret_key,
@@ -846,6 +935,10 @@ impl From<Deploy> for DeployItem {
             deploy.header().gas_price(),
             authorization_keys,
             casper_types::DeployHash::new(deploy.id().inner().to_array()),
+            deploy
+                .header()
+                .fee_payer()
+                .map(|fee_payer| fee_payer.to_account_hash()),
         )
     }
 }
@@ -904,7 +997,7 @@ impl FromBytes for Deploy {
 mod tests {
     use std::{iter, time::Duration};
 
-    use casper_execution_engine::core::engine_state::MAX_PAYMENT_AMOUNT;
+    use casper_execution_engine::core::engine_state::{BatchedCall, MAX_PAYMENT_AMOUNT};
     use casper_types::{bytesrepr::Bytes, CLValue};
 
     use super::*;
@@ -928,6 +1021,21 @@ mod tests {
         assert_eq!(deploy, deserialized);
     }
 
+    #[test]
+    fn bincode_roundtrip_with_and_without_fee_payer() {
+        let mut rng = crate::new_rng();
+        let mut deploy = Deploy::random(&mut rng);
+        assert!(deploy.header().fee_payer().is_none());
+        let serialized = bincode::serialize(&deploy).unwrap();
+        let deserialized: Deploy = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deploy, deserialized);
+
+        deploy.header.fee_payer = Some(PublicKey::from(&SecretKey::random(&mut rng)));
+        let serialized = bincode::serialize(&deploy).unwrap();
+        let deserialized: Deploy = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deploy, deserialized);
+    }
+
     #[test]
     fn bytesrepr_roundtrip() {
         let mut rng = crate::new_rng();
@@ -939,6 +1047,40 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&deploy);
     }
 
+    #[test]
+    fn bytesrepr_roundtrip_with_fee_payer() {
+        let mut rng = crate::new_rng();
+        let mut deploy = Deploy::random(&mut rng);
+        deploy.header.fee_payer = Some(PublicKey::from(&SecretKey::random(&mut rng)));
+        bytesrepr::test_serialization_roundtrip(deploy.header());
+        bytesrepr::test_serialization_roundtrip(&deploy);
+
+        let json_string = serde_json::to_string_pretty(&deploy).unwrap();
+        let decoded: Deploy = serde_json::from_str(&json_string).unwrap();
+        assert_eq!(deploy, decoded);
+    }
+
+    #[test]
+    fn header_without_fee_payer_serializes_as_before() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random(&mut rng);
+        let header = deploy.header();
+        assert!(header.fee_payer().is_none());
+
+        let mut expected_bytes = vec![];
+        expected_bytes.extend(header.account().to_bytes().unwrap());
+        expected_bytes.extend(header.timestamp().to_bytes().unwrap());
+        expected_bytes.extend(header.ttl().to_bytes().unwrap());
+        expected_bytes.extend(header.gas_price().to_bytes().unwrap());
+        expected_bytes.extend(header.body_hash().to_bytes().unwrap());
+        expected_bytes.extend(header.dependencies().to_bytes().unwrap());
+        expected_bytes.extend(header.chain_name().to_string().to_bytes().unwrap());
+
+        assert_eq!(header.to_bytes().unwrap(), expected_bytes);
+        let decoded: DeployHeader = bytesrepr::deserialize(expected_bytes).unwrap();
+        assert_eq!(&decoded, header);
+    }
+
     fn create_deploy(
         rng: &mut TestRng,
         ttl: TimeDiff,
@@ -962,6 +1104,7 @@ mod tests {
             1,
             dependencies,
             chain_name.to_string(),
+            None,
             ExecutableDeployItem::ModuleBytes {
                 module_bytes: Bytes::new(),
                 args: RuntimeArgs::new(),
@@ -1163,4 +1306,71 @@ mod tests {
             "deploy should not have run expensive `is_valid` call"
         );
     }
+
+    #[test]
+    fn not_acceptable_due_to_fee_payer() {
+        let mut rng = crate::new_rng();
+        let chain_name = "net-1";
+        let deploy_config = DeployConfig::default();
+
+        // The deploy created by `create_deploy` is a wasm-less transfer.
+        let mut deploy = create_deploy(
+            &mut rng,
+            deploy_config.max_ttl,
+            deploy_config.max_dependencies.into(),
+            &chain_name,
+        );
+        deploy.header.fee_payer = Some(PublicKey::from(&SecretKey::random(&mut rng)));
+        assert_eq!(
+            deploy.is_acceptable(chain_name, &deploy_config),
+            Err(DeployValidationFailure::TransferWithFeePayer)
+        );
+
+        deploy.header.fee_payer = Some(*deploy.header.account());
+        assert_eq!(
+            deploy.is_acceptable(chain_name, &deploy_config),
+            Err(DeployValidationFailure::FeePayerIsAccount)
+        );
+        assert!(
+            deploy.is_valid.is_none(),
+            "deploy should not have run expensive `is_valid` call"
+        );
+    }
+
+    #[test]
+    fn not_acceptable_due_to_invalid_batch() {
+        let mut rng = crate::new_rng();
+        let chain_name = "net-1";
+        let deploy_config = DeployConfig::default();
+
+        let mut deploy = create_deploy(
+            &mut rng,
+            deploy_config.max_ttl,
+            deploy_config.max_dependencies.into(),
+            &chain_name,
+        );
+
+        deploy.session = ExecutableDeployItem::Batch { calls: vec![] };
+        assert_eq!(
+            deploy.is_acceptable(chain_name, &deploy_config),
+            Err(DeployValidationFailure::EmptyBatch)
+        );
+
+        let stored_call = BatchedCall::StoredContractByName {
+            name: String::from("counter"),
+            entry_point: String::from("increment"),
+            args: RuntimeArgs::new(),
+        };
+        deploy.payment = ExecutableDeployItem::Batch {
+            calls: vec![stored_call],
+        };
+        assert_eq!(
+            deploy.is_acceptable(chain_name, &deploy_config),
+            Err(DeployValidationFailure::BatchedPayment)
+        );
+        assert!(
+            deploy.is_valid.is_none(),
+            "deploy should not have run expensive `is_valid` call"
+        );
+    }
 }
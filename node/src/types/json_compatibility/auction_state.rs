@@ -9,7 +9,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use casper_types::{
-    system::auction::{Bid, Bids, DelegationRate, Delegator, EraId, EraValidators},
+    system::auction::{
+        Bid, Bids, DelegationRate, Delegator, EraId, EraValidators, RewardDestination,
+    },
     AccessRights, PublicKey, SecretKey, URef, U512,
 };
 
@@ -43,13 +45,15 @@ static BIDS: Lazy<Bids> = Lazy::new(|| {
     let mut delegators = BTreeMap::new();
     delegators.insert(delegator_public_key, delegator);
 
-    let bid = Bid::locked(
+    let mut bid = Bid::locked(
         validator_public_key,
         bonding_purse,
         staked_amount,
         DelegationRate::zero(),
         release_era,
     );
+    bid.with_delegation_limits(Some(U512::from(5)), None, Some(100))
+        .expect("should have valid delegation limits");
     let mut bids = BTreeMap::new();
     bids.insert(validator_public_key, bid);
 
@@ -87,6 +91,7 @@ pub struct JsonDelegator {
     staked_amount: U512,
     bonding_purse: URef,
     delegatee: PublicKey,
+    reward_destination: RewardDestination,
 }
 
 /// An entry in a founding validator map representing a bid.
@@ -103,6 +108,16 @@ pub struct JsonBid {
     delegators: Vec<JsonDelegator>,
     /// Is this an inactive validator.
     inactive: bool,
+    /// The minimum amount a delegator must have staked with this validator.
+    minimum_delegation_amount: Option<U512>,
+    /// The maximum amount a delegator may have staked with this validator.
+    maximum_delegation_amount: Option<U512>,
+    /// The maximum number of delegators this validator accepts.
+    maximum_delegators: Option<u32>,
+    /// The era from which this validator may unjail, if it is jailed.
+    jailed_until: Option<EraId>,
+    /// Where this validator's era rewards go.
+    reward_destination: RewardDestination,
 }
 
 impl From<Bid> for JsonBid {
@@ -114,6 +129,7 @@ impl From<Bid> for JsonBid {
                 staked_amount: *delegator.staked_amount(),
                 bonding_purse: *delegator.bonding_purse(),
                 delegatee: *delegator.validator_public_key(),
+                reward_destination: *delegator.reward_destination(),
             });
         }
         JsonBid {
@@ -122,6 +138,11 @@ impl From<Bid> for JsonBid {
             delegation_rate: *bid.delegation_rate(),
             delegators: json_delegators,
             inactive: bid.inactive(),
+            minimum_delegation_amount: bid.minimum_delegation_amount().copied(),
+            maximum_delegation_amount: bid.maximum_delegation_amount().copied(),
+            maximum_delegators: bid.maximum_delegators(),
+            jailed_until: bid.jailed_until(),
+            reward_destination: *bid.reward_destination(),
         }
     }
 }
@@ -9,7 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::types::json_compatibility::vectorize;
 use casper_types::{
     Contract as DomainContract, ContractHash, ContractPackage as DomainContractPackage,
-    ContractPackageHash, ContractWasmHash, EntryPoint, NamedKey, URef,
+    ContractPackageHash, ContractWasmHash, EntryPoint, NamedKey, URef, UpgradePolicy,
+    VersionActivation,
 };
 
 #[derive(
@@ -27,6 +28,14 @@ pub struct DisabledVersion {
     contract_version: u32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema)]
+pub struct PendingVersion {
+    protocol_version_major: u32,
+    contract_version: u32,
+    #[data_size(skip)]
+    activation: VersionActivation,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, DataSize, JsonSchema)]
 pub struct Groups {
     group: String,
@@ -76,6 +85,9 @@ pub struct ContractPackage {
     versions: Vec<ContractVersion>,
     disabled_versions: Vec<DisabledVersion>,
     groups: Vec<Groups>,
+    #[data_size(skip)]
+    upgrade_policy: UpgradePolicy,
+    pending_versions: Vec<PendingVersion>,
 }
 
 impl From<&DomainContractPackage> for ContractPackage {
@@ -108,11 +120,23 @@ impl From<&DomainContractPackage> for ContractPackage {
             })
             .collect();
 
+        let pending_versions = contract_package
+            .pending_versions()
+            .iter()
+            .map(|(version, activation)| PendingVersion {
+                protocol_version_major: version.protocol_version_major(),
+                contract_version: version.contract_version(),
+                activation: *activation,
+            })
+            .collect();
+
         ContractPackage {
             access_key: contract_package.access_key(),
             versions,
             disabled_versions,
             groups,
+            upgrade_policy: contract_package.upgrade_policy().clone(),
+            pending_versions,
         }
     }
 }
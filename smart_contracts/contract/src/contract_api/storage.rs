@@ -6,7 +6,7 @@ use core::{convert::From, mem::MaybeUninit};
 use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    contracts::{ContractVersion, EntryPoints, NamedKeys},
+    contracts::{ContractVersion, EntryPoints, NamedKeys, UpgradePolicy},
     AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, HashAddr, Key,
     URef, UREF_SERIALIZED_LENGTH,
 };
@@ -312,6 +312,57 @@ pub fn add_contract_version(
     (contract_hash, contract_version)
 }
 
+/// Add a new version of a contract to the contract stored at the given `Key`, first calling the
+/// `migration_entry_point` of the new version.
+///
+/// The migration entry point must be a contract entry point, and is passed the contract package's
+/// access `URef` as the runtime arg named
+/// [`casper_types::contracts::MIGRATION_ACCESS_KEY_ARG_NAME`]. The new version only becomes
+/// callable once the migration has returned. If the migration reverts, the whole execution fails,
+/// and neither the new version nor any of the migration's effects are committed.
+pub fn add_contract_version_with_migration(
+    contract_package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    named_keys: NamedKeys,
+    migration_entry_point: &str,
+) -> (ContractHash, ContractVersion) {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
+        contract_api::to_ptr(contract_package_hash);
+    let (entry_points_ptr, entry_points_size, _bytes2) = contract_api::to_ptr(entry_points);
+    let (named_keys_ptr, named_keys_size, _bytes3) = contract_api::to_ptr(named_keys);
+    let (migration_entry_point_ptr, migration_entry_point_size, _bytes4) =
+        contract_api::to_ptr(migration_entry_point);
+
+    let mut output_ptr = vec![0u8; Key::max_serialized_length()];
+    let mut total_bytes: usize = 0;
+
+    let mut contract_version: ContractVersion = 0;
+
+    let ret = unsafe {
+        ext_ffi::casper_add_contract_version_with_migration(
+            contract_package_hash_ptr,
+            contract_package_hash_size,
+            &mut contract_version as *mut ContractVersion,
+            entry_points_ptr,
+            entry_points_size,
+            named_keys_ptr,
+            named_keys_size,
+            migration_entry_point_ptr,
+            migration_entry_point_size,
+            output_ptr.as_mut_ptr(),
+            output_ptr.len(),
+            &mut total_bytes as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => {}
+        Err(e) => revert(e),
+    }
+    output_ptr.truncate(total_bytes);
+    let contract_hash = bytesrepr::deserialize(output_ptr).unwrap_or_revert();
+    (contract_hash, contract_version)
+}
+
 /// Disable a version of a contract from the contract stored at the given
 /// `Key`. That version of the contract will no longer be callable by
 /// `call_versioned_contract`. Note that this contract must have been created by
@@ -335,3 +386,29 @@ pub fn disable_contract_version(
 
     api_error::result_from(result)
 }
+
+/// Replaces the upgrade policy of the contract package stored at the given hash.
+///
+/// The new policy must be at least as strict as the current one, and the calling context must
+/// hold a URef from each of the current policy's approval groups. The same approval is required
+/// by `add_contract_version` and `disable_contract_version` from then on, and new versions only
+/// become callable once the policy's upgrade delay has passed.
+pub fn set_upgrade_policy(
+    contract_package_hash: ContractPackageHash,
+    upgrade_policy: UpgradePolicy,
+) -> Result<(), ApiError> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
+        contract_api::to_ptr(contract_package_hash);
+    let (upgrade_policy_ptr, upgrade_policy_size, _bytes2) = contract_api::to_ptr(upgrade_policy);
+
+    let result = unsafe {
+        ext_ffi::casper_set_upgrade_policy(
+            contract_package_hash_ptr,
+            contract_package_hash_size,
+            upgrade_policy_ptr,
+            upgrade_policy_size,
+        )
+    };
+
+    api_error::result_from(result)
+}
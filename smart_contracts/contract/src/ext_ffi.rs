@@ -528,6 +528,41 @@ extern "C" {
         output_size: usize,
         bytes_written_ptr: *mut usize,
     ) -> i32;
+    /// Adds new contract version to a contract package, first calling the given migration entry
+    /// point of the new version with the contract package's access
+    /// [`casper_types::URef`] as the runtime arg named
+    /// [`casper_types::contracts::MIGRATION_ACCESS_KEY_ARG_NAME`]. If the migration fails, the
+    /// whole execution fails and the version isn't added.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `version_ptr` - output parameter where new version assigned by host is set
+    /// * `entry_points_ptr` - pointer to serialized [`casper_types::EntryPoints`]
+    /// * `entry_points_size` - size of serialized [`casper_types::EntryPoints`]
+    /// * `named_keys_ptr` - pointer to serialized [`casper_types::contracts::NamedKeys`]
+    /// * `named_keys_size` - size of serialized [`casper_types::contracts::NamedKeys`]
+    /// * `migration_entry_point_ptr` - pointer to serialized name of the migration entry point
+    /// * `migration_entry_point_size` - size of serialized name of the migration entry point
+    /// * `output_ptr` - pointer to a memory where host assigned contract hash is set to
+    /// * `output_size` - size of memory area that host can write to
+    /// * `bytes_written_ptr` - pointer to a value where host will set a number of bytes written to
+    ///   the `output_size` pointer
+    pub fn casper_add_contract_version_with_migration(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        version_ptr: *const u32,
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        migration_entry_point_ptr: *const u8,
+        migration_entry_point_size: usize,
+        output_ptr: *mut u8,
+        output_size: usize,
+        bytes_written_ptr: *mut usize,
+    ) -> i32;
     /// Disables contract in a contract package. Returns non-zero standard error for a failure,
     /// otherwise a zero indicates success.
     ///
@@ -543,6 +578,21 @@ extern "C" {
         contract_hash_ptr: *const u8,
         contract_hash_size: usize,
     ) -> i32;
+    /// Replaces the upgrade policy of a contract package. Returns non-zero standard error for a
+    /// failure, otherwise a zero indicates success.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `upgrade_policy_ptr` - pointer to serialized upgrade policy.
+    /// * `upgrade_policy_size` - size of upgrade policy in serialized form.
+    pub fn casper_set_upgrade_policy(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        upgrade_policy_ptr: *const u8,
+        upgrade_policy_size: usize,
+    ) -> i32;
     /// Calls a contract by its hash. Requires entry point name that has to be present on a
     /// specified contract, and serialized named arguments. Returns a standard error code in
     /// case of failure, otherwise a successful execution returns zero. Bytes returned from contract
@@ -700,6 +750,18 @@ extern "C" {
         out_ptr: *mut u8,
         out_size: usize,
     ) -> i32;
+    /// Loads the call stack of the current execution into the host buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `call_stack_len_ptr` - pointer to the location where the number of call stack elements
+    ///   will be written
+    /// * `result_size_ptr` - pointer to the location where the size of the serialized call stack
+    ///   will be written
+    pub fn casper_load_call_stack(
+        call_stack_len_ptr: *mut usize,
+        result_size_ptr: *mut usize,
+    ) -> i32;
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
@@ -0,0 +1,79 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPointAccess, EntryPointType, EntryPoints},
+    ApiError, CLType, RuntimeArgs,
+};
+
+const RECORD_CALL_STACK_ENTRY_POINT: &str = "record_call_stack";
+const REENTER_ENTRY_POINT: &str = "reenter";
+const CALL_STACK_KEY_NAME: &str = "call_stack";
+const CALL_STACK_PACKAGE_HASH_KEY_NAME: &str = "call_stack_package_hash";
+const CALL_STACK_ACCESS_KEY_NAME: &str = "call_stack_access";
+const CALL_STACK_CONTRACT_HASH_KEY_NAME: &str = "call_stack_contract_hash";
+
+#[no_mangle]
+pub extern "C" fn record_call_stack() {
+    let call_stack = runtime::get_call_stack();
+    runtime::put_key(CALL_STACK_KEY_NAME, storage::new_uref(call_stack).into());
+}
+
+#[no_mangle]
+pub extern "C" fn reenter() {
+    let contract_package_hash = *runtime::get_call_stack()
+        .last()
+        .and_then(|call_stack_element| call_stack_element.contract_package_hash())
+        .ok_or(ApiError::User(0))
+        .unwrap_or_revert();
+    runtime::call_versioned_contract(
+        contract_package_hash,
+        None,
+        REENTER_ENTRY_POINT,
+        RuntimeArgs::new(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+
+        let record_call_stack = EntryPoint::new(
+            RECORD_CALL_STACK_ENTRY_POINT.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(record_call_stack);
+
+        let reenter = EntryPoint::new(
+            REENTER_ENTRY_POINT.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        )
+        .non_reentrant();
+        entry_points.add_entry_point(reenter);
+
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::new_contract(
+        entry_points,
+        None,
+        Some(CALL_STACK_PACKAGE_HASH_KEY_NAME.to_string()),
+        Some(CALL_STACK_ACCESS_KEY_NAME.to_string()),
+    );
+    runtime::put_key(CALL_STACK_CONTRACT_HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,54 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::iter::FromIterator;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ApiError, ContractPackageHash, URef};
+
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const APPROVERS_GROUP_NAME: &str = "approvers";
+const APPROVER_KEY_NAME: &str = "approver";
+const ARG_ACTION: &str = "action";
+const ARG_UREFS: &str = "urefs";
+const ACTION_PROVISION: &str = "provision";
+const ACTION_REMOVE: &str = "remove";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let action: String = runtime::get_named_arg(ARG_ACTION);
+
+    let do_nothing_package_hash: ContractPackageHash =
+        runtime::get_key(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert()
+            .into();
+
+    match action.as_str() {
+        ACTION_PROVISION => {
+            let approver = storage::provision_contract_user_group_uref(
+                do_nothing_package_hash,
+                APPROVERS_GROUP_NAME,
+            )
+            .unwrap_or_revert();
+            runtime::put_key(APPROVER_KEY_NAME, approver.into());
+        }
+        ACTION_REMOVE => {
+            let urefs: Vec<URef> = runtime::get_named_arg(ARG_UREFS);
+            storage::remove_contract_user_group_urefs(
+                do_nothing_package_hash,
+                APPROVERS_GROUP_NAME,
+                BTreeSet::from_iter(urefs),
+            )
+            .unwrap_or_revert();
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
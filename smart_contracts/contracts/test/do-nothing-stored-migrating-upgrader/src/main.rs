@@ -0,0 +1,92 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{
+        EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, NamedKeys,
+        MIGRATION_ACCESS_KEY_ARG_NAME,
+    },
+    ApiError, CLType, ContractPackageHash, Key, URef,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const MIGRATE_FUNCTION_NAME: &str = "migrate";
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const MIGRATED_KEY_NAME: &str = "migrated";
+const REVERT_MIGRATION_KEY_NAME: &str = "revert_migration";
+const CONTRACT_VERSION: &str = "contract_version";
+const ARG_REVERT_MIGRATION: &str = "revert_migration";
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+    let access_key: URef = runtime::get_named_arg(MIGRATION_ACCESS_KEY_ARG_NAME);
+    // Stored under the named keys of the new version, so only visible if the migration succeeds.
+    runtime::put_key(MIGRATED_KEY_NAME, access_key.into());
+
+    if runtime::has_key(REVERT_MIGRATION_KEY_NAME) {
+        runtime::revert(ApiError::User(0));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let revert_migration: bool = runtime::get_named_arg(ARG_REVERT_MIGRATION);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+
+        let delegate = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(delegate);
+
+        let migrate = EntryPoint::new(
+            MIGRATE_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(migrate);
+
+        entry_points
+    };
+
+    let named_keys = {
+        let mut named_keys = NamedKeys::new();
+        if revert_migration {
+            named_keys.insert(REVERT_MIGRATION_KEY_NAME.to_string(), Key::Hash([0; 32]));
+        }
+        named_keys
+    };
+
+    let do_nothing_package_hash: ContractPackageHash =
+        runtime::get_key(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert()
+            .into();
+
+    let (_contract_hash, contract_version) = storage::add_contract_version_with_migration(
+        do_nothing_package_hash,
+        entry_points,
+        named_keys,
+        MIGRATE_FUNCTION_NAME,
+    );
+    runtime::put_key(CONTRACT_VERSION, storage::new_uref(contract_version).into());
+}
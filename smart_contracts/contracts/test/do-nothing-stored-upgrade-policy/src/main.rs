@@ -0,0 +1,54 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{UpgradeDelay, UpgradePolicy},
+    ContractPackageHash, Group,
+};
+
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const APPROVERS_GROUP_NAME: &str = "approvers";
+const APPROVER_KEY_NAME: &str = "approver";
+const ARG_UPGRADE_DELAY_MILLIS: &str = "upgrade_delay_millis";
+const ARG_APPROVER_UREFS: &str = "approver_urefs";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let upgrade_delay_millis: u64 = runtime::get_named_arg(ARG_UPGRADE_DELAY_MILLIS);
+    let approver_urefs: u8 = runtime::get_named_arg(ARG_APPROVER_UREFS);
+
+    let do_nothing_package_hash: ContractPackageHash =
+        runtime::get_key(DO_NOTHING_PACKAGE_HASH_KEY_NAME)
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert()
+            .into();
+
+    let approvers = storage::create_contract_user_group(
+        do_nothing_package_hash,
+        APPROVERS_GROUP_NAME,
+        approver_urefs,
+        BTreeSet::new(),
+    )
+    .unwrap_or_revert();
+    // Without a URef held by the account, no later upgrade of the package can be approved.
+    if let Some(approver) = approvers.first() {
+        runtime::put_key(APPROVER_KEY_NAME, (*approver).into());
+    }
+
+    let mut approval_groups = BTreeSet::new();
+    approval_groups.insert(Group::new(APPROVERS_GROUP_NAME));
+    let upgrade_policy = UpgradePolicy::new(
+        Some(UpgradeDelay::Millis(upgrade_delay_millis)),
+        approval_groups,
+    );
+    storage::set_upgrade_policy(do_nothing_package_hash, upgrade_policy).unwrap_or_revert();
+}
@@ -9,7 +9,6 @@ use alloc::{
     collections::{BTreeMap, BTreeSet, VecDeque},
     str,
     string::String,
-    vec::Vec,
 };
 #[cfg(debug_assertions)]
 use core::any;
@@ -21,6 +20,9 @@ use num_rational::Ratio;
 use serde::{Deserialize, Serialize};
 
 pub use bytes::Bytes;
+// Re-exported for the code generated by the derive macros of `casper-types-derive`.
+#[doc(hidden)]
+pub use alloc::vec::Vec;
 
 /// The number of bytes in a serialized `()`.
 pub const UNIT_SERIALIZED_LENGTH: usize = 0;
@@ -15,7 +15,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     Key, URef, U128, U256, U512,
 };
 
@@ -42,6 +42,14 @@ const CL_TYPE_TAG_TUPLE2: u8 = 19;
 const CL_TYPE_TAG_TUPLE3: u8 = 20;
 const CL_TYPE_TAG_ANY: u8 = 21;
 const CL_TYPE_TAG_PUBLIC_KEY: u8 = 22;
+const CL_TYPE_TAG_STRUCT: u8 = 23;
+const CL_TYPE_TAG_ENUM: u8 = 24;
+
+/// The named fields of a [`CLType::Struct`] or of a variant of a [`CLType::Enum`].
+///
+/// The fields of tuple structs and tuple variants are named after their index, i.e. `"0"`, `"1"`,
+/// etc.
+pub type CLFields = Vec<(String, CLType)>;
 
 /// Casper types, i.e. types which can be stored and manipulated by smart contracts.
 ///
@@ -101,6 +109,16 @@ pub enum CLType {
     Tuple3([Box<CLType>; 3]),
     /// Unspecified type.
     Any,
+    /// Named struct with named fields of `CLType`s, serialized as its fields in order.
+    #[allow(missing_docs)] // generated docs are explicit enough.
+    Struct { name: String, fields: CLFields },
+    /// Named enum with named variants, each holding named fields of `CLType`s.  Serialized as the
+    /// `u8` index of the variant followed by its fields in order.
+    #[allow(missing_docs)] // generated docs are explicit enough.
+    Enum {
+        name: String,
+        variants: Vec<(String, CLFields)>,
+    },
 }
 
 impl CLType {
@@ -130,8 +148,53 @@ impl CLType {
                 CLType::Tuple1(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple2(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple3(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Struct { name, fields } => {
+                    name.serialized_length() + serialized_length_of_cl_fields(fields)
+                }
+                CLType::Enum { name, variants } => {
+                    name.serialized_length()
+                        + U32_SERIALIZED_LENGTH
+                        + variants
+                            .iter()
+                            .map(|(variant_name, fields)| {
+                                variant_name.serialized_length()
+                                    + serialized_length_of_cl_fields(fields)
+                            })
+                            .sum::<usize>()
+                }
             }
     }
+
+    /// Returns a [`CLType::Struct`] with the given name and named fields.
+    pub fn new_struct(name: &str, fields: &[(&str, CLType)]) -> CLType {
+        CLType::Struct {
+            name: name.into(),
+            fields: to_cl_fields(fields),
+        }
+    }
+
+    /// Returns a [`CLType::Enum`] with the given name and named variants.
+    pub fn new_enum(name: &str, variants: &[(&str, &[(&str, CLType)])]) -> CLType {
+        CLType::Enum {
+            name: name.into(),
+            variants: variants
+                .iter()
+                .map(|(variant_name, fields)| ((*variant_name).into(), to_cl_fields(fields)))
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if `self` is a [`CLType::Struct`] or a [`CLType::Enum`].
+    pub fn is_named(&self) -> bool {
+        matches!(self, CLType::Struct { .. } | CLType::Enum { .. })
+    }
+}
+
+fn to_cl_fields(fields: &[(&str, CLType)]) -> CLFields {
+    fields
+        .iter()
+        .map(|(field_name, cl_type)| ((*field_name).into(), cl_type.clone()))
+        .collect()
 }
 
 /// Returns the `CLType` describing a "named key" on the system, i.e. a `(String, Key)`.
@@ -188,6 +251,20 @@ impl CLType {
                 serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE3, cl_type_array, stream)?
             }
             CLType::Any => stream.push(CL_TYPE_TAG_ANY),
+            CLType::Struct { name, fields } => {
+                stream.push(CL_TYPE_TAG_STRUCT);
+                stream.append(&mut name.to_bytes()?);
+                serialize_cl_fields(fields, stream)?;
+            }
+            CLType::Enum { name, variants } => {
+                stream.push(CL_TYPE_TAG_ENUM);
+                stream.append(&mut name.to_bytes()?);
+                stream.append(&mut (variants.len() as u32).to_bytes()?);
+                for (variant_name, fields) in variants {
+                    stream.append(&mut variant_name.to_bytes()?);
+                    serialize_cl_fields(fields, stream)?;
+                }
+            }
         }
         Ok(())
     }
@@ -274,6 +351,25 @@ impl FromBytes for CLType {
                 Ok((cl_type, remainder))
             }
             CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
+            CL_TYPE_TAG_STRUCT => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (fields, remainder) = parse_cl_fields(remainder)?;
+                let cl_type = CLType::Struct { name, fields };
+                Ok((cl_type, remainder))
+            }
+            CL_TYPE_TAG_ENUM => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (count, mut remainder) = u32::from_bytes(remainder)?;
+                let mut variants = Vec::new();
+                for _ in 0..count {
+                    let (variant_name, rem) = String::from_bytes(remainder)?;
+                    let (fields, rem) = parse_cl_fields(rem)?;
+                    variants.push((variant_name, fields));
+                    remainder = rem;
+                }
+                let cl_type = CLType::Enum { name, variants };
+                Ok((cl_type, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -314,6 +410,40 @@ fn serialized_length_of_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>
         .sum()
 }
 
+fn serialize_cl_fields(
+    fields: &[(String, CLType)],
+    stream: &mut Vec<u8>,
+) -> Result<(), bytesrepr::Error> {
+    stream.append(&mut (fields.len() as u32).to_bytes()?);
+    for (field_name, cl_type) in fields {
+        stream.append(&mut field_name.to_bytes()?);
+        cl_type.append_bytes(stream)?;
+    }
+    Ok(())
+}
+
+fn parse_cl_fields(bytes: &[u8]) -> Result<(CLFields, &[u8]), bytesrepr::Error> {
+    let (count, mut bytes) = u32::from_bytes(bytes)?;
+    let mut fields = Vec::new();
+    for _ in 0..count {
+        let (field_name, remainder) = String::from_bytes(bytes)?;
+        let (cl_type, remainder) = CLType::from_bytes(remainder)?;
+        fields.push((field_name, cl_type));
+        bytes = remainder;
+    }
+    Ok((fields, bytes))
+}
+
+fn serialized_length_of_cl_fields(fields: &[(String, CLType)]) -> usize {
+    U32_SERIALIZED_LENGTH
+        + fields
+            .iter()
+            .map(|(field_name, cl_type)| {
+                field_name.serialized_length() + cl_type.serialized_length()
+            })
+            .sum::<usize>()
+}
+
 /// A type which can be described as a [`CLType`].
 pub trait CLTyped {
     /// The `CLType` of `Self`.
@@ -708,4 +838,130 @@ mod tests {
         let any = Any("Any test".to_string());
         round_trip(&any);
     }
+
+    #[test]
+    fn struct_should_work() {
+        #[derive(PartialEq, Debug, Clone)]
+        struct Entry {
+            amount: u64,
+            name: String,
+        }
+
+        impl CLTyped for Entry {
+            fn cl_type() -> CLType {
+                CLType::new_struct(
+                    "Entry",
+                    &[("amount", CLType::U64), ("name", CLType::String)],
+                )
+            }
+        }
+
+        impl ToBytes for Entry {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                let mut result = bytesrepr::allocate_buffer(self)?;
+                result.append(&mut self.amount.to_bytes()?);
+                result.append(&mut self.name.to_bytes()?);
+                Ok(result)
+            }
+
+            fn serialized_length(&self) -> usize {
+                self.amount.serialized_length() + self.name.serialized_length()
+            }
+        }
+
+        impl FromBytes for Entry {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (amount, remainder) = u64::from_bytes(bytes)?;
+                let (name, remainder) = String::from_bytes(remainder)?;
+                Ok((Entry { amount, name }, remainder))
+            }
+        }
+
+        round_trip(&Entry {
+            amount: 1,
+            name: String::from("Entry test"),
+        });
+    }
+
+    #[test]
+    fn enum_should_work() {
+        #[derive(PartialEq, Debug, Clone)]
+        enum Shape {
+            Empty,
+            Square(u32),
+        }
+
+        impl CLTyped for Shape {
+            fn cl_type() -> CLType {
+                CLType::new_enum(
+                    "Shape",
+                    &[("Empty", &[]), ("Square", &[("0", CLType::U32)])],
+                )
+            }
+        }
+
+        impl ToBytes for Shape {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                let mut result = bytesrepr::allocate_buffer(self)?;
+                match self {
+                    Shape::Empty => result.push(0),
+                    Shape::Square(side) => {
+                        result.push(1);
+                        result.append(&mut side.to_bytes()?);
+                    }
+                }
+                Ok(result)
+            }
+
+            fn serialized_length(&self) -> usize {
+                1 + match self {
+                    Shape::Empty => 0,
+                    Shape::Square(side) => side.serialized_length(),
+                }
+            }
+        }
+
+        impl FromBytes for Shape {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (index, remainder) = u8::from_bytes(bytes)?;
+                match index {
+                    0 => Ok((Shape::Empty, remainder)),
+                    1 => {
+                        let (side, remainder) = u32::from_bytes(remainder)?;
+                        Ok((Shape::Square(side), remainder))
+                    }
+                    _ => Err(bytesrepr::Error::Formatting),
+                }
+            }
+        }
+
+        round_trip(&Shape::Empty);
+        round_trip(&Shape::Square(2));
+    }
+
+    #[test]
+    fn named_cl_types_should_serialize() {
+        let cl_type = CLType::new_enum(
+            "Shape",
+            &[
+                ("Empty", &[]),
+                (
+                    "Polygon",
+                    &[
+                        ("name", CLType::String),
+                        (
+                            "corner",
+                            CLType::new_struct("Point", &[("x", CLType::I32), ("y", CLType::I32)]),
+                        ),
+                    ],
+                ),
+            ],
+        );
+        let mut bytes = Vec::new();
+        cl_type.append_bytes(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), cl_type.serialized_length());
+        let (parsed_cl_type, remainder) = CLType::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(parsed_cl_type, cl_type);
+    }
 }
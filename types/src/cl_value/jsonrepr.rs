@@ -1,7 +1,7 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use serde::Serialize;
-use serde_json::{json, Value};
+use serde_json::{json, Map, Value};
 
 use crate::{
     bytesrepr::{self, FromBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
@@ -98,7 +98,32 @@ fn to_json<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Option<(Value, &'a [u8])> {
             Some((json!([t1, t2, t3]), remainder))
         }
         CLType::Any => None,
+        CLType::Struct { fields, .. } => fields_to_json(fields, bytes),
+        CLType::Enum { variants, .. } => {
+            let (index, remainder) = u8::from_bytes(bytes).ok()?;
+            let (variant_name, fields) = variants.get(index as usize)?;
+            if fields.is_empty() {
+                return Some((json!(variant_name), remainder));
+            }
+            let (value, remainder) = fields_to_json(fields, remainder)?;
+            let mut result = Map::new();
+            result.insert(variant_name.clone(), value);
+            Some((Value::Object(result), remainder))
+        }
+    }
+}
+
+fn fields_to_json<'a>(
+    fields: &[(String, CLType)],
+    mut bytes: &'a [u8],
+) -> Option<(Value, &'a [u8])> {
+    let mut result = Map::new();
+    for (field_name, cl_type) in fields {
+        let (value, remainder) = to_json(cl_type, bytes)?;
+        result.insert(field_name.clone(), value);
+        bytes = remainder;
     }
+    Some((Value::Object(result), bytes))
 }
 
 fn simple_type_to_json<T: FromBytes + Serialize>(bytes: &[u8]) -> Option<(Value, &[u8])> {
@@ -218,4 +243,44 @@ mod tests {
         test_value((v1.clone(), v2.clone()));
         test_value((v1, v2, v3));
     }
+
+    #[test]
+    fn struct_to_json_value() {
+        let cl_type = CLType::new_struct(
+            "Entry",
+            &[("name", CLType::String), ("values", <Vec<u8>>::cl_type())],
+        );
+        let bytes = (String::from("Hello"), vec![1u8, 2]).to_bytes().unwrap();
+        let cl_value = CLValue::from_components(cl_type, bytes);
+        let cl_value_as_json = cl_value_to_json(&cl_value).unwrap();
+        let expected = json!({ "name": "Hello", "values": [1, 2] });
+        assert_eq!(cl_value_as_json, expected);
+    }
+
+    #[test]
+    fn enum_to_json_value() {
+        let cl_type = CLType::new_enum(
+            "Shape",
+            &[
+                ("Empty", &[]),
+                (
+                    "Rectangle",
+                    &[("width", CLType::U32), ("height", CLType::U32)],
+                ),
+            ],
+        );
+
+        let cl_value = CLValue::from_components(cl_type.clone(), 0u8.to_bytes().unwrap());
+        let cl_value_as_json = cl_value_to_json(&cl_value).unwrap();
+        assert_eq!(cl_value_as_json, json!("Empty"));
+
+        let bytes = (1u8, 3u32, 4u32).to_bytes().unwrap();
+        let cl_value = CLValue::from_components(cl_type.clone(), bytes);
+        let cl_value_as_json = cl_value_to_json(&cl_value).unwrap();
+        let expected = json!({ "Rectangle": { "width": 3, "height": 4 } });
+        assert_eq!(cl_value_as_json, expected);
+
+        let cl_value = CLValue::from_components(cl_type, 2u8.to_bytes().unwrap());
+        assert!(cl_value_to_json(&cl_value).is_none());
+    }
 }
@@ -22,11 +22,12 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 use crate::{
     account,
     account::TryFromSliceForAccountHashError,
-    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
     contract_wasm::ContractWasmHash,
+    system::auction::EraId,
     uref,
     uref::URef,
-    CLType, CLTyped, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
+    BlockTime, CLType, CLTyped, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
 };
 
 /// Maximum number of distinct user groups.
@@ -65,6 +66,11 @@ pub enum Error {
     GroupInUse = 8,
     /// URef already exists in given group.
     URefAlreadyExists = 9,
+    /// The calling context doesn't hold a URef from every approval group required by the contract
+    /// package's upgrade policy.
+    UpgradeNotApproved = 10,
+    /// Attempted to replace an upgrade policy with one which is less strict.
+    UpgradePolicyLoosened = 11,
 }
 
 /// Associated error type of `TryFrom<&[u8]>` for `ContractHash`.
@@ -259,6 +265,10 @@ pub type DisabledVersions = BTreeSet<ContractVersionKey>;
 /// Collection of named groups.
 pub type Groups = BTreeMap<Group, BTreeSet<URef>>;
 
+/// Collection of contract versions which have been added but can't be called yet, along with the
+/// point at which each becomes callable.
+pub type PendingVersions = BTreeMap<ContractVersionKey, VersionActivation>;
+
 /// A newtype wrapping a `HashAddr` which is the raw bytes of
 /// the ContractHash
 #[derive(DataSize, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
@@ -600,6 +610,223 @@ impl FromBytes for ContractPackageStatus {
     }
 }
 
+const UPGRADE_DELAY_ERAS_TAG: u8 = 0;
+const UPGRADE_DELAY_MILLIS_TAG: u8 = 1;
+
+/// The time which must pass after a new contract version is added before it can be called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub enum UpgradeDelay {
+    /// The new version becomes callable once this many eras have started.
+    Eras(u64),
+    /// The new version becomes callable once this many milliseconds of block time have passed.
+    Millis(u64),
+}
+
+impl UpgradeDelay {
+    /// Returns the point at which a version added in the given era and at the given block time
+    /// becomes callable.
+    pub fn activation_after(self, era_id: EraId, block_time: BlockTime) -> VersionActivation {
+        match self {
+            UpgradeDelay::Eras(eras) => VersionActivation::Era(era_id.saturating_add(eras)),
+            UpgradeDelay::Millis(millis) => {
+                let block_time: u64 = block_time.into();
+                VersionActivation::Timestamp(block_time.saturating_add(millis))
+            }
+        }
+    }
+
+    /// Returns `true` if this delay is measured in the same unit as `other` and isn't shorter.
+    pub fn is_at_least(self, other: UpgradeDelay) -> bool {
+        match (self, other) {
+            (UpgradeDelay::Eras(this), UpgradeDelay::Eras(other))
+            | (UpgradeDelay::Millis(this), UpgradeDelay::Millis(other)) => this >= other,
+            _ => false,
+        }
+    }
+}
+
+impl ToBytes for UpgradeDelay {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            UpgradeDelay::Eras(eras) => {
+                result.push(UPGRADE_DELAY_ERAS_TAG);
+                result.append(&mut eras.to_bytes()?);
+            }
+            UpgradeDelay::Millis(millis) => {
+                result.push(UPGRADE_DELAY_MILLIS_TAG);
+                result.append(&mut millis.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                UpgradeDelay::Eras(eras) => eras.serialized_length(),
+                UpgradeDelay::Millis(millis) => millis.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for UpgradeDelay {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, bytes) = u8::from_bytes(bytes)?;
+        match tag {
+            UPGRADE_DELAY_ERAS_TAG => {
+                let (eras, bytes) = u64::from_bytes(bytes)?;
+                Ok((UpgradeDelay::Eras(eras), bytes))
+            }
+            UPGRADE_DELAY_MILLIS_TAG => {
+                let (millis, bytes) = u64::from_bytes(bytes)?;
+                Ok((UpgradeDelay::Millis(millis), bytes))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// The point at which a pending contract version becomes callable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub enum VersionActivation {
+    /// The version becomes callable at the start of this era.
+    Era(EraId),
+    /// The version becomes callable at this block time, in milliseconds since the Unix epoch.
+    Timestamp(u64),
+}
+
+impl VersionActivation {
+    /// Returns `true` if a version with this activation point is callable in the given era and at
+    /// the given block time.
+    pub fn is_reached(self, era_id: EraId, block_time: BlockTime) -> bool {
+        match self {
+            VersionActivation::Era(activation_era_id) => era_id >= activation_era_id,
+            VersionActivation::Timestamp(timestamp) => {
+                let block_time: u64 = block_time.into();
+                block_time >= timestamp
+            }
+        }
+    }
+}
+
+impl ToBytes for VersionActivation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            VersionActivation::Era(era_id) => {
+                result.push(UPGRADE_DELAY_ERAS_TAG);
+                result.append(&mut era_id.to_bytes()?);
+            }
+            VersionActivation::Timestamp(timestamp) => {
+                result.push(UPGRADE_DELAY_MILLIS_TAG);
+                result.append(&mut timestamp.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                VersionActivation::Era(era_id) => era_id.serialized_length(),
+                VersionActivation::Timestamp(timestamp) => timestamp.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for VersionActivation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, bytes) = u8::from_bytes(bytes)?;
+        match tag {
+            UPGRADE_DELAY_ERAS_TAG => {
+                let (era_id, bytes) = EraId::from_bytes(bytes)?;
+                Ok((VersionActivation::Era(era_id), bytes))
+            }
+            UPGRADE_DELAY_MILLIS_TAG => {
+                let (timestamp, bytes) = u64::from_bytes(bytes)?;
+                Ok((VersionActivation::Timestamp(timestamp), bytes))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// The rules a contract package enforces on adding and disabling its versions, on top of
+/// requiring its access key.
+///
+/// A policy can only ever be replaced by one at least as strict, so users of a contract can rely on
+/// it not being loosened later.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct UpgradePolicy {
+    /// The delay before a newly-added version becomes callable, if any.
+    upgrade_delay: Option<UpgradeDelay>,
+    /// The groups which must all approve adding or disabling a version. A group approves by the
+    /// calling context holding any one of its URefs.
+    approval_groups: BTreeSet<Group>,
+}
+
+impl UpgradePolicy {
+    /// Creates a new `UpgradePolicy`.
+    pub fn new(upgrade_delay: Option<UpgradeDelay>, approval_groups: BTreeSet<Group>) -> Self {
+        UpgradePolicy {
+            upgrade_delay,
+            approval_groups,
+        }
+    }
+
+    /// Returns the delay before a newly-added version becomes callable, if any.
+    pub fn upgrade_delay(&self) -> Option<UpgradeDelay> {
+        self.upgrade_delay
+    }
+
+    /// Returns the groups which must all approve adding or disabling a version.
+    pub fn approval_groups(&self) -> &BTreeSet<Group> {
+        &self.approval_groups
+    }
+
+    /// Returns `true` if this policy is at least as strict as `other`, i.e. its delay is no shorter
+    /// and it requires approval from at least the same groups.
+    pub fn is_at_least_as_strict_as(&self, other: &UpgradePolicy) -> bool {
+        let delay_is_at_least = match (self.upgrade_delay, other.upgrade_delay) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(this), Some(other)) => this.is_at_least(other),
+        };
+        delay_is_at_least && self.approval_groups.is_superset(&other.approval_groups)
+    }
+}
+
+impl ToBytes for UpgradePolicy {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.upgrade_delay.to_bytes()?);
+        result.append(&mut self.approval_groups.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.upgrade_delay.serialized_length() + self.approval_groups.serialized_length()
+    }
+}
+
+impl FromBytes for UpgradePolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (upgrade_delay, bytes) = Option::<UpgradeDelay>::from_bytes(bytes)?;
+        let (approval_groups, bytes) = BTreeSet::<Group>::from_bytes(bytes)?;
+        Ok((
+            UpgradePolicy {
+                upgrade_delay,
+                approval_groups,
+            },
+            bytes,
+        ))
+    }
+}
+
 /// Contract definition, metadata, and security container.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
 pub struct ContractPackage {
@@ -616,6 +843,10 @@ pub struct ContractPackage {
     groups: Groups,
     /// A flag that determines whether a contract is locked
     lock_status: ContractPackageStatus,
+    /// Rules enforced on adding and disabling versions
+    upgrade_policy: UpgradePolicy,
+    /// Versions which can't be called until their activation point is reached
+    pending_versions: PendingVersions,
 }
 
 impl ContractPackage {
@@ -633,6 +864,8 @@ impl ContractPackage {
             disabled_versions,
             groups,
             lock_status,
+            upgrade_policy: UpgradePolicy::default(),
+            pending_versions: PendingVersions::new(),
         }
     }
 
@@ -669,8 +902,12 @@ impl ContractPackage {
     }
 
     /// Checks if the given contract version exists and is available for use.
+    ///
+    /// A pending version isn't available until [`ContractPackage::activate_versions`] has been
+    /// called with an era and block time at or after its activation point.
     pub fn is_version_enabled(&self, contract_version_key: ContractVersionKey) -> bool {
         !self.disabled_versions.contains(&contract_version_key)
+            && !self.pending_versions.contains_key(&contract_version_key)
             && self.versions.contains_key(&contract_version_key)
     }
 
@@ -686,6 +923,61 @@ impl ContractPackage {
         key
     }
 
+    /// Insert a new contract version which can't be called until `activation` is reached; the next
+    /// sequential version number will be issued.
+    pub fn insert_pending_contract_version(
+        &mut self,
+        protocol_version_major: ProtocolVersionMajor,
+        contract_hash: ContractHash,
+        activation: VersionActivation,
+    ) -> ContractVersionKey {
+        let key = self.insert_contract_version(protocol_version_major, contract_hash);
+        self.pending_versions.insert(key, activation);
+        key
+    }
+
+    /// Returns all of this contract's pending versions along with their activation points.
+    pub fn pending_versions(&self) -> &PendingVersions {
+        &self.pending_versions
+    }
+
+    /// Returns `true` if the given contract is a pending version of this package.
+    pub fn is_contract_pending(&self, contract_hash: ContractHash) -> bool {
+        self.pending_versions
+            .keys()
+            .any(|version| self.versions.get(version) == Some(&contract_hash))
+    }
+
+    /// Makes every pending version whose activation point has been reached in the given era and
+    /// at the given block time available for use.
+    pub fn activate_versions(&mut self, era_id: EraId, block_time: BlockTime) {
+        self.pending_versions
+            .retain(|_, activation| !activation.is_reached(era_id, block_time));
+    }
+
+    /// Returns the upgrade policy of this contract package.
+    pub fn upgrade_policy(&self) -> &UpgradePolicy {
+        &self.upgrade_policy
+    }
+
+    /// Replaces the upgrade policy of this contract package.
+    ///
+    /// Returns an error if the new policy is less strict than the current one.
+    pub fn set_upgrade_policy(&mut self, upgrade_policy: UpgradePolicy) -> Result<(), Error> {
+        if !upgrade_policy.is_at_least_as_strict_as(&self.upgrade_policy) {
+            return Err(Error::UpgradePolicyLoosened);
+        }
+        if upgrade_policy
+            .approval_groups()
+            .iter()
+            .any(|group| !self.groups.contains_key(group))
+        {
+            return Err(Error::GroupDoesNotExist);
+        }
+        self.upgrade_policy = upgrade_policy;
+        Ok(())
+    }
+
     /// Disable the contract version corresponding to the given hash (if it exists).
     pub fn disable_contract_version(&mut self, contract_hash: ContractHash) -> Result<(), Error> {
         let contract_version_key = self
@@ -792,6 +1084,33 @@ impl ContractPackage {
     }
 }
 
+/// Set in the serialized flags of a locked [`ContractPackage`].
+///
+/// The flags take the place of the former lock status, so packages using none of the fields added
+/// since are serialized exactly as before and the ones already stored can still be read.
+const CONTRACT_PACKAGE_LOCKED_FLAG: u8 = 0b01;
+/// Set in the serialized flags of a [`ContractPackage`] which is followed by its upgrade policy and
+/// pending versions.
+const CONTRACT_PACKAGE_UPGRADE_POLICY_FLAG: u8 = 0b10;
+
+impl ContractPackage {
+    fn has_upgrade_policy(&self) -> bool {
+        self.upgrade_policy != UpgradePolicy::default() || !self.pending_versions.is_empty()
+    }
+
+    /// Returns the flags serialized in place of the former lock status.
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.is_locked() {
+            flags |= CONTRACT_PACKAGE_LOCKED_FLAG;
+        }
+        if self.has_upgrade_policy() {
+            flags |= CONTRACT_PACKAGE_UPGRADE_POLICY_FLAG;
+        }
+        flags
+    }
+}
+
 impl ToBytes for ContractPackage {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -800,7 +1119,11 @@ impl ToBytes for ContractPackage {
         result.append(&mut self.versions.to_bytes()?);
         result.append(&mut self.disabled_versions.to_bytes()?);
         result.append(&mut self.groups.to_bytes()?);
-        result.append(&mut self.lock_status.to_bytes()?);
+        result.push(self.flags());
+        if self.has_upgrade_policy() {
+            result.append(&mut self.upgrade_policy.to_bytes()?);
+            result.append(&mut self.pending_versions.to_bytes()?);
+        }
 
         Ok(result)
     }
@@ -810,7 +1133,12 @@ impl ToBytes for ContractPackage {
             + self.versions.serialized_length()
             + self.disabled_versions.serialized_length()
             + self.groups.serialized_length()
-            + self.lock_status.serialized_length()
+            + self.flags().serialized_length()
+            + if self.has_upgrade_policy() {
+                self.upgrade_policy.serialized_length() + self.pending_versions.serialized_length()
+            } else {
+                0
+            }
     }
 }
 
@@ -820,13 +1148,27 @@ impl FromBytes for ContractPackage {
         let (versions, bytes) = ContractVersions::from_bytes(bytes)?;
         let (disabled_versions, bytes) = DisabledVersions::from_bytes(bytes)?;
         let (groups, bytes) = Groups::from_bytes(bytes)?;
-        let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+        let (flags, bytes) = u8::from_bytes(bytes)?;
+        if flags & !(CONTRACT_PACKAGE_LOCKED_FLAG | CONTRACT_PACKAGE_UPGRADE_POLICY_FLAG) != 0 {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let lock_status = ContractPackageStatus::new(flags & CONTRACT_PACKAGE_LOCKED_FLAG != 0);
+        let (upgrade_policy, pending_versions, bytes) =
+            if flags & CONTRACT_PACKAGE_UPGRADE_POLICY_FLAG != 0 {
+                let (upgrade_policy, bytes) = UpgradePolicy::from_bytes(bytes)?;
+                let (pending_versions, bytes) = PendingVersions::from_bytes(bytes)?;
+                (upgrade_policy, pending_versions, bytes)
+            } else {
+                (UpgradePolicy::default(), PendingVersions::new(), bytes)
+            };
         let result = ContractPackage {
             access_key,
             versions,
             disabled_versions,
             groups,
             lock_status,
+            upgrade_policy,
+            pending_versions,
         };
 
         Ok((result, bytes))
@@ -1122,6 +1464,10 @@ pub const ENTRY_POINT_NAME_INSTALL: &str = "install";
 /// Default name for an upgrader entry point
 pub const UPGRADE_ENTRY_POINT_NAME: &str = "upgrade";
 
+/// Name of the runtime arg holding the contract package's access `URef`, passed to the migration
+/// entry point run when adding a contract version with a migration
+pub const MIGRATION_ACCESS_KEY_ARG_NAME: &str = "contract_package_access_key";
+
 /// Collection of entry point parameters.
 pub type Parameters = Vec<Parameter>;
 
@@ -1135,6 +1481,8 @@ pub struct EntryPoint {
     ret: CLType,
     access: EntryPointAccess,
     entry_point_type: EntryPointType,
+    #[serde(default)]
+    non_reentrant: bool,
 }
 
 impl From<EntryPoint> for (String, Parameters, CLType, EntryPointAccess, EntryPointType) {
@@ -1164,9 +1512,18 @@ impl EntryPoint {
             ret,
             access,
             entry_point_type,
+            non_reentrant: false,
         }
     }
 
+    /// Marks the entry point as non-reentrant: calling it while a call into the same contract
+    /// package is already on the call stack fails with
+    /// [`ApiError::ReentrantCall`](crate::ApiError::ReentrantCall).
+    pub fn non_reentrant(mut self) -> Self {
+        self.non_reentrant = true;
+        self
+    }
+
     /// Create a default [`EntryPoint`] with specified name.
     pub fn default_with_name<T: Into<String>>(name: T) -> Self {
         EntryPoint {
@@ -1199,6 +1556,23 @@ impl EntryPoint {
     pub fn entry_point_type(&self) -> EntryPointType {
         self.entry_point_type
     }
+
+    /// Returns `true` if the entry point can't be re-entered, see [`EntryPoint::non_reentrant`].
+    pub fn is_non_reentrant(&self) -> bool {
+        self.non_reentrant
+    }
+
+    /// Returns the flags serialized in place of the former [`EntryPointType`].
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.entry_point_type == EntryPointType::Contract {
+            flags |= ENTRY_POINT_CONTRACT_FLAG;
+        }
+        if self.non_reentrant {
+            flags |= ENTRY_POINT_NON_REENTRANT_FLAG;
+        }
+        flags
+    }
 }
 
 impl Default for EntryPoint {
@@ -1210,10 +1584,19 @@ impl Default for EntryPoint {
             ret: CLType::Unit,
             access: EntryPointAccess::Public,
             entry_point_type: EntryPointType::Session,
+            non_reentrant: false,
         }
     }
 }
 
+/// Set in the serialized flags of an [`EntryPoint`] of type [`EntryPointType::Contract`].
+///
+/// The flags take the place of the former [`EntryPointType`], so reentrant entry points are
+/// serialized exactly as before and the ones already stored can still be read.
+const ENTRY_POINT_CONTRACT_FLAG: u8 = 0b01;
+/// Set in the serialized flags of a non-reentrant [`EntryPoint`].
+const ENTRY_POINT_NON_REENTRANT_FLAG: u8 = 0b10;
+
 impl ToBytes for EntryPoint {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -1221,7 +1604,7 @@ impl ToBytes for EntryPoint {
         result.append(&mut self.args.to_bytes()?);
         self.ret.append_bytes(&mut result)?;
         result.append(&mut self.access.to_bytes()?);
-        result.append(&mut self.entry_point_type.to_bytes()?);
+        result.push(self.flags());
 
         Ok(result)
     }
@@ -1231,7 +1614,7 @@ impl ToBytes for EntryPoint {
             + self.args.serialized_length()
             + self.ret.serialized_length()
             + self.access.serialized_length()
-            + self.entry_point_type.serialized_length()
+            + self.flags().serialized_length()
     }
 }
 
@@ -1241,7 +1624,16 @@ impl FromBytes for EntryPoint {
         let (args, bytes) = Vec::<Parameter>::from_bytes(bytes)?;
         let (ret, bytes) = CLType::from_bytes(bytes)?;
         let (access, bytes) = EntryPointAccess::from_bytes(bytes)?;
-        let (entry_point_type, bytes) = EntryPointType::from_bytes(bytes)?;
+        let (flags, bytes) = u8::from_bytes(bytes)?;
+        if flags & !(ENTRY_POINT_CONTRACT_FLAG | ENTRY_POINT_NON_REENTRANT_FLAG) != 0 {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let entry_point_type = if flags & ENTRY_POINT_CONTRACT_FLAG != 0 {
+            EntryPointType::Contract
+        } else {
+            EntryPointType::Session
+        };
+        let non_reentrant = flags & ENTRY_POINT_NON_REENTRANT_FLAG != 0;
 
         Ok((
             EntryPoint {
@@ -1250,6 +1642,7 @@ impl FromBytes for EntryPoint {
                 ret,
                 access,
                 entry_point_type,
+                non_reentrant,
             },
             bytes,
         ))
@@ -1337,6 +1730,11 @@ impl Parameter {
         }
     }
 
+    /// Get the name of this argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Get the type of this argument.
     pub fn cl_type(&self) -> &CLType {
         &self.cl_type
@@ -1471,6 +1869,116 @@ mod tests {
         assert_eq!(rem.len(), 0);
     }
 
+    #[test]
+    fn should_decode_contract_in_former_encoding() {
+        // A contract with a single entry point, as serialized before entry points could be marked
+        // non-reentrant.
+        let mut bytes = vec![];
+        bytes.extend([41u8; 32].to_bytes().unwrap());
+        bytes.extend([43u8; 32].to_bytes().unwrap());
+        bytes.extend(NamedKeys::new().to_bytes().unwrap());
+        bytes.extend(1u32.to_bytes().unwrap());
+        bytes.extend(DEFAULT_ENTRY_POINT_NAME.to_bytes().unwrap());
+        bytes.extend(DEFAULT_ENTRY_POINT_NAME.to_bytes().unwrap());
+        bytes.extend(Parameters::new().to_bytes().unwrap());
+        bytes.extend(CLType::Unit.to_bytes().unwrap());
+        bytes.push(ENTRYPOINTACCESS_PUBLIC_TAG);
+        bytes.push(EntryPointType::Contract as u8);
+        bytes.extend(ProtocolVersion::V1_0_0.to_bytes().unwrap());
+
+        let contract: Contract = bytesrepr::deserialize(bytes.clone()).unwrap();
+        let entry_point = contract
+            .entry_point(DEFAULT_ENTRY_POINT_NAME)
+            .expect("should have entry point");
+        assert_eq!(entry_point.entry_point_type(), EntryPointType::Contract);
+        assert!(!entry_point.is_non_reentrant());
+        assert_eq!(contract.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn non_reentrant_entry_point_roundtrip() {
+        let entry_point = EntryPoint::new(
+            DEFAULT_ENTRY_POINT_NAME,
+            vec![Parameter::new("Foo", CLType::U32)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        bytesrepr::test_serialization_roundtrip(&entry_point);
+        bytesrepr::test_serialization_roundtrip(&entry_point.non_reentrant());
+    }
+
+    #[test]
+    fn should_not_decode_entry_point_with_unknown_flags() {
+        let entry_point = EntryPoint::new(
+            DEFAULT_ENTRY_POINT_NAME,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        );
+        let mut bytes = entry_point.to_bytes().unwrap();
+        *bytes.last_mut().unwrap() = 0b100;
+        assert_eq!(
+            EntryPoint::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
+    #[test]
+    fn should_decode_contract_package_in_former_encoding() {
+        let contract_package = make_contract_package();
+        assert!(!contract_package.has_upgrade_policy());
+
+        // The package as serialized before upgrade policies existed.
+        let mut bytes = vec![];
+        bytes.extend(contract_package.access_key().to_bytes().unwrap());
+        bytes.extend(contract_package.versions().to_bytes().unwrap());
+        bytes.extend(contract_package.disabled_versions().to_bytes().unwrap());
+        bytes.extend(contract_package.groups().to_bytes().unwrap());
+        bytes.extend(true.to_bytes().unwrap());
+
+        let decoded: ContractPackage = bytesrepr::deserialize(bytes.clone()).unwrap();
+        assert!(decoded.is_locked());
+        assert_eq!(decoded.upgrade_policy(), &UpgradePolicy::default());
+        assert!(decoded.pending_versions().is_empty());
+        assert_eq!(decoded.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn should_not_decode_contract_package_with_unknown_flags() {
+        let contract_package = make_contract_package();
+        let mut bytes = contract_package.to_bytes().unwrap();
+        *bytes.last_mut().unwrap() = 0b100;
+        assert_eq!(
+            ContractPackage::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
+    #[test]
+    fn contract_package_with_upgrade_policy_roundtrip() {
+        let mut contract_package = make_contract_package();
+        let mut approval_groups = BTreeSet::new();
+        approval_groups.insert(Group::new("Group 1"));
+        contract_package
+            .set_upgrade_policy(UpgradePolicy::new(
+                Some(UpgradeDelay::Eras(2)),
+                approval_groups,
+            ))
+            .unwrap();
+        bytesrepr::test_serialization_roundtrip(&contract_package);
+
+        contract_package.insert_pending_contract_version(
+            1,
+            [44; 32].into(),
+            VersionActivation::Era(3),
+        );
+        assert!(contract_package.is_contract_pending([44; 32].into()));
+        assert!(!contract_package.is_contract_pending([42; 32].into()));
+        bytesrepr::test_serialization_roundtrip(&contract_package);
+    }
+
     #[test]
     fn should_remove_group() {
         let mut contract_package = make_contract_package();
@@ -1515,6 +2023,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_activate_pending_contract_version() {
+        const CONTRACT_HASH: ContractHash = ContractHash::new([123; 32]);
+        let mut contract_package = make_contract_package();
+        let current_version = contract_package.current_contract_version();
+
+        let activation = UpgradeDelay::Eras(2).activation_after(5, BlockTime::new(0));
+        assert_eq!(activation, VersionActivation::Era(7));
+        let pending_version =
+            contract_package.insert_pending_contract_version(1, CONTRACT_HASH, activation);
+        assert!(
+            !contract_package.is_version_enabled(pending_version),
+            "pending version should not be enabled"
+        );
+        assert_eq!(contract_package.current_contract_version(), current_version);
+
+        contract_package.activate_versions(6, BlockTime::new(u64::MAX));
+        assert!(!contract_package.is_version_enabled(pending_version));
+
+        contract_package.activate_versions(7, BlockTime::new(0));
+        assert!(contract_package.is_version_enabled(pending_version));
+        assert_eq!(
+            contract_package.current_contract_version(),
+            Some(pending_version)
+        );
+        assert!(contract_package.pending_versions().is_empty());
+    }
+
+    #[test]
+    fn should_not_loosen_upgrade_policy() {
+        let mut contract_package = make_contract_package();
+        let mut approval_groups = BTreeSet::new();
+        approval_groups.insert(Group::new("Group 1"));
+
+        let policy = UpgradePolicy::new(Some(UpgradeDelay::Millis(1_000)), approval_groups);
+        assert_eq!(contract_package.set_upgrade_policy(policy.clone()), Ok(()));
+        assert_eq!(contract_package.upgrade_policy(), &policy);
+
+        let shorter_delay = UpgradePolicy::new(
+            Some(UpgradeDelay::Millis(999)),
+            policy.approval_groups().clone(),
+        );
+        let other_unit = UpgradePolicy::new(
+            Some(UpgradeDelay::Eras(1_000)),
+            policy.approval_groups().clone(),
+        );
+        let fewer_groups = UpgradePolicy::new(policy.upgrade_delay(), BTreeSet::new());
+        for loosened in &[shorter_delay, other_unit, fewer_groups] {
+            assert_eq!(
+                contract_package.set_upgrade_policy(loosened.clone()),
+                Err(Error::UpgradePolicyLoosened)
+            );
+        }
+
+        let mut unknown_group = policy.approval_groups().clone();
+        unknown_group.insert(Group::new("Non-existent group"));
+        assert_eq!(
+            contract_package
+                .set_upgrade_policy(UpgradePolicy::new(policy.upgrade_delay(), unknown_group)),
+            Err(Error::GroupDoesNotExist)
+        );
+
+        let mut more_groups = policy.approval_groups().clone();
+        more_groups.insert(Group::new("Group 2"));
+        let stricter = UpgradePolicy::new(Some(UpgradeDelay::Millis(2_000)), more_groups);
+        assert_eq!(
+            contract_package.set_upgrade_policy(stricter.clone()),
+            Ok(())
+        );
+        assert_eq!(contract_package.upgrade_policy(), &stricter);
+    }
+
     #[test]
     fn contract_hash_from_slice() {
         let bytes: Vec<u8> = (0..32).collect();
@@ -14,12 +14,14 @@ use proptest::{
 
 use crate::{
     account::{AccountHash, Weight},
+    bytesrepr::ToBytes,
     contracts::{
         ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters,
     },
     AccessRights, CLType, CLValue, Contract, ContractHash, ContractPackage, ContractVersionKey,
     ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, NamedArg,
-    Parameter, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    Parameter, Phase, ProtocolVersion, SemVer, URef, UpgradeDelay, UpgradePolicy,
+    VersionActivation, U128, U256, U512,
 };
 
 use crate::deploy_info::gens::{deploy_hash_arb, transfer_addr_arb};
@@ -151,13 +153,27 @@ pub fn cl_type_arb() -> impl Strategy<Value = CLType> {
                 Box::new(cl_type1),
                 Box::new(cl_type2)
             ])),
-            (element.clone(), element.clone(), element).prop_map(
+            (element.clone(), element.clone(), element.clone()).prop_map(
                 |(cl_type1, cl_type2, cl_type3)| CLType::Tuple3([
                     Box::new(cl_type1),
                     Box::new(cl_type2),
                     Box::new(cl_type3)
                 ])
             ),
+            // Named structs and enums
+            (
+                "[A-Z][a-zA-Z]{0,8}",
+                vec(("[a-z_]{1,8}", element.clone()), 0..4)
+            )
+                .prop_map(|(name, fields)| CLType::Struct { name, fields }),
+            (
+                "[A-Z][a-zA-Z]{0,8}",
+                vec(
+                    ("[A-Z][a-zA-Z]{0,8}", vec(("[a-z_]{1,8}", element), 0..3)),
+                    1..4
+                )
+            )
+                .prop_map(|(name, variants)| CLType::Enum { name, variants }),
         ]
     })
 }
@@ -190,7 +206,9 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::Tuple1(_)
             | CLType::Tuple2(_)
             | CLType::Tuple3(_)
-            | CLType::Any => (),
+            | CLType::Any
+            | CLType::Struct { .. }
+            | CLType::Enum { .. } => (),
         }
     };
 
@@ -221,6 +239,19 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         // Fixed lists of any size
         any::<u8>().prop_map(|len| CLValue::from_t([len; 32]).expect("should create CLValue")),
+        // Named structs and enums
+        (any::<u64>(), key_arb()).prop_map(|x| {
+            let cl_type =
+                CLType::new_struct("Entry", &[("amount", CLType::U64), ("key", CLType::Key)]);
+            CLValue::from_components(cl_type, x.to_bytes().expect("should serialize"))
+        }),
+        option::of(uref_arb()).prop_map(|x| {
+            let cl_type = CLType::new_enum(
+                "MaybePurse",
+                &[("None", &[]), ("Some", &[("0", CLType::URef)])],
+            );
+            CLValue::from_components(cl_type, x.to_bytes().expect("should serialize"))
+        }),
     ]
 }
 
@@ -265,10 +296,17 @@ pub fn entry_point_arb() -> impl Strategy<Value = EntryPoint> {
         entry_point_type_arb(),
         entry_point_access_arb(),
         cl_type_arb(),
+        any::<bool>(),
     )
         .prop_map(
-            |(name, parameters, entry_point_type, entry_point_access, ret)| {
-                EntryPoint::new(name, parameters, ret, entry_point_access, entry_point_type)
+            |(name, parameters, entry_point_type, entry_point_access, ret, non_reentrant)| {
+                let entry_point =
+                    EntryPoint::new(name, parameters, ret, entry_point_access, entry_point_type);
+                if non_reentrant {
+                    entry_point.non_reentrant()
+                } else {
+                    entry_point
+                }
             },
         )
 }
@@ -329,20 +367,54 @@ pub fn groups_arb() -> impl Strategy<Value = Groups> {
     btree_map(group_arb(), btree_set(uref_arb(), 1..10), 0..5)
 }
 
+pub fn upgrade_delay_arb() -> impl Strategy<Value = UpgradeDelay> {
+    prop_oneof![
+        any::<u64>().prop_map(UpgradeDelay::Eras),
+        any::<u64>().prop_map(UpgradeDelay::Millis),
+    ]
+}
+
+pub fn version_activation_arb() -> impl Strategy<Value = VersionActivation> {
+    prop_oneof![
+        any::<u64>().prop_map(VersionActivation::Era),
+        any::<u64>().prop_map(VersionActivation::Timestamp),
+    ]
+}
+
 pub fn contract_package_arb() -> impl Strategy<Value = ContractPackage> {
     (
         uref_arb(),
         contract_versions_arb(),
         disabled_versions_arb(),
         groups_arb(),
+        option::of(upgrade_delay_arb()),
+        version_activation_arb(),
     )
-        .prop_map(|(access_key, versions, disabled_versions, groups)| {
-            ContractPackage::new(
-                access_key,
-                versions,
-                disabled_versions,
-                groups,
-                ContractPackageStatus::default(),
-            )
-        })
+        .prop_map(
+            |(access_key, versions, disabled_versions, groups, upgrade_delay, activation)| {
+                let approval_groups = groups.keys().cloned().collect();
+                let mut contract_package = ContractPackage::new(
+                    access_key,
+                    ContractVersions::default(),
+                    disabled_versions,
+                    groups,
+                    ContractPackageStatus::default(),
+                );
+                contract_package
+                    .set_upgrade_policy(UpgradePolicy::new(upgrade_delay, approval_groups))
+                    .expect("should set upgrade policy");
+                for (index, (key, contract_hash)) in versions.into_iter().enumerate() {
+                    if index % 2 == 0 {
+                        contract_package.versions_mut().insert(key, contract_hash);
+                    } else {
+                        contract_package.insert_pending_contract_version(
+                            key.protocol_version_major(),
+                            contract_hash,
+                            activation,
+                        );
+                    }
+                }
+                contract_package
+            },
+        )
 }
@@ -55,13 +55,13 @@ pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
 pub use api_error::ApiError;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
-pub use cl_type::{named_key_type, CLType, CLTyped};
+pub use cl_type::{named_key_type, CLFields, CLType, CLTyped};
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
 pub use contract_wasm::{ContractWasm, ContractWasmHash};
 pub use contracts::{
     Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersion,
     ContractVersionKey, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group,
-    Parameter,
+    Parameter, UpgradeDelay, UpgradePolicy, VersionActivation,
 };
 pub use crypto::*;
 pub use deploy_info::DeployInfo;
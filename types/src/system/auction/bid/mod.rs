@@ -5,18 +5,31 @@ mod vesting;
 
 use alloc::{collections::BTreeMap, vec::Vec};
 
+use num_rational::Ratio;
 #[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{DelegationRate, Delegator, Error},
+    system::auction::{DelegationRate, Delegator, EraId, Error, RewardDestination},
     CLType, CLTyped, PublicKey, URef, U512,
 };
 
 pub use vesting::VestingSchedule;
 
+/// Set in the serialized flags of a bid if the validator has been "evicted".
+///
+/// The flags take the place of the former `inactive` flag, so bids using none of the fields added
+/// since are serialized exactly as before and the ones already stored can still be read.
+const INACTIVE_FLAG: u8 = 0b0001;
+/// Set in the serialized flags of a bid which is followed by its delegation limits.
+const DELEGATION_LIMITS_FLAG: u8 = 0b0010;
+/// Set in the serialized flags of a bid which is followed by the era its validator is jailed until.
+const JAILED_FLAG: u8 = 0b0100;
+/// Set in the serialized flags of a bid which is followed by its reward destination.
+const REWARD_DESTINATION_FLAG: u8 = 0b1000;
+
 /// An entry in the validator map.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -36,6 +49,16 @@ pub struct Bid {
     delegators: BTreeMap<PublicKey, Delegator>,
     /// `true` if validator has been "evicted"
     inactive: bool,
+    /// Minimum amount a delegator must have staked with this validator.
+    minimum_delegation_amount: Option<U512>,
+    /// Maximum amount a delegator may have staked with this validator.
+    maximum_delegation_amount: Option<U512>,
+    /// Maximum number of delegators this validator accepts.
+    maximum_delegators: Option<u32>,
+    /// Era from which a slashed validator may unjail. `None` if validator is not jailed.
+    jailed_until: Option<EraId>,
+    /// Where the validator's era rewards go.
+    reward_destination: RewardDestination,
 }
 
 impl Bid {
@@ -58,6 +81,11 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            minimum_delegation_amount: None,
+            maximum_delegation_amount: None,
+            maximum_delegators: None,
+            jailed_until: None,
+            reward_destination: RewardDestination::Compound,
         }
     }
 
@@ -79,6 +107,11 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            minimum_delegation_amount: None,
+            maximum_delegation_amount: None,
+            maximum_delegators: None,
+            jailed_until: None,
+            reward_destination: RewardDestination::Compound,
         }
     }
 
@@ -134,6 +167,42 @@ impl Bid {
         self.inactive
     }
 
+    /// Returns the minimum amount a delegator must have staked with the provided bid.
+    pub fn minimum_delegation_amount(&self) -> Option<&U512> {
+        self.minimum_delegation_amount.as_ref()
+    }
+
+    /// Returns the maximum amount a delegator may have staked with the provided bid.
+    pub fn maximum_delegation_amount(&self) -> Option<&U512> {
+        self.maximum_delegation_amount.as_ref()
+    }
+
+    /// Returns the maximum number of delegators of the provided bid.
+    pub fn maximum_delegators(&self) -> Option<u32> {
+        self.maximum_delegators
+    }
+
+    /// Returns the era from which the provided bid may be unjailed. `None` if validator is not
+    /// jailed.
+    pub fn jailed_until(&self) -> Option<EraId> {
+        self.jailed_until
+    }
+
+    /// Returns `true` if validator is jailed
+    pub fn is_jailed(&self) -> bool {
+        self.jailed_until.is_some()
+    }
+
+    /// Returns where the validator's era rewards go.
+    pub fn reward_destination(&self) -> &RewardDestination {
+        &self.reward_destination
+    }
+
+    /// Sets where the validator's era rewards go.
+    pub fn set_reward_destination(&mut self, reward_destination: RewardDestination) {
+        self.reward_destination = reward_destination;
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -187,6 +256,65 @@ impl Bid {
         self
     }
 
+    /// Updates the delegation limits of the provided bid.
+    ///
+    /// Fails if the minimum delegation amount is greater than the maximum delegation amount.
+    pub fn with_delegation_limits(
+        &mut self,
+        minimum_delegation_amount: Option<U512>,
+        maximum_delegation_amount: Option<U512>,
+        maximum_delegators: Option<u32>,
+    ) -> Result<&mut Self, Error> {
+        if let (Some(minimum), Some(maximum)) =
+            (minimum_delegation_amount, maximum_delegation_amount)
+        {
+            if minimum > maximum {
+                return Err(Error::InvalidDelegationLimits);
+            }
+        }
+        self.minimum_delegation_amount = minimum_delegation_amount;
+        self.maximum_delegation_amount = maximum_delegation_amount;
+        self.maximum_delegators = maximum_delegators;
+        Ok(self)
+    }
+
+    /// Checks that delegating `amount` on behalf of the given delegator keeps the provided bid
+    /// within its delegation limits.
+    pub fn check_delegation(
+        &self,
+        delegator_public_key: &PublicKey,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let delegated_amount = match self.delegators.get(delegator_public_key) {
+            Some(delegator) => delegator
+                .staked_amount()
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?,
+            None => {
+                if let Some(maximum_delegators) = self.maximum_delegators {
+                    if self.delegators.len() >= maximum_delegators as usize {
+                        return Err(Error::ExceededDelegatorSizeLimit);
+                    }
+                }
+                amount
+            }
+        };
+
+        if let Some(minimum_delegation_amount) = self.minimum_delegation_amount {
+            if delegated_amount < minimum_delegation_amount {
+                return Err(Error::DelegationAmountTooSmall);
+            }
+        }
+
+        if let Some(maximum_delegation_amount) = self.maximum_delegation_amount {
+            if delegated_amount > maximum_delegation_amount {
+                return Err(Error::DelegationAmountTooLarge);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Initializes the vesting schedule of provided bid if the provided timestamp is greater than
     /// or equal to the bid's initial release timestamp and the bid is owned by a genesis
     /// validator.
@@ -235,6 +363,59 @@ impl Bid {
         true
     }
 
+    /// Jails the validator of the provided bid until the given era.
+    pub fn jail(&mut self, jailed_until: EraId) {
+        self.jailed_until = Some(jailed_until);
+    }
+
+    /// Releases the validator of the provided bid from jail.
+    ///
+    /// Fails if the validator is not jailed, or if `era_id` is earlier than the era the validator
+    /// is jailed until.
+    pub fn unjail(&mut self, era_id: EraId) -> Result<(), Error> {
+        match self.jailed_until {
+            Some(jailed_until) if era_id < jailed_until => Err(Error::ValidatorJailed),
+            Some(_) => {
+                self.jailed_until = None;
+                Ok(())
+            }
+            None => Err(Error::ValidatorNotJailed),
+        }
+    }
+
+    /// Burns `slashing_rate` of the validator's stake and `delegation_slashing_rate` of each of
+    /// its delegators' stakes. Delegators left with no stake are removed.
+    ///
+    /// Returns the amounts burned from the validator and from its delegators respectively.
+    pub fn slash(
+        &mut self,
+        slashing_rate: Ratio<U512>,
+        delegation_slashing_rate: Ratio<U512>,
+    ) -> (U512, U512) {
+        let validator_amount = (Ratio::from(self.staked_amount) * slashing_rate).to_integer();
+        self.staked_amount -= validator_amount;
+
+        let mut delegators_amount = U512::zero();
+        for delegator in self.delegators.values_mut() {
+            let delegator_amount =
+                (Ratio::from(*delegator.staked_amount()) * delegation_slashing_rate).to_integer();
+            *delegator.staked_amount_mut() -= delegator_amount;
+            delegators_amount += delegator_amount;
+        }
+        self.delegators
+            .retain(|_, delegator| !delegator.staked_amount().is_zero());
+
+        (validator_amount, delegators_amount)
+    }
+
+    /// Moves the provided bid and its delegators over to a new validator public key.
+    pub fn change_validator_public_key(&mut self, new_public_key: PublicKey) {
+        self.validator_public_key = new_public_key;
+        for delegator in self.delegators.values_mut() {
+            delegator.set_validator_public_key(new_public_key);
+        }
+    }
+
     /// Returns the total staked amount of validator + all delegators
     pub fn total_staked_amount(&self) -> Result<U512, Error> {
         self.delegators
@@ -245,6 +426,34 @@ impl Bid {
             .and_then(|delegators_sum| delegators_sum.checked_add(*self.staked_amount()))
             .ok_or(Error::InvalidAmount)
     }
+
+    fn has_delegation_limits(&self) -> bool {
+        self.minimum_delegation_amount.is_some()
+            || self.maximum_delegation_amount.is_some()
+            || self.maximum_delegators.is_some()
+    }
+
+    fn has_reward_destination(&self) -> bool {
+        self.reward_destination != RewardDestination::Compound
+    }
+
+    /// Returns the flags serialized in place of the former `inactive` flag.
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.inactive {
+            flags |= INACTIVE_FLAG;
+        }
+        if self.has_delegation_limits() {
+            flags |= DELEGATION_LIMITS_FLAG;
+        }
+        if self.jailed_until.is_some() {
+            flags |= JAILED_FLAG;
+        }
+        if self.has_reward_destination() {
+            flags |= REWARD_DESTINATION_FLAG;
+        }
+        flags
+    }
 }
 
 impl CLTyped for Bid {
@@ -262,7 +471,18 @@ impl ToBytes for Bid {
         result.extend(self.delegation_rate.to_bytes()?);
         result.extend(self.vesting_schedule.to_bytes()?);
         result.extend(self.delegators.to_bytes()?);
-        result.extend(self.inactive.to_bytes()?);
+        result.push(self.flags());
+        if self.has_delegation_limits() {
+            result.extend(self.minimum_delegation_amount.to_bytes()?);
+            result.extend(self.maximum_delegation_amount.to_bytes()?);
+            result.extend(self.maximum_delegators.to_bytes()?);
+        }
+        if let Some(jailed_until) = self.jailed_until {
+            result.extend(jailed_until.to_bytes()?);
+        }
+        if self.has_reward_destination() {
+            result.extend(self.reward_destination.to_bytes()?);
+        }
         Ok(result)
     }
 
@@ -273,7 +493,23 @@ impl ToBytes for Bid {
             + self.delegation_rate.serialized_length()
             + self.vesting_schedule.serialized_length()
             + self.delegators.serialized_length()
-            + self.inactive.serialized_length()
+            + self.flags().serialized_length()
+            + if self.has_delegation_limits() {
+                self.minimum_delegation_amount.serialized_length()
+                    + self.maximum_delegation_amount.serialized_length()
+                    + self.maximum_delegators.serialized_length()
+            } else {
+                0
+            }
+            + self
+                .jailed_until
+                .as_ref()
+                .map_or(0, EraId::serialized_length)
+            + if self.has_reward_destination() {
+                self.reward_destination.serialized_length()
+            } else {
+                0
+            }
     }
 }
 
@@ -285,7 +521,35 @@ impl FromBytes for Bid {
         let (delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegators, bytes) = FromBytes::from_bytes(bytes)?;
-        let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
+        let (flags, bytes) = u8::from_bytes(bytes)?;
+        if flags & !(INACTIVE_FLAG | DELEGATION_LIMITS_FLAG | JAILED_FLAG | REWARD_DESTINATION_FLAG)
+            != 0
+        {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let inactive = flags & INACTIVE_FLAG != 0;
+        let (minimum_delegation_amount, maximum_delegation_amount, maximum_delegators, bytes) =
+            if flags & DELEGATION_LIMITS_FLAG != 0 {
+                let (minimum_delegation_amount, bytes) = FromBytes::from_bytes(bytes)?;
+                let (maximum_delegation_amount, bytes) = FromBytes::from_bytes(bytes)?;
+                let (maximum_delegators, bytes) = FromBytes::from_bytes(bytes)?;
+                (
+                    minimum_delegation_amount,
+                    maximum_delegation_amount,
+                    maximum_delegators,
+                    bytes,
+                )
+            } else {
+                (None, None, None, bytes)
+            };
+        let (jailed_until, bytes) = if flags & JAILED_FLAG != 0 {
+            let (jailed_until, bytes) = EraId::from_bytes(bytes)?;
+            (Some(jailed_until), bytes)
+        } else {
+            (None, bytes)
+        };
+        let (reward_destination, bytes) =
+            RewardDestination::from_bytes_if_present(flags & REWARD_DESTINATION_FLAG != 0, bytes)?;
         Ok((
             Bid {
                 validator_public_key,
@@ -295,6 +559,11 @@ impl FromBytes for Bid {
                 vesting_schedule,
                 delegators,
                 inactive,
+                minimum_delegation_amount,
+                maximum_delegation_amount,
+                maximum_delegators,
+                jailed_until,
+                reward_destination,
             },
             bytes,
         ))
@@ -305,9 +574,13 @@ impl FromBytes for Bid {
 mod tests {
     use alloc::collections::BTreeMap;
 
+    use num_rational::Ratio;
+
     use crate::{
-        bytesrepr,
-        system::auction::{bid::VestingSchedule, Bid, DelegationRate, Delegator},
+        bytesrepr::{self, FromBytes, ToBytes},
+        system::auction::{
+            bid::VestingSchedule, Bid, DelegationRate, Delegator, EraId, Error, RewardDestination,
+        },
         AccessRights, PublicKey, SecretKey, URef, U512,
     };
 
@@ -323,10 +596,186 @@ mod tests {
             vesting_schedule: Some(VestingSchedule::default()),
             delegators: BTreeMap::default(),
             inactive: true,
+            minimum_delegation_amount: Some(U512::one()),
+            maximum_delegation_amount: Some(U512::max_value()),
+            maximum_delegators: Some(u32::max_value()),
+            jailed_until: Some(EraId::max_value()),
+            reward_destination: RewardDestination::Purse(URef::new(
+                [43; 32],
+                AccessRights::READ_ADD_WRITE,
+            )),
         };
         bytesrepr::test_serialization_roundtrip(&founding_validator);
     }
 
+    #[test]
+    fn serialization_roundtrip_with_delegation_limits() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 0);
+        bytesrepr::test_serialization_roundtrip(&bid);
+
+        bid.with_delegation_limits(None, None, Some(1)).unwrap();
+        bytesrepr::test_serialization_roundtrip(&bid);
+
+        bid.deactivate();
+        bid.with_delegation_limits(Some(U512::one()), Some(U512::from(10)), None)
+            .unwrap();
+        bytesrepr::test_serialization_roundtrip(&bid);
+    }
+
+    #[test]
+    fn should_check_delegation_limits() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let delegator_1_pk = SecretKey::ed25519([43; 32]).into();
+        let delegator_2_pk = SecretKey::ed25519([44; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 0);
+        assert!(bid.check_delegation(&delegator_1_pk, U512::one()).is_ok());
+
+        assert_eq!(
+            bid.with_delegation_limits(Some(U512::from(100)), Some(U512::from(10)), None)
+                .unwrap_err(),
+            Error::InvalidDelegationLimits
+        );
+        bid.with_delegation_limits(Some(U512::from(10)), Some(U512::from(100)), Some(1))
+            .unwrap();
+
+        assert_eq!(
+            bid.check_delegation(&delegator_1_pk, U512::from(9)),
+            Err(Error::DelegationAmountTooSmall)
+        );
+        assert_eq!(
+            bid.check_delegation(&delegator_1_pk, U512::from(101)),
+            Err(Error::DelegationAmountTooLarge)
+        );
+        assert!(bid
+            .check_delegation(&delegator_1_pk, U512::from(10))
+            .is_ok());
+
+        let delegator_1 =
+            Delegator::unlocked(delegator_1_pk, U512::from(10), bonding_purse, validator_pk);
+        bid.delegators_mut().insert(delegator_1_pk, delegator_1);
+
+        // Top-ups only need to keep the total delegated amount within the limits.
+        assert!(bid.check_delegation(&delegator_1_pk, U512::one()).is_ok());
+        assert_eq!(
+            bid.check_delegation(&delegator_1_pk, U512::from(91)),
+            Err(Error::DelegationAmountTooLarge)
+        );
+        assert_eq!(
+            bid.check_delegation(&delegator_2_pk, U512::from(10)),
+            Err(Error::ExceededDelegatorSizeLimit)
+        );
+    }
+
+    #[test]
+    fn should_slash_and_jail() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let delegator_pk = SecretKey::ed25519([43; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 0);
+        let delegator =
+            Delegator::unlocked(delegator_pk, U512::from(501), bonding_purse, validator_pk);
+        bid.delegators_mut().insert(delegator_pk, delegator);
+
+        let (validator_amount, delegators_amount) = bid.slash(
+            Ratio::new(U512::one(), U512::from(10)),
+            Ratio::new(U512::one(), U512::from(10)),
+        );
+        assert_eq!(validator_amount, U512::from(100));
+        assert_eq!(delegators_amount, U512::from(50));
+        assert_eq!(bid.staked_amount(), &U512::from(900));
+        assert_eq!(
+            bid.delegators()[&delegator_pk].staked_amount(),
+            &U512::from(451)
+        );
+
+        assert_eq!(bid.unjail(5), Err(Error::ValidatorNotJailed));
+        bid.jail(5);
+        assert!(bid.is_jailed());
+        assert_eq!(bid.unjail(4), Err(Error::ValidatorJailed));
+        assert!(bid.unjail(5).is_ok());
+        assert!(!bid.is_jailed());
+    }
+
+    #[test]
+    fn serialization_roundtrip_when_jailed() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 0);
+        bid.jail(5);
+        bytesrepr::test_serialization_roundtrip(&bid);
+
+        bid.with_delegation_limits(Some(U512::one()), None, None)
+            .unwrap();
+        bytesrepr::test_serialization_roundtrip(&bid);
+    }
+
+    #[test]
+    fn should_decode_bid_in_former_encoding() {
+        let validator_pk: PublicKey = SecretKey::ed25519([42; 32]).into();
+        let delegator_pk: PublicKey = SecretKey::ed25519([43; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+        let staked_amount = U512::from(1000);
+        let delegation_rate: DelegationRate = 10;
+        let vesting_schedule = Some(VestingSchedule::new(42));
+
+        let mut delegators = BTreeMap::new();
+        delegators.insert(
+            delegator_pk,
+            Delegator::unlocked(delegator_pk, U512::from(500), bonding_purse, validator_pk),
+        );
+
+        // The delegator's own former encoding is covered by its tests, and is unchanged here as
+        // its rewards are compounded.
+        for inactive in &[false, true] {
+            let mut bytes = validator_pk.to_bytes().unwrap();
+            bytes.extend(bonding_purse.to_bytes().unwrap());
+            bytes.extend(staked_amount.to_bytes().unwrap());
+            bytes.extend(delegation_rate.to_bytes().unwrap());
+            bytes.extend(vesting_schedule.to_bytes().unwrap());
+            bytes.extend(delegators.to_bytes().unwrap());
+            bytes.extend(inactive.to_bytes().unwrap());
+
+            let (bid, rem) = Bid::from_bytes(&bytes).unwrap();
+            assert!(rem.is_empty());
+            assert_eq!(bid.validator_public_key(), &validator_pk);
+            assert_eq!(bid.staked_amount(), &staked_amount);
+            assert_eq!(bid.delegators(), &delegators);
+            assert_eq!(bid.inactive(), *inactive);
+            assert!(!bid.is_jailed());
+            assert_eq!(bid.reward_destination(), &RewardDestination::Compound);
+            assert_eq!(bid.to_bytes().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn should_change_validator_public_key() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let new_validator_pk = SecretKey::ed25519([44; 32]).into();
+        let delegator_pk = SecretKey::ed25519([43; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 0);
+        let delegator =
+            Delegator::unlocked(delegator_pk, U512::from(501), bonding_purse, validator_pk);
+        bid.delegators_mut().insert(delegator_pk, delegator);
+
+        bid.change_validator_public_key(new_validator_pk);
+        assert_eq!(bid.validator_public_key(), &new_validator_pk);
+        assert_eq!(bid.bonding_purse(), &bonding_purse);
+        assert_eq!(bid.staked_amount(), &U512::from(1000));
+        assert_eq!(
+            bid.delegators()[&delegator_pk].validator_public_key(),
+            &new_validator_pk
+        );
+    }
+
     #[test]
     fn should_initialize_delegators_different_timestamps() {
         const WEEK_MILLIS: u64 = 7 * 24 * 60 * 60 * 1000;
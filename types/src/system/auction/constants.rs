@@ -29,6 +29,18 @@ pub const ARG_PUBLIC_KEY: &str = "public_key";
 pub const ARG_VALIDATOR: &str = "validator";
 /// Named constant for `delegator`.
 pub const ARG_DELEGATOR: &str = "delegator";
+/// Named constant for `new_validator`.
+pub const ARG_NEW_VALIDATOR: &str = "new_validator";
+/// Named constant for `new_public_key`.
+pub const ARG_NEW_PUBLIC_KEY: &str = "new_public_key";
+/// Named constant for `reward_destination`.
+pub const ARG_REWARD_DESTINATION: &str = "reward_destination";
+/// Named constant for `minimum_delegation_amount`.
+pub const ARG_MINIMUM_DELEGATION_AMOUNT: &str = "minimum_delegation_amount";
+/// Named constant for `maximum_delegation_amount`.
+pub const ARG_MAXIMUM_DELEGATION_AMOUNT: &str = "maximum_delegation_amount";
+/// Named constant for `maximum_delegators`.
+pub const ARG_MAXIMUM_DELEGATORS: &str = "maximum_delegators";
 /// Named constant for `validator_purse`.
 pub const ARG_VALIDATOR_PURSE: &str = "validator_purse";
 /// Named constant for `validator_keys`.
@@ -72,6 +84,10 @@ pub const METHOD_WITHDRAW_BID: &str = "withdraw_bid";
 pub const METHOD_DELEGATE: &str = "delegate";
 /// Named constant for method `undelegate`.
 pub const METHOD_UNDELEGATE: &str = "undelegate";
+/// Named constant for method `redelegate`.
+pub const METHOD_REDELEGATE: &str = "redelegate";
+/// Named constant for method `set_delegation_limits`.
+pub const METHOD_SET_DELEGATION_LIMITS: &str = "set_delegation_limits";
 /// Named constant for method `run_auction`.
 pub const METHOD_RUN_AUCTION: &str = "run_auction";
 /// Named constant for method `slash`.
@@ -82,9 +98,17 @@ pub const METHOD_DISTRIBUTE: &str = "distribute";
 pub const METHOD_READ_ERA_ID: &str = "read_era_id";
 /// Named constant for method `activate_bid`.
 pub const METHOD_ACTIVATE_BID: &str = "activate_bid";
+/// Named constant for method `unjail`.
+pub const METHOD_UNJAIL: &str = "unjail";
+/// Named constant for method `change_bid_public_key`.
+pub const METHOD_CHANGE_BID_PUBLIC_KEY: &str = "change_bid_public_key";
+/// Named constant for method `set_reward_destination`.
+pub const METHOD_SET_REWARD_DESTINATION: &str = "set_reward_destination";
 
 /// Storage for `UnbondingPurses`
 pub const UNBONDING_PURSES_KEY: &str = "unbonding_purses";
+/// Storage for `Redelegations`.
+pub const REDELEGATIONS_KEY: &str = "redelegations";
 /// Storage for `EraId`.
 pub const ERA_ID_KEY: &str = "era_id";
 /// Storage for era-end timestamp.
@@ -99,3 +123,13 @@ pub const AUCTION_DELAY_KEY: &str = "auction_delay";
 pub const LOCKED_FUNDS_PERIOD_KEY: &str = "locked_funds_period";
 /// Unbonding delay expressed in eras.
 pub const UNBONDING_DELAY_KEY: &str = "unbonding_delay";
+/// Fraction of a slashed validator's stake and of its unbonding entries that is burned.
+pub const SLASHING_RATE_KEY: &str = "slashing_rate";
+/// Fraction of the active delegations to a slashed validator that is burned.
+pub const DELEGATION_SLASHING_RATE_KEY: &str = "delegation_slashing_rate";
+/// Number of eras a slashed validator is jailed for.
+pub const JAIL_PERIOD_KEY: &str = "jail_period";
+/// Storage for `SlashingRecords`.
+pub const SLASHING_RECORDS_KEY: &str = "slashing_records";
+/// Storage for `BidPublicKeyChanges`.
+pub const BID_PUBLIC_KEY_CHANGES_KEY: &str = "bid_public_key_changes";
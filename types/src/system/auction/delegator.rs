@@ -9,10 +9,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{bid::VestingSchedule, Error},
+    system::auction::{bid::VestingSchedule, Error, RewardDestination},
     CLType, CLTyped, PublicKey, URef, U512,
 };
 
+/// Set in the serialized flags of a delegator which is followed by its vesting schedule.
+///
+/// The flags take the place of the former tag of the optional vesting schedule, so delegators using
+/// none of the fields added since are serialized exactly as before and the ones already stored can
+/// still be read.
+const VESTING_SCHEDULE_FLAG: u8 = 0b01;
+/// Set in the serialized flags of a delegator which is followed by its reward destination.
+const REWARD_DESTINATION_FLAG: u8 = 0b10;
+
 /// Represents a party delegating their stake to a validator (or "delegatee")
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -23,6 +32,7 @@ pub struct Delegator {
     bonding_purse: URef,
     validator_public_key: PublicKey,
     vesting_schedule: Option<VestingSchedule>,
+    reward_destination: RewardDestination,
 }
 
 impl Delegator {
@@ -40,6 +50,7 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            reward_destination: RewardDestination::Compound,
         }
     }
 
@@ -58,6 +69,7 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            reward_destination: RewardDestination::Compound,
         }
     }
 
@@ -66,6 +78,11 @@ impl Delegator {
         &self.staked_amount
     }
 
+    /// Returns a mutable reference to the staked amount
+    pub fn staked_amount_mut(&mut self) -> &mut U512 {
+        &mut self.staked_amount
+    }
+
     /// Returns the bonding purse
     pub fn bonding_purse(&self) -> &URef {
         &self.bonding_purse
@@ -76,6 +93,21 @@ impl Delegator {
         &self.validator_public_key
     }
 
+    /// Returns where the delegator's era rewards go
+    pub fn reward_destination(&self) -> &RewardDestination {
+        &self.reward_destination
+    }
+
+    /// Sets where the delegator's era rewards go
+    pub fn set_reward_destination(&mut self, reward_destination: RewardDestination) {
+        self.reward_destination = reward_destination;
+    }
+
+    /// Sets the delegatee
+    pub fn set_validator_public_key(&mut self, validator_public_key: PublicKey) {
+        self.validator_public_key = validator_public_key;
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -134,6 +166,22 @@ impl Delegator {
     pub fn vesting_schedule_mut(&mut self) -> Option<&mut VestingSchedule> {
         self.vesting_schedule.as_mut()
     }
+
+    fn has_reward_destination(&self) -> bool {
+        self.reward_destination != RewardDestination::Compound
+    }
+
+    /// Returns the flags serialized in place of the former vesting schedule tag.
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.vesting_schedule.is_some() {
+            flags |= VESTING_SCHEDULE_FLAG;
+        }
+        if self.has_reward_destination() {
+            flags |= REWARD_DESTINATION_FLAG;
+        }
+        flags
+    }
 }
 
 impl CLTyped for Delegator {
@@ -149,7 +197,13 @@ impl ToBytes for Delegator {
         buffer.extend(self.staked_amount.to_bytes()?);
         buffer.extend(self.bonding_purse.to_bytes()?);
         buffer.extend(self.validator_public_key.to_bytes()?);
-        buffer.extend(self.vesting_schedule.to_bytes()?);
+        buffer.push(self.flags());
+        if let Some(vesting_schedule) = &self.vesting_schedule {
+            buffer.extend(vesting_schedule.to_bytes()?);
+        }
+        if self.has_reward_destination() {
+            buffer.extend(self.reward_destination.to_bytes()?);
+        }
         Ok(buffer)
     }
 
@@ -158,7 +212,16 @@ impl ToBytes for Delegator {
             + self.staked_amount.serialized_length()
             + self.bonding_purse.serialized_length()
             + self.validator_public_key.serialized_length()
-            + self.vesting_schedule.serialized_length()
+            + self.flags().serialized_length()
+            + self
+                .vesting_schedule
+                .as_ref()
+                .map_or(0, VestingSchedule::serialized_length)
+            + if self.has_reward_destination() {
+                self.reward_destination.serialized_length()
+            } else {
+                0
+            }
     }
 }
 
@@ -168,7 +231,18 @@ impl FromBytes for Delegator {
         let (staked_amount, bytes) = U512::from_bytes(bytes)?;
         let (bonding_purse, bytes) = URef::from_bytes(bytes)?;
         let (validator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
-        let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
+        let (flags, bytes) = u8::from_bytes(bytes)?;
+        if flags & !(VESTING_SCHEDULE_FLAG | REWARD_DESTINATION_FLAG) != 0 {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (vesting_schedule, bytes) = if flags & VESTING_SCHEDULE_FLAG != 0 {
+            let (vesting_schedule, bytes) = VestingSchedule::from_bytes(bytes)?;
+            (Some(vesting_schedule), bytes)
+        } else {
+            (None, bytes)
+        };
+        let (reward_destination, bytes) =
+            RewardDestination::from_bytes_if_present(flags & REWARD_DESTINATION_FLAG != 0, bytes)?;
         Ok((
             Delegator {
                 delegator_public_key,
@@ -176,6 +250,7 @@ impl FromBytes for Delegator {
                 bonding_purse,
                 validator_public_key,
                 vesting_schedule,
+                reward_destination,
             },
             bytes,
         ))
@@ -184,7 +259,12 @@ impl FromBytes for Delegator {
 
 #[cfg(test)]
 mod tests {
-    use crate::{bytesrepr, system::auction::Delegator, AccessRights, SecretKey, URef, U512};
+    use crate::{
+        account::AccountHash,
+        bytesrepr::{self, FromBytes, ToBytes},
+        system::auction::{bid::VestingSchedule, Delegator, RewardDestination},
+        AccessRights, PublicKey, SecretKey, URef, U512,
+    };
 
     #[test]
     fn serialization_roundtrip() {
@@ -209,5 +289,75 @@ mod tests {
             release_timestamp_millis,
         );
         bytesrepr::test_serialization_roundtrip(&locked_delegator);
+
+        let mut paying_out_delegator = locked_delegator;
+        paying_out_delegator
+            .set_reward_destination(RewardDestination::Account(AccountHash::new([44; 32])));
+        bytesrepr::test_serialization_roundtrip(&paying_out_delegator);
+    }
+    #[test]
+    fn should_decode_delegator_in_former_encoding() {
+        let staked_amount = U512::one();
+        let bonding_purse = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let delegator_public_key: PublicKey =
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let validator_public_key: PublicKey =
+            SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into();
+        let vesting_schedule = Some(VestingSchedule::new(42));
+
+        let mut bytes = delegator_public_key.to_bytes().unwrap();
+        bytes.extend(staked_amount.to_bytes().unwrap());
+        bytes.extend(bonding_purse.to_bytes().unwrap());
+        bytes.extend(validator_public_key.to_bytes().unwrap());
+        bytes.extend(vesting_schedule.to_bytes().unwrap());
+
+        let (delegator, rem) = Delegator::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(
+            delegator,
+            Delegator::locked(
+                delegator_public_key,
+                staked_amount,
+                bonding_purse,
+                validator_public_key,
+                42
+            )
+        );
+        assert_eq!(delegator.to_bytes().unwrap(), bytes);
+
+        let mut bytes = delegator_public_key.to_bytes().unwrap();
+        bytes.extend(staked_amount.to_bytes().unwrap());
+        bytes.extend(bonding_purse.to_bytes().unwrap());
+        bytes.extend(validator_public_key.to_bytes().unwrap());
+        bytes.extend(Option::<VestingSchedule>::None.to_bytes().unwrap());
+
+        let (delegator, rem) = Delegator::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(
+            delegator,
+            Delegator::unlocked(
+                delegator_public_key,
+                staked_amount,
+                bonding_purse,
+                validator_public_key,
+            )
+        );
+        assert_eq!(delegator.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn should_not_decode_delegator_with_unknown_flags() {
+        let delegator = Delegator::unlocked(
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into(),
+            U512::one(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into(),
+        );
+        let mut bytes = delegator.to_bytes().unwrap();
+        *bytes.last_mut().unwrap() = 0b100;
+        assert_eq!(
+            Delegator::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
     }
 }
@@ -7,8 +7,10 @@ use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        constants::*, Auction, Bids, EraId, Error, RuntimeProvider, SeigniorageAllocation,
-        SeigniorageRecipientsSnapshot, StorageProvider, UnbondingPurse, UnbondingPurses,
+        constants::*, Auction, BidPublicKeyChanges, Bids, Delegator, EraId, Error, Redelegation,
+        Redelegations, RewardDestination, RuntimeProvider, SeigniorageAllocation,
+        SeigniorageRecipientsSnapshot, SlashingRecords, StorageProvider, UnbondingPurse,
+        UnbondingPurses,
     },
     CLTyped, Key, KeyTag, PublicKey, URef, U512,
 };
@@ -86,6 +88,68 @@ where
     write_to(provider, UNBONDING_PURSES_KEY, unbonding_purses)
 }
 
+pub fn get_redelegations<P>(provider: &mut P) -> Result<Redelegations, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    Ok(read_from(provider, REDELEGATIONS_KEY)?)
+}
+
+pub fn set_redelegations<P>(provider: &mut P, redelegations: Redelegations) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    write_to(provider, REDELEGATIONS_KEY, redelegations)
+}
+
+pub fn get_slashing_records<P>(provider: &mut P) -> Result<SlashingRecords, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    Ok(read_from(provider, SLASHING_RECORDS_KEY)?)
+}
+
+pub fn set_slashing_records<P>(
+    provider: &mut P,
+    slashing_records: SlashingRecords,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    write_to(provider, SLASHING_RECORDS_KEY, slashing_records)
+}
+
+pub fn get_bid_public_key_changes<P>(provider: &mut P) -> Result<BidPublicKeyChanges, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    Ok(read_from(provider, BID_PUBLIC_KEY_CHANGES_KEY)?)
+}
+
+pub fn set_bid_public_key_changes<P>(
+    provider: &mut P,
+    bid_public_key_changes: BidPublicKeyChanges,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    write_to(provider, BID_PUBLIC_KEY_CHANGES_KEY, bid_public_key_changes)
+}
+
+/// Returns the public key the bid of the given validator is currently stored under, following
+/// any changes of the bid's public key.
+pub(crate) fn resolve_bid_public_key(
+    bid_public_key_changes: &BidPublicKeyChanges,
+    validator_public_key: PublicKey,
+) -> PublicKey {
+    let mut public_key = validator_public_key;
+    // A bid can't be moved to a key which had a bid before, so the changes can't form a cycle.
+    while let Some(new_public_key) = bid_public_key_changes.get(&public_key) {
+        public_key = *new_public_key;
+    }
+    public_key
+}
+
 pub fn get_era_id<P>(provider: &mut P) -> Result<EraId, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -166,6 +230,27 @@ where
     read_from(provider, UNBONDING_DELAY_KEY)
 }
 
+pub fn get_slashing_rate<P>(provider: &mut P) -> Result<Ratio<U512>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_from(provider, SLASHING_RATE_KEY)
+}
+
+pub fn get_delegation_slashing_rate<P>(provider: &mut P) -> Result<Ratio<U512>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_from(provider, DELEGATION_SLASHING_RATE_KEY)
+}
+
+pub fn get_jail_period<P>(provider: &mut P) -> Result<u64, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_from(provider, JAIL_PERIOD_KEY)
+}
+
 /// Iterates over unbonding entries and checks if a locked amount can be paid already if
 /// a specific era is reached.
 ///
@@ -189,19 +274,7 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(provider: &mut P) ->
             // current era id + unbonding delay is equal or greater than the `era_of_creation` that
             // was calculated on `unbond` attempt.
             if current_era_id >= unbonding_purse.era_of_creation() + unbonding_delay {
-                let account_hash =
-                    AccountHash::from_public_key(unbonding_purse.unbonder_public_key(), |x| {
-                        provider.blake2b(x)
-                    });
-
-                // Move funds from bid purse to unbonding purse
-                provider
-                    .transfer_purse_to_account(
-                        *unbonding_purse.bonding_purse(),
-                        account_hash,
-                        *unbonding_purse.amount(),
-                    )
-                    .map_err(|_| Error::TransferToUnbondingPurse)?;
+                pay_out_unbonding_purse(provider, unbonding_purse)?;
             } else {
                 new_unbonding_list.push(*unbonding_purse);
             }
@@ -216,9 +289,112 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(provider: &mut P) ->
         .collect();
 
     set_unbonding_purses(provider, unbonding_purses)?;
+
+    // Update `redelegations` data
+    let mut redelegations: Redelegations = get_redelegations(provider)?;
+
+    for redelegation_list in redelegations.values_mut() {
+        let mut new_redelegation_list = Vec::new();
+        for redelegation in redelegation_list.iter() {
+            let unbonding_purse = redelegation.unbonding_purse();
+            if current_era_id >= unbonding_purse.era_of_creation() + unbonding_delay {
+                // Redelegate the funds if the new validator is still bonded and accepts the
+                // delegation, otherwise they are paid out to the unbonder as with a regular
+                // unbonding request.
+                if !redelegate_unbonding_purse(
+                    provider,
+                    unbonding_purse,
+                    *redelegation.new_validator(),
+                )? {
+                    pay_out_unbonding_purse(provider, unbonding_purse)?;
+                }
+            } else {
+                new_redelegation_list.push(*redelegation);
+            }
+        }
+        *redelegation_list = new_redelegation_list;
+    }
+
+    // Prune empty entries
+    let redelegations = redelegations
+        .into_iter()
+        .filter(|(_k, redelegations)| !redelegations.is_empty())
+        .collect();
+
+    set_redelegations(provider, redelegations)?;
     Ok(())
 }
 
+/// Pays out the funds of a matured unbonding request to the unbonder's main purse.
+fn pay_out_unbonding_purse<P: Auction + ?Sized>(
+    provider: &mut P,
+    unbonding_purse: &UnbondingPurse,
+) -> Result<(), Error> {
+    let account_hash = AccountHash::from_public_key(unbonding_purse.unbonder_public_key(), |x| {
+        provider.blake2b(x)
+    });
+
+    // Move funds from bid purse to unbonding purse
+    provider
+        .transfer_purse_to_account(
+            *unbonding_purse.bonding_purse(),
+            account_hash,
+            *unbonding_purse.amount(),
+        )
+        .map_err(|_| Error::TransferToUnbondingPurse)
+}
+
+/// Moves the funds of a matured unbonding request into a delegation to its new validator.
+///
+/// Returns `false` without moving any funds if the new validator no longer has an active bid, or
+/// if the delegation would not be within its delegation limits.
+fn redelegate_unbonding_purse<P: Auction + ?Sized>(
+    provider: &mut P,
+    unbonding_purse: &UnbondingPurse,
+    new_validator_public_key: PublicKey,
+) -> Result<bool, Error> {
+    let new_validator_account_hash = AccountHash::from(&new_validator_public_key);
+    let mut bid = match provider.read_bid(&new_validator_account_hash)? {
+        Some(bid) if !bid.inactive() => bid,
+        _ => return Ok(false),
+    };
+
+    let delegator_public_key = *unbonding_purse.unbonder_public_key();
+    let source = *unbonding_purse.bonding_purse();
+    let amount = *unbonding_purse.amount();
+
+    if bid.check_delegation(&delegator_public_key, amount).is_err() {
+        return Ok(false);
+    }
+
+    let delegators = bid.delegators_mut();
+    match delegators.get_mut(&delegator_public_key) {
+        Some(delegator) => {
+            provider
+                .transfer_purse_to_purse(source, *delegator.bonding_purse(), amount)
+                .map_err(|_| Error::TransferToDelegatorPurse)?;
+            delegator.increase_stake(amount)?;
+        }
+        None => {
+            let bonding_purse = provider.create_purse()?;
+            provider
+                .transfer_purse_to_purse(source, bonding_purse, amount)
+                .map_err(|_| Error::TransferToDelegatorPurse)?;
+            let delegator = Delegator::unlocked(
+                delegator_public_key,
+                amount,
+                bonding_purse,
+                new_validator_public_key,
+            );
+            delegators.insert(delegator_public_key, delegator);
+        }
+    }
+
+    provider.write_bid(new_validator_account_hash, bid)?;
+
+    Ok(true)
+}
+
 /// Creates a new purse in unbonding_purses given a validator's key, amount, and a destination
 /// unbonding purse. Returns the amount of motes remaining in the validator's bid purse.
 pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
@@ -228,19 +404,15 @@ pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
     bonding_purse: URef,
     amount: U512,
 ) -> Result<(), Error> {
-    if provider.get_balance(bonding_purse)?.unwrap_or_default() < amount {
-        return Err(Error::UnbondTooLarge);
-    }
-
-    let mut unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
-    let era_of_creation = provider.read_era_id()?;
-    let new_unbonding_purse = UnbondingPurse::new(
-        bonding_purse,
+    let new_unbonding_purse = new_unbonding_purse(
+        provider,
         validator_public_key,
         unbonder_public_key,
-        era_of_creation,
+        bonding_purse,
         amount,
-    );
+    )?;
+
+    let mut unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
     unbonding_purses
         .entry(validator_public_key)
         .or_default()
@@ -250,13 +422,65 @@ pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
     Ok(())
 }
 
-/// Reinvests delegator reward by increasing its stake.
+/// Creates a new redelegation request given a validator's key, amount, a delegator's bonding purse
+/// and the validator the amount is delegated to once the unbonding delay passes.
+pub(crate) fn create_redelegation<P: Auction + ?Sized>(
+    provider: &mut P,
+    validator_public_key: PublicKey,
+    delegator_public_key: PublicKey,
+    bonding_purse: URef,
+    amount: U512,
+    new_validator: PublicKey,
+) -> Result<(), Error> {
+    let unbonding_purse = new_unbonding_purse(
+        provider,
+        validator_public_key,
+        delegator_public_key,
+        bonding_purse,
+        amount,
+    )?;
+
+    let mut redelegations: Redelegations = get_redelegations(provider)?;
+    redelegations
+        .entry(validator_public_key)
+        .or_default()
+        .push(Redelegation::new(unbonding_purse, new_validator));
+    set_redelegations(provider, redelegations)?;
+
+    Ok(())
+}
+
+/// Creates an unbonding request for `amount`, which the bonding purse has to cover.
+fn new_unbonding_purse<P: Auction + ?Sized>(
+    provider: &mut P,
+    validator_public_key: PublicKey,
+    unbonder_public_key: PublicKey,
+    bonding_purse: URef,
+    amount: U512,
+) -> Result<UnbondingPurse, Error> {
+    if provider.get_balance(bonding_purse)?.unwrap_or_default() < amount {
+        return Err(Error::UnbondTooLarge);
+    }
+
+    let era_of_creation = provider.read_era_id()?;
+    Ok(UnbondingPurse::new(
+        bonding_purse,
+        validator_public_key,
+        unbonder_public_key,
+        era_of_creation,
+        amount,
+    ))
+}
+
+/// Reinvests delegator rewards by increasing their stakes, unless a delegator's reward destination
+/// says otherwise. Returns each payout together with the delegator's bonding purse and reward
+/// destination.
 pub fn reinvest_delegator_rewards<P>(
     provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     rewards: impl Iterator<Item = (PublicKey, Ratio<U512>)>,
-) -> Result<Vec<(U512, URef)>, Error>
+) -> Result<Vec<(U512, URef, RewardDestination)>, Error>
 where
     P: StorageProvider,
 {
@@ -278,15 +502,23 @@ where
         };
 
         let delegator_reward_trunc = delegator_reward.to_integer();
+        let reward_destination = *delegator.reward_destination();
 
-        delegator.increase_stake(delegator_reward_trunc)?;
+        if reward_destination == RewardDestination::Compound {
+            delegator.increase_stake(delegator_reward_trunc)?;
+        }
 
-        delegator_payouts.push((delegator_reward_trunc, *delegator.bonding_purse()));
+        delegator_payouts.push((
+            delegator_reward_trunc,
+            *delegator.bonding_purse(),
+            reward_destination,
+        ));
 
         let allocation = SeigniorageAllocation::delegator(
             delegator_key,
             validator_public_key,
             delegator_reward_trunc,
+            reward_destination,
         );
 
         seigniorage_allocations.push(allocation);
@@ -297,13 +529,14 @@ where
     Ok(delegator_payouts)
 }
 
-/// Reinvests validator reward by increasing its stake and returns its bonding purse.
+/// Reinvests validator reward by increasing its stake, unless the validator's reward destination
+/// says otherwise. Returns the validator's bonding purse and reward destination.
 pub fn reinvest_validator_reward<P>(
     provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     amount: U512,
-) -> Result<URef, Error>
+) -> Result<(URef, RewardDestination), Error>
 where
     P: StorageProvider,
 {
@@ -316,9 +549,14 @@ where
         }
     };
 
-    bid.increase_stake(amount)?;
+    let reward_destination = *bid.reward_destination();
+
+    if reward_destination == RewardDestination::Compound {
+        bid.increase_stake(amount)?;
+    }
 
-    let allocation = SeigniorageAllocation::validator(validator_public_key, amount);
+    let allocation =
+        SeigniorageAllocation::validator(validator_public_key, amount, reward_destination);
 
     seigniorage_allocations.push(allocation);
 
@@ -326,5 +564,25 @@ where
 
     provider.write_bid(validator_account_hash, bid)?;
 
-    Ok(bonding_purse)
+    Ok((bonding_purse, reward_destination))
+}
+
+/// Transfers a reward from `source` to its reward destination, which is `bonding_purse` for
+/// compounded rewards.
+pub(crate) fn transfer_reward<P: Auction + ?Sized>(
+    provider: &mut P,
+    source: URef,
+    bonding_purse: URef,
+    reward_destination: RewardDestination,
+    amount: U512,
+) -> Result<(), Error> {
+    match reward_destination {
+        RewardDestination::Compound => {
+            provider.transfer_purse_to_purse(source, bonding_purse, amount)
+        }
+        RewardDestination::Purse(purse) => provider.transfer_purse_to_purse(source, purse, amount),
+        RewardDestination::Account(account_hash) => provider
+            .transfer_purse_to_account(source, account_hash, amount)
+            .map(|_| ()),
+    }
 }
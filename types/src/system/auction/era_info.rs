@@ -9,11 +9,18 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::RewardDestination,
     CLType, CLTyped, PublicKey, U512,
 };
 
-const SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG: u8 = 0;
-const SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG: u8 = 1;
+/// Set in the serialized flags of a seigniorage allocation for a delegator.
+///
+/// The flags take the place of the former variant tag, so allocations using none of the fields
+/// added since are serialized exactly as before and the ones already stored can still be read.
+const SEIGNIORAGE_ALLOCATION_DELEGATOR_FLAG: u8 = 0b01;
+/// Set in the serialized flags of a seigniorage allocation which is followed by its reward
+/// destination.
+const SEIGNIORAGE_ALLOCATION_REWARD_DESTINATION_FLAG: u8 = 0b10;
 
 /// Information about a seigniorage allocation
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
@@ -26,6 +33,8 @@ pub enum SeigniorageAllocation {
         validator_public_key: PublicKey,
         /// Allocated amount
         amount: U512,
+        /// Where the allocated amount went
+        reward_destination: RewardDestination,
     },
     /// Info about a seigniorage allocation for a delegator
     Delegator {
@@ -35,15 +44,22 @@ pub enum SeigniorageAllocation {
         validator_public_key: PublicKey,
         /// Allocated amount
         amount: U512,
+        /// Where the allocated amount went
+        reward_destination: RewardDestination,
     },
 }
 
 impl SeigniorageAllocation {
     /// Constructs a [`SeigniorageAllocation::Validator`]
-    pub const fn validator(validator_public_key: PublicKey, amount: U512) -> Self {
+    pub const fn validator(
+        validator_public_key: PublicKey,
+        amount: U512,
+        reward_destination: RewardDestination,
+    ) -> Self {
         SeigniorageAllocation::Validator {
             validator_public_key,
             amount,
+            reward_destination,
         }
     }
 
@@ -52,11 +68,13 @@ impl SeigniorageAllocation {
         delegator_public_key: PublicKey,
         validator_public_key: PublicKey,
         amount: U512,
+        reward_destination: RewardDestination,
     ) -> Self {
         SeigniorageAllocation::Delegator {
             delegator_public_key,
             validator_public_key,
             amount,
+            reward_destination,
         }
     }
 
@@ -68,22 +86,44 @@ impl SeigniorageAllocation {
         }
     }
 
-    fn tag(&self) -> u8 {
+    /// Returns where the amount of a given seigniorage allocation went
+    pub fn reward_destination(&self) -> &RewardDestination {
         match self {
-            SeigniorageAllocation::Validator { .. } => SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG,
-            SeigniorageAllocation::Delegator { .. } => SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG,
+            SeigniorageAllocation::Validator {
+                reward_destination, ..
+            } => reward_destination,
+            SeigniorageAllocation::Delegator {
+                reward_destination, ..
+            } => reward_destination,
+        }
+    }
+
+    fn has_reward_destination(&self) -> bool {
+        *self.reward_destination() != RewardDestination::Compound
+    }
+
+    /// Returns the flags serialized in place of the former variant tag.
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if let SeigniorageAllocation::Delegator { .. } = self {
+            flags |= SEIGNIORAGE_ALLOCATION_DELEGATOR_FLAG;
+        }
+        if self.has_reward_destination() {
+            flags |= SEIGNIORAGE_ALLOCATION_REWARD_DESTINATION_FLAG;
         }
+        flags
     }
 }
 
 impl ToBytes for SeigniorageAllocation {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
-        buffer.append(&mut self.tag().to_bytes()?);
+        buffer.append(&mut self.flags().to_bytes()?);
         match self {
             SeigniorageAllocation::Validator {
                 validator_public_key,
                 amount,
+                ..
             } => {
                 buffer.append(&mut validator_public_key.to_bytes()?);
                 buffer.append(&mut amount.to_bytes()?);
@@ -92,61 +132,81 @@ impl ToBytes for SeigniorageAllocation {
                 delegator_public_key,
                 validator_public_key,
                 amount,
+                ..
             } => {
                 buffer.append(&mut delegator_public_key.to_bytes()?);
                 buffer.append(&mut validator_public_key.to_bytes()?);
                 buffer.append(&mut amount.to_bytes()?);
             }
         }
+        if self.has_reward_destination() {
+            buffer.append(&mut self.reward_destination().to_bytes()?);
+        }
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.tag().serialized_length()
+        self.flags().serialized_length()
             + match self {
                 SeigniorageAllocation::Validator {
                     validator_public_key,
                     amount,
+                    ..
                 } => validator_public_key.serialized_length() + amount.serialized_length(),
                 SeigniorageAllocation::Delegator {
                     delegator_public_key,
                     validator_public_key,
                     amount,
+                    ..
                 } => {
                     delegator_public_key.serialized_length()
                         + validator_public_key.serialized_length()
                         + amount.serialized_length()
                 }
             }
+            + if self.has_reward_destination() {
+                self.reward_destination().serialized_length()
+            } else {
+                0
+            }
     }
 }
 
 impl FromBytes for SeigniorageAllocation {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (tag, rem) = <u8>::from_bytes(bytes)?;
-        match tag {
-            SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG => {
-                let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
-                let (amount, rem) = U512::from_bytes(rem)?;
-                Ok((
-                    SeigniorageAllocation::validator(validator_public_key, amount),
-                    rem,
-                ))
-            }
-            SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG => {
-                let (delegator_public_key, rem) = PublicKey::from_bytes(rem)?;
-                let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
-                let (amount, rem) = U512::from_bytes(rem)?;
-                Ok((
-                    SeigniorageAllocation::delegator(
-                        delegator_public_key,
-                        validator_public_key,
-                        amount,
-                    ),
-                    rem,
-                ))
-            }
-            _ => Err(bytesrepr::Error::Formatting),
+        let (flags, rem) = <u8>::from_bytes(bytes)?;
+        if flags
+            & !(SEIGNIORAGE_ALLOCATION_DELEGATOR_FLAG
+                | SEIGNIORAGE_ALLOCATION_REWARD_DESTINATION_FLAG)
+            != 0
+        {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let has_reward_destination = flags & SEIGNIORAGE_ALLOCATION_REWARD_DESTINATION_FLAG != 0;
+        if flags & SEIGNIORAGE_ALLOCATION_DELEGATOR_FLAG == 0 {
+            let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
+            let (amount, rem) = U512::from_bytes(rem)?;
+            let (reward_destination, rem) =
+                RewardDestination::from_bytes_if_present(has_reward_destination, rem)?;
+            Ok((
+                SeigniorageAllocation::validator(validator_public_key, amount, reward_destination),
+                rem,
+            ))
+        } else {
+            let (delegator_public_key, rem) = PublicKey::from_bytes(rem)?;
+            let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
+            let (amount, rem) = U512::from_bytes(rem)?;
+            let (reward_destination, rem) =
+                RewardDestination::from_bytes_if_present(has_reward_destination, rem)?;
+            Ok((
+                SeigniorageAllocation::delegator(
+                    delegator_public_key,
+                    validator_public_key,
+                    amount,
+                    reward_destination,
+                ),
+                rem,
+            ))
         }
     }
 }
@@ -246,21 +306,36 @@ pub mod gens {
     use crate::{
         crypto::gens::public_key_arb,
         gens::u512_arb,
-        system::auction::{EraInfo, SeigniorageAllocation},
+        system::auction::{
+            reward_destination::gens::reward_destination_arb, EraInfo, SeigniorageAllocation,
+        },
     };
 
     fn seigniorage_allocation_validator_arb() -> impl Strategy<Value = SeigniorageAllocation> {
-        (public_key_arb(), u512_arb()).prop_map(|(validator_public_key, amount)| {
-            SeigniorageAllocation::validator(validator_public_key, amount)
-        })
+        (public_key_arb(), u512_arb(), reward_destination_arb()).prop_map(
+            |(validator_public_key, amount, reward_destination)| {
+                SeigniorageAllocation::validator(validator_public_key, amount, reward_destination)
+            },
+        )
     }
 
     fn seigniorage_allocation_delegator_arb() -> impl Strategy<Value = SeigniorageAllocation> {
-        (public_key_arb(), public_key_arb(), u512_arb()).prop_map(
-            |(delegator_public_key, validator_public_key, amount)| {
-                SeigniorageAllocation::delegator(delegator_public_key, validator_public_key, amount)
-            },
+        (
+            public_key_arb(),
+            public_key_arb(),
+            u512_arb(),
+            reward_destination_arb(),
         )
+            .prop_map(
+                |(delegator_public_key, validator_public_key, amount, reward_destination)| {
+                    SeigniorageAllocation::delegator(
+                        delegator_public_key,
+                        validator_public_key,
+                        amount,
+                        reward_destination,
+                    )
+                },
+            )
     }
 
     /// Creates an arbitrary [`SeignorageAllocation`](crate::system::auction::SeigniorageAllocation)
@@ -285,10 +360,72 @@ pub mod gens {
 mod tests {
     use proptest::prelude::*;
 
-    use crate::bytesrepr;
+    use crate::{
+        bytesrepr::{self, FromBytes, ToBytes},
+        system::auction::{RewardDestination, SeigniorageAllocation},
+        PublicKey, SecretKey, U512,
+    };
 
     use super::gens;
 
+    const FORMER_VALIDATOR_TAG: u8 = 0;
+    const FORMER_DELEGATOR_TAG: u8 = 1;
+
+    #[test]
+    fn should_decode_seigniorage_allocations_in_former_encoding() {
+        let validator_public_key: PublicKey = SecretKey::ed25519([42; 32]).into();
+        let delegator_public_key: PublicKey = SecretKey::ed25519([43; 32]).into();
+        let amount = U512::from(1000);
+
+        let mut bytes = vec![FORMER_VALIDATOR_TAG];
+        bytes.extend(validator_public_key.to_bytes().unwrap());
+        bytes.extend(amount.to_bytes().unwrap());
+        let (allocation, rem) = SeigniorageAllocation::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(
+            allocation,
+            SeigniorageAllocation::validator(
+                validator_public_key,
+                amount,
+                RewardDestination::Compound
+            )
+        );
+        assert_eq!(allocation.to_bytes().unwrap(), bytes);
+
+        let mut bytes = vec![FORMER_DELEGATOR_TAG];
+        bytes.extend(delegator_public_key.to_bytes().unwrap());
+        bytes.extend(validator_public_key.to_bytes().unwrap());
+        bytes.extend(amount.to_bytes().unwrap());
+        let (allocation, rem) = SeigniorageAllocation::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(
+            allocation,
+            SeigniorageAllocation::delegator(
+                delegator_public_key,
+                validator_public_key,
+                amount,
+                RewardDestination::Compound
+            )
+        );
+        assert_eq!(allocation.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn should_not_decode_seigniorage_allocation_with_unknown_flags() {
+        let validator_public_key: PublicKey = SecretKey::ed25519([42; 32]).into();
+        let allocation = SeigniorageAllocation::validator(
+            validator_public_key,
+            U512::from(1000),
+            RewardDestination::Compound,
+        );
+        let mut bytes = allocation.to_bytes().unwrap();
+        bytes[0] = 0b100;
+        assert_eq!(
+            SeigniorageAllocation::from_bytes(&bytes).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
     proptest! {
         #[test]
         fn test_serialization_roundtrip(era_info in gens::era_info_arb(0..32)) {
@@ -138,6 +138,42 @@ pub enum Error {
     /// made.
     #[fail(display = "Delegator's funds are locked")]
     DelegatorFundsLocked = 38,
+    /// Raised when an attempt to redelegate is made to a validator that has no bid.
+    #[fail(display = "Redelegation validator not found")]
+    RedelegationValidatorNotFound = 39,
+    /// Raised when an attempt to redelegate is made to the currently delegated validator.
+    #[fail(display = "Redelegation to the same validator")]
+    RedelegationToSameValidator = 40,
+    /// Raised when a delegation would leave the delegator below the validator's minimum
+    /// delegation amount.
+    #[fail(display = "Delegation amount too small")]
+    DelegationAmountTooSmall = 41,
+    /// Raised when a delegation would leave the delegator above the validator's maximum
+    /// delegation amount.
+    #[fail(display = "Delegation amount too large")]
+    DelegationAmountTooLarge = 42,
+    /// Raised when a new delegator would exceed the validator's maximum number of delegators.
+    #[fail(display = "Exceeded delegator size limit")]
+    ExceededDelegatorSizeLimit = 43,
+    /// Raised when the minimum delegation amount is greater than the maximum delegation amount.
+    #[fail(display = "Invalid delegation limits")]
+    InvalidDelegationLimits = 44,
+    /// Raised when a jailed validator attempts to unjail before its jail period has passed.
+    #[fail(display = "Validator is still jailed")]
+    ValidatorJailed = 45,
+    /// Raised when a validator that is not jailed attempts to unjail.
+    #[fail(display = "Validator is not jailed")]
+    ValidatorNotJailed = 46,
+    /// Raised when a validator attempts to move its bid to a public key which already has a bid.
+    #[fail(display = "Validator public key already in use")]
+    ValidatorPublicKeyInUse = 47,
+    /// Raised when a reward destination purse does not exist or can't be added to.
+    #[fail(display = "Invalid reward destination")]
+    InvalidRewardDestination = 48,
+    /// Raised when a validator attempts to move a bid which has already been moved to another
+    /// public key.
+    #[fail(display = "Bid public key already changed")]
+    BidPublicKeyChanged = 49,
 
     // NOTE: These variants below and related plumbing will be removed once support for WASM
     // system contracts will be dropped.
@@ -217,6 +253,23 @@ impl TryFrom<u8> for Error {
             d if d == Error::Transfer as u8 => Ok(Error::Transfer),
             d if d == Error::DelegationRateTooLarge as u8 => Ok(Error::DelegationRateTooLarge),
             d if d == Error::DelegatorFundsLocked as u8 => Ok(Error::DelegatorFundsLocked),
+            d if d == Error::RedelegationValidatorNotFound as u8 => {
+                Ok(Error::RedelegationValidatorNotFound)
+            }
+            d if d == Error::RedelegationToSameValidator as u8 => {
+                Ok(Error::RedelegationToSameValidator)
+            }
+            d if d == Error::DelegationAmountTooSmall as u8 => Ok(Error::DelegationAmountTooSmall),
+            d if d == Error::DelegationAmountTooLarge as u8 => Ok(Error::DelegationAmountTooLarge),
+            d if d == Error::ExceededDelegatorSizeLimit as u8 => {
+                Ok(Error::ExceededDelegatorSizeLimit)
+            }
+            d if d == Error::InvalidDelegationLimits as u8 => Ok(Error::InvalidDelegationLimits),
+            d if d == Error::ValidatorJailed as u8 => Ok(Error::ValidatorJailed),
+            d if d == Error::ValidatorNotJailed as u8 => Ok(Error::ValidatorNotJailed),
+            d if d == Error::ValidatorPublicKeyInUse as u8 => Ok(Error::ValidatorPublicKeyInUse),
+            d if d == Error::InvalidRewardDestination as u8 => Ok(Error::InvalidRewardDestination),
+            d if d == Error::BidPublicKeyChanged as u8 => Ok(Error::BidPublicKeyChanged),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
             _ => Err(TryFromU8ForError(())),
         }
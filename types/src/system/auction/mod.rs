@@ -6,10 +6,13 @@ mod detail;
 mod era_info;
 mod error;
 mod providers;
+mod redelegation;
+mod reward_destination;
 mod seigniorage_recipient;
+mod slashing_record;
 mod unbonding_purse;
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
 use num_rational::Ratio;
 
@@ -23,7 +26,10 @@ pub use error::Error;
 pub use providers::{
     AccountProvider, MintProvider, RuntimeProvider, StorageProvider, SystemProvider,
 };
+pub use redelegation::Redelegation;
+pub use reward_destination::RewardDestination;
 pub use seigniorage_recipient::SeigniorageRecipient;
+pub use slashing_record::SlashingRecord;
 pub use unbonding_purse::UnbondingPurse;
 
 /// Representation of delegation rate of tokens. Range from 0..=100.
@@ -50,6 +56,15 @@ pub type SeigniorageRecipientsSnapshot = BTreeMap<EraId, SeigniorageRecipients>;
 /// Validators and delegators mapped to their unbonding purses.
 pub type UnbondingPurses = BTreeMap<PublicKey, Vec<UnbondingPurse>>;
 
+/// Validators mapped to the redelegation requests made by their delegators.
+pub type Redelegations = BTreeMap<PublicKey, Vec<Redelegation>>;
+
+/// Slashed validators mapped to the records of their slashings.
+pub type SlashingRecords = BTreeMap<PublicKey, Vec<SlashingRecord>>;
+
+/// Former validator public keys mapped to the public keys their bids were moved to.
+pub type BidPublicKeyChanges = BTreeMap<PublicKey, PublicKey>;
+
 /// Bonding auction contract interface
 pub trait Auction:
     StorageProvider + SystemProvider + RuntimeProvider + MintProvider + AccountProvider + Sized
@@ -141,6 +156,36 @@ pub trait Auction:
         Ok(updated_amount)
     }
 
+    /// Sets the limits a validator places on delegations to its bid: the minimum and maximum
+    /// amount each delegator may have staked, and the maximum number of delegators. `None` lifts
+    /// the respective limit.
+    ///
+    /// The limits are enforced on subsequent delegations only; existing delegations are kept.
+    fn set_delegation_limits(
+        &mut self,
+        public_key: PublicKey,
+        minimum_delegation_amount: Option<U512>,
+        maximum_delegation_amount: Option<U512>,
+        maximum_delegators: Option<u32>,
+    ) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = self
+            .read_bid(&account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        bid.with_delegation_limits(
+            minimum_delegation_amount,
+            maximum_delegation_amount,
+            maximum_delegators,
+        )?;
+
+        self.write_bid(account_hash, bid)
+    }
+
     /// For a non-founder validator, implements essentially the same logic as add_bid, but reducing
     /// the number of tokens and calling unbond in lieu of bond.
     ///
@@ -227,6 +272,8 @@ pub trait Auction:
             }
         };
 
+        bid.check_delegation(&delegator_public_key, amount)?;
+
         let delegators = bid.delegators_mut();
 
         let new_delegation_amount = match delegators.get_mut(&delegator_public_key) {
@@ -306,43 +353,180 @@ pub trait Auction:
         Ok(new_amount)
     }
 
+    /// Unbonds delegator's stake from a validator like [`Auction::undelegate`], but delegates the
+    /// unbonded amount to `new_validator` instead of paying it out once the unbonding delay
+    /// passes.
+    ///
+    /// If `new_validator` no longer has an active bid at that point, or no longer accepts the
+    /// delegation because of its delegation limits, the amount is paid out to the delegator as
+    /// with a regular unbonding request.
+    ///
+    /// Returns the remaining staked amount with the current validator.
+    fn redelegate(
+        &mut self,
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+        new_validator: PublicKey,
+    ) -> Result<U512, Error> {
+        let account_hash = AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        if new_validator == validator_public_key {
+            return Err(Error::RedelegationToSameValidator);
+        }
+
+        if self.read_bid(&AccountHash::from(&new_validator))?.is_none() {
+            return Err(Error::RedelegationValidatorNotFound);
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = match self.read_bid(&validator_account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        let delegators = bid.delegators_mut();
+
+        let new_amount = match delegators.get_mut(&delegator_public_key) {
+            Some(delegator) => {
+                detail::create_redelegation(
+                    self,
+                    validator_public_key,
+                    delegator_public_key,
+                    *delegator.bonding_purse(),
+                    amount,
+                    new_validator,
+                )?;
+
+                let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
+                let updated_stake = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
+                if updated_stake == U512::zero() {
+                    delegators.remove(&delegator_public_key);
+                };
+                updated_stake
+            }
+            None => return Err(Error::DelegatorNotFound),
+        };
+
+        self.write_bid(validator_account_hash, bid)?;
+
+        Ok(new_amount)
+    }
+
     /// Slashes each validator.
     ///
+    /// Burns the configured slashing rate of the validator's stake, its delegators' stakes and
+    /// the amounts of unbonding requests pending with the validator, then jails the validator for
+    /// the configured jail period. Each slashing is recorded under the validator's public key.
+    ///
     /// This can be only invoked through a system call.
     fn slash(&mut self, validator_public_keys: Vec<PublicKey>) -> Result<(), Error> {
         if self.get_caller() != SYSTEM_ACCOUNT {
             return Err(Error::InvalidCaller);
         }
 
+        let slashing_rate = detail::get_slashing_rate(self)?;
+        let delegation_slashing_rate = detail::get_delegation_slashing_rate(self)?;
+        let jail_period = detail::get_jail_period(self)?;
+        let era_id = detail::get_era_id(self)?;
+        let jailed_until = era_id.saturating_add(jail_period);
+
         let mut burned_amount: U512 = U512::zero();
 
         let mut unbonding_purses: UnbondingPurses = detail::get_unbonding_purses(self)?;
         let mut unbonding_purses_modified = false;
 
+        let mut redelegations: Redelegations = detail::get_redelegations(self)?;
+        let mut redelegations_modified = false;
+
+        let mut slashing_records = detail::get_slashing_records(self)?;
+
+        let bid_public_key_changes = detail::get_bid_public_key_changes(self)?;
+
         for validator_public_key in validator_public_keys {
-            // Burn stake, deactivate
-            let validator_account_hash = AccountHash::from(&validator_public_key);
-            if let Some(mut bid) = self.read_bid(&validator_account_hash)? {
-                burned_amount += *bid.staked_amount();
-                *bid.staked_amount_mut() = U512::zero();
-                bid.deactivate();
-                self.write_bid(validator_account_hash, bid)?;
-            };
+            // The validator's bid may have been moved to a new public key since the equivocation
+            let bid_public_key =
+                detail::resolve_bid_public_key(&bid_public_key_changes, validator_public_key);
+
+            // Burn stake, jail
+            let validator_account_hash = AccountHash::from(&bid_public_key);
+            let (validator_amount, delegators_amount) =
+                match self.read_bid(&validator_account_hash)? {
+                    Some(mut bid) => {
+                        let burned_amounts = bid.slash(slashing_rate, delegation_slashing_rate);
+                        bid.jail(jailed_until);
+                        self.write_bid(validator_account_hash, bid)?;
+                        burned_amounts
+                    }
+                    // Bids are never removed, so a key without one was never a validator
+                    None => continue,
+                };
 
-            // Update unbonding entries for given validator
-            if let Some(unbonding_list) = unbonding_purses.remove(&validator_public_key) {
-                burned_amount += unbonding_list
-                    .into_iter()
-                    .map(|unbonding_purse| *unbonding_purse.amount())
-                    .sum();
-                unbonding_purses_modified = true;
+            // Update unbonding entries for given validator, created under either of its keys
+            let mut unbonding_amount = U512::zero();
+            let mut unbonding_public_keys = vec![validator_public_key];
+            if bid_public_key != validator_public_key {
+                unbonding_public_keys.push(bid_public_key);
+            }
+            for public_key in unbonding_public_keys {
+                if let Some(unbonding_list) = unbonding_purses.get_mut(&public_key) {
+                    for unbonding_purse in unbonding_list.iter_mut() {
+                        let amount =
+                            (Ratio::from(*unbonding_purse.amount()) * slashing_rate).to_integer();
+                        *unbonding_purse.amount_mut() -= amount;
+                        unbonding_amount += amount;
+                    }
+                    unbonding_list.retain(|unbonding_purse| !unbonding_purse.amount().is_zero());
+                    if unbonding_list.is_empty() {
+                        unbonding_purses.remove(&public_key);
+                    }
+                    unbonding_purses_modified = true;
+                }
+
+                if let Some(redelegation_list) = redelegations.get_mut(&public_key) {
+                    for redelegation in redelegation_list.iter_mut() {
+                        let unbonding_purse = redelegation.unbonding_purse_mut();
+                        let amount =
+                            (Ratio::from(*unbonding_purse.amount()) * slashing_rate).to_integer();
+                        *unbonding_purse.amount_mut() -= amount;
+                        unbonding_amount += amount;
+                    }
+                    redelegation_list
+                        .retain(|redelegation| !redelegation.unbonding_purse().amount().is_zero());
+                    if redelegation_list.is_empty() {
+                        redelegations.remove(&public_key);
+                    }
+                    redelegations_modified = true;
+                }
             }
+
+            burned_amount += validator_amount + delegators_amount + unbonding_amount;
+
+            slashing_records
+                .entry(bid_public_key)
+                .or_default()
+                .push(SlashingRecord::new(
+                    era_id,
+                    validator_amount,
+                    delegators_amount,
+                    unbonding_amount,
+                    jailed_until,
+                ));
         }
 
         if unbonding_purses_modified {
             detail::set_unbonding_purses(self, unbonding_purses)?;
         }
 
+        if redelegations_modified {
+            detail::set_redelegations(self, redelegations)?;
+        }
+
+        detail::set_slashing_records(self, slashing_records)?;
+
         self.reduce_total_supply(burned_amount)?;
 
         Ok(())
@@ -367,10 +551,17 @@ pub trait Auction:
         let snapshot_size = auction_delay as usize + 1;
         let mut era_id = detail::get_era_id(self)?;
         let mut bids = detail::get_bids(self)?;
+        let mut bid_public_key_changes = detail::get_bid_public_key_changes(self)?;
 
         // Process unbond requests
         detail::process_unbond_requests(self)?;
 
+        // Evicted validators' bids may have been moved to new public keys
+        let evicted_validators: Vec<PublicKey> = evicted_validators
+            .into_iter()
+            .map(|public_key| detail::resolve_bid_public_key(&bid_public_key_changes, public_key))
+            .collect();
+
         // Process bids
         let mut bids_modified = false;
         for (validator_public_key, bid) in bids.iter_mut() {
@@ -387,7 +578,9 @@ pub trait Auction:
         let winners: ValidatorWeights = {
             let founder_weights: ValidatorWeights = bids
                 .iter()
-                .filter(|(_public_key, bid)| bid.vesting_schedule().is_some() && !bid.inactive())
+                .filter(|(_public_key, bid)| {
+                    bid.vesting_schedule().is_some() && !bid.inactive() && !bid.is_jailed()
+                })
                 .map(|(public_key, bid)| {
                     let total_staked_amount = bid.total_staked_amount()?;
                     Ok((*public_key, total_staked_amount))
@@ -397,7 +590,9 @@ pub trait Auction:
             // We collect these into a vec for sorting
             let mut non_founder_weights: Vec<(PublicKey, U512)> = bids
                 .iter()
-                .filter(|(_public_key, bid)| bid.vesting_schedule().is_none() && !bid.inactive())
+                .filter(|(_public_key, bid)| {
+                    bid.vesting_schedule().is_none() && !bid.inactive() && !bid.is_jailed()
+                })
                 .map(|(public_key, bid)| {
                     let total_staked_amount = bid.total_staked_amount()?;
                     Ok((*public_key, total_staked_amount))
@@ -440,7 +635,20 @@ pub trait Auction:
             let previous_recipients = snapshot.insert(delayed_era, recipients);
             assert!(previous_recipients.is_none());
 
-            let snapshot = snapshot.into_iter().rev().take(snapshot_size).collect();
+            let snapshot: SeigniorageRecipientsSnapshot =
+                snapshot.into_iter().rev().take(snapshot_size).collect();
+
+            // Forget about public key changes once the former keys have left the snapshot
+            let changes_count = bid_public_key_changes.len();
+            bid_public_key_changes.retain(|public_key, _| {
+                snapshot
+                    .values()
+                    .any(|recipients| recipients.contains_key(public_key))
+            });
+            if bid_public_key_changes.len() != changes_count {
+                detail::set_bid_public_key_changes(self, bid_public_key_changes)?;
+            }
+
             detail::set_seigniorage_recipients_snapshot(self, snapshot)?;
         }
 
@@ -456,6 +664,9 @@ pub trait Auction:
 
     /// Mint and distribute seigniorage rewards to validators and their delegators,
     /// according to `reward_factors` returned by the consensus component.
+    ///
+    /// Rewards are added to the stakes, unless the validator or delegator has set a reward
+    /// destination to have them transferred elsewhere.
     fn distribute(&mut self, reward_factors: BTreeMap<PublicKey, u64>) -> Result<(), Error> {
         if self.get_caller() != SYSTEM_ACCOUNT {
             return Err(Error::InvalidCaller);
@@ -464,6 +675,7 @@ pub trait Auction:
         let seigniorage_recipients = self.read_seigniorage_recipients()?;
         let base_round_reward = self.read_base_round_reward()?;
         let era_id = detail::get_era_id(self)?;
+        let bid_public_key_changes = detail::get_bid_public_key_changes(self)?;
 
         if reward_factors.keys().ne(seigniorage_recipients.keys()) {
             return Err(Error::MismatchedEraValidators);
@@ -477,6 +689,10 @@ pub trait Auction:
                 .get(&public_key)
                 .ok_or(Error::ValidatorNotFound)?;
 
+            // Rewards go to the validator's bid, wherever it has been moved since the snapshot
+            let bid_public_key =
+                detail::resolve_bid_public_key(&bid_public_key_changes, public_key);
+
             let total_stake = recipient.total_stake();
             if total_stake.is_zero() {
                 // TODO: error?
@@ -514,28 +730,31 @@ pub trait Auction:
             let delegator_payouts = detail::reinvest_delegator_rewards(
                 self,
                 &mut seigniorage_allocations,
-                public_key,
+                bid_public_key,
                 delegator_rewards,
             )?;
             let total_delegator_payout = delegator_payouts
                 .iter()
-                .map(|(amount, _bonding_purse)| *amount)
+                .map(|(amount, _bonding_purse, _reward_destination)| *amount)
                 .sum();
 
             let validators_part: Ratio<U512> = total_reward - Ratio::from(total_delegator_payout);
             let validator_reward = validators_part.to_integer();
-            let validator_bonding_purse = detail::reinvest_validator_reward(
-                self,
-                &mut seigniorage_allocations,
-                public_key,
-                validator_reward,
-            )?;
+            let (validator_bonding_purse, validator_reward_destination) =
+                detail::reinvest_validator_reward(
+                    self,
+                    &mut seigniorage_allocations,
+                    bid_public_key,
+                    validator_reward,
+                )?;
             // TODO: add "mint into existing purse" facility
             let tmp_validator_reward_purse =
                 self.mint(validator_reward).map_err(|_| Error::MintReward)?;
-            self.transfer_purse_to_purse(
+            detail::transfer_reward(
+                self,
                 tmp_validator_reward_purse,
                 validator_bonding_purse,
+                validator_reward_destination,
                 validator_reward,
             )
             .map_err(|_| Error::ValidatorRewardTransfer)?;
@@ -544,10 +763,12 @@ pub trait Auction:
             let tmp_delegator_reward_purse = self
                 .mint(total_delegator_payout)
                 .map_err(|_| Error::MintReward)?;
-            for (delegator_payout, bonding_purse) in delegator_payouts {
-                self.transfer_purse_to_purse(
+            for (delegator_payout, bonding_purse, reward_destination) in delegator_payouts {
+                detail::transfer_reward(
+                    self,
                     tmp_delegator_reward_purse,
                     bonding_purse,
+                    reward_destination,
                     delegator_payout,
                 )
                 .map_err(|_| Error::DelegatorRewardTransfer)?;
@@ -583,4 +804,119 @@ pub trait Auction:
 
         Ok(())
     }
+
+    /// Releases a slashed validator from jail once its jail period has passed, letting it take
+    /// part in subsequent auctions again.
+    fn unjail(&mut self, validator_public_key: PublicKey) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&validator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = match self.read_bid(&account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        let era_id = detail::get_era_id(self)?;
+        bid.unjail(era_id)?;
+
+        self.write_bid(account_hash, bid)?;
+
+        Ok(())
+    }
+
+    /// Sets where the era rewards of a validator go or, if `delegator_public_key` is given, where
+    /// the era rewards of that delegator of the validator go.
+    ///
+    /// A purse given as the reward destination has to exist and be addable.
+    fn set_reward_destination(
+        &mut self,
+        validator_public_key: PublicKey,
+        delegator_public_key: Option<PublicKey>,
+        reward_destination: RewardDestination,
+    ) -> Result<(), Error> {
+        let caller_public_key = delegator_public_key.unwrap_or(validator_public_key);
+        let account_hash = AccountHash::from_public_key(&caller_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        if let RewardDestination::Purse(purse) = reward_destination {
+            if !purse.is_addable() || self.get_balance(purse)?.is_none() {
+                return Err(Error::InvalidRewardDestination);
+            }
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = self
+            .read_bid(&validator_account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        match delegator_public_key {
+            Some(delegator_public_key) => bid
+                .delegators_mut()
+                .get_mut(&delegator_public_key)
+                .ok_or(Error::DelegatorNotFound)?
+                .set_reward_destination(reward_destination),
+            None => bid.set_reward_destination(reward_destination),
+        }
+
+        self.write_bid(validator_account_hash, bid)
+    }
+
+    /// Moves a validator's bid, together with its delegators, to a new public key which doesn't
+    /// have a bid yet. The bid left under the former public key is emptied and deactivated, and
+    /// can't be moved again while the change is remembered.
+    ///
+    /// The change takes effect with the next auction, so the former public key remains in the
+    /// validator sets of the eras already decided. Until these have passed, rewards and slashings
+    /// of the former public key are applied to the moved bid.
+    ///
+    /// Returns the first era in which the new public key can be a validator.
+    fn change_bid_public_key(
+        &mut self,
+        public_key: PublicKey,
+        new_public_key: PublicKey,
+    ) -> Result<EraId, Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = self
+            .read_bid(&account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        // The former bid stays under the former public key, so moving it again would redirect the
+        // rewards and slashings of the former public key away from the moved bid.
+        let mut bid_public_key_changes = detail::get_bid_public_key_changes(self)?;
+        if bid_public_key_changes.contains_key(&public_key) {
+            return Err(Error::BidPublicKeyChanged);
+        }
+
+        let new_account_hash = AccountHash::from(&new_public_key);
+        if self.read_bid(&new_account_hash)?.is_some() {
+            return Err(Error::ValidatorPublicKeyInUse);
+        }
+
+        let mut former_bid = Bid::unlocked(
+            public_key,
+            self.create_purse()?,
+            U512::zero(),
+            *bid.delegation_rate(),
+        );
+        former_bid.deactivate();
+        self.write_bid(account_hash, former_bid)?;
+
+        bid.change_validator_public_key(new_public_key);
+        self.write_bid(new_account_hash, bid)?;
+
+        bid_public_key_changes.insert(public_key, new_public_key);
+        detail::set_bid_public_key_changes(self, bid_public_key_changes)?;
+
+        let era_id = detail::get_era_id(self)?;
+        let auction_delay = detail::get_auction_delay(self)?;
+        Ok(era_id + auction_delay + 1)
+    }
 }
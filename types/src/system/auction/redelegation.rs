@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::UnbondingPurse,
+    CLType, CLTyped, PublicKey,
+};
+
+/// Unbonding request whose amount is delegated to a new validator once the unbonding delay
+/// passes, rather than paid out to the unbonder.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Redelegation {
+    /// Unbonding request the amount is taken from.
+    unbonding_purse: UnbondingPurse,
+    /// Validator to which the unbonded amount is delegated.
+    new_validator: PublicKey,
+}
+
+impl Redelegation {
+    /// Creates [`Redelegation`] instance for a redelegation request.
+    pub const fn new(unbonding_purse: UnbondingPurse, new_validator: PublicKey) -> Self {
+        Self {
+            unbonding_purse,
+            new_validator,
+        }
+    }
+
+    /// Returns the unbonding request the amount is taken from.
+    pub fn unbonding_purse(&self) -> &UnbondingPurse {
+        &self.unbonding_purse
+    }
+
+    /// Returns a mutable reference to the unbonding request the amount is taken from.
+    pub fn unbonding_purse_mut(&mut self) -> &mut UnbondingPurse {
+        &mut self.unbonding_purse
+    }
+
+    /// Returns public key of the validator the unbonded amount will be delegated to.
+    pub fn new_validator(&self) -> &PublicKey {
+        &self.new_validator
+    }
+}
+
+impl ToBytes for Redelegation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(&self.unbonding_purse.to_bytes()?);
+        result.extend(&self.new_validator.to_bytes()?);
+        Ok(result)
+    }
+    fn serialized_length(&self) -> usize {
+        self.unbonding_purse.serialized_length() + self.new_validator.serialized_length()
+    }
+}
+
+impl FromBytes for Redelegation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (unbonding_purse, bytes) = FromBytes::from_bytes(bytes)?;
+        let (new_validator, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            Redelegation {
+                unbonding_purse,
+                new_validator,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl CLTyped for Redelegation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytesrepr,
+        system::auction::{EraId, Redelegation, UnbondingPurse},
+        AccessRights, PublicKey, SecretKey, URef, U512,
+    };
+
+    #[test]
+    fn serialization_roundtrip() {
+        let validator_public_key: PublicKey =
+            SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let delegator_public_key: PublicKey =
+            SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into();
+        let new_validator_public_key: PublicKey =
+            SecretKey::ed25519([44; SecretKey::ED25519_LENGTH]).into();
+
+        let unbonding_purse = UnbondingPurse::new(
+            URef::new([41; 32], AccessRights::READ_ADD_WRITE),
+            validator_public_key,
+            delegator_public_key,
+            EraId::max_value(),
+            U512::max_value() - 1,
+        );
+        let redelegation = Redelegation::new(unbonding_purse, new_validator_public_key);
+
+        bytesrepr::test_serialization_roundtrip(&redelegation);
+    }
+}
@@ -0,0 +1,149 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, URef,
+};
+
+const REWARD_DESTINATION_COMPOUND_TAG: u8 = 0;
+const REWARD_DESTINATION_PURSE_TAG: u8 = 1;
+const REWARD_DESTINATION_ACCOUNT_TAG: u8 = 2;
+
+/// Where the era rewards of a validator or of a delegator go.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub enum RewardDestination {
+    /// Rewards are added to the staked amount.
+    Compound,
+    /// Rewards are transferred to the given purse.
+    Purse(URef),
+    /// Rewards are transferred to the main purse of the given account.
+    Account(AccountHash),
+}
+
+impl RewardDestination {
+    fn tag(&self) -> u8 {
+        match self {
+            RewardDestination::Compound => REWARD_DESTINATION_COMPOUND_TAG,
+            RewardDestination::Purse(_) => REWARD_DESTINATION_PURSE_TAG,
+            RewardDestination::Account(_) => REWARD_DESTINATION_ACCOUNT_TAG,
+        }
+    }
+
+    /// Deserializes a reward destination if `is_present`, otherwise returns
+    /// [`RewardDestination::Compound`] without consuming any bytes.
+    ///
+    /// Used by the types which only serialize their reward destination when it isn't the default.
+    pub(crate) fn from_bytes_if_present(
+        is_present: bool,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        if is_present {
+            Self::from_bytes(bytes)
+        } else {
+            Ok((RewardDestination::Compound, bytes))
+        }
+    }
+}
+
+impl Default for RewardDestination {
+    fn default() -> Self {
+        RewardDestination::Compound
+    }
+}
+
+impl CLTyped for RewardDestination {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for RewardDestination {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.tag().to_bytes()?);
+        match self {
+            RewardDestination::Compound => {}
+            RewardDestination::Purse(purse) => result.append(&mut purse.to_bytes()?),
+            RewardDestination::Account(account_hash) => {
+                result.append(&mut account_hash.to_bytes()?)
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                RewardDestination::Compound => 0,
+                RewardDestination::Purse(purse) => purse.serialized_length(),
+                RewardDestination::Account(account_hash) => account_hash.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for RewardDestination {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            REWARD_DESTINATION_COMPOUND_TAG => Ok((RewardDestination::Compound, rem)),
+            REWARD_DESTINATION_PURSE_TAG => {
+                let (purse, rem) = URef::from_bytes(rem)?;
+                Ok((RewardDestination::Purse(purse), rem))
+            }
+            REWARD_DESTINATION_ACCOUNT_TAG => {
+                let (account_hash, rem) = AccountHash::from_bytes(rem)?;
+                Ok((RewardDestination::Account(account_hash), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Generators for [`RewardDestination`]
+#[cfg(any(feature = "gens", test))]
+pub mod gens {
+    use proptest::{
+        prelude::{Just, Strategy},
+        prop_oneof,
+    };
+
+    use crate::{
+        gens::{account_hash_arb, uref_arb},
+        system::auction::RewardDestination,
+    };
+
+    /// Creates an arbitrary [`RewardDestination`]
+    pub fn reward_destination_arb() -> impl Strategy<Value = RewardDestination> {
+        prop_oneof![
+            Just(RewardDestination::Compound),
+            uref_arb().prop_map(RewardDestination::Purse),
+            account_hash_arb().prop_map(RewardDestination::Account),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::bytesrepr;
+
+    use super::gens;
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(reward_destination in gens::reward_destination_arb()) {
+            bytesrepr::test_serialization_roundtrip(&reward_destination)
+        }
+    }
+}
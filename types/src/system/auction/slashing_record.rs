@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::EraId,
+    CLType, CLTyped, U512,
+};
+
+/// Record of a validator having been slashed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SlashingRecord {
+    /// Era in which the validator was slashed.
+    era_id: EraId,
+    /// Amount burned from the validator's own stake.
+    validator_amount: U512,
+    /// Amount burned from the stakes of the validator's delegators.
+    delegators_amount: U512,
+    /// Amount burned from unbonding requests pending with the validator.
+    unbonding_amount: U512,
+    /// Era from which the validator may unjail.
+    jailed_until: EraId,
+}
+
+impl SlashingRecord {
+    /// Creates [`SlashingRecord`] instance.
+    pub const fn new(
+        era_id: EraId,
+        validator_amount: U512,
+        delegators_amount: U512,
+        unbonding_amount: U512,
+        jailed_until: EraId,
+    ) -> Self {
+        Self {
+            era_id,
+            validator_amount,
+            delegators_amount,
+            unbonding_amount,
+            jailed_until,
+        }
+    }
+
+    /// Returns era in which the validator was slashed.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns amount burned from the validator's own stake.
+    pub fn validator_amount(&self) -> &U512 {
+        &self.validator_amount
+    }
+
+    /// Returns amount burned from the stakes of the validator's delegators.
+    pub fn delegators_amount(&self) -> &U512 {
+        &self.delegators_amount
+    }
+
+    /// Returns amount burned from unbonding requests pending with the validator.
+    pub fn unbonding_amount(&self) -> &U512 {
+        &self.unbonding_amount
+    }
+
+    /// Returns era from which the validator may unjail.
+    pub fn jailed_until(&self) -> EraId {
+        self.jailed_until
+    }
+}
+
+impl ToBytes for SlashingRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(&self.era_id.to_bytes()?);
+        result.extend(&self.validator_amount.to_bytes()?);
+        result.extend(&self.delegators_amount.to_bytes()?);
+        result.extend(&self.unbonding_amount.to_bytes()?);
+        result.extend(&self.jailed_until.to_bytes()?);
+        Ok(result)
+    }
+    fn serialized_length(&self) -> usize {
+        self.era_id.serialized_length()
+            + self.validator_amount.serialized_length()
+            + self.delegators_amount.serialized_length()
+            + self.unbonding_amount.serialized_length()
+            + self.jailed_until.serialized_length()
+    }
+}
+
+impl FromBytes for SlashingRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (era_id, bytes) = FromBytes::from_bytes(bytes)?;
+        let (validator_amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (delegators_amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (unbonding_amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (jailed_until, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            SlashingRecord {
+                era_id,
+                validator_amount,
+                delegators_amount,
+                unbonding_amount,
+                jailed_until,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl CLTyped for SlashingRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytesrepr,
+        system::auction::{EraId, SlashingRecord},
+        U512,
+    };
+
+    #[test]
+    fn serialization_roundtrip() {
+        let slashing_record = SlashingRecord::new(
+            EraId::max_value() - 1,
+            U512::max_value(),
+            U512::one(),
+            U512::zero(),
+            EraId::max_value(),
+        );
+
+        bytesrepr::test_serialization_roundtrip(&slashing_record);
+    }
+}
@@ -73,6 +73,11 @@ impl UnbondingPurse {
     pub fn amount(&self) -> &U512 {
         &self.amount
     }
+
+    /// Returns a mutable reference to the unbonding amount.
+    pub fn amount_mut(&mut self) -> &mut U512 {
+        &mut self.amount
+    }
 }
 
 impl ToBytes for UnbondingPurse {
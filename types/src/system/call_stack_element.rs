@@ -0,0 +1,284 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractHash, ContractPackageHash,
+};
+
+const CALL_STACK_ELEMENT_SESSION_TAG: u8 = 0;
+const CALL_STACK_ELEMENT_STORED_SESSION_TAG: u8 = 1;
+const CALL_STACK_ELEMENT_STORED_CONTRACT_TAG: u8 = 2;
+
+/// A caller in the chain of calls made during the execution of a deploy.
+///
+/// The first element of the call stack is always the [`CallStackElement::Session`] of the account
+/// which sent the deploy, and the last one is the code currently executing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallStackElement {
+    /// Session code, run in the context of the account.
+    Session {
+        /// The account's hash.
+        account_hash: AccountHash,
+    },
+    /// An entry point of a stored contract of type session, run in the context of the account.
+    StoredSession {
+        /// The account's hash.
+        account_hash: AccountHash,
+        /// The hash of the stored contract's package.
+        contract_package_hash: ContractPackageHash,
+        /// The hash of the stored contract.
+        contract_hash: ContractHash,
+        /// The name of the entry point called.
+        entry_point: String,
+    },
+    /// An entry point of a stored contract of type contract, run in the context of the contract.
+    StoredContract {
+        /// The hash of the stored contract's package.
+        contract_package_hash: ContractPackageHash,
+        /// The hash of the stored contract.
+        contract_hash: ContractHash,
+        /// The name of the entry point called.
+        entry_point: String,
+    },
+}
+
+impl CallStackElement {
+    /// Creates a [`CallStackElement::Session`].
+    pub fn session(account_hash: AccountHash) -> Self {
+        CallStackElement::Session { account_hash }
+    }
+
+    /// Creates a [`CallStackElement::StoredSession`].
+    pub fn stored_session<T: Into<String>>(
+        account_hash: AccountHash,
+        contract_package_hash: ContractPackageHash,
+        contract_hash: ContractHash,
+        entry_point: T,
+    ) -> Self {
+        CallStackElement::StoredSession {
+            account_hash,
+            contract_package_hash,
+            contract_hash,
+            entry_point: entry_point.into(),
+        }
+    }
+
+    /// Creates a [`CallStackElement::StoredContract`].
+    pub fn stored_contract<T: Into<String>>(
+        contract_package_hash: ContractPackageHash,
+        contract_hash: ContractHash,
+        entry_point: T,
+    ) -> Self {
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            contract_hash,
+            entry_point: entry_point.into(),
+        }
+    }
+
+    /// Returns the hash of the stored contract's package, or `None` for session code.
+    pub fn contract_package_hash(&self) -> Option<&ContractPackageHash> {
+        match self {
+            CallStackElement::Session { .. } => None,
+            CallStackElement::StoredSession {
+                contract_package_hash,
+                ..
+            }
+            | CallStackElement::StoredContract {
+                contract_package_hash,
+                ..
+            } => Some(contract_package_hash),
+        }
+    }
+
+    /// Returns the hash of the stored contract, or `None` for session code.
+    pub fn contract_hash(&self) -> Option<&ContractHash> {
+        match self {
+            CallStackElement::Session { .. } => None,
+            CallStackElement::StoredSession { contract_hash, .. }
+            | CallStackElement::StoredContract { contract_hash, .. } => Some(contract_hash),
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            CallStackElement::Session { .. } => CALL_STACK_ELEMENT_SESSION_TAG,
+            CallStackElement::StoredSession { .. } => CALL_STACK_ELEMENT_STORED_SESSION_TAG,
+            CallStackElement::StoredContract { .. } => CALL_STACK_ELEMENT_STORED_CONTRACT_TAG,
+        }
+    }
+}
+
+impl CLTyped for CallStackElement {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for CallStackElement {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.tag().to_bytes()?);
+        match self {
+            CallStackElement::Session { account_hash } => {
+                result.append(&mut account_hash.to_bytes()?)
+            }
+            CallStackElement::StoredSession {
+                account_hash,
+                contract_package_hash,
+                contract_hash,
+                entry_point,
+            } => {
+                result.append(&mut account_hash.to_bytes()?);
+                result.append(&mut contract_package_hash.to_bytes()?);
+                result.append(&mut contract_hash.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+            }
+            CallStackElement::StoredContract {
+                contract_package_hash,
+                contract_hash,
+                entry_point,
+            } => {
+                result.append(&mut contract_package_hash.to_bytes()?);
+                result.append(&mut contract_hash.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                CallStackElement::Session { account_hash } => account_hash.serialized_length(),
+                CallStackElement::StoredSession {
+                    account_hash,
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point,
+                } => {
+                    account_hash.serialized_length()
+                        + contract_package_hash.serialized_length()
+                        + contract_hash.serialized_length()
+                        + entry_point.serialized_length()
+                }
+                CallStackElement::StoredContract {
+                    contract_package_hash,
+                    contract_hash,
+                    entry_point,
+                } => {
+                    contract_package_hash.serialized_length()
+                        + contract_hash.serialized_length()
+                        + entry_point.serialized_length()
+                }
+            }
+    }
+}
+
+impl FromBytes for CallStackElement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            CALL_STACK_ELEMENT_SESSION_TAG => {
+                let (account_hash, rem) = AccountHash::from_bytes(rem)?;
+                Ok((CallStackElement::Session { account_hash }, rem))
+            }
+            CALL_STACK_ELEMENT_STORED_SESSION_TAG => {
+                let (account_hash, rem) = AccountHash::from_bytes(rem)?;
+                let (contract_package_hash, rem) = ContractPackageHash::from_bytes(rem)?;
+                let (contract_hash, rem) = ContractHash::from_bytes(rem)?;
+                let (entry_point, rem) = String::from_bytes(rem)?;
+                Ok((
+                    CallStackElement::StoredSession {
+                        account_hash,
+                        contract_package_hash,
+                        contract_hash,
+                        entry_point,
+                    },
+                    rem,
+                ))
+            }
+            CALL_STACK_ELEMENT_STORED_CONTRACT_TAG => {
+                let (contract_package_hash, rem) = ContractPackageHash::from_bytes(rem)?;
+                let (contract_hash, rem) = ContractHash::from_bytes(rem)?;
+                let (entry_point, rem) = String::from_bytes(rem)?;
+                Ok((
+                    CallStackElement::StoredContract {
+                        contract_package_hash,
+                        contract_hash,
+                        entry_point,
+                    },
+                    rem,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Generators for [`CallStackElement`]
+#[cfg(any(feature = "gens", test))]
+pub mod gens {
+    use proptest::{array, bits, prelude::*};
+
+    use crate::{
+        gens::account_hash_arb, system::CallStackElement, ContractHash, ContractPackageHash,
+    };
+
+    fn contract_package_hash_arb() -> impl Strategy<Value = ContractPackageHash> {
+        array::uniform32(bits::u8::ANY).prop_map(ContractPackageHash::new)
+    }
+
+    fn contract_hash_arb() -> impl Strategy<Value = ContractHash> {
+        array::uniform32(bits::u8::ANY).prop_map(ContractHash::new)
+    }
+
+    /// Creates an arbitrary [`CallStackElement`]
+    pub fn call_stack_element_arb() -> impl Strategy<Value = CallStackElement> {
+        prop_oneof![
+            account_hash_arb().prop_map(CallStackElement::session),
+            (
+                account_hash_arb(),
+                contract_package_hash_arb(),
+                contract_hash_arb(),
+                "[a-z_]*"
+            )
+                .prop_map(
+                    |(account_hash, contract_package_hash, contract_hash, entry_point)| {
+                        CallStackElement::stored_session(
+                            account_hash,
+                            contract_package_hash,
+                            contract_hash,
+                            entry_point,
+                        )
+                    }
+                ),
+            (contract_package_hash_arb(), contract_hash_arb(), "[a-z_]*").prop_map(
+                |(contract_package_hash, contract_hash, entry_point)| {
+                    CallStackElement::stored_contract(
+                        contract_package_hash,
+                        contract_hash,
+                        entry_point,
+                    )
+                }
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::bytesrepr;
+
+    use super::gens;
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(call_stack_element in gens::call_stack_element_arb()) {
+            bytesrepr::test_serialization_roundtrip(&call_stack_element)
+        }
+    }
+}
@@ -1,10 +1,12 @@
 //! System modules, formerly known as "system contracts"
 
 pub mod auction;
+mod call_stack_element;
 pub mod handle_payment;
 pub mod mint;
 pub mod standard_payment;
 
+pub use call_stack_element::CallStackElement;
 pub use error::Error;
 pub use system_contract_type::{
     SystemContractType, AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
@@ -0,0 +1,290 @@
+//! Code generation of the derived trait implementations.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Data, DataEnum, DeriveInput, Error, Fields, GenericParam, Generics, Ident, Index,
+    Member, Path, Type,
+};
+
+/// The highest number of variants an enum can have, as the variant index is serialized as a `u8`.
+const MAX_VARIANTS: usize = u8::max_value() as usize + 1;
+
+/// A field of a struct or of an enum variant.
+struct Field<'a> {
+    /// The name of the field in its `CLType`, i.e. its identifier or, if unnamed, its index.
+    name: String,
+    /// How the field is accessed on `self`.
+    member: Member,
+    /// The variable a field of an enum variant is bound to when matching, or parsed into.
+    binding: Ident,
+    ty: &'a Type,
+}
+
+fn fields(fields: &Fields) -> Vec<Field> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (name, member) = match &field.ident {
+                Some(ident) => (ident.to_string(), Member::Named(ident.clone())),
+                None => (index.to_string(), Member::Unnamed(Index::from(index))),
+            };
+            Field {
+                name,
+                member,
+                binding: format_ident!("__field{}", index),
+                ty: &field.ty,
+            }
+        })
+        .collect()
+}
+
+/// Returns the pattern matching a variant or a struct with the given fields, binding them.
+fn pattern(path: TokenStream, kind: &Fields, fields: &[Field]) -> TokenStream {
+    let bindings = fields.iter().map(|field| &field.binding);
+    match kind {
+        Fields::Named(_) => {
+            let members = fields.iter().map(|field| &field.member);
+            quote!(#path { #(#members: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+        Fields::Unit => path,
+    }
+}
+
+fn check_variant_count(data: &DataEnum, span: Span) -> Result<(), Error> {
+    if data.variants.is_empty() {
+        return Err(Error::new(span, "enums without variants are not supported"));
+    }
+    if data.variants.len() > MAX_VARIANTS {
+        return Err(Error::new(
+            span,
+            format!("enums can have at most {} variants", MAX_VARIANTS),
+        ));
+    }
+    Ok(())
+}
+
+/// Adds the given trait as a bound to every type parameter.
+fn add_trait_bounds(mut generics: Generics, bound: &Path) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+fn unsupported_union(input: &DeriveInput) -> Error {
+    Error::new_spanned(&input.ident, "unions are not supported")
+}
+
+/// Returns the `&[(&str, CLType)]` expression describing the given fields.
+fn cl_fields(fields: &[Field]) -> TokenStream {
+    let entries = fields.iter().map(|field| {
+        let name = &field.name;
+        let ty = field.ty;
+        quote!((#name, <#ty as ::casper_types::CLTyped>::cl_type()))
+    });
+    quote!({
+        let fields: &[(&str, ::casper_types::CLType)] = &[#(#entries),*];
+        fields
+    })
+}
+
+pub(crate) fn cl_typed(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        &parse_quote!(::casper_types::CLTyped),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let cl_type = match &input.data {
+        Data::Struct(data) => {
+            let cl_fields = cl_fields(&fields(&data.fields));
+            quote!(::casper_types::CLType::new_struct(#name, #cl_fields))
+        }
+        Data::Enum(data) => {
+            check_variant_count(data, ident.span())?;
+            let cl_variants = data.variants.iter().map(|variant| {
+                let variant_name = variant.ident.to_string();
+                let cl_fields = cl_fields(&fields(&variant.fields));
+                quote!((#variant_name, #cl_fields))
+            });
+            quote!(::casper_types::CLType::new_enum(#name, &[#(#cl_variants),*]))
+        }
+        Data::Union(_) => return Err(unsupported_union(input)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::casper_types::CLTyped for #ident #ty_generics #where_clause {
+            fn cl_type() -> ::casper_types::CLType {
+                #cl_type
+            }
+        }
+    })
+}
+
+pub(crate) fn to_bytes(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        &parse_quote!(::casper_types::bytesrepr::ToBytes),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (append_fields, serialized_length) = match &input.data {
+        Data::Struct(data) => {
+            let fields = fields(&data.fields);
+            let members = fields.iter().map(|field| &field.member).collect::<Vec<_>>();
+            let append_fields = quote! {
+                #(
+                    result.append(
+                        &mut ::casper_types::bytesrepr::ToBytes::to_bytes(&self.#members)?
+                    );
+                )*
+            };
+            let serialized_length = quote! {
+                0 #(+ ::casper_types::bytesrepr::ToBytes::serialized_length(&self.#members))*
+            };
+            (append_fields, serialized_length)
+        }
+        Data::Enum(data) => {
+            check_variant_count(data, ident.span())?;
+            let mut append_arms = Vec::new();
+            let mut length_arms = Vec::new();
+            for (index, variant) in data.variants.iter().enumerate() {
+                let index = index as u8;
+                let variant_ident = &variant.ident;
+                let fields = fields(&variant.fields);
+                let pattern = pattern(quote!(Self::#variant_ident), &variant.fields, &fields);
+                let bindings = fields
+                    .iter()
+                    .map(|field| &field.binding)
+                    .collect::<Vec<_>>();
+                append_arms.push(quote! {
+                    #pattern => {
+                        result.push(#index);
+                        #(
+                            result.append(
+                                &mut ::casper_types::bytesrepr::ToBytes::to_bytes(#bindings)?
+                            );
+                        )*
+                    }
+                });
+                length_arms.push(quote! {
+                    #pattern => {
+                        0 #(+ ::casper_types::bytesrepr::ToBytes::serialized_length(#bindings))*
+                    }
+                });
+            }
+            let append_fields = quote! {
+                match self {
+                    #(#append_arms)*
+                }
+            };
+            let serialized_length = quote! {
+                ::casper_types::bytesrepr::U8_SERIALIZED_LENGTH + match self {
+                    #(#length_arms)*
+                }
+            };
+            (append_fields, serialized_length)
+        }
+        Data::Union(_) => return Err(unsupported_union(input)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::casper_types::bytesrepr::ToBytes for #ident #ty_generics
+            #where_clause
+        {
+            fn to_bytes(
+                &self,
+            ) -> ::core::result::Result<
+                ::casper_types::bytesrepr::Vec<u8>,
+                ::casper_types::bytesrepr::Error,
+            > {
+                let mut result = ::casper_types::bytesrepr::allocate_buffer(self)?;
+                #append_fields
+                ::core::result::Result::Ok(result)
+            }
+
+            fn serialized_length(&self) -> usize {
+                #serialized_length
+            }
+        }
+    })
+}
+
+/// Returns the statements parsing the given fields from `remainder`, each into its binding.
+fn parse_fields(fields: &[Field]) -> TokenStream {
+    let bindings = fields.iter().map(|field| &field.binding);
+    let types = fields.iter().map(|field| field.ty);
+    quote! {
+        #(
+            let (#bindings, remainder) =
+                <#types as ::casper_types::bytesrepr::FromBytes>::from_bytes(remainder)?;
+        )*
+    }
+}
+
+pub(crate) fn from_bytes(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let ident = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        &parse_quote!(::casper_types::bytesrepr::FromBytes),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = fields(&data.fields);
+            let parse_fields = parse_fields(&fields);
+            let value = pattern(quote!(Self), &data.fields, &fields);
+            quote! {
+                let remainder = bytes;
+                #parse_fields
+                ::core::result::Result::Ok((#value, remainder))
+            }
+        }
+        Data::Enum(data) => {
+            check_variant_count(data, ident.span())?;
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let index = index as u8;
+                let variant_ident = &variant.ident;
+                let fields = fields(&variant.fields);
+                let parse_fields = parse_fields(&fields);
+                let value = pattern(quote!(Self::#variant_ident), &variant.fields, &fields);
+                quote! {
+                    #index => {
+                        #parse_fields
+                        ::core::result::Result::Ok((#value, remainder))
+                    }
+                }
+            });
+            quote! {
+                let (index, remainder) =
+                    <u8 as ::casper_types::bytesrepr::FromBytes>::from_bytes(bytes)?;
+                match index {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(::casper_types::bytesrepr::Error::Formatting),
+                }
+            }
+        }
+        Data::Union(_) => return Err(unsupported_union(input)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::casper_types::bytesrepr::FromBytes for #ident #ty_generics
+            #where_clause
+        {
+            fn from_bytes(
+                bytes: &[u8],
+            ) -> ::core::result::Result<(Self, &[u8]), ::casper_types::bytesrepr::Error> {
+                #body
+            }
+        }
+    })
+}
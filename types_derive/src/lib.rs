@@ -0,0 +1,43 @@
+//! Derive macros for the `CLTyped`, `ToBytes` and `FromBytes` traits of `casper-types`. See
+//! `README.md` for details.
+
+#![doc(html_root_url = "https://docs.rs/casper-types-derive/0.9.0")]
+#![doc(
+    html_favicon_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Favicon_RGB_50px.png",
+    html_logo_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Symbol_RGB.png",
+    test(attr(forbid(warnings)))
+)]
+#![warn(missing_docs, trivial_casts, trivial_numeric_casts)]
+
+mod gen;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `CLTyped`, describing a struct as a `CLType::Struct` and an enum as a `CLType::Enum`.
+#[proc_macro_derive(CLTyped)]
+pub fn derive_cl_typed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    gen::cl_typed(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `ToBytes`, serializing the fields in order, preceded by the `u8` index of the variant
+/// for enums.
+#[proc_macro_derive(ToBytes)]
+pub fn derive_to_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    gen::to_bytes(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `FromBytes`, the inverse of the derived `ToBytes`.
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    gen::from_bytes(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
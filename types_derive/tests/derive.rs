@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+
+use serde_json::json;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue, Key, URef, U512,
+};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+
+#[derive(CLTyped, ToBytes, FromBytes, PartialEq, Debug, Clone)]
+struct Payout {
+    recipient: String,
+    amount: U512,
+}
+
+#[derive(CLTyped, ToBytes, FromBytes, PartialEq, Debug, Clone)]
+struct Purse(URef);
+
+#[derive(CLTyped, ToBytes, FromBytes, PartialEq, Debug, Clone)]
+struct Marker;
+
+#[derive(CLTyped, ToBytes, FromBytes, PartialEq, Debug, Clone)]
+enum Status {
+    Pending,
+    Paid(Payout, Option<Purse>),
+    Failed { reason: String, retries: u8 },
+}
+
+#[derive(CLTyped, ToBytes, FromBytes, PartialEq, Debug, Clone)]
+struct Entry<T> {
+    key: Key,
+    value: T,
+}
+
+fn round_trip<T: CLTyped + FromBytes + ToBytes + PartialEq + Debug + Clone>(value: &T) {
+    let bytes = value.to_bytes().unwrap();
+    assert_eq!(bytes.len(), value.serialized_length());
+    let parsed_value: T = bytesrepr::deserialize(bytes).unwrap();
+    assert_eq!(*value, parsed_value);
+
+    let cl_value = CLValue::from_t(value.clone()).unwrap();
+    let parsed_value: T = cl_value.into_t().unwrap();
+    assert_eq!(*value, parsed_value);
+}
+
+fn payout() -> Payout {
+    Payout {
+        recipient: String::from("Alice"),
+        amount: U512::from(10),
+    }
+}
+
+#[test]
+fn should_describe_structs() {
+    assert_eq!(
+        Payout::cl_type(),
+        CLType::new_struct(
+            "Payout",
+            &[("recipient", CLType::String), ("amount", CLType::U512)]
+        )
+    );
+    assert_eq!(
+        Purse::cl_type(),
+        CLType::new_struct("Purse", &[("0", CLType::URef)])
+    );
+    assert_eq!(Marker::cl_type(), CLType::new_struct("Marker", &[]));
+    assert_eq!(
+        Entry::<u64>::cl_type(),
+        CLType::new_struct("Entry", &[("key", CLType::Key), ("value", CLType::U64)])
+    );
+}
+
+#[test]
+fn should_describe_enums() {
+    assert_eq!(
+        Status::cl_type(),
+        CLType::new_enum(
+            "Status",
+            &[
+                ("Pending", &[]),
+                (
+                    "Paid",
+                    &[("0", Payout::cl_type()), ("1", <Option<Purse>>::cl_type())]
+                ),
+                (
+                    "Failed",
+                    &[("reason", CLType::String), ("retries", CLType::U8)]
+                ),
+            ]
+        )
+    );
+}
+
+#[test]
+fn should_round_trip_structs() {
+    round_trip(&payout());
+    round_trip(&Purse(URef::default()));
+    round_trip(&Marker);
+    round_trip(&Entry {
+        key: Key::Hash([1; 32]),
+        value: payout(),
+    });
+}
+
+#[test]
+fn should_round_trip_enums() {
+    round_trip(&Status::Pending);
+    round_trip(&Status::Paid(payout(), Some(Purse(URef::default()))));
+    round_trip(&Status::Failed {
+        reason: String::from("Insufficient funds"),
+        retries: 3,
+    });
+}
+
+#[test]
+fn should_serialize_enums_as_variant_index_and_fields() {
+    let status = Status::Failed {
+        reason: String::from("Insufficient funds"),
+        retries: 3,
+    };
+    let mut expected = vec![2u8];
+    expected.append(&mut String::from("Insufficient funds").to_bytes().unwrap());
+    expected.push(3);
+    assert_eq!(status.to_bytes().unwrap(), expected);
+
+    assert_eq!(
+        bytesrepr::deserialize::<Status>(vec![3]),
+        Err(bytesrepr::Error::Formatting)
+    );
+}
+
+#[test]
+fn should_render_as_json() {
+    let cl_value = CLValue::from_t(Status::Paid(payout(), None)).unwrap();
+    let json = serde_json::to_value(&cl_value).unwrap();
+    assert_eq!(
+        json["parsed"],
+        json!({ "Paid": { "0": { "recipient": "Alice", "amount": "10" }, "1": null } })
+    );
+}